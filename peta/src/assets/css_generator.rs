@@ -3,6 +3,25 @@
 use crate::core::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use syntect::highlighting::{Color, Highlighter, Theme};
+use syntect::parsing::Scope;
+
+/// Scope whose color a syntect theme gives each token class
+const THEME_TOKEN_SCOPES: [(&str, &str); 13] = [
+    ("keyword", "keyword"),
+    ("string", "string"),
+    ("comment", "comment"),
+    ("number", "constant.numeric"),
+    ("function", "entity.name.function"),
+    ("variable", "variable"),
+    ("type", "storage.type"),
+    ("operator", "keyword.operator"),
+    ("punctuation", "punctuation"),
+    ("property", "support.type.property-name"),
+    ("tag", "entity.name.tag"),
+    ("attribute", "entity.other.attribute-name"),
+    ("selector", "entity.name.tag.css"),
+];
 
 /// CSS generator for code block styling
 pub struct CssGenerator {
@@ -66,6 +85,38 @@ impl CssGenerator {
         generator
     }
 
+    /// Create a CSS generator taking token colors from a syntect theme
+    ///
+    /// Used for `.tmTheme` files and syntect's bundled themes, which have no
+    /// built-in palette here. The theme's background replaces the gradient.
+    pub fn with_syntax_theme(mut config: CssConfig, theme: &Theme) -> Self {
+        if let Some(background) = theme.settings.background {
+            config.background_gradient_start = Self::hex_color(background);
+            config.background_gradient_end = Self::hex_color(background);
+        }
+
+        let highlighter = Highlighter::new(theme);
+        let mut token_colors = HashMap::new();
+        for (token_type, scope) in THEME_TOKEN_SCOPES {
+            let Ok(scope) = Scope::new(scope) else { continue };
+            let style = highlighter.style_for_stack(&[scope]);
+            token_colors.insert(token_type.to_string(), Self::hex_color(style.foreground));
+        }
+        if let Some(foreground) = theme.settings.foreground {
+            token_colors.insert("plain".to_string(), Self::hex_color(foreground));
+        }
+
+        Self {
+            config,
+            token_colors,
+        }
+    }
+
+    /// CSS notation of a theme color
+    fn hex_color(color: Color) -> String {
+        format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+    }
+
     /// Generate complete CSS for code blocks
     pub fn generate(&self) -> Result<String> {
         let mut css = String::new();
@@ -87,6 +138,12 @@ impl CssGenerator {
         // Copy button styles
         css.push_str(&self.generate_copy_button_styles());
 
+        // Tabbed code groups
+        css.push_str(&self.generate_tab_styles());
+
+        // Output of code-run blocks
+        css.push_str(&self.generate_output_styles());
+
         // Token colors
         css.push_str(&self.generate_token_colors());
 
@@ -377,6 +434,127 @@ impl CssGenerator {
         .to_string()
     }
 
+    /// Generate styles for `code-tabs` groups
+    fn generate_tab_styles(&self) -> String {
+        format!(
+            r#"
+/* Code Tabs */
+
+.code-tabs {{
+  margin: 1.5rem 0;
+}}
+
+.code-tabs-nav {{
+  display: flex;
+  flex-wrap: wrap;
+  gap: 0.25rem;
+  padding: 0.25rem;
+  background: {};
+  border-radius: {} {} 0 0;
+}}
+
+.code-tab {{
+  padding: 0.4rem 1rem;
+  font-size: 0.875rem;
+  font-weight: 500;
+  color: #94a3b8;
+  background: none;
+  border: none;
+  border-radius: 0.5rem;
+  cursor: pointer;
+  transition: all 0.2s ease;
+}}
+
+.code-tab:hover {{
+  color: #e2e8f0;
+  background: rgba(255, 255, 255, 0.08);
+}}
+
+.code-tab.active {{
+  color: #f1f5f9;
+  background: rgba(59, 130, 246, 0.25);
+}}
+
+.code-tab:focus-visible {{
+  outline: 2px solid #3b82f6;
+  outline-offset: 2px;
+}}
+
+.code-tab-panel[hidden] {{
+  display: none;
+}}
+
+.code-tab-panel .code-block {{
+  margin-top: 0;
+  border-top-left-radius: 0;
+  border-top-right-radius: 0;
+}}
+
+"#,
+            self.config.header_background, self.config.border_radius, self.config.border_radius
+        )
+    }
+
+    /// Generate styles for the captured output of `code-run` blocks
+    fn generate_output_styles(&self) -> String {
+        format!(
+            r#"
+/* Code Output */
+
+.code-run .code-block {{
+  margin-bottom: 0;
+  border-bottom-left-radius: 0;
+  border-bottom-right-radius: 0;
+}}
+
+.code-output {{
+  margin-bottom: 1.5rem;
+  background: rgba(15, 23, 42, 0.85);
+  border-top: 1px solid rgba(255, 255, 255, 0.1);
+  border-radius: 0 0 {} {};
+  overflow: hidden;
+}}
+
+.code-output-header {{
+  padding: 0.4rem 1rem;
+  font-size: 0.75rem;
+  font-weight: 600;
+  letter-spacing: 0.05em;
+  text-transform: uppercase;
+  color: #94a3b8;
+}}
+
+.code-output pre {{
+  margin: 0;
+  padding: 0.5rem 1rem 1rem;
+  font-family: {};
+  font-size: {};
+  line-height: {};
+  color: #e2e8f0;
+  background: none;
+  overflow-x: auto;
+}}
+
+.code-output-stderr {{
+  color: #fca5a5 !important;
+}}
+
+.code-output-status {{
+  padding: 0.4rem 1rem;
+  font-size: 0.8rem;
+  color: #fca5a5;
+  border-top: 1px solid rgba(239, 68, 68, 0.3);
+}}
+
+"#,
+            self.config.border_radius,
+            self.config.border_radius,
+            self.config.font_family,
+            self.config.font_size,
+            self.config.line_height
+        )
+    }
+
     /// Generate token colors
     fn generate_token_colors(&self) -> String {
         let mut css = String::new();
@@ -499,6 +677,8 @@ mod tests {
         assert!(css.contains(".code-content"));
         assert!(css.contains(".line-number"));
         assert!(css.contains(".code-copy-button"));
+        assert!(css.contains(".code-tab.active"));
+        assert!(css.contains(".code-output-stderr"));
         assert!(css.contains(".token-keyword"));
     }
 
@@ -511,6 +691,17 @@ mod tests {
         assert_eq!(generator.token_colors.get("string"), Some(&"#2aa198".to_string()));
     }
 
+    #[test]
+    fn test_syntax_theme_colors() {
+        let themes = syntect::highlighting::ThemeSet::load_defaults();
+        let theme = &themes.themes["InspiredGitHub"];
+        let generator = CssGenerator::with_syntax_theme(CssConfig::default(), theme);
+
+        assert_eq!(generator.config().background_gradient_start, "#ffffff");
+        assert_eq!(generator.token_colors.get("comment"), Some(&"#969896".to_string()));
+        assert!(generator.generate().unwrap().contains(".token-plain {"));
+    }
+
     #[test]
     fn test_custom_config() {
         let config = CssConfig {
@@ -68,6 +68,9 @@ impl JsGenerator {
             js.push_str(&self.generate_keyboard_shortcuts_function());
         }
 
+        // Tabbed code groups
+        js.push_str(&self.generate_code_tabs_function());
+
         // Initialization
         js.push_str(&self.generate_initialization());
 
@@ -206,6 +209,75 @@ function initializeKeyboardShortcuts() {
     });
 }
 
+"#
+        .to_string()
+    }
+
+    /// Generate tab switching for `code-tabs` groups
+    fn generate_code_tabs_function(&self) -> String {
+        r#"
+/**
+ * Storage key of the last selected code tab language
+ */
+const CODE_TAB_STORAGE_KEY = 'peta-code-tab';
+
+/**
+ * Show the tab for a language in every code tab group that has one
+ * @param key - The language key of the tab
+ * @param remember - Whether to keep the choice for other pages
+ */
+function selectCodeTab(key, remember) {
+    document.querySelectorAll('.code-tabs').forEach(group => {
+        const tabs = group.querySelectorAll('.code-tab');
+        if (!Array.from(tabs).some(tab => tab.dataset.tabKey === key)) {
+            return;
+        }
+
+        tabs.forEach(tab => {
+            const selected = tab.dataset.tabKey === key;
+            tab.classList.toggle('active', selected);
+            tab.setAttribute('aria-selected', selected ? 'true' : 'false');
+        });
+        group.querySelectorAll('.code-tab-panel').forEach(panel => {
+            const selected = panel.dataset.tabKey === key;
+            panel.classList.toggle('active', selected);
+            panel.hidden = !selected;
+        });
+    });
+
+    if (remember) {
+        try {
+            localStorage.setItem(CODE_TAB_STORAGE_KEY, key);
+        } catch (err) {
+            // Storage may be disabled; the choice then lasts for this page only
+        }
+    }
+}
+
+/**
+ * Wire up code tab groups and restore the last selected language
+ */
+function initializeCodeTabs() {
+    document.querySelectorAll('.code-tabs .code-tab').forEach(tab => {
+        tab.addEventListener('click', () => {
+            // Keep the clicked tab in place while groups above it change height
+            const top = tab.getBoundingClientRect().top;
+            selectCodeTab(tab.dataset.tabKey, true);
+            window.scrollBy(0, tab.getBoundingClientRect().top - top);
+        });
+    });
+
+    let stored = null;
+    try {
+        stored = localStorage.getItem(CODE_TAB_STORAGE_KEY);
+    } catch (err) {
+        stored = null;
+    }
+    if (stored) {
+        selectCodeTab(stored, false);
+    }
+}
+
 "#
         .to_string()
     }
@@ -218,6 +290,7 @@ function initializeKeyboardShortcuts() {
         init.push_str(" * Initialize code block enhancements when DOM is ready\n");
         init.push_str(" */\n");
         init.push_str("document.addEventListener('DOMContentLoaded', function() {\n");
+        init.push_str("    initializeCodeTabs();\n");
 
         if self.config.enable_line_hover {
             init.push_str("    initializeLineHoverEffects();\n");
@@ -234,6 +307,7 @@ function initializeKeyboardShortcuts() {
         init.push_str(" * Export functions for external use\n");
         init.push_str(" */\n");
         init.push_str("window.CodeBlockComponent = {\n");
+        init.push_str("    selectCodeTab,\n");
 
         if self.config.enable_copy {
             init.push_str("    copyCode,\n");
@@ -281,6 +355,8 @@ mod tests {
         assert!(js.contains("function copyCode"));
         assert!(js.contains("function initializeLineHoverEffects"));
         assert!(js.contains("function initializeKeyboardShortcuts"));
+        assert!(js.contains("function initializeCodeTabs"));
+        assert!(js.contains("localStorage.setItem(CODE_TAB_STORAGE_KEY, key)"));
         assert!(js.contains("document.addEventListener('DOMContentLoaded'"));
         assert!(js.contains("window.CodeBlockComponent"));
     }
@@ -3,7 +3,10 @@
 use crate::core::{Error, Result};
 use crate::components::ComponentRegistry;
 use crate::assets::{CssGenerator, CssConfig, JsGenerator, JsConfig, DiagramCssGenerator, DiagramJsGenerator};
-use crate::content::rst::{MathCssGenerator, MathJsGenerator};
+use crate::content::rst::{KatexAssets, MathCssGenerator, MathJsGenerator};
+use crate::content::rst::math_formulas::math_js_generator::MathJsConfig;
+use crate::content::rst::code_blocks::syntax_assets::{SyntaxAssets, CSS_THEMES};
+use crate::core::config::{CodeBlocksConfig, MathRenderMode, MathRenderingConfig};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -22,6 +25,14 @@ pub struct AssetPipeline {
     component_registry: ComponentRegistry,
     /// Configuration
     config: AssetConfig,
+    /// Math rendering settings
+    math_rendering: MathRenderingConfig,
+    /// Self-hosted KaTeX, when not loaded from the CDN
+    katex_assets: Option<KatexAssets>,
+    /// Code block defaults, for the stylesheet's color theme
+    code_blocks: CodeBlocksConfig,
+    /// Directories with extra `.tmTheme` files
+    syntax_dirs: Vec<PathBuf>,
     /// Asset cache
     #[allow(dead_code)]
     asset_cache: HashMap<String, ProcessedAsset>,
@@ -89,6 +100,10 @@ impl AssetPipeline {
             output_dir: output_dir.to_path_buf(),
             component_registry: ComponentRegistry::new(),
             config: AssetConfig::default(),
+            math_rendering: MathRenderingConfig::default(),
+            katex_assets: None,
+            code_blocks: CodeBlocksConfig::default(),
+            syntax_dirs: Vec::new(),
             asset_cache: HashMap::new(),
         }
     }
@@ -110,6 +125,22 @@ impl AssetPipeline {
         self.config.base_url = base_url;
     }
     
+    /// Set math rendering settings
+    pub fn set_math_rendering(&mut self, math_rendering: MathRenderingConfig) {
+        self.math_rendering = math_rendering;
+    }
+    
+    /// Set the code block defaults and where to find custom color themes
+    pub fn set_code_blocks(&mut self, code_blocks: CodeBlocksConfig, syntax_dirs: Vec<PathBuf>) {
+        self.code_blocks = code_blocks;
+        self.syntax_dirs = syntax_dirs;
+    }
+    
+    /// Set the vendored KaTeX bundle the math assets point at
+    pub fn set_katex_assets(&mut self, katex_assets: Option<KatexAssets>) {
+        self.katex_assets = katex_assets;
+    }
+    
     /// Process all theme assets
     pub fn process_assets(&mut self) -> Result<()> {
         // Create output directories
@@ -152,9 +183,27 @@ impl AssetPipeline {
 
     /// Generate code block CSS and JS from Rust generators
     fn generate_code_block_assets(&mut self) -> Result<()> {
-        // Generate code block CSS
-        let css_config = CssConfig::default();
-        let css_generator = CssGenerator::with_config(css_config);
+        // Generate code block CSS, with token colors from the configured theme
+        let theme = &self.code_blocks.default_theme;
+        let css_config = CssConfig {
+            theme: theme.clone(),
+            ..CssConfig::default()
+        };
+        let syntax_assets = SyntaxAssets::load(&self.syntax_dirs)?;
+        let css_generator = match syntax_assets.theme_set.themes.get(theme) {
+            Some(syntax_theme) if !CSS_THEMES.contains(&theme.as_str()) => {
+                CssGenerator::with_syntax_theme(css_config, syntax_theme)
+            }
+            _ => {
+                if !syntax_assets.has_theme(theme) {
+                    eprintln!(
+                        "Warning: Unknown code block theme '{}', using one-dark colors",
+                        theme
+                    );
+                }
+                CssGenerator::with_config(css_config)
+            }
+        };
         let css_content = css_generator.generate()?;
 
         // Write directly to output_dir/css/ (not output_dir/assets/css/)
@@ -183,7 +232,16 @@ impl AssetPipeline {
     fn generate_math_assets(&mut self) -> Result<()> {
         // Generate math formula CSS
         let math_css_generator = MathCssGenerator::new()?;
-        let math_css_content = math_css_generator.generate()?;
+        let mut math_css_content = math_css_generator.generate()?;
+        
+        // Build-time markup needs the stylesheet of the KaTeX that produced
+        // it, vendored so pages render offline (see `katex_bundle_config`)
+        if self.math_rendering.mode == MathRenderMode::Build {
+            let assets = self.katex_assets.as_ref().ok_or_else(|| {
+                Error::asset("Build-time math rendering needs the vendored KaTeX stylesheet")
+            })?;
+            math_css_content = format!("@import url(\"{}\");\n\n{}", assets.css.url, math_css_content);
+        }
 
         let math_css_output_path = self.output_dir.join("css").join("math-formulas.css");
         fs::create_dir_all(math_css_output_path.parent().unwrap())
@@ -191,8 +249,20 @@ impl AssetPipeline {
         fs::write(&math_css_output_path, math_css_content)
             .map_err(|e| Error::asset(format!("Failed to write math-formulas.css: {}", e)))?;
 
+        // Formulas rendered at build time need no math JS
+        if self.math_rendering.mode == MathRenderMode::Build {
+            return Ok(());
+        }
+        
         // Generate math formula JS
-        let math_js_generator = MathJsGenerator::new()?;
+        let math_js_generator = MathJsGenerator::with_config(MathJsConfig {
+            katex_version: self.math_rendering.katex.version.clone(),
+            cdn_base: self.math_rendering.katex.cdn_base.clone(),
+            load_on_demand: self.math_rendering.on_demand_loading,
+            local_assets: self.katex_assets.clone(),
+            macros: self.math_rendering.macros.clone(),
+            ..MathJsConfig::default()
+        })?;
         let math_js_content = math_js_generator.generate()?;
 
         let math_js_output_path = self.output_dir.join("js").join("math-formulas.js");
@@ -748,6 +818,24 @@ impl AssetPipeline {
         format!("{:x}", hasher.finish())[..8].to_string()
     }
     
+    /// Check whether a file name carries a cache busting hash (`name.<hash>.ext`)
+    pub fn is_fingerprinted(path: &Path) -> bool {
+        let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            Some(stem) => stem,
+            None => return false,
+        };
+        if path.extension().is_none() {
+            return false;
+        }
+
+        match stem.rsplit_once('.') {
+            Some((name, hash)) => {
+                !name.is_empty() && hash.len() == 8 && hash.chars().all(|c| c.is_ascii_hexdigit())
+            }
+            None => false,
+        }
+    }
+
     /// Get image content type
     fn get_image_content_type(&self, path: &Path) -> Result<String> {
         let extension = path.extension()
@@ -843,4 +931,64 @@ mod tests {
         let cache_bust = pipeline.generate_cache_bust(content);
         assert_eq!(cache_bust.len(), 8);
     }
+    
+    #[tokio::test]
+    async fn test_build_mode_math_assets_load_nothing_remote() {
+        use axum::{extract::Path as UrlPath, routing::get, Router};
+
+        // A CDN serving a KaTeX dist bundle whose stylesheet references a font
+        let cdn = Router::new().route(
+            "/npm/*path",
+            get(|UrlPath(path): UrlPath<String>| async move {
+                match path.rsplit('/').next() {
+                    Some("katex.min.js") => "var katex;".to_string(),
+                    Some("katex.min.css") => {
+                        "@font-face{src:url(fonts/KaTeX_Main-Regular.woff2) format(\"woff2\")}.katex{}".to_string()
+                    }
+                    _ => "font".to_string(),
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, cdn).await.unwrap() });
+
+        let mut math = MathRenderingConfig {
+            mode: MathRenderMode::Build,
+            ..MathRenderingConfig::default()
+        };
+        math.katex.cdn_base = format!("http://{}/npm/katex", addr);
+
+        let cache = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        let katex = crate::content::rst::katex_bundle_config(&math, cache.path()).await.unwrap();
+        let assets = crate::content::rst::vendor_katex(&katex, out.path(), "").unwrap();
+
+        let mut pipeline = AssetPipeline::new("test", out.path());
+        pipeline.set_math_rendering(math.clone());
+        pipeline.set_katex_assets(assets);
+        pipeline.generate_math_assets().unwrap();
+
+        let css = fs::read_to_string(out.path().join("css/math-formulas.css")).unwrap();
+        assert!(css.starts_with("@import url(\"/assets/katex/katex.min.css\");"));
+        for file in ["css/math-formulas.css", "katex/katex.min.css"] {
+            let content = fs::read_to_string(out.path().join(file)).unwrap();
+            assert!(!content.contains("://"), "{} loads a remote URL", file);
+        }
+        assert!(out.path().join("katex/fonts/KaTeX_Main-Regular.woff2").is_file());
+        assert!(!out.path().join("js/math-formulas.js").exists());
+
+        // Never falls back to the CDN stylesheet
+        let mut pipeline = AssetPipeline::new("test", out.path());
+        pipeline.set_math_rendering(math);
+        assert!(pipeline.generate_math_assets().is_err());
+    }
+
+    #[test]
+    fn test_is_fingerprinted() {
+        assert!(AssetPipeline::is_fingerprinted(Path::new("assets/css/main.1a2b3c4d.css")));
+        assert!(!AssetPipeline::is_fingerprinted(Path::new("assets/css/main.css")));
+        assert!(!AssetPipeline::is_fingerprinted(Path::new("js/code-blocks.js")));
+        assert!(!AssetPipeline::is_fingerprinted(Path::new("assets/css/main.notahash.css")));
+    }
 }
\ No newline at end of file
@@ -70,13 +70,87 @@ pub enum Commands {
         draft: bool,
     },
     
+    /// Query the search index from the terminal
+    Search {
+        /// Query, in the search page's syntax: words, "exact phrases", -exclusions, tag:rust, type:snippet, ...
+        query: String,
+
+        /// Only results of this content type (repeatable)
+        #[arg(short = 't', long = "type", value_parser = ["article", "book", "snippet", "project"])]
+        types: Vec<String>,
+
+        /// Only results with this tag (repeatable, any of them)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+
+        /// Order of the results
+        #[arg(short, long, default_value = "relevance", value_parser = ["relevance", "date", "title"])]
+        sort: String,
+
+        /// Maximum number of results (default: 10)
+        #[arg(short = 'n', long, default_value = "10")]
+        limit: usize,
+
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Index the content in memory instead of loading the built index
+        #[arg(long)]
+        rebuild: bool,
+    },
+
+    /// Serve search queries from the tantivy index (search.backend = "server")
+    SearchServer {
+        /// Port to serve on (default: search.server.port)
+        #[arg(short, long)]
+        port: Option<u16>,
+
+        /// Host to serve on (default: search.server.host)
+        #[arg(long)]
+        host: Option<String>,
+
+        /// Index directory (default: search.server.index_dir)
+        #[arg(short, long)]
+        index: Option<String>,
+    },
+    
     /// Deploy the site
     Deploy {
-        /// Deployment target (default: "github")
-        #[arg(short, long, default_value = "github")]
-        target: String,
+        /// Deployment target (default: deploy.target from peta.toml)
+        #[arg(short, long)]
+        target: Option<String>,
+
+        /// List the configured deployment targets
+        #[arg(long)]
+        list: bool,
+
+        /// Deploy even if verification of the built output fails
+        #[arg(long)]
+        no_verify: bool,
+    },
+
+    /// Verify the built site (links, anchors, assets, HTML, required files)
+    Check {
+        /// Check the existing output directory without rebuilding
+        #[arg(long)]
+        no_build: bool,
     },
     
+    /// Check the code blocks in the content with the [code_blocks.test] commands
+    TestSnippets {
+        /// Only blocks in sources whose path contains this text
+        filter: Option<String>,
+
+        /// Content directory (default: "_content")
+        #[arg(short, long)]
+        content_dir: Option<String>,
+
+        /// Only blocks in this language (repeatable)
+        #[arg(short, long = "language")]
+        languages: Vec<String>,
+    },
+
     /// Clean build artifacts
     Clean {
         /// Clean all artifacts including output directory
@@ -2,6 +2,9 @@
 
 use crate::cli::output::OutputFormatter;
 use crate::core::SiteConfig;
+use crate::search::client::{read_client_index, CLIENT_INDEX_DIR, MANIFEST_FILE};
+use crate::search::highlight::HighlightField;
+use crate::search::query::{QueryProcessor, SearchOptions};
 use std::path::Path;
 use anyhow::Result;
 
@@ -389,7 +392,7 @@ clean:
 }
 
 /// Build the static site
-pub async fn build_site(content_dir: Option<String>, output_dir: Option<String>, theme: Option<String>, base_url: String, draft: bool, output: &mut OutputFormatter) -> Result<()> {
+pub async fn build_site(content_dir: Option<String>, output_dir: Option<String>, theme: Option<String>, base_url: String, draft: bool, mode: crate::core::BuildMode, output: &mut OutputFormatter) -> Result<crate::core::Site> {
     output.info("Building static site...");
     
     let mut config = SiteConfig::load_from_file("peta.toml")?;
@@ -420,15 +423,15 @@ pub async fn build_site(content_dir: Option<String>, output_dir: Option<String>,
     // Store output directory before moving config
     let output_dir_path = config.build.output_dir.clone();
     
-    let mut builder = crate::core::SiteBuilder::new(config);
+    let mut builder = crate::core::SiteBuilder::new(config).with_mode(mode);
     
     // Build the site
-    let _site = builder.build().await?;
+    let site = builder.build().await?;
     
     output.success(&format!("Site built successfully!"));
     output.info(&format!("Output directory: {}", output_dir_path));
     
-    Ok(())
+    Ok(site)
 }
 
 /// Serve the site locally
@@ -446,10 +449,10 @@ pub async fn serve_site(content_dir: Option<String>, port: u16, host: &str, _ope
     }
     
     // Build the site first
-    build_site(content_dir, None, None, String::new(), draft, output).await?;
+    let built = build_site(content_dir, None, None, String::new(), draft, crate::core::BuildMode::Serve, output).await?;
     
-    // Create site instance
-    let site = crate::core::Site::with_content(config.clone(), vec![]);
+    // Create site instance, keeping the content so its dependencies can be watched
+    let site = crate::core::Site::with_content(config.clone(), built.content);
     
     // Start development server
     let mut dev_server = crate::server::dev_server::DevServer::new(port, site)?;
@@ -460,19 +463,219 @@ pub async fn serve_site(content_dir: Option<String>, port: u16, host: &str, _ope
     Ok(())
 }
 
+/// Query the built search index, or index the content in memory when there
+/// is no built index or `rebuild` is set
+pub async fn search(query: &str, options: &SearchOptions, json: bool, rebuild: bool, output: &mut OutputFormatter) -> Result<()> {
+    let config = SiteConfig::load_from_file("peta.toml")?;
+    let index_dir = Path::new(&config.build.output_dir).join(CLIENT_INDEX_DIR);
+
+    let (index, source) = if !rebuild && index_dir.join(MANIFEST_FILE).exists() {
+        (read_client_index(&index_dir)?, index_dir.display().to_string())
+    } else {
+        let mut builder = crate::core::SiteBuilder::new(config.clone());
+        (builder.build_search_index_only().await?, config.build.content_dir.clone())
+    };
+    let results = QueryProcessor::new().search(query, &index, options)?;
+
+    if json {
+        let results: Vec<serde_json::Value> = results
+            .iter()
+            .map(|result| {
+                let document = &result.document;
+                serde_json::json!({
+                    "title": document.title,
+                    "section": document.section,
+                    "url": document.url,
+                    "content_type": document.content_type,
+                    "tags": document.tags,
+                    "date": document.date,
+                    "score": result.score,
+                    "highlights": result.highlights,
+                })
+            })
+            .collect();
+        let report = serde_json::json!({ "query": query, "source": source, "results": results });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    output.info(&format!("{} result(s) for \"{}\" from {}", results.len(), query, source));
+    if results.is_empty() {
+        if let Some(suggestion) = index.suggest(query) {
+            output.info(&format!("Did you mean \"{}\"?", suggestion));
+        }
+    }
+    for (rank, result) in results.iter().enumerate() {
+        let document = &result.document;
+        let title = match &document.section {
+            Some(section) => format!("{} › {}", document.title, section),
+            None => document.title.clone(),
+        };
+        println!();
+        println!("{:>3}. {}  ({:.3})", rank + 1, title, result.score);
+        println!("     {}  [{}]", document.url, document.content_type);
+        for highlight in result.highlights.iter().filter(|h| h.field == HighlightField::Content) {
+            let snippet = &highlight.snippet;
+            let mut parts: Vec<(&str, bool)> = Vec::new();
+            if snippet.truncated_start {
+                parts.push(("…", false));
+            }
+            parts.extend(snippet.spans.iter().map(|span| (span.text.as_str(), span.matched)));
+            if snippet.truncated_end {
+                parts.push(("…", false));
+            }
+            output.marked("     ", &parts);
+        }
+    }
+
+    Ok(())
+}
+
+/// Serve search queries from the tantivy index written by the build
+pub async fn search_server(port: Option<u16>, host: Option<String>, index_dir: Option<String>, output: &mut OutputFormatter) -> Result<()> {
+    let config = SiteConfig::load_from_file("peta.toml")?;
+    let server = &config.search.server;
+    let index_dir = index_dir.unwrap_or_else(|| server.index_dir.clone());
+
+    if !Path::new(&index_dir).join(crate::search::tantivy_index::LEXICON_FILE).exists() {
+        return Err(anyhow::anyhow!(
+            "No search index in {}; set search.backend = \"server\" in peta.toml and run `peta build`",
+            index_dir
+        ));
+    }
+    let index = crate::search::tantivy_index::TantivyIndex::open(Path::new(&index_dir))?;
+    output.info(&format!("Loaded {} search documents from {}", index.num_docs(), index_dir));
+
+    let search_server = crate::server::SearchServer::new(
+        host.as_deref().unwrap_or(&server.host),
+        port.unwrap_or(server.port),
+        &server.url,
+        index,
+        &config.build.output_dir,
+    )?;
+    search_server.start().await?;
+
+    Ok(())
+}
+
 /// Deploy the site
-pub async fn deploy_site(target: &str, output: &mut OutputFormatter) -> Result<()> {
+pub async fn deploy_site(target: Option<&str>, no_verify: bool, output: &mut OutputFormatter) -> Result<()> {
+    let config = SiteConfig::load_from_file("peta.toml")?;
+    let target = target.unwrap_or(&config.deploy.target);
     output.info(&format!("Deploying site to {}...", target));
-    
-    let _config = SiteConfig::load_from_file("peta.toml")?;
-    
+
+    // Create the deployer first so configuration errors surface before building
+    let deployer = crate::deploy::create_deployer(&config, target)?;
+
     // Build the site first
-    build_site(None, None, None, String::new(), false, output).await?;
-    
-    // For now, just indicate deployment would happen
-    output.warn(&format!("Deployment to {} is not yet implemented", target));
+    let site = build_site(None, None, None, String::new(), false, crate::core::BuildMode::Deploy, output).await?;
+
+    // Verify the output before anything is uploaded
+    if config.deploy.verify && !no_verify {
+        let verification = crate::deploy::SiteVerifier::new(&config).verify(&site)?;
+        if !verification.is_ok() {
+            output.error(&verification.to_string());
+            return Err(anyhow::anyhow!(
+                "Verification failed with {} problem(s); fix them or deploy with --no-verify",
+                verification.issues.len()
+            ));
+        }
+        output.info(&verification.to_string());
+    }
+
+    let hook_context = crate::core::HookContext {
+        output_dir: config.build.output_dir.clone(),
+        content_dir: config.build.content_dir.clone(),
+        mode: crate::core::BuildMode::Deploy,
+        changed_files: Vec::new(),
+        deploy_target: Some(target.to_string()),
+    };
+    crate::core::hooks::run_hooks(&config.hooks, crate::core::HookStage::PreDeploy, &hook_context).await?;
+
+    let report = deployer.deploy(&site).await?;
+
+    output.info(&format!(
+        "Uploaded {} file(s), {} unchanged, {} deleted",
+        report.uploaded, report.unchanged, report.deleted
+    ));
+    if let Some(url) = &report.url {
+        output.info(&format!("URL: {}", url));
+    }
     output.success("Deployment completed!");
-    
+
+    Ok(())
+}
+
+/// Verify the built site
+pub async fn check_site(no_build: bool, output: &mut OutputFormatter) -> Result<()> {
+    let config = SiteConfig::load_from_file("peta.toml")?;
+
+    let site = if no_build {
+        // Checks such as duplicate URLs need the content, not just the output
+        crate::core::SiteBuilder::new(config.clone()).load_site().await?
+    } else {
+        build_site(None, None, None, String::new(), false, crate::core::BuildMode::Build, output).await?
+    };
+
+    output.info("Verifying built site...");
+    let report = crate::deploy::SiteVerifier::new(&config).verify(&site)?;
+    if !report.is_ok() {
+        output.error(&report.to_string());
+        return Err(anyhow::anyhow!("Verification failed with {} problem(s)", report.issues.len()));
+    }
+
+    output.success(&report.to_string());
+    Ok(())
+}
+
+/// List the deployment targets configured in peta.toml
+pub fn list_deploy_targets(output: &mut OutputFormatter) -> Result<()> {
+    let config = SiteConfig::load_from_file("peta.toml")?;
+    let targets = crate::deploy::configured_targets(&config.deploy);
+
+    if targets.is_empty() {
+        output.warn("No deployment targets configured in peta.toml");
+        return Ok(());
+    }
+
+    output.info("Configured deployment targets:");
+    for (name, destination) in targets {
+        let marker = if name == config.deploy.target { " (default)" } else { "" };
+        output.info(&format!("  {:<8} {}{}", name, destination, marker));
+    }
+
+    Ok(())
+}
+
+/// Check code blocks with the commands configured under [code_blocks.test]
+pub fn test_snippets(filter: Option<&str>, content_dir: Option<String>, languages: &[String], output: &mut OutputFormatter) -> Result<()> {
+    let config = SiteConfig::load_from_file("peta.toml")?;
+    let content_dir = content_dir.unwrap_or_else(|| config.build.content_dir.clone());
+
+    if config.code_blocks.test.languages.is_empty() {
+        output.warn("No check commands configured under [code_blocks.test.languages] in peta.toml");
+    }
+
+    let tester = crate::content::rst::SnippetTester::new(&config.code_blocks);
+    let languages: Vec<String> = languages.iter().map(|l| tester.resolve_language(l)).collect();
+    let blocks: Vec<_> = crate::content::rst::SnippetTester::collect_blocks(Path::new(&content_dir))?
+        .into_iter()
+        .filter(|block| filter.is_none_or(|filter| block.file.to_string_lossy().contains(filter)))
+        .filter(|block| languages.is_empty() || languages.contains(&tester.resolve_language(&block.language)))
+        .collect();
+    output.info(&format!("Found {} code block(s) in {}", blocks.len(), content_dir));
+
+    let report = tester.test(&blocks, |block, passed| {
+        let status = if passed { "ok" } else { "FAILED" };
+        println!("test {} ({}) ... {}", block.location(), block.language, status);
+    })?;
+
+    if !report.is_ok() {
+        output.error(&report.to_string());
+        return Err(anyhow::anyhow!("{} code block(s) failed their check", report.failures.len()));
+    }
+
+    output.success(&report.to_string());
     Ok(())
 }
 
@@ -36,6 +36,27 @@ impl OutputFormatter {
         let _ = self.print_with_color(Color::Red, "✗", message);
     }
     
+    /// Print a line of text after `indent`, with the marked parts in bold
+    pub fn marked(&self, indent: &str, parts: &[(&str, bool)]) {
+        let _ = self.print_marked(indent, parts);
+    }
+    
+    fn print_marked(&self, indent: &str, parts: &[(&str, bool)]) -> io::Result<()> {
+        let mut stream = self.stream.lock();
+        write!(stream, "{}", indent)?;
+        for (text, marked) in parts {
+            if *marked {
+                stream.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)).set_bold(true))?;
+                write!(stream, "{}", text)?;
+                stream.reset()?;
+            } else {
+                write!(stream, "{}", text)?;
+            }
+        }
+        writeln!(stream)?;
+        stream.flush()
+    }
+    
     /// Print a message with color and icon
     fn print_with_color(&self, color: Color, icon: &str, message: &str) -> io::Result<()> {
         let mut stream = self.stream.lock();
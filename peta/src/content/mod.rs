@@ -86,6 +86,9 @@ pub struct RstContent {
     pub frontmatter: HashMap<String, serde_json::Value>,
     pub has_math_formulas: bool,
     pub math_formula_count: usize,
+    /// Files outside the document read while rendering it, such as `literalinclude` sources
+    #[serde(default)]
+    pub dependencies: Vec<std::path::PathBuf>,
 }
 
 impl RstContent {
@@ -104,6 +107,7 @@ impl RstContent {
             frontmatter,
             has_math_formulas: false,
             math_formula_count: 0,
+            dependencies: Vec::new(),
         }
     }
 
@@ -124,6 +128,7 @@ impl RstContent {
             frontmatter,
             has_math_formulas,
             math_formula_count,
+            dependencies: Vec::new(),
         }
     }
     
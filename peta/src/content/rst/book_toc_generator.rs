@@ -77,13 +77,44 @@ impl BookTocGenerator {
             .and_then(|n| n.to_str())
             .unwrap_or("book");
         
-        // Find toctree directive
-        let lines: Vec<&str> = content.lines().collect();
+        let mut current_order = 0;
+
+        for chapter_slug in Self::toctree_entries(content) {
+            // Try to find the chapter file - support both structures:
+            // 1. Flat: book_dir/chapter.rst
+            // 2. Folder-based: book_dir/chapter/index.rst
+            let chapter_path = self.find_chapter_path(book_dir, &chapter_slug);
+            
+            if chapter_path.exists() {
+                // Read chapter file to get title and headers
+                let title = self.extract_chapter_title(&chapter_path)?;
+                let headers = self.extract_chapter_headers(&chapter_path)?;
+                
+                // Generate URL based on structure
+                let url = self.generate_chapter_url(book_slug, &chapter_slug, &chapter_path);
+                
+                chapters.push(BookChapter {
+                    title,
+                    url,
+                    slug: chapter_slug,
+                    order: current_order,
+                    headers,
+                });
+                
+                current_order += 1;
+            }
+        }
+
+        Ok(chapters)
+    }
+    
+    /// Chapter slugs listed in the toctree directive of an index file
+    fn toctree_entries(content: &str) -> Vec<String> {
+        let mut entries = Vec::new();
         let mut in_toctree = false;
         let mut indent_level: usize = 0;
-        let mut current_order = 0;
 
-        for line in lines {
+        for line in content.lines() {
             let trimmed: &str = line.trim();
             
             // Check for toctree start
@@ -112,41 +143,39 @@ impl BookTocGenerator {
                     continue;
                 }
 
-                // Parse chapter entry
+                // Normalize the chapter reference (remove /index suffix if present)
                 if let Some(chapter_ref) = trimmed.split_whitespace().next() {
-                    // Normalize the chapter reference (remove /index suffix if present)
-                    let chapter_slug = chapter_ref.trim_end_matches("/index").to_string();
-                    
-                    // Try to find the chapter file - support both structures:
-                    // 1. Flat: book_dir/chapter.rst
-                    // 2. Folder-based: book_dir/chapter/index.rst
-                    let chapter_path = self.find_chapter_path(book_dir, &chapter_slug);
-                    
-                    if chapter_path.exists() {
-                        // Read chapter file to get title and headers
-                        let title = self.extract_chapter_title(&chapter_path)?;
-                        let headers = self.extract_chapter_headers(&chapter_path)?;
-                        
-                        // Generate URL based on structure
-                        let url = self.generate_chapter_url(book_slug, &chapter_slug, &chapter_path);
-                        
-                        chapters.push(BookChapter {
-                            title,
-                            url,
-                            slug: chapter_slug,
-                            order: current_order,
-                            headers,
-                        });
-                        
-                        current_order += 1;
-                    }
+                    entries.push(chapter_ref.trim_end_matches("/index").to_string());
                 }
             }
         }
 
-        Ok(chapters)
+        entries
     }
-    
+
+    /// Position (1-based) of a chapter file in its book's toctree
+    ///
+    /// Chapters are either `book/chapter.rst` or `book/chapter/index.rst`.
+    pub fn chapter_number(&self, chapter_path: &Path) -> Option<usize> {
+        let book_dirs = chapter_path.ancestors().skip(1).take(2);
+
+        for book_dir in book_dirs {
+            let Ok(index) = fs::read_to_string(book_dir.join("index.rst")) else {
+                continue;
+            };
+            let position = Self::toctree_entries(&index)
+                .iter()
+                .map(|slug| self.find_chapter_path(book_dir, slug))
+                .filter(|path| path.exists())
+                .position(|path| path == chapter_path);
+            if let Some(position) = position {
+                return Some(position + 1);
+            }
+        }
+
+        None
+    }
+
     /// Find the chapter file path, supporting both flat and folder-based structures
     fn find_chapter_path(&self, book_dir: &Path, chapter_slug: &str) -> PathBuf {
         // First try folder-based structure: chapter/index.rst
@@ -1,8 +1,11 @@
 //! Unified code block renderer using SyntaxHighlighter
 
 use crate::content::rst::{SyntaxHighlighter, HighlighterConfig};
-use crate::core::Result;
+use crate::core::config::CodeBlocksConfig;
+use crate::core::{Error, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Code block renderer that generates complete HTML structure
 pub struct CodeBlockRenderer {
@@ -27,6 +30,10 @@ pub struct CodeBlockConfig {
     pub font_family: Option<String>,
     /// Font size
     pub font_size: Option<String>,
+    /// Alias to language, from `[code_blocks.languages]`
+    pub language_aliases: HashMap<String, String>,
+    /// Directories with extra `.sublime-syntax` and `.tmTheme` files
+    pub syntax_dirs: Vec<PathBuf>,
 }
 
 impl Default for CodeBlockConfig {
@@ -38,10 +45,104 @@ impl Default for CodeBlockConfig {
             theme: "one-dark".to_string(),
             font_family: None,
             font_size: None,
+            language_aliases: CodeBlocksConfig::default().language_aliases(),
+            syntax_dirs: Vec::new(),
         }
     }
 }
 
+/// Per-block options of the `code-block` directive
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CodeBlockOptions {
+    /// Show line numbers, overriding the renderer default
+    pub line_numbers: Option<bool>,
+    /// Number displayed for the first line
+    pub line_start: Option<usize>,
+    /// Lines to emphasize, counted from the first line of the block
+    pub highlight_lines: Vec<usize>,
+    /// Caption shown in the block header
+    pub caption: Option<String>,
+    /// Target name, used as the block's anchor id
+    pub name: Option<String>,
+}
+
+impl CodeBlockOptions {
+    /// Read `:linenos:`, `:lineno-start:`, `:emphasize-lines:`, `:caption:`
+    /// and `:name:` from a directive's field list
+    pub fn from_directive(options: &HashMap<String, String>) -> Result<Self> {
+        let line_start = options
+            .get("lineno-start")
+            .map(|value| {
+                value.trim().parse::<usize>().map_err(|_| {
+                    Error::rst_parse(format!("Invalid :lineno-start: value '{}'", value))
+                })
+            })
+            .transpose()?;
+
+        let highlight_lines = match options.get("emphasize-lines") {
+            Some(spec) => parse_line_spec(spec, None).ok_or_else(|| {
+                Error::rst_parse(format!("Invalid :emphasize-lines: value '{}'", spec))
+            })?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            // A start number implies numbering, as in Sphinx
+            line_numbers: if options.contains_key("linenos") || line_start.is_some() {
+                Some(true)
+            } else {
+                None
+            },
+            line_start,
+            highlight_lines,
+            caption: options.get("caption").filter(|c| !c.is_empty()).cloned(),
+            name: options.get("name").filter(|n| !n.is_empty()).cloned(),
+        })
+    }
+}
+
+/// Parse a 1-based line list such as `1,3-5`
+///
+/// With a `total`, ranges may be open-ended as in `-3` or `10-`. Returns
+/// `None` for an invalid list.
+pub(crate) fn parse_line_spec(spec: &str, total: Option<usize>) -> Option<Vec<usize>> {
+    let mut lines = Vec::new();
+
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start = match start.trim() {
+                    "" if total.is_some() => 1,
+                    start => start.parse().ok()?,
+                };
+                let end = match end.trim() {
+                    "" => total?,
+                    end => end.parse().ok()?,
+                };
+                if start == 0 || end < start {
+                    return None;
+                }
+                lines.extend(start..=end);
+            }
+            None => match part.parse::<usize>() {
+                Ok(line) if line > 0 => lines.push(line),
+                _ => return None,
+            },
+        }
+    }
+
+    Some(lines)
+}
+
+/// Anchor id for a `:name:` target, normalized like Sphinx reference names
+fn anchor_id(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect()
+}
+
 impl CodeBlockRenderer {
     /// Create a new code block renderer with default configuration
     pub fn new() -> Result<Self> {
@@ -54,6 +155,8 @@ impl CodeBlockRenderer {
             theme: config.theme.clone(),
             line_numbers: config.line_numbers,
             highlight_lines: config.highlight_lines.clone(),
+            language_aliases: config.language_aliases.clone(),
+            syntax_dirs: config.syntax_dirs.clone(),
         };
 
         let highlighter = SyntaxHighlighter::with_config(highlighter_config)?;
@@ -66,28 +169,53 @@ impl CodeBlockRenderer {
 
     /// Render a code block to HTML
     pub fn render(&self, code: &str, language: &str, title: Option<&str>) -> Result<String> {
-        // Highlight code
-        let (highlighted_code, line_count) = if self.config.line_numbers {
-            self.highlighter
-                .highlight_with_line_numbers(code, language, &self.config.highlight_lines)?
+        let options = CodeBlockOptions {
+            caption: title.map(str::to_string),
+            ..CodeBlockOptions::default()
+        };
+        self.render_with_options(code, language, &options)
+    }
+
+    /// Render a code block, letting directive options override the defaults
+    pub fn render_with_options(&self, code: &str, language: &str, options: &CodeBlockOptions) -> Result<String> {
+        let line_numbers = options.line_numbers.unwrap_or(self.config.line_numbers);
+        let highlight_lines = if options.highlight_lines.is_empty() {
+            &self.config.highlight_lines
         } else {
-            let highlighted = self.highlighter.highlight(code, language)?;
-            let line_count = code.lines().count();
-            (highlighted, line_count)
+            &options.highlight_lines
         };
 
+        // Highlight code
+        let (highlighted_code, line_count) = self.highlighter.highlight_with_line_options(
+            code,
+            language,
+            line_numbers.then(|| options.line_start.unwrap_or(1)),
+            highlight_lines,
+        )?;
+
+        if let Some(&line) = highlight_lines.iter().find(|&&line| line > line_count) {
+            eprintln!(
+                "Warning: emphasized line {} is beyond the {} lines of a {} code block",
+                line, line_count, language
+            );
+        }
+
         // Generate HTML structure
         let mut html = String::new();
 
         // Start code block container
         html.push_str(r#"<div class="code-block""#);
 
+        if let Some(name) = &options.name {
+            html.push_str(&format!(r#" id="{}""#, anchor_id(name)));
+        }
+
         // Add data attributes
         html.push_str(&format!(r#" data-language="{}""#, language));
         html.push_str(&format!(r#" data-theme="{}""#, self.config.theme));
         html.push_str(&format!(r#" data-line-count="{}""#, line_count));
 
-        if self.config.line_numbers {
+        if line_numbers {
             html.push_str(r#" data-line-numbers="true""#);
         }
 
@@ -98,7 +226,7 @@ impl CodeBlockRenderer {
         html.push_str("  <div class=\"code-header\">\n");
         html.push_str("    <div class=\"code-info\">\n");
 
-        if let Some(t) = title {
+        if let Some(t) = &options.caption {
             html.push_str(&format!(r#"      <span class="code-title">{}</span>"#, t));
             html.push('\n');
         }
@@ -128,7 +256,7 @@ impl CodeBlockRenderer {
         html.push_str("  </div>\n");
 
         // Code content
-        let content_class = if self.config.line_numbers {
+        let content_class = if line_numbers {
             "code-content with-line-numbers"
         } else {
             "code-content"
@@ -253,6 +381,51 @@ mod tests {
         assert!(html.contains("line-highlight"));
     }
 
+    #[test]
+    fn test_directive_options() {
+        let renderer = CodeBlockRenderer::new().unwrap();
+        let directive: HashMap<String, String> = [
+            ("lineno-start", "10"),
+            ("emphasize-lines", "1,3-4"),
+            ("caption", "main.py"),
+            ("name", "Main Loop"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let options = CodeBlockOptions::from_directive(&directive).unwrap();
+
+        assert_eq!(options.line_numbers, Some(true));
+        assert_eq!(options.highlight_lines, vec![1, 3, 4]);
+
+        let html = renderer
+            .render_with_options("a\nb\nc\nd", "python", &options)
+            .unwrap();
+        assert!(html.contains(r#"<div class="code-block" id="main-loop""#));
+        assert!(html.contains(r#"<span class="code-title">main.py</span>"#));
+        assert!(html.contains(r#"data-line="1">10</span>"#));
+    }
+
+    #[test]
+    fn test_linenos_overrides_disabled_default() {
+        let config = CodeBlockConfig {
+            line_numbers: false,
+            ..CodeBlockConfig::default()
+        };
+        let renderer = CodeBlockRenderer::with_config(config).unwrap();
+        let directive = HashMap::from([("linenos".to_string(), String::new())]);
+        let options = CodeBlockOptions::from_directive(&directive).unwrap();
+
+        let html = renderer.render_with_options("x = 1", "python", &options).unwrap();
+        assert!(html.contains("with-line-numbers"));
+    }
+
+    #[test]
+    fn test_invalid_emphasize_lines() {
+        let directive = HashMap::from([("emphasize-lines".to_string(), "2-x".to_string())]);
+        assert!(CodeBlockOptions::from_directive(&directive).is_err());
+    }
+
     #[test]
     fn test_set_theme() {
         let mut renderer = CodeBlockRenderer::new().unwrap();
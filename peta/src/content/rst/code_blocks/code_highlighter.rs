@@ -1,24 +1,23 @@
 //! Code highlighting for RST content using Syntect
 
+use crate::content::rst::code_blocks::syntax_assets::{warn_once, SyntaxAssets};
+use crate::core::config::CodeBlocksConfig;
 use crate::core::Result;
 use std::collections::HashMap;
-use syntect::highlighting::{ThemeSet, Theme};
-use syntect::parsing::SyntaxSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use syntect::highlighting::Theme;
 use syntect::easy::HighlightLines;
 use syntect::util::LinesWithEndings;
 
 /// Code highlighter for syntax highlighting
 pub struct CodeHighlighter {
-    /// Syntax set for language definitions
-    syntax_set: SyntaxSet,
-    /// Theme set for color schemes
-    theme_set: ThemeSet,
+    /// Syntax definitions and color themes
+    assets: Arc<SyntaxAssets>,
     /// Current theme
     theme: Theme,
     /// Configuration
     config: CodeConfig,
-    /// Language aliases
-    language_aliases: HashMap<String, String>,
 }
 
 /// Configuration for code highlighting
@@ -38,6 +37,10 @@ pub struct CodeConfig {
     pub wrap_lines: bool,
     /// Highlight specific lines
     pub highlight_lines: Option<Vec<usize>>,
+    /// Alias to language, from `[code_blocks.languages]`
+    pub language_aliases: HashMap<String, String>,
+    /// Directories with extra `.sublime-syntax` and `.tmTheme` files
+    pub syntax_dirs: Vec<PathBuf>,
 }
 
 impl Default for CodeConfig {
@@ -50,6 +53,8 @@ impl Default for CodeConfig {
             tab_size: 4,
             wrap_lines: false,
             highlight_lines: None,
+            language_aliases: CodeBlocksConfig::default().language_aliases(),
+            syntax_dirs: Vec::new(),
         }
     }
 }
@@ -62,68 +67,20 @@ impl CodeHighlighter {
     
     /// Create a code highlighter with custom configuration
     pub fn with_config(config: CodeConfig) -> Result<Self> {
-        // Load syntax definitions
-        let syntax_set = SyntaxSet::load_defaults_newlines();
-        
-        // Load themes
-        let theme_set = ThemeSet::load_defaults();
+        let assets = SyntaxAssets::load(&config.syntax_dirs)?;
         
         // Try to load custom theme
-        let theme = theme_set.themes.get(&config.theme)
+        let theme = assets.theme_set.themes.get(&config.theme)
             .cloned()
             .unwrap_or_else(|| {
                 // Fallback to base16-ocean.dark if theme not found
-                theme_set.themes.get("base16-ocean.dark").unwrap().clone()
+                assets.theme_set.themes.get("base16-ocean.dark").unwrap().clone()
             });
         
-        // Set up language aliases
-        let mut language_aliases = HashMap::new();
-        language_aliases.insert("js".to_string(), "javascript".to_string());
-        language_aliases.insert("ts".to_string(), "typescript".to_string());
-        language_aliases.insert("py".to_string(), "python".to_string());
-        language_aliases.insert("rb".to_string(), "ruby".to_string());
-        language_aliases.insert("sh".to_string(), "bash".to_string());
-        language_aliases.insert("shell".to_string(), "bash".to_string());
-        language_aliases.insert("zsh".to_string(), "bash".to_string());
-        language_aliases.insert("fish".to_string(), "fish".to_string());
-        language_aliases.insert("rs".to_string(), "rust".to_string());
-        language_aliases.insert("go".to_string(), "go".to_string());
-        language_aliases.insert("cpp".to_string(), "c++".to_string());
-        language_aliases.insert("cxx".to_string(), "c++".to_string());
-        language_aliases.insert("cc".to_string(), "c++".to_string());
-        language_aliases.insert("c".to_string(), "c".to_string());
-        language_aliases.insert("h".to_string(), "c".to_string());
-        language_aliases.insert("hpp".to_string(), "c++".to_string());
-        language_aliases.insert("java".to_string(), "java".to_string());
-        language_aliases.insert("kt".to_string(), "kotlin".to_string());
-        language_aliases.insert("scala".to_string(), "scala".to_string());
-        language_aliases.insert("cs".to_string(), "csharp".to_string());
-        language_aliases.insert("php".to_string(), "php".to_string());
-        language_aliases.insert("html".to_string(), "html".to_string());
-        language_aliases.insert("htm".to_string(), "html".to_string());
-        language_aliases.insert("xml".to_string(), "xml".to_string());
-        language_aliases.insert("css".to_string(), "css".to_string());
-        language_aliases.insert("scss".to_string(), "scss".to_string());
-        language_aliases.insert("sass".to_string(), "sass".to_string());
-        language_aliases.insert("less".to_string(), "less".to_string());
-        language_aliases.insert("json".to_string(), "json".to_string());
-        language_aliases.insert("yaml".to_string(), "yaml".to_string());
-        language_aliases.insert("yml".to_string(), "yaml".to_string());
-        language_aliases.insert("toml".to_string(), "toml".to_string());
-        language_aliases.insert("sql".to_string(), "sql".to_string());
-        language_aliases.insert("dockerfile".to_string(), "dockerfile".to_string());
-        language_aliases.insert("makefile".to_string(), "makefile".to_string());
-        language_aliases.insert("cmake".to_string(), "cmake".to_string());
-        language_aliases.insert("diff".to_string(), "diff".to_string());
-        language_aliases.insert("patch".to_string(), "diff".to_string());
-        language_aliases.insert("log".to_string(), "log".to_string());
-        
         Ok(Self {
-            syntax_set,
-            theme_set,
+            assets,
             theme,
             config,
-            language_aliases,
         })
     }
     
@@ -141,11 +98,14 @@ impl CodeHighlighter {
         let resolved_language = self.resolve_language(language);
         
         // Find syntax definition
-        let syntax = self.syntax_set
-            .find_syntax_by_token(&resolved_language)
-            .or_else(|| self.syntax_set.find_syntax_by_extension(&resolved_language))
-            .or_else(|| self.syntax_set.find_syntax_by_first_line(code))
-            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let syntax_set = &self.assets.syntax_set;
+        let syntax = self.assets.find_syntax(&resolved_language)
+            .or_else(|| self.assets.find_syntax(language))
+            .or_else(|| {
+                warn_once(format!("Unknown code block language '{}', rendering it as plain text", language));
+                syntax_set.find_syntax_by_first_line(code)
+            })
+            .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
         
         // Create highlighter
         let mut highlighter = HighlightLines::new(syntax, &self.theme);
@@ -155,7 +115,7 @@ impl CodeHighlighter {
         let mut line_number = 1;
         
         for line in LinesWithEndings::from(code) {
-            let ranges = highlighter.highlight_line(line, &self.syntax_set)
+            let ranges = highlighter.highlight_line(line, syntax_set)
                 .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
             
             let highlighted_line = if self.config.line_numbers {
@@ -236,7 +196,7 @@ impl CodeHighlighter {
     
     /// Resolve language alias
     fn resolve_language(&self, language: &str) -> String {
-        self.language_aliases
+        self.config.language_aliases
             .get(language)
             .cloned()
             .unwrap_or_else(|| language.to_string())
@@ -253,12 +213,12 @@ impl CodeHighlighter {
     
     /// Get available themes
     pub fn available_themes(&self) -> Vec<&str> {
-        self.theme_set.themes.keys().map(|s| s.as_str()).collect()
+        self.assets.theme_set.themes.keys().map(|s| s.as_str()).collect()
     }
     
     /// Get available languages
     pub fn available_languages(&self) -> Vec<String> {
-        self.syntax_set.syntaxes()
+        self.assets.syntax_set.syntaxes()
             .iter()
             .filter_map(|s| s.file_extensions.first())
             .map(|s| s.to_string())
@@ -267,7 +227,7 @@ impl CodeHighlighter {
     
     /// Set theme
     pub fn set_theme(&mut self, theme_name: &str) -> Result<()> {
-        if let Some(theme) = self.theme_set.themes.get(theme_name) {
+        if let Some(theme) = self.assets.theme_set.themes.get(theme_name) {
             self.theme = theme.clone();
             self.config.theme = theme_name.to_string();
             Ok(())
@@ -0,0 +1,315 @@
+//! Build-time execution of code examples
+
+use crate::core::config::{CodeRunConfig, RunCommandConfig};
+use crate::core::{Error, Result};
+use crate::utils::process::{in_own_process_group, kill_process_group, shell};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+/// Directory under the build cache holding earlier results
+pub const CACHE_DIR: &str = ".peta_cache/code-run";
+
+/// Captured result of running a code block
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunOutput {
+    pub stdout: String,
+    pub stderr: String,
+    /// Exit code, or `None` when stopped by a signal or the timeout
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+}
+
+impl RunOutput {
+    /// Whether the block exited with status zero
+    pub fn success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+
+    /// How the run ended, for error messages
+    pub fn status(&self, timeout: u64) -> String {
+        match self.exit_code {
+            _ if self.timed_out => format!("timed out after {}s", timeout),
+            Some(code) => format!("exited with {}", code),
+            None => "was killed by a signal".to_string(),
+        }
+    }
+}
+
+/// Runs code blocks through the interpreters configured per language
+///
+/// Results are cached by a hash of the command and code, so unchanged
+/// examples are not re-run on the next build.
+pub struct CodeRunner {
+    config: CodeRunConfig,
+    cache_dir: PathBuf,
+}
+
+impl CodeRunner {
+    /// Create a runner caching results under [`CACHE_DIR`]
+    pub fn new(config: CodeRunConfig) -> Self {
+        Self::with_cache_dir(config, PathBuf::from(CACHE_DIR))
+    }
+
+    /// Create a runner caching results in `cache_dir`
+    pub fn with_cache_dir(config: CodeRunConfig, cache_dir: PathBuf) -> Self {
+        Self { config, cache_dir }
+    }
+
+    /// Whether a command is configured for `language`
+    pub fn supports(&self, language: &str) -> bool {
+        self.config.languages.contains_key(language)
+    }
+
+    /// Whether a failing block fails the build unless the block says otherwise
+    pub fn fail_on_error(&self) -> bool {
+        self.config.fail_on_error
+    }
+
+    /// Seconds a block may run
+    pub fn timeout(&self) -> u64 {
+        self.config.timeout
+    }
+
+    /// Run `code` as `language`, reusing a cached result when there is one
+    pub fn run(&self, language: &str, code: &str) -> Result<RunOutput> {
+        let command = self.config.languages.get(language).ok_or_else(|| {
+            Error::code_run(format!(
+                "no [code_blocks.run.languages] command for '{}'",
+                language
+            ))
+        })?;
+
+        let cache_path = self.cache_dir.join(format!("{}.json", cache_key(command, code)));
+        if let Some(output) = fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|cached| serde_json::from_str(&cached).ok())
+        {
+            return Ok(output);
+        }
+
+        let output = self.execute(command, code)?;
+
+        // A timeout may be a slow machine rather than the code, so try again next build
+        if !output.timed_out {
+            if let Err(e) = fs::create_dir_all(&self.cache_dir)
+                .and_then(|_| fs::write(&cache_path, serde_json::to_string(&output).unwrap_or_default()))
+            {
+                eprintln!("Warning: Failed to cache code run result: {}", e);
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Write the code to a temporary directory and run the command there
+    fn execute(&self, command: &RunCommandConfig, code: &str) -> Result<RunOutput> {
+        let dir = tempfile::TempDir::new()
+            .map_err(|e| Error::code_run(format!("Failed to create a temporary directory: {}", e)))?;
+        fs::write(dir.path().join(&command.file), code)
+            .map_err(|e| Error::code_run(format!("Failed to write {}: {}", command.file, e)))?;
+
+        let shell_command = command.command.replace("{file}", &command.file);
+        run_command(&shell_command, dir.path(), self.config.timeout)
+    }
+}
+
+/// Run a shell command in `dir`, stopping it after `timeout` seconds
+///
+/// Output is captured through files in `dir`, so a chatty command cannot
+/// block on a full pipe while it is being waited for.
+pub fn run_command(command: &str, dir: &Path, timeout: u64) -> Result<RunOutput> {
+    let stdout_path = dir.join(".stdout");
+    let stderr_path = dir.join(".stderr");
+    let capture = |path: &Path| {
+        fs::File::create(path)
+            .map(Stdio::from)
+            .map_err(|e| Error::code_run(format!("Failed to capture output: {}", e)))
+    };
+
+    let mut child = in_own_process_group(&mut shell(command))
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(capture(&stdout_path)?)
+        .stderr(capture(&stderr_path)?)
+        .spawn()
+        .map_err(|e| Error::code_run(format!("Failed to run '{}': {}", command, e)))?;
+
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+    let (status, timed_out) = loop {
+        let status = child
+            .try_wait()
+            .map_err(|e| Error::code_run(format!("Failed to wait for '{}': {}", command, e)))?;
+        match status {
+            Some(status) => break (Some(status), false),
+            None if Instant::now() >= deadline => {
+                // Also stop what the shell started, which would otherwise
+                // keep running after the build
+                let _ = kill_process_group(&mut child);
+                let _ = child.wait();
+                break (None, true);
+            }
+            None => std::thread::sleep(Duration::from_millis(10)),
+        }
+    };
+
+    let read = |path: &Path| String::from_utf8_lossy(&fs::read(path).unwrap_or_default()).into_owned();
+    Ok(RunOutput {
+        stdout: read(&stdout_path),
+        stderr: read(&stderr_path),
+        exit_code: status.and_then(|status| status.code()),
+        timed_out,
+    })
+}
+
+/// Render captured output below its code block
+///
+/// Output is escaped and each line wrapped in a span, so the RST inline
+/// markup passes that run over the page leave it alone.
+pub fn render_output(output: &RunOutput) -> String {
+    let mut html = String::new();
+    html.push_str(&format!(
+        "<div class=\"code-output{}\" data-exit-code=\"{}\">\n",
+        if output.success() { "" } else { " code-output-failed" },
+        output.exit_code.map(|code| code.to_string()).unwrap_or_default()
+    ));
+    html.push_str("<div class=\"code-output-header\">Output</div>\n");
+
+    for (class, text) in [("code-output-stdout", &output.stdout), ("code-output-stderr", &output.stderr)] {
+        if text.trim().is_empty() {
+            continue;
+        }
+        let lines: Vec<String> = text
+            .trim_end()
+            .lines()
+            .map(|line| format!("<span class=\"code-output-line\">{}</span>", escape_output(line)))
+            .collect();
+        html.push_str(&format!("<pre class=\"{}\">{}</pre>\n", class, lines.join("\n")));
+    }
+
+    if output.timed_out {
+        html.push_str("<div class=\"code-output-status\">Timed out</div>\n");
+    } else if let Some(code) = output.exit_code.filter(|&code| code != 0) {
+        html.push_str(&format!("<div class=\"code-output-status\">Exit code {}</div>\n", code));
+    }
+
+    html.push_str("</div>");
+    html
+}
+
+/// Escape HTML and the characters of RST inline markup
+fn escape_output(line: &str) -> String {
+    line.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('*', "&#42;")
+        .replace('`', "&#96;")
+}
+
+/// Hash identifying a result: the same code run by the same command
+fn cache_key(command: &RunCommandConfig, code: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [command.command.as_str(), command.file.as_str(), code] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn runner(cache: &TempDir, command: &str) -> CodeRunner {
+        let mut config = CodeRunConfig {
+            timeout: 2,
+            ..CodeRunConfig::default()
+        };
+        config.languages.insert(
+            "sh".to_string(),
+            RunCommandConfig {
+                command: command.to_string(),
+                file: "main.sh".to_string(),
+            },
+        );
+        CodeRunner::with_cache_dir(config, cache.path().to_path_buf())
+    }
+
+    #[test]
+    fn test_run_captures_output() {
+        let cache = TempDir::new().unwrap();
+        let runner = runner(&cache, "sh {file}");
+
+        let output = runner.run("sh", "echo out\necho err >&2\nexit 3\n").unwrap();
+        assert_eq!(output.stdout, "out\n");
+        assert_eq!(output.stderr, "err\n");
+        assert_eq!(output.exit_code, Some(3));
+        assert!(!output.success());
+    }
+
+    #[test]
+    fn test_results_are_cached() {
+        let cache = TempDir::new().unwrap();
+        let code = "echo $$\n";
+
+        let first = runner(&cache, "sh {file}").run("sh", code).unwrap();
+        let second = runner(&cache, "sh {file}").run("sh", code).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(fs::read_dir(cache.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_timeout_stops_block() {
+        let cache = TempDir::new().unwrap();
+        let runner = CodeRunner {
+            config: CodeRunConfig {
+                timeout: 0,
+                ..runner(&cache, "sh {file}").config
+            },
+            cache_dir: cache.path().to_path_buf(),
+        };
+
+        let output = runner.run("sh", "sleep 5\n").unwrap();
+        assert!(output.timed_out);
+        assert_eq!(output.status(0), "timed out after 0s");
+        assert_eq!(fs::read_dir(cache.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_timeout_stops_background_processes() {
+        let dir = TempDir::new().unwrap();
+        let marker = dir.path().join("marker");
+        let command = format!("(sleep 2; touch '{}') & wait", marker.display());
+
+        let output = run_command(&command, dir.path(), 1).unwrap();
+        assert!(output.timed_out);
+        std::thread::sleep(Duration::from_secs(2));
+        assert!(!marker.exists());
+    }
+
+    #[test]
+    fn test_unconfigured_language() {
+        let cache = TempDir::new().unwrap();
+        let err = runner(&cache, "sh {file}").run("ruby", "puts 1").unwrap_err();
+        assert!(err.to_string().contains("'ruby'"));
+    }
+
+    #[test]
+    fn test_render_output_escapes_markup() {
+        let output = RunOutput {
+            stdout: "<b>*not emphasis*</b>\n".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            timed_out: false,
+        };
+        let html = render_output(&output);
+
+        assert!(html.contains("&lt;b&gt;&#42;not emphasis&#42;&lt;/b&gt;"));
+        assert!(!html.contains("code-output-stderr"));
+        assert!(!html.contains("code-output-status"));
+    }
+}
@@ -0,0 +1,85 @@
+//! Tabbed groups of code blocks showing one example in several languages
+
+/// One tab of a `code-tabs` group
+#[derive(Debug, Clone)]
+pub struct CodeTab {
+    /// Language the tab is selected by, shared by tabs across groups and pages
+    pub key: String,
+    /// Text of the tab button
+    pub label: String,
+    /// Rendered code block
+    pub html: String,
+}
+
+/// Render code blocks as one tabbed widget
+///
+/// The first tab is shown until the code block script restores the
+/// reader's last selected language. Each tag sits on its own line, so
+/// paragraph conversion keeps the group as one HTML block.
+pub fn render_code_tabs(tabs: &[CodeTab]) -> String {
+    let mut html = String::new();
+    html.push_str("<div class=\"code-tabs\">\n");
+
+    html.push_str("<div class=\"code-tabs-nav\" role=\"tablist\">\n");
+    for (i, tab) in tabs.iter().enumerate() {
+        let active = i == 0;
+        html.push_str(&format!(
+            "<button class=\"code-tab{}\" role=\"tab\" data-tab-key=\"{}\" aria-selected=\"{}\">{}</button>\n",
+            if active { " active" } else { "" },
+            escape_attribute(&tab.key),
+            active,
+            escape_attribute(&tab.label)
+        ));
+    }
+    html.push_str("</div>\n");
+
+    for (i, tab) in tabs.iter().enumerate() {
+        let active = i == 0;
+        html.push_str(&format!(
+            "<div class=\"code-tab-panel{}\" role=\"tabpanel\" data-tab-key=\"{}\"{}>\n",
+            if active { " active" } else { "" },
+            escape_attribute(&tab.key),
+            if active { "" } else { " hidden" }
+        ));
+        html.push_str(tab.html.trim_end());
+        html.push_str("\n</div>\n");
+    }
+
+    html.push_str("</div>\n");
+    html
+}
+
+/// Escape text for an attribute or button label
+fn escape_attribute(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_code_tabs() {
+        let tabs = vec![
+            CodeTab {
+                key: "python".to_string(),
+                label: "Python".to_string(),
+                html: "<div class=\"code-block\">py</div>\n".to_string(),
+            },
+            CodeTab {
+                key: "bash".to_string(),
+                label: "curl".to_string(),
+                html: "<div class=\"code-block\">sh</div>\n".to_string(),
+            },
+        ];
+        let html = render_code_tabs(&tabs);
+
+        assert!(html.contains(r#"<button class="code-tab active" role="tab" data-tab-key="python" aria-selected="true">Python</button>"#));
+        assert!(html.contains(r#"data-tab-key="bash" aria-selected="false">curl</button>"#));
+        assert!(html.contains(r#"<div class="code-tab-panel" role="tabpanel" data-tab-key="bash" hidden>"#));
+        assert_eq!(html.matches("<div").count(), html.matches("</div>").count());
+    }
+}
@@ -2,8 +2,16 @@
 
 pub mod code_block_renderer;
 pub mod code_highlighter;
+pub mod code_runner;
+pub mod code_tabs;
+pub mod snippet_tests;
+pub mod syntax_assets;
 pub mod syntax_highlighter;
 
-pub use code_block_renderer::CodeBlockRenderer;
+pub use code_block_renderer::{CodeBlockConfig, CodeBlockOptions, CodeBlockRenderer};
 pub use code_highlighter::CodeHighlighter;
+pub use code_runner::{CodeRunner, RunOutput};
+pub use code_tabs::{render_code_tabs, CodeTab};
+pub use snippet_tests::{SnippetBlock, SnippetTestReport, SnippetTester};
+pub use syntax_assets::SyntaxAssets;
 pub use syntax_highlighter::{SyntaxHighlighter, HighlighterConfig};
\ No newline at end of file
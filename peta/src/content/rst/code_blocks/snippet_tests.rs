@@ -0,0 +1,386 @@
+//! Doctest-style checks of the code blocks in the content
+//!
+//! `peta test-snippets` extracts code blocks from the RST sources, writes
+//! each into a scratch project per language and runs the check command
+//! configured for the language, reporting failures by source location.
+
+use crate::content::rst::code_blocks::code_runner::{run_command, RunOutput};
+use crate::content::rst::directives::dedent_lines;
+use crate::core::config::{CodeBlocksConfig, CodeTestConfig, TestCommandConfig};
+use crate::core::{Error, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+static CODE_DIRECTIVE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\s*)\.\.\s+(?:code-block|code|sourcecode|code-run)::\s*(\S*)\s*$").unwrap());
+static OPTION_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^:([\w-]+):").unwrap());
+
+/// A code block found in an RST source
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetBlock {
+    pub file: PathBuf,
+    /// Line of the directive, counted from 1
+    pub line: usize,
+    pub language: String,
+    pub code: String,
+    /// `Some(true)` for `:test:`, `Some(false)` for `:no-test:`
+    pub test: Option<bool>,
+}
+
+impl SnippetBlock {
+    /// Source location as `file:line`
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file.display(), self.line)
+    }
+}
+
+/// Extract the code blocks of one RST source
+///
+/// Blocks nested in other directives, such as `code-tabs`, are included.
+pub fn extract_blocks(file: &Path, content: &str) -> Vec<SnippetBlock> {
+    let lines: Vec<&str> = content.lines().collect();
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let mut blocks = Vec::new();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(caps) = CODE_DIRECTIVE_RE.captures(lines[i]) else {
+            i += 1;
+            continue;
+        };
+        let directive_indent = caps[1].len();
+        let language = caps[2].to_string();
+        let line = i + 1;
+        i += 1;
+
+        // Field list options come first, the code after the first blank line
+        let mut test = None;
+        while i < lines.len() && !lines[i].trim().is_empty() && indent(lines[i]) > directive_indent {
+            if let Some(option) = OPTION_RE.captures(lines[i].trim()) {
+                match &option[1] {
+                    "test" => test = Some(true),
+                    "no-test" => test = Some(false),
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+
+        let start = i;
+        while i < lines.len() && (lines[i].trim().is_empty() || indent(lines[i]) > directive_indent) {
+            i += 1;
+        }
+
+        let code = dedent_lines(&lines[start..i].join("\n"), None);
+        if !language.is_empty() && !code.is_empty() {
+            blocks.push(SnippetBlock {
+                file: file.to_path_buf(),
+                line,
+                language,
+                code,
+                test,
+            });
+        }
+    }
+
+    blocks
+}
+
+/// Why a checked block failed
+#[derive(Debug, Clone)]
+pub enum FailureReason {
+    /// The check command exited non-zero or timed out
+    Check(RunOutput),
+    /// The block could not be checked, e.g. no command for `:test:`
+    Setup(String),
+}
+
+/// A block whose check failed
+#[derive(Debug, Clone)]
+pub struct SnippetFailure {
+    pub block: SnippetBlock,
+    /// Scratch directory the block was checked in
+    pub scratch_dir: Option<PathBuf>,
+    pub reason: FailureReason,
+}
+
+/// Result of checking the code blocks
+#[derive(Debug, Clone, Default)]
+pub struct SnippetTestReport {
+    pub passed: usize,
+    /// Blocks without a check, in languages with no command or not selected
+    pub ignored: usize,
+    pub failures: Vec<SnippetFailure>,
+    /// Check timeout, for failure messages
+    timeout: u64,
+}
+
+impl SnippetTestReport {
+    /// Whether every checked block passed
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for SnippetTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_ok() {
+            writeln!(f, "failures:\n")?;
+            for failure in &self.failures {
+                writeln!(f, "---- {} ({}) ----", failure.block.location(), failure.block.language)?;
+                match &failure.reason {
+                    FailureReason::Check(output) => {
+                        writeln!(f, "check {}", output.status(self.timeout))?;
+                        for text in [&output.stdout, &output.stderr] {
+                            if !text.trim().is_empty() {
+                                writeln!(f, "{}", text.trim_end())?;
+                            }
+                        }
+                    }
+                    FailureReason::Setup(message) => writeln!(f, "{}", message)?,
+                }
+                if let Some(dir) = &failure.scratch_dir {
+                    writeln!(f, "scratch project: {}", dir.display())?;
+                }
+                writeln!(f)?;
+            }
+
+            writeln!(f, "failures:")?;
+            for failure in &self.failures {
+                writeln!(f, "    {}", failure.block.location())?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(
+            f,
+            "test result: {}. {} passed; {} failed; {} ignored",
+            if self.is_ok() { "ok" } else { "FAILED" },
+            self.passed,
+            self.failures.len(),
+            self.ignored
+        )
+    }
+}
+
+/// Checks code blocks with the commands under `[code_blocks.test]`
+pub struct SnippetTester {
+    config: CodeTestConfig,
+    language_aliases: HashMap<String, String>,
+    scratch_dir: PathBuf,
+}
+
+impl SnippetTester {
+    /// Create a tester writing scratch projects to the configured directory
+    pub fn new(config: &CodeBlocksConfig) -> Self {
+        Self {
+            config: config.test.clone(),
+            language_aliases: config.language_aliases(),
+            scratch_dir: PathBuf::from(&config.test.scratch_dir),
+        }
+    }
+
+    /// Write scratch projects to `dir` instead
+    pub fn with_scratch_dir(mut self, dir: PathBuf) -> Self {
+        self.scratch_dir = dir;
+        self
+    }
+
+    /// Extract the code blocks of every RST file under `dir`
+    pub fn collect_blocks(dir: &Path) -> Result<Vec<SnippetBlock>> {
+        let mut blocks = Vec::new();
+        for entry in WalkDir::new(dir).sort_by_file_name().into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !entry.file_type().is_file() || path.extension().and_then(|e| e.to_str()) != Some("rst") {
+                continue;
+            }
+            let content = fs::read_to_string(path)?;
+            blocks.extend(extract_blocks(path, &content));
+        }
+        Ok(blocks)
+    }
+
+    /// Language a block name is checked as, resolving aliases such as `py`
+    pub fn resolve_language(&self, name: &str) -> String {
+        let language = name.to_lowercase();
+        self.language_aliases.get(&language).cloned().unwrap_or(language)
+    }
+
+    /// Check command for a block, or `None` when the block is not checked
+    fn command(&self, block: &SnippetBlock) -> Option<std::result::Result<(String, &TestCommandConfig), String>> {
+        let language = self.resolve_language(&block.language);
+        let command = self.config.languages.get(&language);
+        match (block.test, command) {
+            (Some(false), _) => None,
+            (Some(true), None) => Some(Err(format!(
+                "marked :test:, but there is no [code_blocks.test.languages] command for '{}'",
+                language
+            ))),
+            (Some(true), Some(command)) => Some(Ok((language, command))),
+            (None, Some(command)) if command.all => Some(Ok((language, command))),
+            (None, _) => None,
+        }
+    }
+
+    /// Check `blocks`, calling `progress` with each block and whether it passed
+    pub fn test(&self, blocks: &[SnippetBlock], mut progress: impl FnMut(&SnippetBlock, bool)) -> Result<SnippetTestReport> {
+        let mut report = SnippetTestReport {
+            timeout: self.config.timeout,
+            ..SnippetTestReport::default()
+        };
+
+        for block in blocks {
+            let (language, command) = match self.command(block) {
+                None => {
+                    report.ignored += 1;
+                    continue;
+                }
+                Some(Err(message)) => {
+                    progress(block, false);
+                    report.failures.push(SnippetFailure {
+                        block: block.clone(),
+                        scratch_dir: None,
+                        reason: FailureReason::Setup(message),
+                    });
+                    continue;
+                }
+                Some(Ok(found)) => found,
+            };
+
+            let dir = self.project_dir(&language, block);
+            let output = self.check(&dir, command, &block.code)?;
+            progress(block, output.success());
+            if output.success() {
+                report.passed += 1;
+            } else {
+                report.failures.push(SnippetFailure {
+                    block: block.clone(),
+                    scratch_dir: Some(dir),
+                    reason: FailureReason::Check(output),
+                });
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Write the block into a fresh scratch project and run the check there
+    fn check(&self, dir: &Path, command: &TestCommandConfig, code: &str) -> Result<RunOutput> {
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(&command.file), format!("{}\n", code))
+            .map_err(|e| Error::code_run(format!("Failed to write {}: {}", command.file, e)))?;
+
+        let shell_command = command.command.replace("{file}", &command.file);
+        run_command(&shell_command, dir, self.config.timeout)
+    }
+
+    /// Scratch project of a block: `<scratch_dir>/<language>/<source>-<line>`
+    fn project_dir(&self, language: &str, block: &SnippetBlock) -> PathBuf {
+        let source: String = block
+            .file
+            .with_extension("")
+            .to_string_lossy()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        self.scratch_dir
+            .join(language)
+            .join(format!("{}-{}", source.trim_matches('-'), block.line))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const SOURCE: &str = r#"---
+title: Examples
+---
+
+.. code-block:: py
+   :test:
+
+   print("ok")
+
+.. code-block:: python
+
+   print("untested")
+
+.. code-tabs::
+
+   .. code-block:: sh
+      :caption: Shell
+
+      exit 1
+"#;
+
+    fn tester(scratch: &TempDir, all: bool) -> SnippetTester {
+        let mut config = CodeBlocksConfig::default();
+        for (language, command) in [("python", "grep -q print {file}"), ("bash", "sh {file}")] {
+            config.test.languages.insert(
+                language.to_string(),
+                TestCommandConfig {
+                    command: command.to_string(),
+                    file: "main".to_string(),
+                    all,
+                },
+            );
+        }
+        SnippetTester::new(&config).with_scratch_dir(scratch.path().to_path_buf())
+    }
+
+    #[test]
+    fn test_extract_blocks() {
+        let blocks = extract_blocks(Path::new("page.rst"), SOURCE);
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!((blocks[0].line, blocks[0].test), (5, Some(true)));
+        assert_eq!(blocks[0].code, "print(\"ok\")");
+        assert_eq!(blocks[1].test, None);
+        assert_eq!((blocks[2].line, blocks[2].language.as_str()), (16, "sh"));
+        assert_eq!(blocks[2].code, "exit 1");
+    }
+
+    #[test]
+    fn test_only_marked_blocks_are_checked() {
+        let scratch = TempDir::new().unwrap();
+        let blocks = extract_blocks(Path::new("page.rst"), SOURCE);
+
+        let report = tester(&scratch, false).test(&blocks, |_, _| {}).unwrap();
+        assert!(report.is_ok(), "{}", report);
+        assert_eq!((report.passed, report.ignored), (1, 2));
+        assert!(scratch.path().join("python/page-5/main").exists());
+    }
+
+    #[test]
+    fn test_failures_report_source_locations() {
+        let scratch = TempDir::new().unwrap();
+        let blocks = extract_blocks(Path::new("page.rst"), SOURCE);
+
+        let report = tester(&scratch, true).test(&blocks, |_, _| {}).unwrap();
+        assert_eq!((report.passed, report.failures.len()), (2, 1));
+
+        let text = report.to_string();
+        assert!(text.contains("---- page.rst:16 (sh) ----\ncheck exited with 1"));
+        assert!(text.ends_with("test result: FAILED. 2 passed; 1 failed; 0 ignored"));
+    }
+
+    #[test]
+    fn test_marked_block_without_command_fails() {
+        let scratch = TempDir::new().unwrap();
+        let blocks = extract_blocks(Path::new("page.rst"), ".. code-block:: ruby\n   :test:\n\n   puts 1\n");
+
+        let report = tester(&scratch, false).test(&blocks, |_, _| {}).unwrap();
+        assert!(matches!(report.failures[0].reason, FailureReason::Setup(_)));
+        assert!(report.to_string().contains("command for 'ruby'"));
+    }
+}
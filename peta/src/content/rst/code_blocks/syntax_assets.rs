@@ -0,0 +1,151 @@
+//! Syntax definitions and color themes, including site-provided ones
+
+use crate::core::{Error, Result};
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use walkdir::WalkDir;
+
+/// Themes the code block stylesheet has a built-in palette for
+pub const CSS_THEMES: [&str; 2] = ["one-dark", "solarized"];
+
+/// Language names rendered as plain text without a warning
+const PLAIN_TEXT_LANGUAGES: [&str; 6] = ["", "text", "txt", "plain", "plaintext", "none"];
+
+/// Loaded assets by the syntax and theme files they were built from
+type AssetCache = HashMap<Vec<(PathBuf, Option<SystemTime>)>, Arc<SyntaxAssets>>;
+
+static CACHE: Lazy<Mutex<AssetCache>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Unknown languages and themes already reported
+static WARNED: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Syntect's default syntaxes and themes plus those found in extra directories
+pub struct SyntaxAssets {
+    pub syntax_set: SyntaxSet,
+    pub theme_set: ThemeSet,
+}
+
+impl SyntaxAssets {
+    /// Load the defaults plus every `.sublime-syntax` and `.tmTheme` under `dirs`
+    ///
+    /// Missing directories are skipped. Later directories take precedence,
+    /// and loaded sets are shared until one of their files changes, so
+    /// creating a highlighter per page stays cheap.
+    pub fn load(dirs: &[PathBuf]) -> Result<Arc<Self>> {
+        let key: Vec<_> = dirs
+            .iter()
+            .filter(|dir| dir.is_dir())
+            .flat_map(|dir| {
+                WalkDir::new(dir)
+                    .sort_by_file_name()
+                    .into_iter()
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.file_type().is_file() && Self::is_asset_file(e.path()))
+                    .map(|e| {
+                        let modified = e.metadata().ok().and_then(|m| m.modified().ok());
+                        (e.into_path(), modified)
+                    })
+            })
+            .collect();
+
+        let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(assets) = cache.get(&key) {
+            return Ok(Arc::clone(assets));
+        }
+
+        let mut syntax_builder = SyntaxSet::load_defaults_newlines().into_builder();
+        let mut theme_set = ThemeSet::load_defaults();
+        for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+            syntax_builder
+                .add_from_folder(dir, true)
+                .map_err(|e| Error::config(format!("Failed to load syntaxes from {}: {}", dir.display(), e)))?;
+            theme_set
+                .add_from_folder(dir)
+                .map_err(|e| Error::config(format!("Failed to load themes from {}: {}", dir.display(), e)))?;
+        }
+
+        let assets = Arc::new(Self {
+            syntax_set: syntax_builder.build(),
+            theme_set,
+        });
+        cache.insert(key, Arc::clone(&assets));
+        Ok(assets)
+    }
+
+    /// Find a syntax by name or file extension
+    ///
+    /// Plain text names such as `text` resolve to the plain text syntax.
+    pub fn find_syntax(&self, language: &str) -> Option<&SyntaxReference> {
+        if PLAIN_TEXT_LANGUAGES.contains(&language.to_lowercase().as_str()) {
+            return Some(self.syntax_set.find_syntax_plain_text());
+        }
+
+        self.syntax_set
+            .find_syntax_by_token(language)
+            .or_else(|| self.syntax_set.find_syntax_by_extension(language))
+    }
+
+    /// Whether `name` is a built-in palette or a loaded color theme
+    pub fn has_theme(&self, name: &str) -> bool {
+        CSS_THEMES.contains(&name) || self.theme_set.themes.contains_key(name)
+    }
+
+    /// Whether a file is a syntax definition or color theme
+    fn is_asset_file(path: &Path) -> bool {
+        matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("sublime-syntax") | Some("tmTheme")
+        )
+    }
+}
+
+/// Print a warning unless the same one was printed before
+pub fn warn_once(message: String) {
+    let mut warned = WARNED.lock().unwrap_or_else(|e| e.into_inner());
+    if warned.insert(message.clone()) {
+        eprintln!("Warning: {}", message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const DSL_SYNTAX: &str = r#"%YAML 1.2
+---
+name: Query DSL
+file_extensions: [qdsl]
+scope: source.qdsl
+contexts:
+  main:
+    - match: '\b(select|where)\b'
+      scope: keyword.control.qdsl
+"#;
+
+    #[test]
+    fn test_loads_custom_syntax() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("qdsl.sublime-syntax"), DSL_SYNTAX).unwrap();
+
+        let assets = SyntaxAssets::load(&[dir.path().to_path_buf()]).unwrap();
+        assert_eq!(assets.find_syntax("qdsl").unwrap().name, "Query DSL");
+        assert!(assets.find_syntax("rust").is_some());
+    }
+
+    #[test]
+    fn test_unknown_names() {
+        let assets = SyntaxAssets::load(&[PathBuf::from("does-not-exist")]).unwrap();
+
+        assert!(assets.find_syntax("no-such-language").is_none());
+        assert!(assets.find_syntax("text").is_some());
+        assert!(assets.has_theme("one-dark"));
+        assert!(assets.has_theme("base16-ocean.dark"));
+        assert!(!assets.has_theme("no-such-theme"));
+    }
+}
@@ -1,19 +1,21 @@
 //! Syntax highlighting using Syntect for code blocks
 
+use crate::content::rst::code_blocks::syntax_assets::{warn_once, SyntaxAssets};
+use crate::core::config::CodeBlocksConfig;
 use crate::core::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use syntect::highlighting::{Theme, ThemeSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use syntect::highlighting::Theme;
 use syntect::html::{ClassedHTMLGenerator, ClassStyle};
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::SyntaxReference;
 use syntect::util::LinesWithEndings;
 
 /// Syntax highlighter for code blocks
 pub struct SyntaxHighlighter {
-    /// Syntax set for language definitions
-    syntax_set: SyntaxSet,
-    /// Theme set for color schemes
-    theme_set: ThemeSet,
+    /// Syntax definitions and color themes
+    assets: Arc<SyntaxAssets>,
     /// Current theme
     theme: Theme,
     /// Language aliases
@@ -31,6 +33,10 @@ pub struct HighlighterConfig {
     pub line_numbers: bool,
     /// Highlight specific lines
     pub highlight_lines: Vec<usize>,
+    /// Alias to language, from `[code_blocks.languages]`
+    pub language_aliases: HashMap<String, String>,
+    /// Directories with extra `.sublime-syntax` and `.tmTheme` files
+    pub syntax_dirs: Vec<PathBuf>,
 }
 
 impl Default for HighlighterConfig {
@@ -39,6 +45,8 @@ impl Default for HighlighterConfig {
             theme: "one-dark".to_string(),
             line_numbers: true,
             highlight_lines: Vec::new(),
+            language_aliases: CodeBlocksConfig::default().language_aliases(),
+            syntax_dirs: Vec::new(),
         }
     }
 }
@@ -50,96 +58,50 @@ impl SyntaxHighlighter {
     }
 
     /// Create a syntax highlighter with custom configuration
+    ///
+    /// Colors come from the code block stylesheet, so an unknown theme only
+    /// matters there and is reported when the stylesheet is generated.
     pub fn with_config(config: HighlighterConfig) -> Result<Self> {
-        // Load syntax definitions
-        let syntax_set = SyntaxSet::load_defaults_newlines();
-
-        // Load themes
-        let theme_set = ThemeSet::load_defaults();
+        let assets = SyntaxAssets::load(&config.syntax_dirs)?;
 
         // Try to load theme
-        let theme = theme_set
+        let theme = assets
+            .theme_set
             .themes
             .get(&config.theme)
             .cloned()
             .unwrap_or_else(|| {
                 // Fallback to base16-ocean.dark if theme not found
-                theme_set
+                assets
+                    .theme_set
                     .themes
                     .get("base16-ocean.dark")
                     .unwrap()
                     .clone()
             });
 
-        // Set up language aliases
-        let mut language_aliases = HashMap::new();
-        language_aliases.insert("js".to_string(), "javascript".to_string());
-        language_aliases.insert("ts".to_string(), "typescript".to_string());
-        language_aliases.insert("py".to_string(), "python".to_string());
-        language_aliases.insert("py3".to_string(), "python".to_string());
-        language_aliases.insert("python3".to_string(), "python".to_string());
-        language_aliases.insert("rb".to_string(), "ruby".to_string());
-        language_aliases.insert("sh".to_string(), "bash".to_string());
-        language_aliases.insert("shell".to_string(), "bash".to_string());
-        language_aliases.insert("zsh".to_string(), "bash".to_string());
-        language_aliases.insert("fish".to_string(), "fish".to_string());
-        language_aliases.insert("rs".to_string(), "rust".to_string());
-        language_aliases.insert("go".to_string(), "go".to_string());
-        language_aliases.insert("cpp".to_string(), "c++".to_string());
-        language_aliases.insert("cxx".to_string(), "c++".to_string());
-        language_aliases.insert("cc".to_string(), "c++".to_string());
-        language_aliases.insert("h".to_string(), "c".to_string());
-        language_aliases.insert("hpp".to_string(), "c++".to_string());
-        language_aliases.insert("java".to_string(), "java".to_string());
-        language_aliases.insert("kt".to_string(), "kotlin".to_string());
-        language_aliases.insert("scala".to_string(), "scala".to_string());
-        language_aliases.insert("cs".to_string(), "csharp".to_string());
-        language_aliases.insert("php".to_string(), "php".to_string());
-        language_aliases.insert("html".to_string(), "html".to_string());
-        language_aliases.insert("htm".to_string(), "html".to_string());
-        language_aliases.insert("xml".to_string(), "xml".to_string());
-        language_aliases.insert("css".to_string(), "css".to_string());
-        language_aliases.insert("scss".to_string(), "scss".to_string());
-        language_aliases.insert("sass".to_string(), "sass".to_string());
-        language_aliases.insert("less".to_string(), "less".to_string());
-        language_aliases.insert("json".to_string(), "json".to_string());
-        language_aliases.insert("yaml".to_string(), "yaml".to_string());
-        language_aliases.insert("yml".to_string(), "yaml".to_string());
-        language_aliases.insert("toml".to_string(), "toml".to_string());
-        language_aliases.insert("sql".to_string(), "sql".to_string());
-        language_aliases.insert("dockerfile".to_string(), "dockerfile".to_string());
-        language_aliases.insert("makefile".to_string(), "makefile".to_string());
-        language_aliases.insert("cmake".to_string(), "cmake".to_string());
-        language_aliases.insert("diff".to_string(), "diff".to_string());
-        language_aliases.insert("patch".to_string(), "diff".to_string());
-        language_aliases.insert("log".to_string(), "log".to_string());
-
         Ok(Self {
-            syntax_set,
-            theme_set,
+            assets,
             theme,
-            language_aliases,
+            language_aliases: config.language_aliases,
             theme_name: config.theme,
         })
     }
 
     /// Highlight code and return HTML with syntax highlighting
     pub fn highlight(&self, code: &str, language: &str) -> Result<String> {
-        // Resolve language
-        let resolved_language = self.resolve_language(language);
+        let syntax_set = &self.assets.syntax_set;
 
         // Find syntax definition
         let syntax = self
-            .syntax_set
-            .find_syntax_by_token(&resolved_language)
-            .or_else(|| self.syntax_set.find_syntax_by_extension(&resolved_language))
-            .or_else(|| self.syntax_set.find_syntax_by_first_line(code))
-            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+            .find_syntax(language)
+            .or_else(|| syntax_set.find_syntax_by_first_line(code))
+            .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
 
         // Create HTML generator
         let mut html_generator = ClassedHTMLGenerator::new_with_class_style(
             syntax,
-            &self.syntax_set,
+            syntax_set,
             ClassStyle::Spaced,
         );
 
@@ -231,9 +193,44 @@ impl SyntaxHighlighter {
         code: &str,
         language: &str,
         highlight_lines: &[usize],
+    ) -> Result<(String, usize)> {
+        self.highlight_with_line_options(code, language, Some(1), highlight_lines)
+    }
+
+    /// Highlight code, numbering lines from `first_line` if given
+    ///
+    /// `highlight_lines` count from the first line of the block, whatever
+    /// number it is displayed with.
+    pub fn highlight_with_line_options(
+        &self,
+        code: &str,
+        language: &str,
+        first_line: Option<usize>,
+        highlight_lines: &[usize],
     ) -> Result<(String, usize)> {
         let highlighted = self.highlight(code, language)?;
 
+        let Some(first_line) = first_line else {
+            if highlight_lines.is_empty() {
+                return Ok((highlighted, code.lines().count()));
+            }
+
+            // Emphasized lines carry their newline so the block span adds no blank line
+            let lines: Vec<&str> = highlighted.lines().collect();
+            let line_count = lines.len();
+            let mut result = String::new();
+            for (i, line) in lines.iter().enumerate() {
+                let newline = if i + 1 < line_count { "\n" } else { "" };
+                if highlight_lines.contains(&(i + 1)) {
+                    result.push_str(&format!(r#"<span class="line-highlight">{}{}</span>"#, line, newline));
+                } else {
+                    result.push_str(line);
+                    result.push_str(newline);
+                }
+            }
+            return Ok((result, line_count));
+        };
+
         // Split into lines
         let lines: Vec<&str> = highlighted.lines().collect();
         let line_count = lines.len();
@@ -252,36 +249,51 @@ impl SyntaxHighlighter {
 
             result.push(format!(
                 r#"<span class="line-number {}" data-line="{}">{}</span>{}"#,
-                highlight_class, line_num, line_num, line
+                highlight_class, line_num, first_line + i, line
             ));
         }
 
         Ok((result.join("\n"), line_count))
     }
 
+    /// Find the syntax for a language or one of its aliases
+    ///
+    /// Unknown languages are reported once and fall back to plain text.
+    fn find_syntax(&self, language: &str) -> Option<&SyntaxReference> {
+        let resolved = self.resolve_language(language);
+        let syntax = self
+            .assets
+            .find_syntax(&resolved)
+            .or_else(|| self.assets.find_syntax(language))
+            // TypeScript isn't in syntect's default set, use JavaScript as fallback
+            .or_else(|| (resolved == "typescript").then(|| self.assets.find_syntax("javascript")).flatten());
+
+        if syntax.is_none() {
+            warn_once(format!(
+                "Unknown code block language '{}', rendering it as plain text",
+                language
+            ));
+        }
+        syntax
+    }
+
     /// Resolve language alias
     fn resolve_language(&self, language: &str) -> String {
-        let resolved = self.language_aliases
+        self.language_aliases
             .get(language)
             .cloned()
-            .unwrap_or_else(|| language.to_string());
-
-        // TypeScript isn't in syntect's default set, use JavaScript as fallback
-        if resolved == "typescript" || resolved == "ts" {
-            "javascript".to_string()
-        } else {
-            resolved
-        }
+            .unwrap_or_else(|| language.to_string())
     }
 
     /// Get available themes
     pub fn available_themes(&self) -> Vec<&str> {
-        self.theme_set.themes.keys().map(|s| s.as_str()).collect()
+        self.assets.theme_set.themes.keys().map(|s| s.as_str()).collect()
     }
 
     /// Get available languages
     pub fn available_languages(&self) -> Vec<String> {
-        self.syntax_set
+        self.assets
+            .syntax_set
             .syntaxes()
             .iter()
             .filter_map(|s| s.file_extensions.first())
@@ -291,7 +303,7 @@ impl SyntaxHighlighter {
 
     /// Set theme
     pub fn set_theme(&mut self, theme_name: &str) -> Result<()> {
-        if let Some(theme) = self.theme_set.themes.get(theme_name) {
+        if let Some(theme) = self.assets.theme_set.themes.get(theme_name) {
             self.theme = theme.clone();
             self.theme_name = theme_name.to_string();
             Ok(())
@@ -366,6 +378,24 @@ mod tests {
         assert!(html.contains("line-highlight"));
     }
 
+    #[test]
+    fn test_line_options() {
+        let highlighter = SyntaxHighlighter::new().unwrap();
+        let code = "line1\nline2\nline3";
+
+        let (numbered, _) = highlighter
+            .highlight_with_line_options(code, "text", Some(10), &[2])
+            .unwrap();
+        assert!(numbered.contains(r#"<span class="line-number line-highlight" data-line="2">11</span>"#));
+
+        let (plain, line_count) = highlighter
+            .highlight_with_line_options(code, "text", None, &[3])
+            .unwrap();
+        assert_eq!(line_count, 3);
+        assert!(!plain.contains("line-number"));
+        assert!(plain.contains(r#"<span class="line-highlight">"#));
+    }
+
     #[test]
     fn test_available_themes() {
         let highlighter = SyntaxHighlighter::new().unwrap();
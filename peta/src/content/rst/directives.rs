@@ -9,6 +9,11 @@ pub trait DirectiveHandler: Any {
     /// Handle a directive and return the generated content
     fn handle(&mut self, directive_type: &str, content: &str, options: &HashMap<String, String>) -> Result<String>;
 
+    /// Files read since the last call, so the dev server can rebuild when they change
+    fn take_dependencies(&mut self) -> Vec<std::path::PathBuf> {
+        Vec::new()
+    }
+
     /// Helper for downcasting
     fn as_any_mut(&mut self) -> &mut dyn Any where Self: Sized {
         self
@@ -16,8 +21,12 @@ pub trait DirectiveHandler: Any {
 }
 
 /// Code block directive handler
+///
+/// Also handles `code-run`, which the parser passes with an `execute`
+/// option, running the block and showing its output below it.
 pub struct CodeBlockHandler {
     renderer: crate::content::rst::CodeBlockRenderer,
+    runner: crate::content::rst::CodeRunner,
 }
 
 impl CodeBlockHandler {
@@ -25,8 +34,46 @@ impl CodeBlockHandler {
         Self {
             renderer: crate::content::rst::CodeBlockRenderer::new()
                 .expect("Failed to create CodeBlockRenderer"),
+            runner: crate::content::rst::CodeRunner::new(Default::default()),
         }
     }
+
+    /// Create a handler rendering with the site's `[code_blocks]` defaults
+    pub fn with_config(config: crate::content::rst::CodeBlockConfig) -> Result<Self> {
+        Ok(Self {
+            renderer: crate::content::rst::CodeBlockRenderer::with_config(config)?,
+            runner: crate::content::rst::CodeRunner::new(Default::default()),
+        })
+    }
+
+    /// Run `:execute:` blocks with the given runner
+    pub fn with_runner(mut self, runner: crate::content::rst::CodeRunner) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Run a block and render its output, failing if it failed and should
+    fn run(&self, language: &str, code: &str, options: &HashMap<String, String>) -> Result<String> {
+        let language = match self.renderer.config().language_aliases.get(language) {
+            Some(resolved) if !self.runner.supports(language) => resolved.as_str(),
+            _ => language,
+        };
+        let output = self.runner.run(language, code)?;
+
+        let fail_on_error = options.contains_key("fail-on-error") || self.runner.fail_on_error();
+        if fail_on_error && !output.success() {
+            let first_line = code.lines().find(|line| !line.trim().is_empty()).unwrap_or("");
+            return Err(crate::core::Error::code_run(format!(
+                "{} block starting `{}` {}:\n{}",
+                language,
+                first_line.trim(),
+                output.status(self.runner.timeout()),
+                output.stderr.trim_end()
+            )));
+        }
+
+        Ok(crate::content::rst::code_blocks::code_runner::render_output(&output))
+    }
 }
 
 impl Default for CodeBlockHandler {
@@ -36,7 +83,7 @@ impl Default for CodeBlockHandler {
 }
 
 impl DirectiveHandler for CodeBlockHandler {
-    fn handle(&mut self, language: &str, content: &str, _options: &std::collections::HashMap<String, String>) -> Result<String> {
+    fn handle(&mut self, language: &str, content: &str, options: &std::collections::HashMap<String, String>) -> Result<String> {
         // Language is passed directly from the directive (e.g., "python", "rust", "typescript")
         // If no language specified, default to "text"
         let language = if language.is_empty() {
@@ -51,9 +98,334 @@ impl DirectiveHandler for CodeBlockHandler {
         // Remove paragraph tags that might have been added by the paragraph converter
         code = code.replace("<p>", "").replace("</p>", "\n");
 
-        // Use the new CodeBlockRenderer
-        self.renderer.render(&code, language, None)
+        // Strip the directive's indentation, then apply :dedent:
+        let code = dedent_lines(&code, None);
+        let code = match options.get("dedent") {
+            Some(amount) if amount.is_empty() => dedent_lines(&code, None),
+            Some(amount) => {
+                let amount = amount.trim().parse::<usize>().map_err(|_| {
+                    crate::core::Error::rst_parse(format!("Invalid :dedent: value '{}'", amount))
+                })?;
+                dedent_lines(&code, Some(amount))
+            }
+            None => code,
+        };
+
+        // :force: needs no handling, since unknown languages fall back to plain text,
+        // and :test: and :no-test: are only read by `peta test-snippets`
+        let block_options = crate::content::rst::CodeBlockOptions::from_directive(options)?;
+        let html = self.renderer.render_with_options(&code, language, &block_options)?;
+
+        if options.contains_key("execute") {
+            let output = self.run(language, &code, options)?;
+            return Ok(format!("<div class=\"code-run\">\n{}\n{}\n</div>\n", html, output));
+        }
+        Ok(format!("{}\n", html))
+    }
+}
+
+/// Remove up to `amount` leading spaces from every line, or the common
+/// indentation when `amount` is `None`, dropping blank lines at both ends
+pub(crate) fn dedent_lines(code: &str, amount: Option<usize>) -> String {
+    let lines: Vec<&str> = code.lines().collect();
+    let first = lines.iter().position(|line| !line.trim().is_empty()).unwrap_or(lines.len());
+    let last = lines.iter().rposition(|line| !line.trim().is_empty()).map_or(first, |i| i + 1);
+    let lines = &lines[first..last];
+
+    let indent = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let common = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| indent(line))
+        .min()
+        .unwrap_or(0);
+    let amount = amount.unwrap_or(common);
+
+    lines
+        .iter()
+        .map(|line| &line[indent(line).min(amount)..])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// literalinclude directive handler
+///
+/// Embeds a source file, or part of it, as a code block. Relative paths
+/// are resolved against the document's directory and paths starting with
+/// `/` against the site root.
+pub struct LiteralIncludeHandler {
+    renderer: crate::content::rst::CodeBlockRenderer,
+    document_dir: Option<std::path::PathBuf>,
+    dependencies: Vec<std::path::PathBuf>,
+}
+
+impl LiteralIncludeHandler {
+    pub fn new() -> Self {
+        Self {
+            renderer: crate::content::rst::CodeBlockRenderer::new()
+                .expect("Failed to create CodeBlockRenderer"),
+            document_dir: None,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Create a handler for a document, rendering with the site's `[code_blocks]` defaults
+    pub fn with_config(
+        config: crate::content::rst::CodeBlockConfig,
+        document_dir: Option<std::path::PathBuf>,
+    ) -> Result<Self> {
+        Ok(Self {
+            renderer: crate::content::rst::CodeBlockRenderer::with_config(config)?,
+            document_dir,
+            dependencies: Vec::new(),
+        })
+    }
+
+    /// Resolve an included path and read it, recording it as a dependency
+    fn read(&mut self, file_ref: &str) -> Result<(std::path::PathBuf, String)> {
+        let path = match file_ref.strip_prefix('/') {
+            Some(from_root) => std::path::PathBuf::from(from_root),
+            None => self
+                .document_dir
+                .as_deref()
+                .unwrap_or_else(|| std::path::Path::new("."))
+                .join(file_ref),
+        };
+
+        let source = std::fs::read_to_string(&path).map_err(|e| {
+            crate::core::Error::content(format!("literalinclude: failed to read {}: {}", path.display(), e))
+        })?;
+        self.dependencies.push(path.canonicalize().unwrap_or_else(|_| path.clone()));
+        Ok((path, source))
+    }
+}
+
+impl Default for LiteralIncludeHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DirectiveHandler for LiteralIncludeHandler {
+    fn handle(&mut self, file_ref: &str, _content: &str, options: &std::collections::HashMap<String, String>) -> Result<String> {
+        let file_ref = file_ref.trim();
+        if file_ref.is_empty() {
+            return Err(crate::core::Error::rst_parse("literalinclude requires a file name"));
+        }
+        let (path, source) = self.read(file_ref)?;
+
+        let mut block_options = crate::content::rst::CodeBlockOptions::from_directive(options)?;
+        if options.get("caption").is_some_and(|c| c.is_empty()) {
+            block_options.caption = Some(file_ref.to_string());
+        }
+
+        // :diff: shows the changes from another file to this one, unfiltered
+        if let Some(old_ref) = options.get("diff") {
+            let (_, old_source) = self.read(old_ref.trim())?;
+            let diff = unified_diff(&old_source, &source, old_ref.trim(), file_ref);
+            let html = self.renderer.render_with_options(&diff, "diff", &block_options)?;
+            return Ok(format!("{}\n", html));
+        }
+
+        let selection = select_lines(&source, options)
+            .map_err(|e| crate::core::Error::rst_parse(format!("literalinclude {}: {}", file_ref, e)))?;
+
+        if options.contains_key("lineno-match") {
+            let first = selection.first_line.ok_or_else(|| {
+                crate::core::Error::rst_parse(format!(
+                    "literalinclude {}: :lineno-match: needs a contiguous selection",
+                    file_ref
+                ))
+            })?;
+            block_options.line_numbers = Some(true);
+            block_options.line_start = Some(first);
+        }
+
+        let code = match options.get("dedent") {
+            Some(amount) if amount.is_empty() => dedent_lines(&selection.code, None),
+            Some(amount) => {
+                let amount = amount.trim().parse::<usize>().map_err(|_| {
+                    crate::core::Error::rst_parse(format!("Invalid :dedent: value '{}'", amount))
+                })?;
+                dedent_lines(&selection.code, Some(amount))
+            }
+            None => selection.code.trim_end_matches('\n').to_string(),
+        };
+
+        let language = match options.get("language") {
+            Some(language) if !language.is_empty() => language.clone(),
+            _ => language_for_path(&path),
+        };
+
+        let html = self.renderer.render_with_options(&code, &language, &block_options)?;
+        Ok(format!("{}\n", html))
+    }
+
+    fn take_dependencies(&mut self) -> Vec<std::path::PathBuf> {
+        std::mem::take(&mut self.dependencies)
+    }
+}
+
+/// Lines picked out of an included file
+struct LineSelection {
+    code: String,
+    /// Source line number of the first line, if the selection is contiguous
+    first_line: Option<usize>,
+}
+
+/// Apply `:pyobject:`, `:region:`, `:start-after:`/`:start-at:`,
+/// `:end-before:`/`:end-at:` and `:lines:`, in that order
+fn select_lines(
+    source: &str,
+    options: &std::collections::HashMap<String, String>,
+) -> std::result::Result<LineSelection, String> {
+    // (source line number, text)
+    let mut lines: Vec<(usize, &str)> = source.lines().enumerate().map(|(i, l)| (i + 1, l)).collect();
+
+    if let Some(object) = options.get("pyobject") {
+        let texts: Vec<&str> = lines.iter().map(|(_, l)| *l).collect();
+        let (start, end) = find_pyobject(&texts, object.trim())
+            .ok_or_else(|| format!("Python object '{}' not found", object.trim()))?;
+        lines = lines[start..end].to_vec();
+    }
+
+    if let Some(region) = options.get("region") {
+        let region = region.trim();
+        let start_marker = format!("[START {}]", region);
+        let end_marker = format!("[END {}]", region);
+        let start = lines
+            .iter()
+            .position(|(_, l)| l.contains(&start_marker))
+            .ok_or_else(|| format!("region '{}' not found", region))?;
+        let end = lines[start..]
+            .iter()
+            .position(|(_, l)| l.contains(&end_marker))
+            .map(|i| start + i)
+            .ok_or_else(|| format!("region '{}' has no {} marker", region, end_marker))?;
+        // Markers of nested regions are dropped too
+        lines = lines[start + 1..end]
+            .iter()
+            .filter(|(_, l)| !is_region_marker(l))
+            .copied()
+            .collect();
+    }
+
+    for (option, inclusive) in [("start-after", false), ("start-at", true)] {
+        if let Some(marker) = options.get(option) {
+            let pos = lines
+                .iter()
+                .position(|(_, l)| l.contains(marker.as_str()))
+                .ok_or_else(|| format!(":{}: marker '{}' not found", option, marker))?;
+            lines.drain(..if inclusive { pos } else { pos + 1 });
+        }
+    }
+
+    for (option, inclusive) in [("end-before", false), ("end-at", true)] {
+        if let Some(marker) = options.get(option) {
+            let pos = lines
+                .iter()
+                .position(|(_, l)| l.contains(marker.as_str()))
+                .ok_or_else(|| format!(":{}: marker '{}' not found", option, marker))?;
+            lines.truncate(if inclusive { pos + 1 } else { pos });
+        }
     }
+
+    if let Some(spec) = options.get("lines") {
+        let picked = crate::content::rst::code_blocks::code_block_renderer::parse_line_spec(spec, Some(lines.len()))
+            .ok_or_else(|| format!("invalid :lines: value '{}'", spec))?;
+        if let Some(line) = picked.iter().find(|&&line| line > lines.len()) {
+            return Err(format!(":lines: selects line {} of {}", line, lines.len()));
+        }
+        lines = picked.iter().map(|&line| lines[line - 1]).collect();
+    }
+
+    let contiguous = lines.windows(2).all(|pair| pair[1].0 == pair[0].0 + 1);
+    Ok(LineSelection {
+        code: lines.iter().map(|(_, l)| *l).collect::<Vec<_>>().join("\n"),
+        first_line: if contiguous { lines.first().map(|(n, _)| *n) } else { None },
+    })
+}
+
+/// Whether a line is a `[START name]` or `[END name]` region marker
+fn is_region_marker(line: &str) -> bool {
+    static MARKER_REGEX: once_cell::sync::Lazy<regex::Regex> =
+        once_cell::sync::Lazy::new(|| regex::Regex::new(r"\[(START|END) [\w.-]+\]").unwrap());
+    MARKER_REGEX.is_match(line)
+}
+
+/// Line range of a Python class or function, including its decorators
+///
+/// Dotted names such as `Parser.parse` are looked up inside the enclosing class.
+fn find_pyobject(lines: &[&str], name: &str) -> Option<(usize, usize)> {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let (mut start, mut end) = (0, lines.len());
+
+    for part in name.split('.') {
+        let definition = regex::Regex::new(&format!(
+            r"^(\s*)(async\s+def|def|class)\s+{}\b",
+            regex::escape(part)
+        ))
+        .ok()?;
+        let def_line = (start..end).find(|&i| definition.is_match(lines[i]))?;
+        let def_indent = indent(lines[def_line]);
+
+        // Decorators directly above belong to the object
+        let mut first = def_line;
+        while first > start && lines[first - 1].trim_start().starts_with('@') {
+            first -= 1;
+        }
+
+        // The body runs until a non-blank line at the same or lower indentation
+        let mut last = def_line + 1;
+        while last < end && (lines[last].trim().is_empty() || indent(lines[last]) > def_indent) {
+            last += 1;
+        }
+        while last > def_line + 1 && lines[last - 1].trim().is_empty() {
+            last -= 1;
+        }
+
+        start = first;
+        end = last;
+    }
+
+    Some((start, end))
+}
+
+/// Unified diff from `old` to `new`
+fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    let split = |text: &str| -> Vec<String> { text.lines().map(|l| format!("{}\n", l)).collect() };
+    difflib::unified_diff(&split(old), &split(new), old_name, new_name, "", "", 3)
+        .iter()
+        .map(|line| line.trim_end_matches(['\n', '\t']))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Highlighting language for a file extension
+pub(crate) fn language_for_path(path: &std::path::Path) -> String {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let language = match extension.as_str() {
+        "py" | "pyw" => "python",
+        "rs" => "rust",
+        "js" | "mjs" | "cjs" => "javascript",
+        "ts" | "tsx" => "typescript",
+        "rb" => "ruby",
+        "sh" | "bash" | "zsh" => "bash",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hpp" => "cpp",
+        "cs" => "csharp",
+        "kt" | "kts" => "kotlin",
+        "yml" | "yaml" => "yaml",
+        "md" => "markdown",
+        "rst" => "rst",
+        "" => "text",
+        other => other,
+    };
+    language.to_string()
 }
 
 /// Snippet card directive handler
@@ -329,6 +701,15 @@ impl DirectiveHandler for MathDirectiveHandler {
         
         let latex = latex_dedented.trim();
         
+        // Bare multi-line content is aligned, as Sphinx does
+        let wrapped;
+        let latex = if latex.contains("\\\\") && !latex.contains("\\begin{") {
+            wrapped = format!("\\begin{{aligned}}\n{}\n\\end{{aligned}}", latex);
+            wrapped.as_str()
+        } else {
+            latex
+        };
+        
         // Render as display math
         let rendered = if let Ok(eq) = self.renderer.render_equation(latex, true) {
             eq
@@ -342,9 +723,9 @@ impl DirectiveHandler for MathDirectiveHandler {
             // The rendered HTML is <div class="math-display" data-latex="..."></div>
             // We need to add data-label="..." to it
             let labeled = rendered.replace(r#"<div class="math-display""#, &format!(r#"<div class="math-display" data-label="{}""#, label));
-            Ok(labeled)
+            Ok(format!("{}\n", labeled))
         } else {
-            Ok(rendered)
+            Ok(format!("{}\n", rendered))
         }
     }
 }
@@ -172,6 +172,7 @@ mod tests {
             title: "Test Snippet".to_string(),
             content_type: ContentType::Snippet,
             date: "2023-01-01".to_string(),
+            date_time: None,
             tags: vec!["test".to_string(), "example".to_string()],
             author: None,
             excerpt: None,
@@ -187,6 +188,7 @@ mod tests {
             frontmatter: HashMap::new(),
             has_math_formulas: false,
             math_formula_count: 0,
+            dependencies: Vec::new(),
         };
 
         let html = renderer.render(&snippet).unwrap();
@@ -205,7 +207,7 @@ mod tests {
         let renderer = EmbeddedSnippetCardRenderer::new().unwrap();
 
         let html_with_headings = "<h1>Level 1</h1><h2>Level 2</h2><h3>Level 3</h3>";
-        let adjusted = renderer.adjust_heading_hierarchy(html_with_headings);
+        let adjusted = renderer.adjust_heading_hierarchy(html_with_headings, "snippet");
 
         assert!(adjusted.contains("<h3>Level 1</h3>"));
         assert!(adjusted.contains("<h4>Level 2</h4>"));
@@ -239,6 +241,7 @@ mod tests {
             title: "Test".to_string(),
             content_type: ContentType::Snippet,
             date: String::new(),
+            date_time: None,
             tags: Vec::new(),
             author: None,
             excerpt: None,
@@ -254,6 +257,7 @@ mod tests {
             frontmatter: HashMap::new(),
             has_math_formulas: false,
             math_formula_count: 0,
+            dependencies: Vec::new(),
         };
 
         let html = renderer.render(&snippet).unwrap();
@@ -0,0 +1,152 @@
+//! Equation numbering and `:eq:` cross-references
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Numbers labeled display equations and resolves `:eq:` references
+///
+/// Runs on a page's HTML after directives and roles have been processed, so
+/// equations from `include`d article parts share one sequence.
+pub struct EquationNumberer {
+    /// Chapter prefix for book chapters numbered as "(3.2)"
+    chapter: Option<usize>,
+}
+
+/// Result of numbering a page
+#[derive(Debug, Clone)]
+pub struct NumberedEquations {
+    /// HTML with numbered equations and linked references
+    pub html: String,
+    /// Duplicate labels and references to unknown labels
+    pub warnings: Vec<String>,
+}
+
+impl EquationNumberer {
+    /// Create a numberer producing (1), (2), ...
+    pub fn new() -> Self {
+        Self { chapter: None }
+    }
+
+    /// Create a numberer prefixing numbers with a chapter number
+    pub fn with_chapter(chapter: Option<usize>) -> Self {
+        Self { chapter }
+    }
+
+    /// Number labeled equations and link references to them
+    pub fn apply(&self, html: &str) -> NumberedEquations {
+        static LABELED_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#"<div class="math-display" data-label="([^"]+)"[^>]*></div>"#).unwrap()
+        });
+        static REF_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#"<a class="math-eq-ref" data-eq-ref="([^"]*)"></a>"#).unwrap()
+        });
+
+        let mut numbers: HashMap<String, String> = HashMap::new();
+        let mut warnings = Vec::new();
+
+        let numbered = LABELED_REGEX.replace_all(html, |caps: &regex::Captures| {
+            let label = caps[1].to_string();
+            let number = self.format_number(numbers.len() + 1);
+
+            if numbers.contains_key(&label) {
+                warnings.push(format!("duplicate equation label '{}'", label));
+                return caps[0].to_string();
+            }
+            numbers.insert(label.clone(), number.clone());
+
+            format!(
+                r#"<div class="math-equation" id="{}">{}<span class="math-equation-number">({})</span></div>"#,
+                Self::anchor(&label),
+                &caps[0],
+                number
+            )
+        });
+
+        let html = REF_REGEX.replace_all(&numbered, |caps: &regex::Captures| {
+            let label = &caps[1];
+            match numbers.get(label) {
+                Some(number) => format!(
+                    r##"<a class="math-eq-ref" href="#{}">({})</a>"##,
+                    Self::anchor(label),
+                    number
+                ),
+                None => {
+                    warnings.push(format!("unknown equation label '{}'", label));
+                    format!(
+                        r#"<span class="math-eq-ref math-eq-ref-unknown" title="Unknown equation {}">(??)</span>"#,
+                        label
+                    )
+                }
+            }
+        });
+
+        NumberedEquations {
+            html: html.into_owned(),
+            warnings,
+        }
+    }
+
+    /// Format the n-th equation number of the page
+    fn format_number(&self, n: usize) -> String {
+        match self.chapter {
+            Some(chapter) => format!("{}.{}", chapter, n),
+            None => n.to_string(),
+        }
+    }
+
+    /// Element id of a labeled equation
+    fn anchor(label: &str) -> String {
+        let slug: String = label
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+            .collect();
+        format!("eq-{}", slug)
+    }
+}
+
+impl Default for EquationNumberer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = concat!(
+        r#"<p>By <a class="math-eq-ref" data-eq-ref="euler"></a></p>"#,
+        r#"<div class="math-display" data-label="euler" data-latex="e^{i\pi} = -1"></div>"#,
+        r#"<div class="math-display" data-latex="x"></div>"#,
+        r#"<div class="math-display" data-label="pythagoras" data-latex="a^2 + b^2 = c^2"></div>"#,
+    );
+
+    #[test]
+    fn test_numbers_labeled_equations() {
+        let result = EquationNumberer::new().apply(PAGE);
+
+        assert!(result.html.contains(r#"<div class="math-equation" id="eq-euler"><div class="math-display" data-label="euler""#));
+        assert!(result.html.contains(r#"<span class="math-equation-number">(1)</span>"#));
+        assert!(result.html.contains(r#"<span class="math-equation-number">(2)</span>"#));
+        assert!(result.html.contains(r##"<a class="math-eq-ref" href="#eq-euler">(1)</a>"##));
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_chapter_numbering() {
+        let result = EquationNumberer::with_chapter(Some(3)).apply(PAGE);
+
+        assert!(result.html.contains(r#"<span class="math-equation-number">(3.2)</span>"#));
+        assert!(result.html.contains(r##"href="#eq-euler">(3.1)</a>"##));
+    }
+
+    #[test]
+    fn test_unknown_label_warns() {
+        let html = r#"<a class="math-eq-ref" data-eq-ref="missing"></a>"#;
+        let result = EquationNumberer::new().apply(html);
+
+        assert!(result.html.contains("(??)"));
+        assert_eq!(result.warnings, vec!["unknown equation label 'missing'"]);
+    }
+}
@@ -0,0 +1,286 @@
+//! Self-hosted KaTeX assets for offline math rendering
+
+use crate::core::config::{KatexConfig, KatexSource, MathRenderMode, MathRenderingConfig};
+use crate::core::{Error, Result};
+use base64::Engine;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha384};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directory under the assets output holding the vendored bundle
+pub const KATEX_ASSET_DIR: &str = "katex";
+
+/// Where bundles fetched from the CDN for build-time math are kept
+pub const CACHE_DIR: &str = ".peta_cache/katex";
+
+/// Relative `url(...)` references of a stylesheet, i.e. KaTeX's fonts
+static CSS_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"url\(\s*['"]?([^'")]+?)['"]?\s*\)"#).unwrap()
+});
+
+/// Files a KaTeX bundle must provide
+const REQUIRED_FILES: [&str; 2] = ["katex.min.js", "katex.min.css"];
+
+/// Optional auto-render extension shipped in KaTeX's `dist/contrib`
+const AUTO_RENDER_FILE: &str = "contrib/auto-render.min.js";
+
+/// A vendored file and its subresource integrity hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VendoredAsset {
+    /// URL the page loads the file from
+    pub url: String,
+    /// `sha384-...` value for the `integrity` attribute
+    pub integrity: String,
+}
+
+/// KaTeX files served from the site instead of a CDN
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KatexAssets {
+    pub css: VendoredAsset,
+    pub js: VendoredAsset,
+    pub auto_render: Option<VendoredAsset>,
+}
+
+/// Copy the configured local KaTeX bundle into `assets_dir`
+///
+/// Returns `None` when KaTeX is loaded from the CDN. A local source without
+/// a complete bundle is an error, so the build stops instead of shipping
+/// pages whose math never loads.
+pub fn vendor_katex(config: &KatexConfig, assets_dir: &Path, base_url: &str) -> Result<Option<KatexAssets>> {
+    if config.source != KatexSource::Local {
+        return Ok(None);
+    }
+
+    let bundle_dir = config.path.as_deref().ok_or_else(|| {
+        Error::asset("[math_rendering.katex] source = \"local\" requires a path to the KaTeX dist directory")
+    })?;
+    let bundle_dir = Path::new(bundle_dir);
+
+    for file in REQUIRED_FILES.iter().copied().chain(std::iter::once("fonts")) {
+        if !bundle_dir.join(file).exists() {
+            return Err(Error::asset(format!(
+                "KaTeX bundle {} is missing {}",
+                bundle_dir.display(),
+                file
+            )));
+        }
+    }
+
+    let target_dir = assets_dir.join(KATEX_ASSET_DIR);
+    copy_bundle(bundle_dir, &target_dir)?;
+
+    let asset = |file: &str| -> Result<VendoredAsset> {
+        let content = fs::read(target_dir.join(file))
+            .map_err(|e| Error::asset(format!("Failed to read vendored {}: {}", file, e)))?;
+        Ok(VendoredAsset {
+            url: crate::utils::url::build_url(base_url, &format!("assets/{}/{}", KATEX_ASSET_DIR, file)),
+            integrity: integrity(&content),
+        })
+    };
+
+    Ok(Some(KatexAssets {
+        css: asset("katex.min.css")?,
+        js: asset("katex.min.js")?,
+        auto_render: if target_dir.join(AUTO_RENDER_FILE).exists() {
+            Some(asset(AUTO_RENDER_FILE)?)
+        } else {
+            None
+        },
+    }))
+}
+
+/// KaTeX settings to vendor the bundle with
+///
+/// Build-time math ships KaTeX markup, which only renders with the stylesheet
+/// and fonts of the KaTeX version that produced it. With `source = "cdn"`
+/// that bundle is downloaded from `cdn_base` into `cache_dir` once and then
+/// vendored like a local one, so the built pages make no CDN requests.
+pub async fn katex_bundle_config(math: &MathRenderingConfig, cache_dir: &Path) -> Result<KatexConfig> {
+    let mut config = math.katex.clone();
+    if math.mode == MathRenderMode::Build && config.source == KatexSource::Cdn {
+        let bundle = download_katex(&config.cdn_base, katex::KATEX_VERSION.trim(), cache_dir).await?;
+        config.source = KatexSource::Local;
+        config.path = Some(bundle.to_string_lossy().into_owned());
+    }
+    Ok(config)
+}
+
+/// Download the `dist` bundle of KaTeX `version` into `cache_dir/<version>`
+///
+/// Fetches the JS, the stylesheet and every font the stylesheet references.
+/// A complete earlier download is reused.
+pub async fn download_katex(cdn_base: &str, version: &str, cache_dir: &Path) -> Result<PathBuf> {
+    let bundle_dir = cache_dir.join(version);
+    if bundle_dir.join("fonts").is_dir() && REQUIRED_FILES.iter().all(|file| bundle_dir.join(file).is_file()) {
+        return Ok(bundle_dir);
+    }
+
+    let dist = format!("{}@{}/dist", cdn_base.trim_end_matches('/'), version);
+    let client = reqwest::Client::new();
+    let fetch = |file: String| {
+        let client = &client;
+        let url = format!("{}/{}", dist, file);
+        async move {
+            let response = client
+                .get(&url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(|e| Error::asset(format!("Failed to download {}: {}", url, e)))?;
+            let content = response
+                .bytes()
+                .await
+                .map_err(|e| Error::asset(format!("Failed to download {}: {}", url, e)))?;
+            Ok::<_, Error>((file, content))
+        }
+    };
+
+    let mut files = vec![fetch("katex.min.js".to_string()).await?];
+    let css = fetch("katex.min.css".to_string()).await?;
+    let fonts: Vec<String> = CSS_URL_REGEX
+        .captures_iter(&String::from_utf8_lossy(&css.1))
+        .map(|c| c[1].to_string())
+        .filter(|url| !url.contains(':') && !url.starts_with('/') && !url.split('/').any(|part| part == ".."))
+        .collect();
+    files.push(css);
+    for font in fonts {
+        files.push(fetch(font).await?);
+    }
+
+    // Written aside and renamed, so an interrupted download is never reused
+    let partial = cache_dir.join(format!("{}.partial", version));
+    if partial.exists() {
+        fs::remove_dir_all(&partial)
+            .map_err(|e| Error::asset(format!("Failed to clear {}: {}", partial.display(), e)))?;
+    }
+    fs::create_dir_all(partial.join("fonts"))
+        .map_err(|e| Error::asset(format!("Failed to create {}: {}", partial.display(), e)))?;
+    for (file, content) in files {
+        let target = partial.join(&file);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::asset(format!("Failed to create {}: {}", parent.display(), e)))?;
+        }
+        fs::write(&target, content)
+            .map_err(|e| Error::asset(format!("Failed to write {}: {}", target.display(), e)))?;
+    }
+    if bundle_dir.exists() {
+        fs::remove_dir_all(&bundle_dir)
+            .map_err(|e| Error::asset(format!("Failed to clear {}: {}", bundle_dir.display(), e)))?;
+    }
+    fs::rename(&partial, &bundle_dir)
+        .map_err(|e| Error::asset(format!("Failed to move {}: {}", partial.display(), e)))?;
+
+    Ok(bundle_dir)
+}
+
+/// Copy the stylesheet, scripts and fonts of a KaTeX bundle
+fn copy_bundle(bundle_dir: &Path, target_dir: &Path) -> Result<()> {
+    let mut files: Vec<_> = REQUIRED_FILES.iter().map(|file| bundle_dir.join(file)).collect();
+    files.push(bundle_dir.join(AUTO_RENDER_FILE));
+    files.extend(
+        WalkDir::new(bundle_dir.join("fonts"))
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path()),
+    );
+
+    for source in files.into_iter().filter(|path| path.is_file()) {
+        let relative = source.strip_prefix(bundle_dir).unwrap_or(&source);
+        let target = target_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::asset(format!("Failed to create {}: {}", parent.display(), e)))?;
+        }
+        fs::copy(&source, &target)
+            .map_err(|e| Error::asset(format!("Failed to copy {}: {}", source.display(), e)))?;
+    }
+
+    Ok(())
+}
+
+/// Subresource integrity value for `content`
+fn integrity(content: &[u8]) -> String {
+    let digest = Sha384::digest(content);
+    format!("sha384-{}", base64::engine::general_purpose::STANDARD.encode(digest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn local_config(path: &Path) -> KatexConfig {
+        KatexConfig {
+            source: KatexSource::Local,
+            path: Some(path.to_string_lossy().to_string()),
+            ..KatexConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_cdn_source_vendors_nothing() {
+        let out = TempDir::new().unwrap();
+        let assets = vendor_katex(&KatexConfig::default(), out.path(), "").unwrap();
+        assert!(assets.is_none());
+        assert!(!out.path().join(KATEX_ASSET_DIR).exists());
+    }
+
+    #[test]
+    fn test_vendor_local_bundle() {
+        let bundle = TempDir::new().unwrap();
+        fs::write(bundle.path().join("katex.min.js"), "var katex;").unwrap();
+        fs::write(bundle.path().join("katex.min.css"), ".katex{}").unwrap();
+        fs::create_dir_all(bundle.path().join("fonts")).unwrap();
+        fs::write(bundle.path().join("fonts/KaTeX_Main-Regular.woff2"), [0u8; 4]).unwrap();
+
+        let out = TempDir::new().unwrap();
+        let assets = vendor_katex(&local_config(bundle.path()), out.path(), "/docs")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(assets.css.url, "/docs/assets/katex/katex.min.css");
+        assert_eq!(assets.js.integrity, integrity(b"var katex;"));
+        assert!(assets.js.integrity.starts_with("sha384-"));
+        assert!(assets.auto_render.is_none());
+        assert!(out.path().join("katex/fonts/KaTeX_Main-Regular.woff2").exists());
+    }
+
+    #[tokio::test]
+    async fn test_downloaded_bundle_is_reused() {
+        let cache = TempDir::new().unwrap();
+        let bundle = cache.path().join("0.16.9");
+        fs::create_dir_all(bundle.join("fonts")).unwrap();
+        fs::write(bundle.join("katex.min.js"), "var katex;").unwrap();
+        fs::write(bundle.join("katex.min.css"), ".katex{}").unwrap();
+
+        // Nothing listens on the discard port, so any request would fail
+        let dir = download_katex("http://127.0.0.1:9/npm/katex", "0.16.9", cache.path()).await.unwrap();
+        assert_eq!(dir, bundle);
+        assert!(download_katex("http://127.0.0.1:9/npm/katex", "0.16.10", cache.path()).await.is_err());
+        assert!(!cache.path().join("0.16.10").exists());
+    }
+
+    #[tokio::test]
+    async fn test_client_mode_keeps_the_cdn() {
+        let cache = TempDir::new().unwrap();
+        let config = katex_bundle_config(&MathRenderingConfig::default(), cache.path()).await.unwrap();
+        assert_eq!(config.source, KatexSource::Cdn);
+        assert!(fs::read_dir(cache.path()).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn test_missing_bundle_is_an_error() {
+        let bundle = TempDir::new().unwrap();
+        fs::write(bundle.path().join("katex.min.js"), "var katex;").unwrap();
+
+        let out = TempDir::new().unwrap();
+        let err = vendor_katex(&local_config(bundle.path()), out.path(), "").unwrap_err();
+        assert!(err.to_string().contains("missing katex.min.css"));
+    }
+}
@@ -67,6 +67,10 @@ impl MathCssGenerator {
         css.push_str(&self.generate_inline_styles());
         css.push('\n');
 
+        // Numbered equation styles
+        css.push_str(&self.generate_equation_styles());
+        css.push('\n');
+
         // KaTeX base styles
         css.push_str(&self.generate_katex_base_styles());
         css.push('\n');
@@ -133,6 +137,37 @@ impl MathCssGenerator {
         )
     }
 
+    /// Generate numbered equation and reference styles
+    fn generate_equation_styles(&self) -> String {
+        r#"/* Numbered Equation Styles */
+.math-equation {
+    display: flex;
+    align-items: center;
+    scroll-margin-top: 4em;
+}
+
+.math-equation > .math-display {
+    flex: 1;
+    min-width: 0;
+}
+
+.math-equation-number {
+    flex-shrink: 0;
+    margin-left: 1em;
+    white-space: nowrap;
+}
+
+.math-eq-ref {
+    white-space: nowrap;
+}
+
+.math-eq-ref-unknown {
+    color: #dc2626;
+}
+"#
+        .to_string()
+    }
+
     /// Generate KaTeX base styles
     fn generate_katex_base_styles(&self) -> String {
         r#"/* KaTeX Base Styles */
@@ -269,6 +304,7 @@ mod tests {
 
         assert!(css.contains(".math-display"));
         assert!(css.contains(".math-inline"));
+        assert!(css.contains(".math-equation-number"));
         assert!(css.contains("@media print"));
         assert!(css.contains("@media (max-width: 768px)"));
     }
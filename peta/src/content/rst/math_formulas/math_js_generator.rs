@@ -1,7 +1,9 @@
 //! JavaScript generator for math formulas
 
+use super::katex_assets::{KatexAssets, VendoredAsset};
 use crate::core::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// JavaScript generator for math formulas
 pub struct MathJsGenerator {
@@ -23,6 +25,10 @@ pub struct MathJsConfig {
     pub debug_mode: bool,
     /// Support for modals
     pub modal_support: bool,
+    /// Self-hosted KaTeX replacing the CDN
+    pub local_assets: Option<KatexAssets>,
+    /// Site-wide LaTeX macros passed to every render call
+    pub macros: BTreeMap<String, String>,
 }
 
 impl Default for MathJsConfig {
@@ -34,6 +40,8 @@ impl Default for MathJsConfig {
             auto_render: true,
             debug_mode: false,
             modal_support: true,
+            local_assets: None,
+            macros: BTreeMap::new(),
         }
     }
 }
@@ -105,8 +113,10 @@ if (typeof window.petaMathLoaded === 'undefined') {{
     window.petaMathLoaded = false;
     window.petaMathPending = false;
 }}
+window.petaMathMacros = {};
 
 {}"#,
+            serde_json::to_string(&self.config.macros).unwrap_or_else(|_| "{}".to_string()),
             if self.config.debug_mode {
                 "console.log('[Peta Math] Initialized');".to_string()
             } else {
@@ -117,18 +127,45 @@ if (typeof window.petaMathLoaded === 'undefined') {{
 
     /// Generate loader
     fn generate_loader(&self) -> String {
-        let css_url = format!(
-            "{}@{}/dist/katex.min.css",
-            self.config.cdn_base, self.config.katex_version
-        );
-        let js_url = format!(
-            "{}@{}/dist/katex.min.js",
-            self.config.cdn_base, self.config.katex_version
-        );
-        let auto_render_url = format!(
-            "{}@{}/dist/contrib/auto-render.min.js",
-            self.config.cdn_base, self.config.katex_version
-        );
+        let cdn_asset = |file: &str| VendoredAsset {
+            url: format!(
+                "{}@{}/dist/{}",
+                self.config.cdn_base, self.config.katex_version, file
+            ),
+            integrity: String::new(),
+        };
+        let (css, js, auto_render) = match &self.config.local_assets {
+            Some(assets) => (
+                assets.css.clone(),
+                assets.js.clone(),
+                assets.auto_render.clone(),
+            ),
+            None => (
+                cdn_asset("katex.min.css"),
+                cdn_asset("katex.min.js"),
+                Some(cdn_asset("contrib/auto-render.min.js")),
+            ),
+        };
+
+        let on_katex_load = match &auto_render {
+            Some(auto_render) => format!(
+                r#"            // Load auto-render extension
+            const autoRender = document.createElement('script');
+            autoRender.src = '{}';
+            autoRender.async = true;{}
+
+            autoRender.onload = function() {{
+{}
+            }};
+
+            autoRender.onerror = reject;
+            document.body.appendChild(autoRender);"#,
+                auto_render.url,
+                Self::integrity_js("autoRender", &auto_render.integrity, "            "),
+                self.loaded_js("                ")
+            ),
+            None => self.loaded_js("            "),
+        };
 
         format!(
             r#"// Load KaTeX on demand
@@ -142,29 +179,17 @@ function loadKaTeX() {{
         if (!document.querySelector('link[href*="katex.min.css"]')) {{
             const css = document.createElement('link');
             css.rel = 'stylesheet';
-            css.href = '{}';
+            css.href = '{}';{}
             document.head.appendChild(css);
         }}
 
         // Load KaTeX JS
         const katex = document.createElement('script');
         katex.src = '{}';
-        katex.async = true;
+        katex.async = true;{}
 
         katex.onload = function() {{
-            // Load auto-render extension
-            const autoRender = document.createElement('script');
-            autoRender.src = '{}';
-            autoRender.async = true;
-
-            autoRender.onload = function() {{
-                window.petaMathLoaded = true;
-                {}
-                resolve();
-            }};
-
-            autoRender.onerror = reject;
-            document.body.appendChild(autoRender);
+{}
         }};
 
         katex.onerror = reject;
@@ -172,14 +197,32 @@ function loadKaTeX() {{
     }});
 }}
 "#,
-            css_url,
-            js_url,
-            auto_render_url,
-            if self.config.debug_mode {
-                "console.log('[Peta Math] KaTeX loaded');"
-            } else {
-                ""
-            }
+            css.url,
+            Self::integrity_js("css", &css.integrity, "            "),
+            js.url,
+            Self::integrity_js("katex", &js.integrity, "        "),
+            on_katex_load
+        )
+    }
+
+    /// Mark KaTeX as loaded and resolve the loader promise
+    fn loaded_js(&self, indent: &str) -> String {
+        let mut js = format!("{}window.petaMathLoaded = true;\n", indent);
+        if self.config.debug_mode {
+            js.push_str(&format!("{}console.log('[Peta Math] KaTeX loaded');\n", indent));
+        }
+        js.push_str(&format!("{}resolve();", indent));
+        js
+    }
+
+    /// Set the subresource integrity of a loaded element, if known
+    fn integrity_js(element: &str, integrity: &str, indent: &str) -> String {
+        if integrity.is_empty() {
+            return String::new();
+        }
+        format!(
+            "\n{2}{0}.integrity = '{1}';\n{2}{0}.crossOrigin = 'anonymous';",
+            element, integrity, indent
         )
     }
 
@@ -202,7 +245,8 @@ function renderMath() {
                 window.katex.render(latex, el, {
                     displayMode: el.classList.contains('math-display'),
                     throwOnError: false,
-                    trust: true
+                    trust: true,
+                    macros: Object.assign({}, window.petaMathMacros)
                 });
             } catch (e) {
                 handleError(e, el);
@@ -340,4 +384,37 @@ mod tests {
         assert!(!js.contains("setupModalSupport"));
         assert!(!js.contains("MutationObserver"));
     }
+
+    #[test]
+    fn test_local_assets_with_integrity() {
+        let asset = |file: &str| VendoredAsset {
+            url: format!("/assets/katex/{}", file),
+            integrity: format!("sha384-{}", file),
+        };
+        let config = MathJsConfig {
+            local_assets: Some(KatexAssets {
+                css: asset("katex.min.css"),
+                js: asset("katex.min.js"),
+                auto_render: None,
+            }),
+            ..MathJsConfig::default()
+        };
+        let js = MathJsGenerator::with_config(config).unwrap().generate().unwrap();
+
+        assert!(!js.contains("cdn.jsdelivr.net"));
+        assert!(js.contains("katex.src = '/assets/katex/katex.min.js';"));
+        assert!(js.contains("katex.integrity = 'sha384-katex.min.js';"));
+        assert!(js.contains("css.integrity = 'sha384-katex.min.css';"));
+        assert!(!js.contains("autoRender.src"));
+    }
+
+    #[test]
+    fn test_macros_passed_to_katex() {
+        let mut config = MathJsConfig::default();
+        config.macros.insert("\\R".to_string(), "\\mathbb{R}".to_string());
+        let js = MathJsGenerator::with_config(config).unwrap().generate().unwrap();
+
+        assert!(js.contains(r#"window.petaMathMacros = {"\\R":"\\mathbb{R}"};"#));
+        assert!(js.contains("macros: Object.assign({}, window.petaMathMacros)"));
+    }
 }
@@ -0,0 +1,194 @@
+//! Site-wide LaTeX macros applied to every formula
+
+use crate::core::{Error, Result, SiteConfig};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Macro file read from the content directory
+pub const MACROS_FILE: &str = "macros.tex";
+
+/// Macros from `macros.tex` overridden by `[math_rendering.macros]`
+///
+/// Keys are normalized to start with a backslash, as KaTeX expects, so
+/// `R = "\\mathbb{R}"` and `"\\R" = "\\mathbb{R}"` define the same macro.
+pub fn load_math_macros(config: &SiteConfig) -> Result<BTreeMap<String, String>> {
+    let mut macros = BTreeMap::new();
+
+    let macros_path = Path::new(&config.build.content_dir).join(MACROS_FILE);
+    if macros_path.exists() {
+        let source = std::fs::read_to_string(&macros_path)
+            .map_err(|e| Error::content(format!("Failed to read {}: {}", macros_path.display(), e)))?;
+        macros.extend(
+            parse_macros(&source)
+                .map_err(|e| Error::content(format!("Invalid {}: {}", macros_path.display(), e)))?,
+        );
+    }
+
+    for (name, expansion) in &config.math_rendering.macros {
+        macros.insert(normalize_name(name), expansion.clone());
+    }
+
+    Ok(macros)
+}
+
+/// Parse `\newcommand`, `\renewcommand` and `\def` definitions
+///
+/// Argument counts such as `[1]` are dropped, since KaTeX infers them from
+/// the `#1` placeholders of the expansion.
+pub fn parse_macros(source: &str) -> std::result::Result<BTreeMap<String, String>, String> {
+    let mut macros = BTreeMap::new();
+    let source: String = source
+        .lines()
+        .map(strip_comment)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut rest = source.trim_start();
+
+    while !rest.is_empty() {
+        let (name, after_name) = if let Some(after) = rest
+            .strip_prefix("\\newcommand")
+            .or_else(|| rest.strip_prefix("\\renewcommand"))
+        {
+            let after = after.trim_start().strip_prefix('*').unwrap_or(after).trim_start();
+            match after.strip_prefix('{') {
+                Some(braced) => {
+                    let (name, after) = take_group(braced)?;
+                    (name.trim().to_string(), after)
+                }
+                None => take_control_sequence(after)?,
+            }
+        } else if let Some(after) = rest.strip_prefix("\\def") {
+            take_control_sequence(after.trim_start())?
+        } else {
+            let line = rest.lines().next().unwrap_or(rest);
+            return Err(format!("expected \\newcommand or \\def, found `{}`", line.trim()));
+        };
+
+        let mut after_name = after_name.trim_start();
+        // Skip an argument count or \def parameter text
+        if let Some(after) = after_name.strip_prefix('[') {
+            let end = after.find(']').ok_or_else(|| format!("unclosed argument count for {}", name))?;
+            after_name = after[end + 1..].trim_start();
+        }
+        while let Some(after) = after_name.strip_prefix('#') {
+            after_name = after.trim_start_matches(|c: char| c.is_ascii_digit()).trim_start();
+        }
+
+        let body = after_name
+            .strip_prefix('{')
+            .ok_or_else(|| format!("missing definition for {}", name))?;
+        let (expansion, after) = take_group(body)?;
+
+        macros.insert(normalize_name(&name), expansion.trim().to_string());
+        rest = after.trim_start();
+    }
+
+    Ok(macros)
+}
+
+/// Drop a `%` comment, keeping escaped `\%`
+fn strip_comment(line: &str) -> &str {
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '\\' => escaped = !escaped,
+            '%' if !escaped => return &line[..i],
+            _ => escaped = false,
+        }
+    }
+    line
+}
+
+/// Split a brace group whose opening brace was consumed into body and rest
+fn take_group(text: &str) -> std::result::Result<(&str, &str), String> {
+    let mut depth = 1;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '\\' => {
+                escaped = !escaped;
+                continue;
+            }
+            '{' if !escaped => depth += 1,
+            '}' if !escaped => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((&text[..i], &text[i + 1..]));
+                }
+            }
+            _ => {}
+        }
+        escaped = false;
+    }
+    Err("unbalanced braces".to_string())
+}
+
+/// Split a leading `\name` from the rest
+fn take_control_sequence(text: &str) -> std::result::Result<(String, &str), String> {
+    let after = text
+        .strip_prefix('\\')
+        .ok_or_else(|| format!("expected a macro name, found `{}`", text.lines().next().unwrap_or("")))?;
+    let end = after
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphabetic())
+        .map(|(i, _)| i)
+        .unwrap_or(after.len());
+    // Single-character control symbols such as \,
+    let end = if end == 0 { after.chars().next().map_or(0, char::len_utf8) } else { end };
+    Ok((format!("\\{}", &after[..end]), &after[end..]))
+}
+
+/// Prefix a macro name with a backslash if it has none
+fn normalize_name(name: &str) -> String {
+    if name.starts_with('\\') {
+        name.to_string()
+    } else {
+        format!("\\{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_macros() {
+        let source = r#"
+% Number sets
+\newcommand{\R}{\mathbb{R}}
+\renewcommand{\norm}[1]{\left\| #1 \right\|}  % with an argument
+\def\abs#1{\left| #1 \right|}
+\newcommand\set[1]{\{ #1 \}}
+"#;
+        let macros = parse_macros(source).unwrap();
+
+        assert_eq!(macros["\\R"], "\\mathbb{R}");
+        assert_eq!(macros["\\norm"], "\\left\\| #1 \\right\\|");
+        assert_eq!(macros["\\abs"], "\\left| #1 \\right|");
+        assert_eq!(macros["\\set"], "\\{ #1 \\}");
+    }
+
+    #[test]
+    fn test_invalid_macros() {
+        assert!(parse_macros("\\newcommand{\\R}{\\mathbb{R}").is_err());
+        assert!(parse_macros("\\R = \\mathbb{R}").is_err());
+    }
+
+    #[test]
+    fn test_config_overrides_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(MACROS_FILE),
+            "\\newcommand{\\R}{\\mathbb{R}}\n\\newcommand{\\C}{\\mathbb{C}}\n",
+        )
+        .unwrap();
+
+        let mut config = SiteConfig::default();
+        config.build.content_dir = dir.path().to_string_lossy().to_string();
+        config.math_rendering.macros.insert("R".to_string(), "\\mathbf{R}".to_string());
+
+        let macros = load_math_macros(&config).unwrap();
+        assert_eq!(macros["\\R"], "\\mathbf{R}");
+        assert_eq!(macros["\\C"], "\\mathbb{C}");
+    }
+}
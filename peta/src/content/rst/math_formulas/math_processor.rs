@@ -1,8 +1,11 @@
 //! Math processing for RST content with KaTeX integration
 
+use super::math_renderer::{split_display_override, unescape_latex_attribute};
+use crate::core::config::MathOutput;
 use crate::core::{Error, Result};
+use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Math block extracted from RST content
 #[derive(Debug, Clone)]
@@ -26,7 +29,7 @@ pub struct MathProcessor {
     /// Regex patterns for math detection
     display_math_regex: Regex,
     inline_math_regex: Regex,
-    /// Cached rendered formulas
+    /// Cached rendered formulas, keyed by formula hash
     render_cache: HashMap<String, String>,
     /// Cached detection results
     detection_cache: HashMap<String, MathDetectionResult>,
@@ -60,6 +63,10 @@ pub struct MathConfig {
     pub display_delimiters: (String, String),
     /// Inline math delimiters
     pub inline_delimiters: (String, String),
+    /// Markup produced by KaTeX
+    pub output: MathOutput,
+    /// Macros applied to every formula
+    pub macros: BTreeMap<String, String>,
 }
 
 impl Default for MathConfig {
@@ -71,6 +78,8 @@ impl Default for MathConfig {
             cache_rendered: true,
             display_delimiters: ("$$".to_string(), "$$".to_string()),
             inline_delimiters: ("$".to_string(), "$".to_string()),
+            output: MathOutput::default(),
+            macros: BTreeMap::new(),
         }
     }
 }
@@ -90,7 +99,7 @@ impl MathProcessor {
             .map_err(|e| Error::content(format!("Invalid inline math regex: {}", e)))?;
         
         Ok(Self {
-            katex_renderer: KatexRenderer::new()?,
+            katex_renderer: KatexRenderer::with_output(config.output)?.with_macros(config.macros.clone()),
             display_math_regex,
             inline_math_regex,
             render_cache: HashMap::new(),
@@ -129,7 +138,7 @@ impl MathProcessor {
             math_blocks.push(MathBlock {
                 original: full_match.as_str().to_string(),
                 formula: self.clean_formula(formula_match.as_str()),
-                display: true,
+                display: split_display_override(formula_match.as_str()).0.unwrap_or(true),
                 start_pos: full_match.start(),
                 end_pos: full_match.end(),
             });
@@ -151,16 +160,17 @@ impl MathProcessor {
             
             let formula_match = caps.get(1).unwrap();
             
-            // Skip if this looks like it should be display math
+            // Skip if this looks like it should be display math, unless marked
             let formula = formula_match.as_str();
-            if self.should_be_display_math(formula) {
+            let forced = split_display_override(formula).0;
+            if forced.is_none() && self.should_be_display_math(formula) {
                 continue;
             }
             
             math_blocks.push(MathBlock {
                 original: full_match.as_str().to_string(),
                 formula: self.clean_formula(formula),
-                display: false,
+                display: forced.unwrap_or(false),
                 start_pos: full_match.start(),
                 end_pos: full_match.end(),
             });
@@ -171,7 +181,7 @@ impl MathProcessor {
     
     /// Clean formula by removing extra delimiters and fixing common issues
     fn clean_formula(&self, formula: &str) -> String {
-        let mut cleaned = formula.trim().to_string();
+        let mut cleaned = split_display_override(formula.trim()).1.to_string();
         
         // Remove extra delimiters
         cleaned = cleaned.replace("$$", "");
@@ -199,7 +209,13 @@ impl MathProcessor {
     }
     
     /// Check if formula should be display math
+    ///
+    /// A leading `%display` or `%inline` marker overrides the heuristics.
     fn should_be_display_math(&self, formula: &str) -> bool {
+        if let (Some(display), _) = split_display_override(formula) {
+            return display;
+        }
+        
         // Contains display-style operators
         let display_indicators = [
             r"\int_", r"\sum_", r"\prod_", r"\lim_", r"\begin{", r"\end{",
@@ -245,28 +261,64 @@ impl MathProcessor {
     
     /// Render a single math block
     fn render_math_block(&mut self, block: &MathBlock) -> Result<String> {
-        // Check cache first
+        if self.config.use_katex {
+            self.render_formula(&block.formula, block.display)
+        } else {
+            // Fallback to MathJax or plain text
+            self.render_fallback(&block.formula, block.display)
+        }
+    }
+    
+    /// Render a formula with KaTeX, going through the render cache
+    pub fn render_formula(&mut self, formula: &str, display: bool) -> Result<String> {
+        let cache_key = self.hash_content(&format!("{}:{}", display, formula));
         if self.config.cache_rendered {
-            if let Some(cached) = self.render_cache.get(&block.formula) {
+            if let Some(cached) = self.render_cache.get(&cache_key) {
                 return Ok(cached.clone());
             }
         }
         
-        let rendered = if self.config.use_katex {
-            self.katex_renderer.render(&block.formula, block.display)?
-        } else {
-            // Fallback to MathJax or plain text
-            self.render_fallback(&block.formula, block.display)?
-        };
+        let rendered = self.katex_renderer.render(formula, display)?;
         
-        // Cache the result
         if self.config.cache_rendered {
-            self.render_cache.insert(block.formula.clone(), rendered.clone());
+            self.render_cache.insert(cache_key, rendered.clone());
         }
         
         Ok(rendered)
     }
     
+    /// Replace `data-latex` placeholders in rendered HTML with KaTeX markup
+    ///
+    /// The placeholder element and its other attributes (such as `data-label`)
+    /// are kept, so pages need no JavaScript to display the formula.
+    pub fn render_placeholders(&mut self, html: &str) -> Result<String> {
+        static PLACEHOLDER_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#"<(div|span) class="math-(display|inline)"([^>]*?) data-latex="([^"]*)"([^>]*)></(?:div|span)>"#).unwrap()
+        });
+        
+        let mut result = String::with_capacity(html.len());
+        let mut last_end = 0;
+        
+        for caps in PLACEHOLDER_REGEX.captures_iter(html) {
+            let full_match = caps.get(0).unwrap();
+            let tag = &caps[1];
+            let kind = &caps[2];
+            let formula = unescape_latex_attribute(&caps[4]);
+            
+            let rendered = self.render_formula(formula.trim(), kind == "display")?;
+            
+            result.push_str(&html[last_end..full_match.start()]);
+            result.push_str(&format!(
+                r#"<{} class="math-{}"{}{}>{}</{}>"#,
+                tag, kind, &caps[3], &caps[5], rendered, tag
+            ));
+            last_end = full_match.end();
+        }
+        
+        result.push_str(&html[last_end..]);
+        Ok(result)
+    }
+    
     /// Render math with fallback method
     fn render_fallback(&self, formula: &str, display: bool) -> Result<String> {
         if self.config.fallback_mathjax {
@@ -368,33 +420,53 @@ impl MathProcessor {
 
 /// KaTeX renderer for math formulas
 pub struct KatexRenderer {
-    /// Whether KaTeX is available
-    available: bool,
+    /// Markup KaTeX produces
+    output: MathOutput,
+    /// Macros applied to every formula
+    macros: BTreeMap<String, String>,
 }
 
 impl KatexRenderer {
     /// Create a new KaTeX renderer
     pub fn new() -> Result<Self> {
-        // In a real implementation, this would check if KaTeX is available
-        // For now, we'll assume it's always available for the static site generator
-        Ok(Self { available: true })
+        Self::with_output(MathOutput::default())
+    }
+    
+    /// Create a KaTeX renderer producing the given markup
+    pub fn with_output(output: MathOutput) -> Result<Self> {
+        Ok(Self {
+            output,
+            macros: BTreeMap::new(),
+        })
+    }
+    
+    /// Apply macros to every rendered formula
+    pub fn with_macros(mut self, macros: BTreeMap<String, String>) -> Self {
+        self.macros = macros;
+        self
     }
     
     /// Render a math formula using KaTeX
     pub fn render(&self, formula: &str, display: bool) -> Result<String> {
-        if !self.available {
-            return Err(Error::content("KaTeX renderer not available".to_string()));
-        }
-        
-        // In a real implementation, this would call the KaTeX rendering engine
-        // For now, we'll generate appropriate HTML structure
-        let tag = if display { "div" } else { "span" };
-        let class = if display { "math-display" } else { "math-inline" };
+        let output_type = match self.output {
+            MathOutput::Html => katex::OutputType::Html,
+            MathOutput::Mathml => katex::OutputType::Mathml,
+            MathOutput::HtmlAndMathml => katex::OutputType::HtmlAndMathml,
+        };
         
-        Ok(format!(
-            "<{} class=\"katex-eq {}\" data-formula=\"{}\">{}</{}>",
-            tag, class, formula, formula, tag
-        ))
+        let opts = katex::Opts::builder()
+            .display_mode(display)
+            .output_type(output_type)
+            .throw_on_error(true)
+            .macros(self.macros.clone().into_iter().collect::<HashMap<_, _>>())
+            .build()
+            .map_err(|e| Error::content(format!("Invalid KaTeX options: {}", e)))?;
+        
+        katex::render_with_opts(formula, &opts).map_err(|e| match e {
+            // Parse errors surface from the JS side
+            katex::Error::JsExecError(message) => Error::math(formula, message),
+            other => Error::content(format!("KaTeX renderer not available: {}", other)),
+        })
     }
 }
 
@@ -434,4 +506,62 @@ mod tests {
         assert!(processor.should_be_display_math("\\sum_{i=1}^n i"));
         assert!(!processor.should_be_display_math("x^2 + y^2"));
     }
+    
+    #[test]
+    fn test_render_placeholders() {
+        let mut processor = MathProcessor::new().unwrap();
+        let html = r#"<p>See <span class="math-inline" data-latex="x^2"></span></p><div class="math-display" data-label="eq1" data-latex="a &lt; b"></div>"#;
+        
+        let rendered = processor.render_placeholders(html).unwrap();
+        assert!(!rendered.contains("data-latex"));
+        assert!(rendered.contains(r#"<span class="math-inline"><span class="katex">"#));
+        assert!(rendered.contains(r#"<div class="math-display" data-label="eq1"><span class="katex-display">"#));
+        assert_eq!(processor.cache_stats().0, 2);
+    }
+    
+    #[test]
+    fn test_invalid_latex_is_an_error() {
+        let mut processor = MathProcessor::new().unwrap();
+        let html = r#"<span class="math-inline" data-latex="\frac{1}{"></span>"#;
+        
+        match processor.render_placeholders(html) {
+            Err(Error::Math { formula, .. }) => assert_eq!(formula, "\\frac{1}{"),
+            other => panic!("expected a math error, got {:?}", other.map(|_| ())),
+        }
+    }
+    
+    #[test]
+    fn test_display_override() {
+        let processor = MathProcessor::new().unwrap();
+        
+        assert!(!processor.should_be_display_math("%inline \\sum_{i=1}^n i"));
+        assert!(processor.should_be_display_math("%display x^2"));
+        
+        let blocks = processor.extract_math_blocks("Sum $%inline \\sum_i x_i$ here.").unwrap();
+        assert_eq!(blocks.len(), 1);
+        assert!(!blocks[0].display);
+        assert_eq!(blocks[0].formula, "\\sum_i x_i");
+    }
+    
+    #[test]
+    fn test_macros_and_environments() {
+        let mut macros = BTreeMap::new();
+        macros.insert("\\R".to_string(), "\\mathbb{R}".to_string());
+        let mut processor = MathProcessor::with_config(MathConfig {
+            macros,
+            ..MathConfig::default()
+        }).unwrap();
+        
+        let html = processor.render_formula("f: \\R \\to \\R", false).unwrap();
+        assert!(html.contains("mathbb"));
+        
+        let environments = [
+            "\\begin{align*} a &= b \\\\ c &= d \\end{align*}",
+            "\\begin{gather} a = b \\\\ c = d \\end{gather}",
+            "|x| = \\begin{cases} x & x \\ge 0 \\\\ -x & x < 0 \\end{cases}",
+        ];
+        for formula in environments {
+            assert!(processor.render_formula(formula, true).is_ok(), "{} failed", formula);
+        }
+    }
 }
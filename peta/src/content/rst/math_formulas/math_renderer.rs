@@ -2,6 +2,63 @@
 
 use crate::core::Result;
 
+/// Marker at the start of a formula forcing display rendering
+pub const DISPLAY_MARKER: &str = "%display";
+
+/// Marker at the start of a formula forcing inline rendering
+pub const INLINE_MARKER: &str = "%inline";
+
+/// Split a leading `%display` or `%inline` override from a formula
+///
+/// Returns the forced mode, if any, and the formula without the marker.
+pub fn split_display_override(formula: &str) -> (Option<bool>, &str) {
+    let trimmed = formula.trim_start();
+    if let Some(rest) = trimmed.strip_prefix(DISPLAY_MARKER) {
+        (Some(true), rest.trim())
+    } else if let Some(rest) = trimmed.strip_prefix(INLINE_MARKER) {
+        (Some(false), rest.trim())
+    } else {
+        (None, formula)
+    }
+}
+
+/// Escape LaTeX for a `data-latex` attribute
+///
+/// Newlines and the characters the RST-to-HTML passes react to (`*`, `` ` ``,
+/// `$`) are encoded too, so multi-line environments such as `align` survive
+/// as a single line of HTML.
+pub fn escape_latex_attribute(latex: &str) -> String {
+    let mut escaped = String::with_capacity(latex.len());
+    for c in latex.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '*' => escaped.push_str("&#42;"),
+            '`' => escaped.push_str("&#96;"),
+            '$' => escaped.push_str("&#36;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverse [`escape_latex_attribute`]
+pub fn unescape_latex_attribute(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&#42;", "*")
+        .replace("&#96;", "`")
+        .replace("&#36;", "$")
+        .replace("&#10;", "\n")
+        .replace("&amp;", "&")
+}
+
 /// Math renderer for LaTeX equations
 pub struct MathRenderer {
     cache: std::collections::HashMap<String, String>,
@@ -49,14 +106,14 @@ impl MathRenderer {
         
         // Handle $$...$$ delimiters
         result = DISPLAY_REGEX.replace_all(&result, |caps: &regex::Captures| {
-            let equation = caps.get(1).unwrap().as_str().trim();
-            self.render_equation(equation, true).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
+            let (forced, equation) = split_display_override(caps.get(1).unwrap().as_str().trim());
+            self.render_equation(equation, forced.unwrap_or(true)).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
         }).to_string();
         
         // Handle \[...\] delimiters
         result = LATEX_REGEX.replace_all(&result, |caps: &regex::Captures| {
-            let equation = caps.get(1).unwrap().as_str().trim();
-            self.render_equation(equation, true).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
+            let (forced, equation) = split_display_override(caps.get(1).unwrap().as_str().trim());
+            self.render_equation(equation, forced.unwrap_or(true)).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
         }).to_string();
         
         Ok(result)
@@ -72,8 +129,8 @@ impl MathRenderer {
         
         // Handle \(...\) delimiters first
         result = LATEX_INLINE_REGEX.replace_all(&result, |caps: &regex::Captures| {
-            let equation = caps.get(1).unwrap().as_str().trim();
-            self.render_equation(equation, false).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
+            let (forced, equation) = split_display_override(caps.get(1).unwrap().as_str().trim());
+            self.render_equation(equation, forced.unwrap_or(false)).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
         }).to_string();
         
         // Handle $...$ delimiters with a custom approach to avoid $$...$$
@@ -91,8 +148,8 @@ impl MathRenderer {
             once_cell::sync::Lazy::new(|| regex::Regex::new(r"\$([^$\n]+?)\$").unwrap());
         
         result = INLINE_REGEX.replace_all(&result, |caps: &regex::Captures| {
-            let equation = caps.get(1).unwrap().as_str().trim();
-            self.render_equation(equation, false).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
+            let (forced, equation) = split_display_override(caps.get(1).unwrap().as_str().trim());
+            self.render_equation(equation, forced.unwrap_or(false)).unwrap_or_else(|_| format!("<span class=\"math-error\">{}</span>", equation))
         }).to_string();
         
         // Restore display math blocks
@@ -112,23 +169,27 @@ impl MathRenderer {
             return Ok(cached.clone());
         }
         
-        // Create HTML elements with data-latex attributes for on-demand rendering
-        let rendered = if display {
+        let rendered = Self::placeholder(equation, display);
+        
+        // Cache the result
+        self.cache.insert(cache_key, rendered.clone());
+        
+        Ok(rendered)
+    }
+    
+    /// Element with a `data-latex` attribute for on-demand rendering
+    pub fn placeholder(latex: &str, display: bool) -> String {
+        if display {
             format!(
                 r#"<div class="math-display" data-latex="{}"></div>"#,
-                equation
+                escape_latex_attribute(latex)
             )
         } else {
             format!(
                 r#"<span class="math-inline" data-latex="{}"></span>"#,
-                equation
+                escape_latex_attribute(latex)
             )
-        };
-        
-        // Cache the result
-        self.cache.insert(cache_key, rendered.clone());
-        
-        Ok(rendered)
+        }
     }
 }
 
@@ -4,8 +4,14 @@ pub mod math_processor;
 pub mod math_renderer;
 pub mod math_css_generator;
 pub mod math_js_generator;
+pub mod katex_assets;
+pub mod equation_numbering;
+pub mod math_macros;
 
 pub use math_processor::{MathProcessor, MathDetectionResult};
 pub use math_renderer::MathRenderer;
 pub use math_css_generator::MathCssGenerator;
-pub use math_js_generator::MathJsGenerator;
\ No newline at end of file
+pub use math_js_generator::MathJsGenerator;
+pub use katex_assets::{katex_bundle_config, vendor_katex, KatexAssets};
+pub use equation_numbering::EquationNumberer;
+pub use math_macros::load_math_macros;
\ No newline at end of file
@@ -1,22 +1,53 @@
 //! RST parser implementation with improved architecture
 
 use crate::content::rst::{
-    toc_generator::TocGenerator, CodeHighlighter, directives::DirectiveHandler, MathProcessor, MathRenderer,
+    toc_generator::TocGenerator, CodeHighlighter, directives::DirectiveHandler, EquationNumberer, MathProcessor,
+    MathRenderer,
 };
+use crate::content::rst::math_formulas::math_processor::MathConfig;
+use crate::content::rst::math_formulas::math_renderer::split_display_override;
 use crate::content::{ContentMetadata, ContentType, RstContent, TocEntry};
+use crate::content::rst::CodeBlockConfig;
+use crate::core::config::{CodeBlocksConfig, EquationNumbering, MathRenderMode, MathRenderingConfig};
 use crate::core::Error;
 use crate::core::Result;
 use regex::Regex;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Directives grouping code blocks into tabs; `tab-set` as in sphinx-design
+const CODE_TABS_DIRECTIVES: [&str; 2] = ["code-tabs", "tab-set"];
+
+/// Point a math error at the file and line the formula came from
+fn locate_math_error(error: Error, source: &str, file_path: Option<&Path>) -> Error {
+    let line = match &error {
+        Error::Math { formula, .. } => formula
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .and_then(|first| source.find(first))
+            .map(|pos| source[..pos].matches('\n').count() + 1),
+        _ => return error,
+    };
+
+    match (file_path, line) {
+        (Some(path), Some(line)) => error.at(format!("{}:{}", path.display(), line)),
+        (Some(path), None) => error.at(path.display().to_string()),
+        (None, Some(line)) => error.at(format!("line {}", line)),
+        (None, None) => error,
+    }
+}
 
 /// Main RST parser that processes RST content directly to HTML
 pub struct RstParser {
     math_renderer: MathRenderer,
     math_processor: MathProcessor,
+    math_mode: MathRenderMode,
+    equation_numbering: EquationNumbering,
     #[allow(dead_code)]
     code_highlighter: CodeHighlighter,
     directive_handlers: HashMap<String, Box<dyn DirectiveHandler>>,
+    code_block_config: CodeBlockConfig,
     toc_generator: TocGenerator,
 }
 
@@ -60,6 +91,10 @@ impl RstParser {
             "include".to_string(),
             Box::new(crate::content::rst::directives::IncludeHandler::new()),
         );
+        directive_handlers.insert(
+            "literalinclude".to_string(),
+            Box::new(crate::content::rst::directives::LiteralIncludeHandler::new()),
+        );
         directive_handlers.insert(
             "csv-table".to_string(),
             Box::new(crate::content::rst::directives::TableDirectiveHandler::new()),
@@ -72,13 +107,46 @@ impl RstParser {
         Ok(Self {
             math_renderer: MathRenderer::new(),
             math_processor: MathProcessor::new()?,
+            math_mode: MathRenderMode::default(),
+            equation_numbering: EquationNumbering::default(),
             code_highlighter: CodeHighlighter::new()
                 .map_err(|e| Error::Content(format!("Failed to create code highlighter: {}", e)))?,
             directive_handlers,
+            code_block_config: CodeBlockConfig::default(),
             toc_generator: TocGenerator::new(),
         })
     }
 
+    /// Apply the `[math_rendering]` settings
+    pub fn with_math_rendering(mut self, config: &MathRenderingConfig) -> Result<Self> {
+        self.math_mode = config.mode;
+        self.equation_numbering = config.equation_numbering;
+        self.math_processor = MathProcessor::with_config(MathConfig {
+            output: config.output,
+            macros: config.macros.clone(),
+            ..MathConfig::default()
+        })?;
+        Ok(self)
+    }
+
+    /// Apply the `[code_blocks]` defaults, loading extra syntaxes from `syntax_dirs`
+    pub fn with_code_blocks(mut self, config: &CodeBlocksConfig, syntax_dirs: &[PathBuf]) -> Result<Self> {
+        self.code_block_config = CodeBlockConfig {
+            line_numbers: config.enable_line_numbers,
+            copy_button: config.enable_copy_button,
+            theme: config.default_theme.clone(),
+            language_aliases: config.language_aliases(),
+            syntax_dirs: syntax_dirs.to_vec(),
+            ..CodeBlockConfig::default()
+        };
+        let handler = crate::content::rst::directives::CodeBlockHandler::with_config(self.code_block_config.clone())?
+            .with_runner(crate::content::rst::CodeRunner::new(config.run.clone()));
+        self.directive_handlers.insert("code-block".to_string(), Box::new(handler));
+        let handler = crate::content::rst::directives::LiteralIncludeHandler::with_config(self.code_block_config.clone(), None)?;
+        self.directive_handlers.insert("literalinclude".to_string(), Box::new(handler));
+        Ok(self)
+    }
+
     /// Parse RST content to HTML
     pub fn parse(&mut self, content: &str) -> Result<RstContent> {
         self.parse_with_type(content, None)
@@ -123,8 +191,40 @@ impl RstParser {
             rst_content.clone()
         };
 
+        // 3b. Resolve literalinclude paths against the document's directory
+        if let Some(parent) = file_path.and_then(Path::parent) {
+            let handler = crate::content::rst::directives::LiteralIncludeHandler::with_config(
+                self.code_block_config.clone(),
+                Some(parent.to_path_buf()),
+            )?;
+            self.directive_handlers.insert("literalinclude".to_string(), Box::new(handler));
+        }
+
         // 4. Parse RST structure and process directives
         let processed_html = self.process_rst_content(&content_to_process)?;
+        let mut dependencies: Vec<_> = self
+            .directive_handlers
+            .values_mut()
+            .flat_map(|handler| handler.take_dependencies())
+            .collect();
+        dependencies.sort();
+        dependencies.dedup();
+
+        // 4b. Number labeled equations and resolve :eq: references
+        let chapter = match (self.equation_numbering, &metadata.content_type, file_path) {
+            (EquationNumbering::Chapter, ContentType::Book, Some(path)) => {
+                crate::content::rst::book_toc_generator::BookTocGenerator::new().chapter_number(path)
+            }
+            _ => None,
+        };
+        let numbered = EquationNumberer::with_chapter(chapter).apply(&processed_html);
+        for warning in &numbered.warnings {
+            match file_path {
+                Some(path) => eprintln!("Warning: {}: {}", path.display(), warning),
+                None => eprintln!("Warning: {}", warning),
+            }
+        }
+        let processed_html = numbered.html;
 
         // 5. Generate table of contents
         let (toc, toc_html) = if metadata.content_type == ContentType::Book {
@@ -148,6 +248,15 @@ impl RstParser {
         // 6. Detect math formulas (keep for optimization, but don't generate script)
         let math_detection = self.math_processor.auto_detect_math_content(&processed_html)?;
 
+        // 7. Render formulas now when no math JavaScript is shipped
+        let processed_html = if self.math_mode == MathRenderMode::Build && math_detection.has_formulas {
+            self.math_processor
+                .render_placeholders(&processed_html)
+                .map_err(|e| locate_math_error(e, content, file_path))?
+        } else {
+            processed_html
+        };
+
         Ok(RstContent {
             metadata,
             html: processed_html,
@@ -156,6 +265,7 @@ impl RstParser {
             frontmatter,
             has_math_formulas: math_detection.has_formulas,
             math_formula_count: math_detection.formula_count,
+            dependencies,
         })
     }
 
@@ -247,6 +357,10 @@ impl RstParser {
         }
 
         for (i, &(start, end, directive_str)) in directive_starts.iter().enumerate() {
+            // Nested in a directive already consumed, such as code-tabs
+            if start < last_pos {
+                continue;
+            }
             result.push_str(&content[last_pos..start]);
 
             // Extract directive name and optional language
@@ -353,8 +467,9 @@ impl RstParser {
                         found_indented_content = true;
                     }
 
-                    // If we found indented content and now hit a non-indented line, stop
-                    if found_indented_content && !is_indented {
+                    // Stop at the first non-indented line; directives such as
+                    // literalinclude may have no indented content at all
+                    if !is_indented {
                         content_end = line_start_pos;
                         break;
                     }
@@ -366,8 +481,9 @@ impl RstParser {
                     // Keep content_end as is (end of file)
                 }
                 
-                // If we have more directives, make sure we don't go past them
-                if i + 1 < directive_starts.len() {
+                // If we have more directives, make sure we don't go past them,
+                // unless they are the code blocks of a code-tabs group
+                if i + 1 < directive_starts.len() && !CODE_TABS_DIRECTIVES.contains(&directive_name) {
                     let next_directive_start = directive_starts[i + 1].0;
                     if content_end > next_directive_start {
                         content_end = next_directive_start;
@@ -377,13 +493,15 @@ impl RstParser {
 
             let directive_content = &content[content_start..content_end];
 
-            // Extract field list options (lines starting with ":")
+            // Extract the leading field list options (lines starting with ":"),
+            // leaving lines like `:param x:` in a code block's body alone
             let mut options = std::collections::HashMap::new();
             let mut content_lines: Vec<&str> = Vec::new();
             
             for line in directive_content.lines() {
                 let trimmed = line.trim();
-                if trimmed.starts_with(':') && trimmed.len() > 1 {
+                let in_field_list = content_lines.iter().all(|line| line.trim().is_empty());
+                if in_field_list && trimmed.starts_with(':') && trimmed.len() > 1 {
                     // This is a field list option
                     if let Some(colon_pos) = trimmed[1..].find(':') {
                         let actual_colon_pos = colon_pos + 1;
@@ -401,14 +519,24 @@ impl RstParser {
             
             let actual_content = content_lines.join("\n");
 
-            if let Some(handler) = self.directive_handlers.get_mut(directive_name) {
+            // code-run is a code-block executed at build time
+            let directive_name = if directive_name == "code-run" {
+                options.insert("execute".to_string(), String::new());
+                "code-block"
+            } else {
+                directive_name
+            };
+
+            if CODE_TABS_DIRECTIVES.contains(&directive_name) {
+                result.push_str(&self.process_code_tabs(&actual_content)?);
+            } else if let Some(handler) = self.directive_handlers.get_mut(directive_name) {
                 // Different directives interpret parameters differently:
                 // - code-block: first param is language, second is content
                 // - snippet-card: first param is snippet ID, second is content (empty)
                 // - include: first param is directive name, second is file reference
                 // - others: first param is directive name, second is content
-                let (handler_directive_type, handler_content) = if directive_name == "code-block" {
-                    // For code-block, language is the first param
+                let (handler_directive_type, handler_content) = if directive_name == "code-block" || directive_name == "literalinclude" {
+                    // For code-block, language is the first param, as is the file for literalinclude
                     (language, actual_content.as_str())
                 } else if directive_name == "snippet-card" || directive_name == "include" {
                     // For snippet-card and include, directive_name is first param, language is second param
@@ -419,6 +547,12 @@ impl RstParser {
                 };
                 
                 let processed = handler.handle(handler_directive_type, handler_content, &options)?;
+                // Included parts may carry directives of their own
+                let processed = if directive_name == "include" {
+                    self.process_directives(&processed)?
+                } else {
+                    processed
+                };
                 result.push_str(&processed);
             }
 
@@ -430,6 +564,76 @@ impl RstParser {
         Ok(result)
     }
 
+    /// Render the code blocks of a `code-tabs` directive as one tabbed widget
+    ///
+    /// Each `code-block` or `literalinclude` becomes a tab named by its
+    /// `:tab:` option or language. Tabs are keyed by the language, with
+    /// aliases resolved, so picking Python selects it in every group.
+    fn process_code_tabs(&mut self, content: &str) -> Result<String> {
+        let body = crate::content::rst::directives::dedent_lines(content, None);
+
+        let mut children: Vec<Vec<&str>> = Vec::new();
+        for line in body.lines() {
+            if line.starts_with(".. ") {
+                children.push(vec![line]);
+            } else if let Some(child) = children.last_mut() {
+                child.push(line);
+            } else if !line.trim().is_empty() {
+                return Err(Error::rst_parse(format!(
+                    "code-tabs may only contain code blocks, found '{}'",
+                    line.trim()
+                )));
+            }
+        }
+
+        let mut tabs = Vec::new();
+        for child in children {
+            let header = child[0].trim_start_matches(".. ");
+            let (name, argument) = header.split_once("::").unwrap_or((header, ""));
+            let (name, argument) = (name.trim(), argument.trim());
+            let option = |key: &str| {
+                child[1..]
+                    .iter()
+                    .map(|line| line.trim())
+                    .take_while(|line| line.starts_with(':'))
+                    .find_map(|line| line.strip_prefix(&format!(":{}:", key)))
+                    .map(|value| value.trim().to_string())
+            };
+
+            let language = match name {
+                "code-block" | "code-run" if argument.is_empty() => "text".to_string(),
+                "code-block" | "code-run" => argument.to_string(),
+                "literalinclude" => option("language").unwrap_or_else(|| {
+                    crate::content::rst::directives::language_for_path(Path::new(argument))
+                }),
+                other => {
+                    return Err(Error::rst_parse(format!(
+                        "code-tabs may only contain code-block, code-run and literalinclude, found '{}'",
+                        other
+                    )))
+                }
+            };
+            let key = self
+                .code_block_config
+                .language_aliases
+                .get(&language)
+                .cloned()
+                .unwrap_or_else(|| language.clone())
+                .to_lowercase();
+
+            tabs.push(crate::content::rst::CodeTab {
+                key,
+                label: option("tab").unwrap_or(language),
+                html: self.process_directives(&child.join("\n"))?,
+            });
+        }
+
+        if tabs.is_empty() {
+            return Err(Error::rst_parse("code-tabs needs at least one code-block"));
+        }
+        Ok(crate::content::rst::render_code_tabs(&tabs))
+    }
+
     /// Process RST inline roles (like :math:`...`)
     fn process_roles(&self, content: &str) -> Result<String> {
         let role_regex = Regex::new(r":([a-zA-Z0-9_-]+):`([^`]*)`").map_err(|e| {
@@ -442,10 +646,15 @@ impl RstParser {
             
             match role_name {
                 "math" => {
-                    // Render as inline math
+                    // Render as inline math unless marked %display
+                    let (forced, latex) = split_display_override(role_content);
+                    MathRenderer::placeholder(latex, forced.unwrap_or(false))
+                }
+                "eq" => {
+                    // Numbered by EquationNumberer once all equations are known
                     format!(
-                        r#"<span class="math-inline" data-latex="{}"></span>"#,
-                        role_content
+                        r#"<a class="math-eq-ref" data-eq-ref="{}"></a>"#,
+                        role_content.trim()
                     )
                 }
                 _ => caps.get(0).unwrap().as_str().to_string(), // Preserve other roles
@@ -963,8 +1172,10 @@ use crate::content::rst::tables::parser::TableParser;
                     result.push(format!("<p>{}</p>", paragraph.join(" ")));
                     paragraph.clear();
                 }
-                html_block_depth += 1;
-                in_html_block = true;
+                // Single-line blocks such as math placeholders close themselves
+                html_block_depth += trimmed.matches("<div").count();
+                html_block_depth -= trimmed.matches("</div>").count().min(html_block_depth);
+                in_html_block = html_block_depth > 0;
                 result.push(line.to_string());
             } else if trimmed.starts_with("</div>") && in_html_block {
                 html_block_depth -= 1;
@@ -1352,6 +1563,140 @@ This concludes the test.
         assertions::assert_html_contains(&result.html, "python");
     }
 
+    #[test]
+    fn test_code_block_options() {
+        let content = format!(
+            "{}.. code-block:: python\n   :emphasize-lines: 2\n   :caption: Greeting\n   :name: greet\n   :dedent: 4\n\n       def greet(name):\n           \"\"\"Say hello.\n\n           :param name: who to greet\n           \"\"\"\n\nAfter the code.\n",
+            fixtures::frontmatter_valid()
+        );
+        let config = CodeBlocksConfig {
+            enable_line_numbers: false,
+            ..CodeBlocksConfig::default()
+        };
+        let mut parser = RstParser::new().unwrap().with_code_blocks(&config, &[]).unwrap();
+        let result = parser.parse(&content).unwrap();
+
+        assertions::assert_html_contains(&result.html, r#"<div class="code-block" id="greet""#);
+        assertions::assert_html_contains(&result.html, r#"<span class="code-title">Greeting</span>"#);
+        assertions::assert_html_contains(&result.html, "param name");
+        assertions::assert_html_contains(&result.html, "<p>After the code.</p>");
+        assert!(!result.html.contains("with-line-numbers"));
+        // :dedent: 4 strips the docstring's indentation under `def`
+        assertions::assert_html_contains(&result.html, r#"<span class="line-highlight"><span class="token-comment">"#);
+    }
+
+    #[test]
+    fn test_literalinclude_directive() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("articles/src")).unwrap();
+        std::fs::write(
+            dir.path().join("articles/src/greet.py"),
+            "import sys\n\n\nclass Greeter:\n    @staticmethod\n    def greet(name):\n        # [START body]\n        return f\"Hello, {name}\"\n        # [END body]\n\n    def leave(self):\n        pass\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("articles/src/old.py"), "import sys\n").unwrap();
+        let page = dir.path().join("articles/page.rst");
+        let rst = format!(
+            "{}.. literalinclude:: src/greet.py\n   :pyobject: Greeter.greet\n   :lineno-match:\n\nBetween.\n\n\
+             .. literalinclude:: src/greet.py\n   :region: body\n   :dedent: 8\n   :caption:\n\n\
+             .. literalinclude:: src/greet.py\n   :diff: src/old.py\n\nAfter.\n",
+            fixtures::frontmatter_valid()
+        );
+
+        let mut parser = RstParser::new().unwrap();
+        let result = parser
+            .parse_with_type_and_path(&rst, Some(ContentType::Article), Some(&page))
+            .unwrap();
+
+        // :pyobject: keeps the decorator, numbered as in the file
+        assertions::assert_html_contains(&result.html, r#"data-line="1">5</span>"#);
+        assertions::assert_html_contains(&result.html, r#"data-language="python""#);
+        assertions::assert_html_contains(&result.html, "<p>Between.</p>");
+        assertions::assert_html_contains(&result.html, r#"<span class="code-title">src/greet.py</span>"#);
+        // Only the :pyobject: and :diff: blocks keep the region markers
+        assert_eq!(result.html.matches("[START body]").count(), 2);
+        assertions::assert_html_contains(&result.html, r#"data-language="diff""#);
+        assertions::assert_html_contains(&result.html, "<p>After.</p>");
+        assert_eq!(result.dependencies.len(), 2);
+        assert!(result.dependencies.iter().any(|d| d.ends_with("greet.py")));
+    }
+
+    #[test]
+    fn test_literalinclude_missing_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let page = dir.path().join("page.rst");
+        let rst = format!("{}.. literalinclude:: missing.py\n", fixtures::frontmatter_valid());
+
+        let mut parser = RstParser::new().unwrap();
+        let error = parser.parse_with_type_and_path(&rst, None, Some(&page)).unwrap_err();
+        assert!(error.to_string().contains("missing.py"));
+    }
+
+    #[test]
+    fn test_code_tabs_directive() {
+        let content = format!(
+            "{}.. code-tabs::\n\n   .. code-block:: py\n\n      print(requests.get(url).json())\n\n   .. code-block:: bash\n      :tab: curl\n\n      curl -s $URL\n\nAfter the tabs.\n",
+            fixtures::frontmatter_valid()
+        );
+        let mut parser = RstParser::new().unwrap();
+        let result = parser.parse(&content).unwrap();
+
+        assertions::assert_html_contains(&result.html, r#"data-tab-key="python" aria-selected="true">py</button>"#);
+        assertions::assert_html_contains(&result.html, r#"data-tab-key="bash" aria-selected="false">curl</button>"#);
+        assertions::assert_html_contains(&result.html, r#"<div class="code-tab-panel" role="tabpanel" data-tab-key="bash" hidden>"#);
+        assertions::assert_html_contains(&result.html, "<p>After the tabs.</p>");
+        assert_eq!(result.html.matches(r#"<div class="code-block""#).count(), 2);
+        assert!(!result.html.contains(":tab:"));
+    }
+
+    #[test]
+    fn test_code_tabs_rejects_other_content() {
+        let content = format!(
+            "{}.. code-tabs::\n\n   Some prose.\n\n   .. code-block:: rust\n\n      fn main() {{}}\n",
+            fixtures::frontmatter_valid()
+        );
+        let mut parser = RstParser::new().unwrap();
+        assert!(parser.parse(&content).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_code_run_directive() {
+        use crate::core::config::{CodeRunConfig, RunCommandConfig};
+
+        let cache = tempfile::TempDir::new().unwrap();
+        let mut config = CodeRunConfig::default();
+        config.languages.insert(
+            "bash".to_string(),
+            RunCommandConfig {
+                command: "sh {file}".to_string(),
+                file: "main.sh".to_string(),
+            },
+        );
+        let mut parser = RstParser::new().unwrap();
+        let runner = crate::content::rst::CodeRunner::with_cache_dir(config, cache.path().to_path_buf());
+        let handler = crate::content::rst::directives::CodeBlockHandler::new().with_runner(runner);
+        parser.directive_handlers.insert("code-block".to_string(), Box::new(handler));
+
+        let content = format!(
+            "{}.. code-run:: sh\n\n   echo \"2 * 3 = $((2 * 3))\"\n\nAfter the run.\n",
+            fixtures::frontmatter_valid()
+        );
+        let result = parser.parse(&content).unwrap();
+        assertions::assert_html_contains(&result.html, r#"<div class="code-run">"#);
+        assertions::assert_html_contains(&result.html, r#"<span class="code-output-line">2 &#42; 3 = 6</span>"#);
+        assertions::assert_html_contains(&result.html, "<p>After the run.</p>");
+
+        let failing = format!(
+            "{}.. code-block:: bash\n   :execute:\n   :fail-on-error:\n\n   echo broken >&2\n   exit 2\n",
+            fixtures::frontmatter_valid()
+        );
+        let error = parser.parse(&failing).unwrap_err();
+        assert!(matches!(error, Error::CodeRun(_)));
+        assert!(error.to_string().contains("exited with 2"));
+        assert!(error.to_string().contains("broken"));
+    }
+
     #[test]
     fn test_process_snippet_card_directive() {
         let content = format!("{}{}", fixtures::frontmatter_valid(), fixtures::snippet_card_directive());
@@ -1393,6 +1738,70 @@ This concludes the test.
         assert!(result.has_math_formulas);
     }
 
+    #[test]
+    fn test_equation_numbering_and_references() {
+        let content = format!(
+            "{}As :eq:`wave` shows:\n\n.. math::\n   :label: wave\n\n   u_{{tt}} = c^2 u_{{xx}}\n\nSee :eq:`heat`.\n",
+            fixtures::frontmatter_valid()
+        );
+        let mut parser = RstParser::new().unwrap();
+        let result = parser.parse(&content).unwrap();
+
+        assertions::assert_html_contains(&result.html, r#"<div class="math-equation" id="eq-wave">"#);
+        assertions::assert_html_contains(&result.html, r#"<span class="math-equation-number">(1)</span>"#);
+        assertions::assert_html_contains(&result.html, r##"<a class="math-eq-ref" href="#eq-wave">(1)</a>"##);
+        assertions::assert_html_contains(&result.html, "math-eq-ref-unknown");
+    }
+
+    #[test]
+    fn test_build_time_math_rendering() {
+        let content = format!("{}{}", fixtures::frontmatter_valid(), fixtures::display_math());
+        let config = MathRenderingConfig {
+            mode: MathRenderMode::Build,
+            ..MathRenderingConfig::default()
+        };
+        let mut parser = RstParser::new().unwrap().with_math_rendering(&config).unwrap();
+        let result = parser.parse(&content).unwrap();
+
+        assertions::assert_html_contains(&result.html, "katex-display");
+        assert!(!result.html.contains("data-latex"));
+        assert!(result.has_math_formulas);
+    }
+
+    #[test]
+    fn test_build_time_math_error_is_located() {
+        let content = format!("{}Broken:\n$$\\frac{{1}}{{$$\n", fixtures::frontmatter_valid());
+        let config = MathRenderingConfig {
+            mode: MathRenderMode::Build,
+            ..MathRenderingConfig::default()
+        };
+        let mut parser = RstParser::new().unwrap().with_math_rendering(&config).unwrap();
+        let error = parser
+            .parse_with_type_and_path(&content, None, Some(Path::new("articles/broken.rst")))
+            .unwrap_err();
+
+        assert!(error.to_string().contains("articles/broken.rst:9: invalid LaTeX `\\frac{1}{`"));
+    }
+
+    #[test]
+    fn test_multiline_math_with_macros() {
+        let content = format!(
+            "{}Intro.\n\n.. math::\n\n   x &= \\R^2 \\\\\n   y &= \\R\n\nAfter the equation.\n",
+            fixtures::frontmatter_valid()
+        );
+        let mut config = MathRenderingConfig {
+            mode: MathRenderMode::Build,
+            ..MathRenderingConfig::default()
+        };
+        config.macros.insert("\\R".to_string(), "\\mathbb{R}".to_string());
+        let mut parser = RstParser::new().unwrap().with_math_rendering(&config).unwrap();
+        let result = parser.parse(&content).unwrap();
+
+        assertions::assert_html_contains(&result.html, "katex-display");
+        assertions::assert_html_contains(&result.html, "<p>After the equation.</p>");
+        assert!(result.html.contains("mathbb"));
+    }
+
     // Integration Tests
     #[test]
     fn test_parse_complete_document() {
@@ -1,9 +1,14 @@
 //! Site builder implementation following RST-first architecture
 
 use crate::core::{Site, SiteConfig, Result, Error};
+use crate::core::hooks::{self, BuildMode, HookContext, HookStage};
+use crate::core::config::SearchBackend;
 use crate::core::theme::{Theme, ThemeSystem};
 use crate::content::{RstContent, ContentType};
-use crate::search::SearchIndex;
+use crate::search::client::{write_client_index, ClientIndexOptions, CLIENT_INDEX_DIR};
+use crate::search::indexer::IndexerConfig;
+use crate::search::tantivy_index::TantivyIndex;
+use crate::search::{RelatedContent, SearchIndex};
 use crate::templates::TemplateEngine;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
@@ -16,7 +21,10 @@ pub struct SiteBuilder {
     rst_content: Vec<RstContent>,
     theme_system: ThemeSystem,
     search_index: SearchIndex,
+    related: RelatedContent,
     component_registry: crate::components::ComponentRegistry,
+    mode: BuildMode,
+    changed_files: Vec<PathBuf>,
 }
 
 impl SiteBuilder {
@@ -35,12 +43,40 @@ impl SiteBuilder {
             rst_content: Vec::new(),
             theme_system,
             search_index: SearchIndex::new(),
+            related: RelatedContent::new(),
             component_registry,
+            mode: BuildMode::default(),
+            changed_files: Vec::new(),
+        }
+    }
+    
+    /// Set what triggered this build, exposed to `[hooks]` commands
+    pub fn with_mode(mut self, mode: BuildMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    
+    /// Set the files that triggered a rebuild, exposed to `[hooks]` commands
+    pub fn with_changed_files(mut self, changed_files: Vec<PathBuf>) -> Self {
+        self.changed_files = changed_files;
+        self
+    }
+    
+    /// Context passed to `[hooks]` commands
+    fn hook_context(&self) -> HookContext {
+        HookContext {
+            output_dir: self.config.build.output_dir.clone(),
+            content_dir: self.config.build.content_dir.clone(),
+            mode: self.mode,
+            changed_files: self.changed_files.clone(),
+            deploy_target: None,
         }
     }
     
     /// Build the complete static site following RST-first architecture
     pub async fn build(&mut self) -> Result<Site> {
+        hooks::run_hooks(&self.config.hooks, HookStage::PreBuild, &self.hook_context()).await?;
+        
         // 1. Load RST content
         self.load_rst_content().await?;
         
@@ -53,19 +89,52 @@ impl SiteBuilder {
         // 4. Build search index
         self.build_search_index().await?;
         
+        // 4b. Find related content from the index
+        self.build_related_content();
+        
         // 5. Generate static site
         self.generate_static_site().await?;
         
+        hooks::run_hooks(&self.config.hooks, HookStage::PostBuild, &self.hook_context()).await?;
+        
+        Ok(Site::with_content(
+            self.config.clone(),
+            self.rst_content.clone()
+        ))
+    }
+    
+    /// Load the content, without running hooks or writing any output
+    pub async fn load_site(&mut self) -> Result<Site> {
+        self.load_rst_content().await?;
+        self.parse_rst_to_html().await?;
+        self.resolve_references().await?;
+        
         Ok(Site::with_content(
             self.config.clone(),
             self.rst_content.clone()
         ))
     }
     
+    /// Load the content and build its search index, without running hooks
+    /// or writing any output
+    pub async fn build_search_index_only(&mut self) -> Result<SearchIndex> {
+        self.load_rst_content().await?;
+        self.parse_rst_to_html().await?;
+        self.resolve_references().await?;
+        
+        let indexer_config = IndexerConfig::from_config(&self.config);
+        self.search_index.build_with_config(&self.rst_content, &indexer_config)?;
+        Ok(self.search_index.clone())
+    }
+    
     /// Load RST content from the content directory
     async fn load_rst_content(&mut self) -> Result<()> {
         let content_dir = PathBuf::from(&self.config.build.content_dir);
         
+        // Merge macros.tex with [math_rendering.macros] for the parser and loader
+        self.config.math_rendering.macros =
+            crate::content::rst::math_formulas::load_math_macros(&self.config)?;
+        
         // Load articles
         self.load_content_type(&content_dir.join("articles"), ContentType::Article).await?;
         
@@ -95,7 +164,7 @@ impl SiteBuilder {
         
         if let Ok(paths) = glob(pattern.to_str().unwrap()) {
             for path in paths.flatten() {
-                if let Ok(content) = self.load_rst_file(&path, content_type.clone()).await {
+                if let Some(content) = Self::skip_unparsable(self.load_rst_file(&path, content_type.clone()).await)? {
                     loaded_files.insert(path.clone());
                     self.rst_content.push(content);
                 }
@@ -107,7 +176,7 @@ impl SiteBuilder {
         
         if let Ok(paths) = glob(pattern.to_str().unwrap()) {
             for path in paths.flatten() {
-                if let Ok(content) = self.load_rst_file(&path, content_type.clone()).await {
+                if let Some(content) = Self::skip_unparsable(self.load_rst_file(&path, content_type.clone()).await)? {
                     loaded_files.insert(path.clone());
                     self.rst_content.push(content);
                 }
@@ -122,7 +191,7 @@ impl SiteBuilder {
                 for path in paths.flatten() {
                     // Skip files that were already loaded
                     if !loaded_files.contains(&path) {
-                        if let Ok(content) = self.load_rst_file(&path, content_type.clone()).await {
+                        if let Some(content) = Self::skip_unparsable(self.load_rst_file(&path, content_type.clone()).await)? {
                             loaded_files.insert(path.clone());
                             self.rst_content.push(content);
                         }
@@ -149,7 +218,7 @@ impl SiteBuilder {
 
                         // Skip article parts - they're loaded as part of the main article
                         if !is_article_part {
-                            if let Ok(content) = self.load_rst_file(&path, content_type.clone()).await {
+                            if let Some(content) = Self::skip_unparsable(self.load_rst_file(&path, content_type.clone()).await)? {
                                 loaded_files.insert(path.clone());
                                 self.rst_content.push(content);
                             }
@@ -197,9 +266,26 @@ impl SiteBuilder {
             .map_err(|e| Error::content(format!("Failed to read file {}: {}", path.display(), e)))?;
         
         // Parse RST content using the RST parser with content type override and file path
-        let mut parser = crate::content::rst::parser::RstParser::new()?;
+        let mut parser = crate::content::rst::parser::RstParser::new()?
+            .with_math_rendering(&self.config.math_rendering)?
+            .with_code_blocks(&self.config.code_blocks, &self.config.syntax_dirs())?;
         parser.parse_with_type_and_path(&content, Some(content_type), Some(path))
-            .map_err(|e| Error::rst_parse(format!("Failed to parse RST file {}: {}", path.display(), e)))
+            .map_err(|e| match e {
+                // Already located at the offending formula
+                Error::Math { .. } => e,
+                Error::CodeRun(message) => Error::code_run(format!("{}: {}", path.display(), message)),
+                e => Error::rst_parse(format!("Failed to parse RST file {}: {}", path.display(), e)),
+            })
+    }
+    
+    /// Skip files that fail to load, except for invalid math and failing
+    /// `code-run` blocks which fail the build
+    fn skip_unparsable(result: Result<RstContent>) -> Result<Option<RstContent>> {
+        match result {
+            Ok(content) => Ok(Some(content)),
+            Err(e @ (Error::Math { .. } | Error::CodeRun(_))) => Err(e),
+            Err(_) => Ok(None),
+        }
     }
     
     /// Parse RST content to HTML following RST-first architecture
@@ -272,16 +358,29 @@ impl SiteBuilder {
         Ok(())
     }
     
-    /// Build search index for client-side search
+    /// Build search index for client-side search, also used for related content
     async fn build_search_index(&mut self) -> Result<()> {
-        if !self.config.search.enabled {
+        if !self.config.search.enabled && !self.config.related.enabled {
             return Ok(());
         }
         
-        self.search_index.build(&self.rst_content)?;
+        let indexer_config = IndexerConfig::from_config(&self.config);
+        self.search_index.build_with_config(&self.rst_content, &indexer_config)?;
         Ok(())
     }
     
+    /// Compute the related items of every page from the search index
+    fn build_related_content(&mut self) {
+        if !self.config.related.enabled {
+            return;
+        }
+        
+        self.related = RelatedContent::compute(&self.search_index, &self.rst_content, &self.config.related);
+        for warning in &self.related.warnings {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+    
     /// Generate static HTML site with V4 architecture support
     
         async fn generate_static_site(&mut self) -> Result<()> {
@@ -372,7 +471,7 @@ impl SiteBuilder {
     
             
     
-                        self.process_assets(&output_dir)?;
+                        self.process_assets(&output_dir).await?;
     
             
     
@@ -553,9 +652,17 @@ impl SiteBuilder {
         let search_html = template_engine.render("search.html", &self.create_base_context())?;
         std::fs::write(output_dir.join("search.html"), search_html)?;
         
-        // Generate search.json for client-side search
-        let search_json = self.search_index.generate_client_search()?;
-        std::fs::write(output_dir.join("search.json"), search_json)?;
+        match self.config.search.backend {
+            // Generate the sharded index for client-side search
+            SearchBackend::Static => {
+                let options = ClientIndexOptions::from_config(&self.config.search);
+                write_client_index(&self.search_index, &output_dir.join(CLIENT_INDEX_DIR), &options)?;
+            }
+            // Generate the tantivy index served by `peta search-server`
+            SearchBackend::Server => {
+                TantivyIndex::create(&self.search_index, Path::new(&self.config.search.server.index_dir))?;
+            }
+        }
         
         Ok(())
     }
@@ -570,7 +677,7 @@ impl SiteBuilder {
     }
     
     /// Process and copy assets
-    fn process_assets(&self, output_dir: &PathBuf) -> Result<()> {
+    async fn process_assets(&self, output_dir: &PathBuf) -> Result<()> {
         let assets_dir = output_dir.join("assets");
         std::fs::create_dir_all(&assets_dir)?;
         
@@ -581,7 +688,20 @@ impl SiteBuilder {
         );
         
         asset_pipeline.set_component_registry(self.component_registry.clone());
-        asset_pipeline.set_base_url(self.config.site.base_url.clone());        if let Err(e) = asset_pipeline.process_assets() {
+        asset_pipeline.set_base_url(self.config.site.base_url.clone());
+        asset_pipeline.set_math_rendering(self.config.math_rendering.clone());
+        asset_pipeline.set_code_blocks(self.config.code_blocks.clone(), self.config.syntax_dirs());
+        
+        // Vendored here rather than in the pipeline, whose failures only warn
+        let katex = crate::content::rst::katex_bundle_config(
+            &self.config.math_rendering,
+            Path::new(crate::content::rst::math_formulas::katex_assets::CACHE_DIR),
+        )
+        .await?;
+        let katex_assets = crate::content::rst::vendor_katex(&katex, &assets_dir, &self.config.site.base_url)?;
+        asset_pipeline.set_katex_assets(katex_assets);
+        
+        if let Err(e) = asset_pipeline.process_assets() {
             eprintln!("Warning: Failed to process theme assets: {}", e);
             // Fallback to basic asset copying
             self.copy_theme_assets("css", &assets_dir)?;
@@ -672,6 +792,7 @@ impl SiteBuilder {
         context.insert("toc", &content.toc);
         context.insert("has_math_formulas", &content.has_math_formulas);
         context.insert("math_formula_count", &content.math_formula_count);
+        context.insert("related", self.related.get(&content.metadata.url));
         
         // Generate book TOC for book pages
         if content.metadata.content_type == ContentType::Book {
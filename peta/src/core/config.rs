@@ -12,11 +12,19 @@ pub struct SiteConfig {
     pub social: SocialConfig,
     pub build: BuildConfig,
     pub rst: RstConfig,
+    #[serde(default)]
+    pub math_rendering: MathRenderingConfig,
+    #[serde(default)]
+    pub code_blocks: CodeBlocksConfig,
     pub server: ServerConfig,
     pub search: SearchConfig,
+    #[serde(default)]
+    pub related: RelatedConfig,
     pub assets: AssetsConfig,
     pub deploy: DeployConfig,
     pub components: crate::components::SiteComponentConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
 }
 
 impl Default for SiteConfig {
@@ -26,11 +34,15 @@ impl Default for SiteConfig {
             social: SocialConfig::default(),
             build: BuildConfig::default(),
             rst: RstConfig::default(),
+            math_rendering: MathRenderingConfig::default(),
+            code_blocks: CodeBlocksConfig::default(),
             server: ServerConfig::default(),
             search: SearchConfig::default(),
+            related: RelatedConfig::default(),
             assets: AssetsConfig::default(),
             deploy: DeployConfig::default(),
             components: crate::components::SiteComponentConfig::default(),
+            hooks: HooksConfig::default(),
         }
     }
 }
@@ -61,6 +73,9 @@ pub struct SiteInfo {
     pub url: String,
     pub author: String,
     pub base_url: String,
+    /// Default content language, overridden per page by `lang` frontmatter
+    #[serde(default = "default_site_lang")]
+    pub lang: String,
 }
 
 impl Default for SiteInfo {
@@ -71,6 +86,7 @@ impl Default for SiteInfo {
             url: "https://example.com".to_string(),
             author: "Peta Team".to_string(),
             base_url: String::new(),
+            lang: default_site_lang(),
         }
     }
 }
@@ -148,6 +164,107 @@ impl Default for MathConfig {
     }
 }
 
+/// Math rendering configuration under `[math_rendering]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MathRenderingConfig {
+    pub engine: String,
+    pub auto_detect: bool,
+    pub on_demand_loading: bool,
+    /// Where formulas are turned into KaTeX markup
+    pub mode: MathRenderMode,
+    /// Markup produced when rendering at build time
+    pub output: MathOutput,
+    /// How labeled display equations are numbered
+    pub equation_numbering: EquationNumbering,
+    /// LaTeX macros applied to every formula, on top of `macros.tex`
+    pub macros: std::collections::BTreeMap<String, String>,
+    pub katex: KatexConfig,
+}
+
+impl Default for MathRenderingConfig {
+    fn default() -> Self {
+        Self {
+            engine: "katex".to_string(),
+            auto_detect: true,
+            on_demand_loading: true,
+            mode: MathRenderMode::default(),
+            output: MathOutput::default(),
+            equation_numbering: EquationNumbering::default(),
+            macros: std::collections::BTreeMap::new(),
+            katex: KatexConfig::default(),
+        }
+    }
+}
+
+/// Where math formulas are rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MathRenderMode {
+    /// KaTeX renders `data-latex` placeholders in the browser
+    #[default]
+    Client,
+    /// Formulas are rendered while building, pages load no math JavaScript
+    Build,
+}
+
+/// Markup produced by build-time math rendering
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MathOutput {
+    Html,
+    Mathml,
+    /// HTML for display, with MathML for screen readers
+    #[default]
+    HtmlAndMathml,
+}
+
+/// Numbering scheme for labeled display equations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EquationNumbering {
+    /// (1), (2), ... on every page
+    #[default]
+    Page,
+    /// (3.1), (3.2), ... in book chapters, using the chapter's toctree position
+    Chapter,
+}
+
+/// KaTeX assets under `[math_rendering.katex]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KatexConfig {
+    /// Version loaded in the browser in client mode
+    pub version: String,
+    pub cdn_base: String,
+    /// Where pages load the KaTeX JS, CSS and fonts from
+    pub source: KatexSource,
+    /// KaTeX `dist` directory copied into the output when `source = "local"`
+    pub path: Option<String>,
+}
+
+impl Default for KatexConfig {
+    fn default() -> Self {
+        Self {
+            version: "0.16.9".to_string(),
+            cdn_base: "https://cdn.jsdelivr.net/npm/katex".to_string(),
+            source: KatexSource::default(),
+            path: None,
+        }
+    }
+}
+
+/// Origin of the KaTeX browser assets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KatexSource {
+    /// `cdn_base` at `version`
+    #[default]
+    Cdn,
+    /// A bundle vendored into the output directory
+    Local,
+}
+
 /// Code highlighting configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeConfig {
@@ -166,6 +283,155 @@ impl Default for CodeConfig {
     }
 }
 
+/// Site-wide code block defaults under `[code_blocks]`
+///
+/// `code-block` directive options such as `:linenos:` override these per block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CodeBlocksConfig {
+    pub default_theme: String,
+    pub enable_line_numbers: bool,
+    pub enable_copy_button: bool,
+    /// Languages by syntax name, with the other names code blocks may use
+    ///
+    /// A `[code_blocks.languages]` table replaces the built-in list.
+    pub languages: std::collections::BTreeMap<String, LanguageConfig>,
+    /// Build-time execution of `code-run` blocks
+    pub run: CodeRunConfig,
+    /// Checks run over code blocks by `peta test-snippets`
+    pub test: CodeTestConfig,
+}
+
+impl Default for CodeBlocksConfig {
+    fn default() -> Self {
+        let languages = DEFAULT_LANGUAGE_ALIASES
+            .iter()
+            .map(|(language, aliases)| {
+                let aliases = aliases.iter().map(|alias| alias.to_string()).collect();
+                (language.to_string(), LanguageConfig { aliases })
+            })
+            .collect();
+
+        Self {
+            default_theme: "one-dark".to_string(),
+            enable_line_numbers: true,
+            enable_copy_button: true,
+            languages,
+            run: CodeRunConfig::default(),
+            test: CodeTestConfig::default(),
+        }
+    }
+}
+
+impl CodeBlocksConfig {
+    /// Map every configured alias to the language it names
+    pub fn language_aliases(&self) -> std::collections::HashMap<String, String> {
+        self.languages
+            .iter()
+            .flat_map(|(language, config)| {
+                config.aliases.iter().map(move |alias| (alias.clone(), language.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Alternative names of a language under `[code_blocks.languages]`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LanguageConfig {
+    pub aliases: Vec<String>,
+}
+
+/// Interpreters for `code-run` blocks under `[code_blocks.run]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CodeRunConfig {
+    /// Seconds a block may run before it is stopped
+    pub timeout: u64,
+    /// Fail the build when a block exits non-zero, without `:fail-on-error:`
+    pub fail_on_error: bool,
+    /// Command running each language
+    pub languages: std::collections::BTreeMap<String, RunCommandConfig>,
+}
+
+impl Default for CodeRunConfig {
+    fn default() -> Self {
+        Self {
+            timeout: 10,
+            fail_on_error: false,
+            languages: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+/// Shell command running one language's code blocks
+///
+/// The block is written to `file` in an empty temporary directory, which
+/// is the working directory of `command`; `{file}` in the command is
+/// replaced with the file name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunCommandConfig {
+    pub command: String,
+    #[serde(default = "default_run_file")]
+    pub file: String,
+}
+
+fn default_run_file() -> String {
+    "main".to_string()
+}
+
+/// Check commands for `peta test-snippets` under `[code_blocks.test]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CodeTestConfig {
+    /// Seconds a check may run before it counts as failed
+    pub timeout: u64,
+    /// Directory the scratch projects are written to
+    pub scratch_dir: String,
+    /// Command checking each language
+    pub languages: std::collections::BTreeMap<String, TestCommandConfig>,
+}
+
+impl Default for CodeTestConfig {
+    fn default() -> Self {
+        Self {
+            timeout: 30,
+            scratch_dir: ".peta_cache/snippet-tests".to_string(),
+            languages: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+/// Shell command checking one language's code blocks
+///
+/// Blocks marked `:test:` are checked; with `all` set, so is every other
+/// block of the language except those marked `:no-test:`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCommandConfig {
+    pub command: String,
+    #[serde(default = "default_run_file")]
+    pub file: String,
+    #[serde(default)]
+    pub all: bool,
+}
+
+/// Aliases used when `peta.toml` has no `[code_blocks.languages]` table
+const DEFAULT_LANGUAGE_ALIASES: &[(&str, &[&str])] = &[
+    ("javascript", &["js", "node"]),
+    ("typescript", &["ts", "tsx"]),
+    ("python", &["py", "py3", "python3"]),
+    ("ruby", &["rb"]),
+    ("bash", &["sh", "shell", "zsh"]),
+    ("rust", &["rs"]),
+    ("c++", &["cpp", "cxx", "cc", "hpp"]),
+    ("c", &["h"]),
+    ("kotlin", &["kt"]),
+    ("csharp", &["cs"]),
+    ("html", &["htm"]),
+    ("yaml", &["yml"]),
+    ("diff", &["patch"]),
+];
+
 /// Server configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
@@ -193,6 +459,33 @@ pub struct SearchConfig {
     pub client_side: bool,
     pub index_content: bool,
     pub index_metadata: bool,
+    /// BM25 term frequency saturation
+    #[serde(default = "default_bm25_k1")]
+    pub k1: f64,
+    /// BM25 length normalization, 0 disables it
+    #[serde(default = "default_bm25_b")]
+    pub b: f64,
+    /// Per-field weights for BM25F scoring
+    #[serde(default)]
+    pub boosts: SearchBoosts,
+    /// Leading term characters that pick a client index shard
+    #[serde(default = "default_shard_prefix_length")]
+    pub shard_prefix_length: usize,
+    /// Document bodies per client index chunk
+    #[serde(default = "default_chunk_size")]
+    pub chunk_size: usize,
+    /// Complete the last query token and tolerate typos
+    #[serde(default = "default_true")]
+    pub fuzzy_search: bool,
+    /// Index each table of contents section as its own result
+    #[serde(default = "default_true")]
+    pub sections: bool,
+    /// Where the search page sends queries
+    #[serde(default)]
+    pub backend: SearchBackend,
+    /// Tantivy index and `peta search-server` settings (`[search.server]`)
+    #[serde(default)]
+    pub server: SearchServerConfig,
 }
 
 impl Default for SearchConfig {
@@ -202,10 +495,124 @@ impl Default for SearchConfig {
             client_side: true,
             index_content: true,
             index_metadata: true,
+            k1: default_bm25_k1(),
+            b: default_bm25_b(),
+            boosts: SearchBoosts::default(),
+            shard_prefix_length: default_shard_prefix_length(),
+            chunk_size: default_chunk_size(),
+            fuzzy_search: true,
+            sections: true,
+            backend: SearchBackend::default(),
+            server: SearchServerConfig::default(),
+        }
+    }
+}
+
+/// Search backend used by the search page
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchBackend {
+    /// Sharded index searched in the browser, needs no server
+    #[default]
+    Static,
+    /// Tantivy index queried through `peta search-server`
+    Server,
+}
+
+/// Server-side search configuration (`[search.server]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchServerConfig {
+    /// Directory the tantivy index is written to, outside the static output
+    pub index_dir: String,
+    /// URL of the search endpoint, as requested by the search page
+    pub url: String,
+    pub port: u16,
+    pub host: String,
+}
+
+impl Default for SearchServerConfig {
+    fn default() -> Self {
+        Self {
+            index_dir: "_out/search-index".to_string(),
+            url: "/api/search".to_string(),
+            port: 3567,
+            host: "127.0.0.1".to_string(),
+        }
+    }
+}
+
+/// Field weights under `[search.boosts]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchBoosts {
+    pub title: f64,
+    pub tags: f64,
+    pub headings: f64,
+    pub body: f64,
+}
+
+impl Default for SearchBoosts {
+    fn default() -> Self {
+        Self {
+            title: 5.0,
+            tags: 3.0,
+            headings: 2.0,
+            body: 1.0,
         }
     }
 }
 
+/// Related content recommendations under `[related]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelatedConfig {
+    pub enabled: bool,
+    /// Items listed per page, not counting pinned ones beyond it
+    pub count: usize,
+    /// Weight of the TF-IDF cosine similarity of the page texts
+    pub text_weight: f64,
+    /// Weight of the share of tags in common
+    pub tag_weight: f64,
+    /// Added for chapters of the same book
+    pub book_weight: f64,
+    /// Lowest combined score an item is listed with
+    pub min_score: f64,
+}
+
+impl Default for RelatedConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            count: 5,
+            text_weight: 1.0,
+            tag_weight: 0.5,
+            book_weight: 0.25,
+            min_score: 0.1,
+        }
+    }
+}
+
+fn default_site_lang() -> String {
+    "en".to_string()
+}
+
+fn default_bm25_k1() -> f64 {
+    1.2
+}
+
+fn default_bm25_b() -> f64 {
+    0.75
+}
+
+fn default_shard_prefix_length() -> usize {
+    2
+}
+
+fn default_chunk_size() -> usize {
+    100
+}
+
 /// Asset processing configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetsConfig {
@@ -226,12 +633,48 @@ impl Default for AssetsConfig {
     }
 }
 
+/// Shell commands run at fixed points of the build (`[hooks]`)
+///
+/// Commands run through the system shell from the site root; a non-zero exit
+/// status aborts the build or deploy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HooksConfig {
+    /// Run before content is loaded
+    #[serde(default)]
+    pub pre_build: Vec<String>,
+    /// Run after the output directory has been generated
+    #[serde(default)]
+    pub post_build: Vec<String>,
+    /// Run after verification, right before uploading
+    #[serde(default)]
+    pub pre_deploy: Vec<String>,
+}
+
 /// Deployment configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeployConfig {
     pub target: String,
     pub branch: String,
     pub domain: String,
+    /// Verify the built output before deploying
+    #[serde(default = "default_true")]
+    pub verify: bool,
+    #[serde(default)]
+    pub s3: Option<S3DeployConfig>,
+    #[serde(default)]
+    pub netlify: Option<NetlifyDeployConfig>,
+    #[serde(default)]
+    pub vercel: Option<VercelDeployConfig>,
+    #[serde(default)]
+    pub local: Option<LocalDeployConfig>,
+    #[serde(default)]
+    pub ssh: Option<SshDeployConfig>,
+    /// Redirects emitted as `_redirects` (Netlify) or `vercel.json` (Vercel)
+    #[serde(default)]
+    pub redirects: Vec<RedirectRule>,
+    /// Response headers emitted as `_headers` (Netlify) or `vercel.json` (Vercel)
+    #[serde(default)]
+    pub headers: Vec<HeaderRule>,
 }
 
 impl Default for DeployConfig {
@@ -240,10 +683,242 @@ impl Default for DeployConfig {
             target: "github".to_string(),
             branch: "gh-pages".to_string(),
             domain: "username.github.io".to_string(),
+            verify: true,
+            s3: None,
+            netlify: None,
+            vercel: None,
+            local: None,
+            ssh: None,
+            redirects: Vec::new(),
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// S3-compatible deployment configuration (`[deploy.s3]`)
+///
+/// Credentials are never read from `peta.toml`; they come from the
+/// `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY` environment variables.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S3DeployConfig {
+    pub bucket: String,
+    #[serde(default = "default_s3_region")]
+    pub region: String,
+    /// Custom endpoint URL for S3-compatible services such as MinIO
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// Key prefix inside the bucket
+    #[serde(default)]
+    pub prefix: String,
+    /// Use path-style addressing (`endpoint/bucket/key`)
+    #[serde(default)]
+    pub path_style: bool,
+    /// Delete remote objects that no longer exist locally
+    #[serde(default)]
+    pub delete_stale: bool,
+    /// Cache-Control for HTML documents
+    #[serde(default = "default_html_cache_control")]
+    pub html_cache_control: String,
+    /// Cache-Control for fingerprinted assets
+    #[serde(default = "default_asset_cache_control")]
+    pub asset_cache_control: String,
+    /// Per-pattern Cache-Control overrides, first match wins
+    #[serde(default)]
+    pub cache_control: Vec<CacheControlRule>,
+}
+
+/// Cache-Control header applied to files matching a glob pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheControlRule {
+    pub pattern: String,
+    pub value: String,
+}
+
+impl Default for S3DeployConfig {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            region: default_s3_region(),
+            endpoint: None,
+            prefix: String::new(),
+            path_style: false,
+            delete_stale: false,
+            html_cache_control: default_html_cache_control(),
+            asset_cache_control: default_asset_cache_control(),
+            cache_control: Vec::new(),
+        }
+    }
+}
+
+/// Netlify deployment configuration (`[deploy.netlify]`)
+///
+/// The access token is read from the `NETLIFY_AUTH_TOKEN` environment variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetlifyDeployConfig {
+    pub site_id: String,
+    /// API base URL, overridable for testing against a mock server
+    #[serde(default = "default_netlify_api_url")]
+    pub api_url: String,
+}
+
+impl Default for NetlifyDeployConfig {
+    fn default() -> Self {
+        Self {
+            site_id: String::new(),
+            api_url: default_netlify_api_url(),
+        }
+    }
+}
+
+/// Vercel deployment configuration (`[deploy.vercel]`)
+///
+/// The access token is read from the `VERCEL_TOKEN` environment variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VercelDeployConfig {
+    pub project: String,
+    #[serde(default)]
+    pub team_id: Option<String>,
+    /// Promote the deployment to production
+    #[serde(default = "default_true")]
+    pub production: bool,
+    /// API base URL, overridable for testing against a mock server
+    #[serde(default = "default_vercel_api_url")]
+    pub api_url: String,
+}
+
+impl Default for VercelDeployConfig {
+    fn default() -> Self {
+        Self {
+            project: String::new(),
+            team_id: None,
+            production: true,
+            api_url: default_vercel_api_url(),
         }
     }
 }
 
+/// Local directory deployment configuration (`[deploy.local]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalDeployConfig {
+    /// Target directory, e.g. a web server's document root
+    pub path: String,
+    #[serde(default)]
+    pub mode: LocalDeployMode,
+    /// Deploy into a new release directory and swap a symlink at `path`
+    #[serde(default)]
+    pub atomic: bool,
+    /// Number of release directories kept for atomic deploys
+    #[serde(default = "default_keep_releases")]
+    pub keep_releases: usize,
+}
+
+impl Default for LocalDeployConfig {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            mode: LocalDeployMode::default(),
+            atomic: false,
+            keep_releases: default_keep_releases(),
+        }
+    }
+}
+
+/// How files are written to a local target
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LocalDeployMode {
+    /// Replace the target directory with a fresh copy
+    Copy,
+    /// Copy changed files and remove files that no longer exist
+    #[default]
+    Sync,
+}
+
+/// SSH deployment configuration (`[deploy.ssh]`)
+///
+/// Authentication is left to the system `sftp` client, so keys from
+/// `ssh-agent` and `~/.ssh/config` are used as usual.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshDeployConfig {
+    pub host: String,
+    #[serde(default)]
+    pub user: Option<String>,
+    #[serde(default = "default_ssh_port")]
+    pub port: u16,
+    /// Remote directory to upload into
+    pub path: String,
+    #[serde(default)]
+    pub identity_file: Option<String>,
+    /// Delete remote files that no longer exist locally
+    #[serde(default)]
+    pub delete_stale: bool,
+}
+
+impl Default for SshDeployConfig {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            user: None,
+            port: default_ssh_port(),
+            path: String::new(),
+            identity_file: None,
+            delete_stale: false,
+        }
+    }
+}
+
+/// Redirect from one site path to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedirectRule {
+    pub from: String,
+    pub to: String,
+    #[serde(default = "default_redirect_status")]
+    pub status: u16,
+}
+
+/// Response headers applied to paths matching a pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeaderRule {
+    pub path: String,
+    pub values: std::collections::BTreeMap<String, String>,
+}
+
+fn default_netlify_api_url() -> String {
+    crate::deploy::netlify::NETLIFY_API_URL.to_string()
+}
+
+fn default_vercel_api_url() -> String {
+    crate::deploy::vercel::VERCEL_API_URL.to_string()
+}
+
+fn default_keep_releases() -> usize {
+    5
+}
+
+fn default_ssh_port() -> u16 {
+    22
+}
+
+fn default_redirect_status() -> u16 {
+    301
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_s3_region() -> String {
+    "us-east-1".to_string()
+}
+
+fn default_html_cache_control() -> String {
+    "public, max-age=300, must-revalidate".to_string()
+}
+
+fn default_asset_cache_control() -> String {
+    "public, max-age=31536000, immutable".to_string()
+}
+
 impl SiteConfig {
     /// Load configuration from file
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
@@ -281,4 +956,15 @@ impl SiteConfig {
     pub fn theme_dir(&self) -> std::path::PathBuf {
         std::path::PathBuf::from(&self.build.theme_dir)
     }
+    
+    /// Directories searched for `.sublime-syntax` and `.tmTheme` files
+    ///
+    /// The site's own `syntaxes/` comes last, so its definitions win over
+    /// the theme's.
+    pub fn syntax_dirs(&self) -> Vec<std::path::PathBuf> {
+        vec![
+            self.theme_dir().join("syntaxes"),
+            std::path::PathBuf::from("syntaxes"),
+        ]
+    }
 }
\ No newline at end of file
@@ -41,6 +41,20 @@ pub enum Error {
     #[error("Deployment error: {0}")]
     Deploy(String),
     
+    #[error("Hook error: {0}")]
+    Hook(String),
+    
+    #[error("Code execution error: {0}")]
+    CodeRun(String),
+    
+    #[error("Math error: {}invalid LaTeX `{formula}`: {message}", .location.as_ref().map(|l| format!("{}: ", l)).unwrap_or_default())]
+    Math {
+        formula: String,
+        message: String,
+        /// `file:line` of the formula, when known
+        location: Option<String>,
+    },
+    
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     
@@ -125,6 +139,37 @@ impl Error {
         Self::Deploy(msg.into())
     }
     
+    /// Create a build hook error
+    pub fn hook<S: Into<String>>(msg: S) -> Self {
+        Self::Hook(msg.into())
+    }
+    
+    /// Create an error for a `code-run` block that failed
+    pub fn code_run<S: Into<String>>(msg: S) -> Self {
+        Self::CodeRun(msg.into())
+    }
+    
+    /// Create an error for a formula KaTeX could not render
+    pub fn math<F: Into<String>, M: Into<String>>(formula: F, message: M) -> Self {
+        Self::Math {
+            formula: formula.into(),
+            message: message.into(),
+            location: None,
+        }
+    }
+    
+    /// Attach the source location to a math error, keeping the first one set
+    pub fn at<S: Into<String>>(self, at: S) -> Self {
+        match self {
+            Self::Math { formula, message, location: None } => Self::Math {
+                formula,
+                message,
+                location: Some(at.into()),
+            },
+            other => other,
+        }
+    }
+    
     /// Create an IO error
     pub fn io(e: std::io::Error) -> Self {
         Self::Io(e)
@@ -0,0 +1,168 @@
+//! Build hooks: shell commands configured under `[hooks]`
+//!
+//! Not to be confused with the browser-side JavaScript hooks written by
+//! `SiteBuilder::generate_hooks_system`. These run on the build machine, e.g.
+//! to generate API docs before the build or optimize PDFs after it.
+
+use crate::core::config::HooksConfig;
+use crate::core::{Error, Result};
+use crate::utils::process::async_shell;
+use std::path::PathBuf;
+
+/// Point in the pipeline at which hooks run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStage {
+    PreBuild,
+    PostBuild,
+    PreDeploy,
+}
+
+impl HookStage {
+    /// Name as used in `[hooks]`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookStage::PreBuild => "pre_build",
+            HookStage::PostBuild => "post_build",
+            HookStage::PreDeploy => "pre_deploy",
+        }
+    }
+
+    /// Commands configured for this stage
+    pub fn commands<'a>(&self, config: &'a HooksConfig) -> &'a [String] {
+        match self {
+            HookStage::PreBuild => &config.pre_build,
+            HookStage::PostBuild => &config.post_build,
+            HookStage::PreDeploy => &config.pre_deploy,
+        }
+    }
+}
+
+/// What triggered the build
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuildMode {
+    /// `peta build` and other one-off builds
+    #[default]
+    Build,
+    /// Rebuilds from the development server
+    Serve,
+    /// Builds for `peta deploy`
+    Deploy,
+}
+
+impl BuildMode {
+    /// Name exposed as `PETA_BUILD_MODE`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuildMode::Build => "build",
+            BuildMode::Serve => "serve",
+            BuildMode::Deploy => "deploy",
+        }
+    }
+}
+
+/// Information passed to hook commands as environment variables
+#[derive(Debug, Clone, Default)]
+pub struct HookContext {
+    pub output_dir: String,
+    pub content_dir: String,
+    pub mode: BuildMode,
+    /// Files that triggered a rebuild; empty for full builds
+    pub changed_files: Vec<PathBuf>,
+    /// Deploy target, for `pre_deploy`
+    pub deploy_target: Option<String>,
+}
+
+impl HookContext {
+    /// Environment variables exposed to hook commands
+    pub fn env(&self, stage: HookStage) -> Vec<(&'static str, String)> {
+        let changed_files = self
+            .changed_files
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut env = vec![
+            ("PETA_HOOK", stage.as_str().to_string()),
+            ("PETA_BUILD_MODE", self.mode.as_str().to_string()),
+            ("PETA_OUTPUT_DIR", self.output_dir.clone()),
+            ("PETA_CONTENT_DIR", self.content_dir.clone()),
+            ("PETA_CHANGED_FILES", changed_files),
+        ];
+        if let Some(target) = &self.deploy_target {
+            env.push(("PETA_DEPLOY_TARGET", target.clone()));
+        }
+        env
+    }
+}
+
+/// Run the commands configured for `stage`, stopping at the first failure
+pub async fn run_hooks(config: &HooksConfig, stage: HookStage, context: &HookContext) -> Result<()> {
+    for command in stage.commands(config) {
+        println!("🪝 Running {} hook: {}", stage.as_str(), command);
+
+        let status = async_shell(command)
+            .envs(context.env(stage))
+            .status()
+            .await
+            .map_err(|e| Error::hook(format!("Failed to run {} hook '{}': {}", stage.as_str(), command, e)))?;
+
+        if !status.success() {
+            let code = status
+                .code()
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "a signal".to_string());
+            return Err(Error::hook(format!(
+                "{} hook '{}' exited with {}",
+                stage.as_str(),
+                command,
+                code
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_hooks_receive_environment() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path().join("env.txt");
+        let config = HooksConfig {
+            post_build: vec![format!(
+                "echo \"$PETA_HOOK $PETA_BUILD_MODE $PETA_OUTPUT_DIR $PETA_CHANGED_FILES\" > {}",
+                out.display()
+            )],
+            ..HooksConfig::default()
+        };
+        let context = HookContext {
+            output_dir: "_out/dist".to_string(),
+            mode: BuildMode::Serve,
+            changed_files: vec![PathBuf::from("_content/a.rst")],
+            ..HookContext::default()
+        };
+
+        run_hooks(&config, HookStage::PostBuild, &context).await.unwrap();
+        // Other stages have no commands configured
+        run_hooks(&config, HookStage::PreBuild, &context).await.unwrap();
+
+        let written = std::fs::read_to_string(out).unwrap();
+        assert_eq!(written.trim(), "post_build serve _out/dist _content/a.rst");
+    }
+
+    #[tokio::test]
+    async fn test_failing_hook_aborts() {
+        let config = HooksConfig {
+            pre_build: vec!["exit 3".to_string(), "echo never".to_string()],
+            ..HooksConfig::default()
+        };
+
+        let err = run_hooks(&config, HookStage::PreBuild, &HookContext::default()).await.unwrap_err();
+        assert_eq!(err.to_string(), "Hook error: pre_build hook 'exit 3' exited with 3");
+    }
+}
@@ -5,9 +5,11 @@ pub mod builder;
 pub mod config;
 pub mod error;
 pub mod theme;
+pub mod hooks;
 
 pub use site::Site;
 pub use builder::SiteBuilder;
 pub use config::SiteConfig;
 pub use error::{Error, Result};
-pub use theme::{Theme, ThemeSystem};
\ No newline at end of file
+pub use theme::{Theme, ThemeSystem};
+pub use hooks::{BuildMode, HookContext, HookStage};
\ No newline at end of file
@@ -60,6 +60,18 @@ impl Site {
             .collect()
     }
     
+    /// Files outside the content pages that the site was rendered from
+    pub fn dependencies(&self) -> Vec<std::path::PathBuf> {
+        let mut dependencies: Vec<_> = self
+            .content
+            .iter()
+            .flat_map(|c| c.dependencies.iter().cloned())
+            .collect();
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+    
     /// Get all unique tags
     pub fn get_all_tags(&self) -> Vec<&String> {
         let mut tags: Vec<&String> = self.tag_counts.keys().collect();
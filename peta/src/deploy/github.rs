@@ -1,6 +1,7 @@
 //! GitHub Pages deployment
 
-use crate::core::{Site, Result};
+use crate::core::{Error, Result, Site};
+use crate::deploy::{DeployReport, Deployer};
 
 /// GitHub Pages deployer
 pub struct GitHubDeployer {
@@ -34,16 +35,27 @@ impl GitHubDeployer {
     pub fn with_config(config: GitHubConfig) -> Self {
         Self { config }
     }
-    
-    /// Deploy to GitHub Pages
-    pub async fn deploy(&self, _site: &Site) -> Result<()> {
+}
+
+#[async_trait::async_trait]
+impl Deployer for GitHubDeployer {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    async fn deploy(&self, _site: &Site) -> Result<DeployReport> {
         // For now, this is a placeholder
         // In a real implementation, you would:
         // 1. Initialize git repository
         // 2. Add and commit files
         // 3. Push to GitHub Pages branch
-        
-        println!("Deploying to GitHub Pages...");
-        Ok(())
+
+        Err(Error::deploy("Deployment to GitHub Pages is not yet implemented"))
     }
-}
\ No newline at end of file
+}
+
+impl Default for GitHubDeployer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
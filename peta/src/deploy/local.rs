@@ -0,0 +1,312 @@
+//! Local directory deployment
+//!
+//! Copies or syncs the output directory into a directory on this machine,
+//! e.g. a web server's document root. With `atomic = true` every deploy goes
+//! into a fresh release directory and a symlink at `path` is swapped to it in
+//! a single rename, so readers never see a half-written site.
+
+use crate::core::config::{LocalDeployConfig, LocalDeployMode};
+use crate::core::{Error, Result, Site};
+use crate::deploy::{collect_file_digests, DeployReport, Deployer, SyncPlan};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Local directory deployer
+pub struct LocalDeployer {
+    config: LocalConfig,
+}
+
+/// Local deployment configuration
+#[derive(Debug, Clone)]
+pub struct LocalConfig {
+    pub path: PathBuf,
+    pub output_dir: String,
+    pub mode: LocalDeployMode,
+    pub atomic: bool,
+    pub keep_releases: usize,
+}
+
+impl LocalConfig {
+    /// Build a runtime config from `[deploy.local]`
+    pub fn from_settings(settings: &LocalDeployConfig, output_dir: &str) -> Result<Self> {
+        if settings.path.is_empty() {
+            return Err(Error::deploy("deploy.local.path is not set"));
+        }
+
+        Ok(Self {
+            path: PathBuf::from(&settings.path),
+            output_dir: output_dir.to_string(),
+            mode: settings.mode.clone(),
+            atomic: settings.atomic,
+            keep_releases: settings.keep_releases,
+        })
+    }
+}
+
+impl LocalDeployer {
+    /// Create a new local deployer
+    pub fn with_config(config: LocalConfig) -> Self {
+        Self { config }
+    }
+
+    /// Directory holding the release directories for atomic deploys
+    fn releases_dir(&self) -> PathBuf {
+        let name = self.config.path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "site".to_string());
+        self.config.path.with_file_name(format!(".{}-releases", name))
+    }
+
+    /// Deploy into a new release directory and swap the symlink
+    fn deploy_atomic(&self, source: &Path) -> Result<DeployReport> {
+        if self.config.path.exists() && !self.config.path.is_symlink() {
+            return Err(Error::deploy(format!(
+                "{} exists and is not a symlink; move it away to enable atomic deploys",
+                self.config.path.display()
+            )));
+        }
+
+        let releases_dir = self.releases_dir();
+        std::fs::create_dir_all(&releases_dir)?;
+
+        // Never reuse a release directory, it may be the one being served
+        let stamp = chrono::Utc::now().format("%Y%m%d%H%M%S%6f").to_string();
+        let mut release = releases_dir.join(&stamp);
+        let mut suffix = 1;
+        while release.exists() {
+            release = releases_dir.join(format!("{}-{}", stamp, suffix));
+            suffix += 1;
+        }
+        // Link targets are relative to the link's directory, not the current one
+        let link_dir = self.config.path.parent().unwrap_or(Path::new(""));
+        let previous = std::fs::read_link(&self.config.path).ok().map(|target| link_dir.join(target));
+
+        // Sync from the previous release so unchanged files are counted as such
+        if let (LocalDeployMode::Sync, Some(previous)) = (&self.config.mode, &previous) {
+            if previous.is_dir() {
+                copy_tree(previous, &release)?;
+            }
+        }
+        let report = sync_tree(source, &release, true)?;
+
+        let temp_link = self.config.path.with_file_name(format!(
+            ".{}.tmp",
+            self.config.path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+        ));
+        if temp_link.is_symlink() {
+            std::fs::remove_file(&temp_link)?;
+        }
+        // Release and link share a parent, so a relative target works wherever `path` is
+        let release_target = release.strip_prefix(link_dir).unwrap_or(&release);
+        symlink_dir(release_target, &temp_link)?;
+        std::fs::rename(&temp_link, &self.config.path)?;
+
+        self.prune_releases(&releases_dir)?;
+
+        Ok(report)
+    }
+
+    /// Remove old releases beyond `keep_releases`
+    fn prune_releases(&self, releases_dir: &Path) -> Result<()> {
+        let mut releases: Vec<PathBuf> = std::fs::read_dir(releases_dir)?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .collect();
+        releases.sort();
+
+        let keep = self.config.keep_releases.max(1);
+        if releases.len() > keep {
+            for old in &releases[..releases.len() - keep] {
+                std::fs::remove_dir_all(old)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Deployer for LocalDeployer {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn deploy(&self, _site: &Site) -> Result<DeployReport> {
+        let source = PathBuf::from(&self.config.output_dir);
+        if !source.exists() {
+            return Err(Error::deploy(format!("Output directory {} does not exist", source.display())));
+        }
+
+        let mut report = if self.config.atomic {
+            self.deploy_atomic(&source)?
+        } else {
+            match self.config.mode {
+                LocalDeployMode::Copy => {
+                    if self.config.path.exists() {
+                        std::fs::remove_dir_all(&self.config.path)?;
+                    }
+                    sync_tree(&source, &self.config.path, false)?
+                }
+                LocalDeployMode::Sync => sync_tree(&source, &self.config.path, true)?,
+            }
+        };
+
+        report.url = Some(format!("file://{}", self.config.path.display()));
+        Ok(report)
+    }
+}
+
+/// Make `target` match `source`, copying changed files and optionally removing stale ones
+fn sync_tree(source: &Path, target: &Path, delete_stale: bool) -> Result<DeployReport> {
+    std::fs::create_dir_all(target)?;
+
+    let local = collect_file_digests(source)?;
+    let remote: HashMap<String, String> = collect_file_digests(target)?
+        .into_iter()
+        .map(|d| (d.path, d.sha1))
+        .collect();
+    let plan = SyncPlan::new(local, &remote, delete_stale);
+
+    for digest in &plan.uploads {
+        let to = target.join(&digest.path);
+        if let Some(parent) = to.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&digest.file, &to)?;
+    }
+
+    for key in &plan.deletes {
+        std::fs::remove_file(target.join(key))?;
+    }
+
+    Ok(DeployReport {
+        uploaded: plan.uploads.len(),
+        unchanged: plan.unchanged,
+        deleted: plan.deletes.len(),
+        url: None,
+    })
+}
+
+/// Recursively copy a directory
+fn copy_tree(source: &Path, target: &Path) -> Result<()> {
+    for entry in walkdir::WalkDir::new(source).into_iter().filter_map(|e| e.ok()) {
+        let relative = entry.path().strip_prefix(source)
+            .map_err(|e| Error::deploy(format!("Failed to get relative path: {}", e)))?;
+        let to = target.join(relative);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&to)?;
+        } else if entry.file_type().is_file() {
+            std::fs::copy(entry.path(), &to)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink_dir(original: &Path, link: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(original, link)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn symlink_dir(_original: &Path, _link: &Path) -> Result<()> {
+    Err(Error::deploy("Atomic local deploys require symlink support (unix only)"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn source_dir() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("index.html"), "home").unwrap();
+        std::fs::create_dir_all(dir.path().join("css")).unwrap();
+        std::fs::write(dir.path().join("css/main.css"), "body{}").unwrap();
+        dir
+    }
+
+    fn deployer(source: &Path, target: &Path, mode: LocalDeployMode, atomic: bool) -> LocalDeployer {
+        LocalDeployer::with_config(LocalConfig {
+            path: target.to_path_buf(),
+            output_dir: source.to_string_lossy().to_string(),
+            mode,
+            atomic,
+            keep_releases: 2,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_sync_copies_changed_and_removes_stale() {
+        let source = source_dir();
+        let target = TempDir::new().unwrap();
+        std::fs::write(target.path().join("stale.html"), "old").unwrap();
+        std::fs::write(target.path().join("index.html"), "home").unwrap();
+
+        let site = Site::new(crate::core::SiteConfig::default());
+        let report = deployer(source.path(), target.path(), LocalDeployMode::Sync, false)
+            .deploy(&site).await.unwrap();
+
+        assert_eq!(report.uploaded, 1);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.deleted, 1);
+        assert!(target.path().join("css/main.css").exists());
+        assert!(!target.path().join("stale.html").exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_atomic_deploy_swaps_symlink() {
+        let source = source_dir();
+        let root = TempDir::new().unwrap();
+        let target = root.path().join("www");
+        let site = Site::new(crate::core::SiteConfig::default());
+        let deployer = deployer(source.path(), &target, LocalDeployMode::Sync, true);
+
+        deployer.deploy(&site).await.unwrap();
+        let first = std::fs::read_link(&target).unwrap();
+        assert_eq!(std::fs::read_to_string(target.join("index.html")).unwrap(), "home");
+
+        std::fs::write(source.path().join("index.html"), "updated").unwrap();
+        let report = deployer.deploy(&site).await.unwrap();
+        let second = std::fs::read_link(&target).unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(report.uploaded, 1);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(std::fs::read_to_string(target.join("index.html")).unwrap(), "updated");
+
+        deployer.deploy(&site).await.unwrap();
+        let releases = std::fs::read_dir(root.path().join(".www-releases")).unwrap().count();
+        assert_eq!(releases, 2);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_atomic_deploy_with_relative_path() {
+        let source = source_dir();
+        let root = TempDir::new().unwrap();
+
+        // The same target, reached relative to the current directory
+        let cwd = std::env::current_dir().unwrap();
+        let mut target = PathBuf::new();
+        for _ in 1..cwd.components().count() {
+            target.push("..");
+        }
+        target.push(root.path().strip_prefix("/").unwrap());
+        target.push("www");
+
+        let site = Site::new(crate::core::SiteConfig::default());
+        let deployer = deployer(source.path(), &target, LocalDeployMode::Sync, true);
+        deployer.deploy(&site).await.unwrap();
+        std::fs::write(source.path().join("index.html"), "updated").unwrap();
+        let report = deployer.deploy(&site).await.unwrap();
+
+        let link = std::fs::read_link(root.path().join("www")).unwrap();
+        assert!(link.starts_with(".www-releases"), "{}", link.display());
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(std::fs::read_to_string(root.path().join("www/index.html")).unwrap(), "updated");
+    }
+}
@@ -4,8 +4,288 @@ pub mod github;
 pub mod netlify;
 pub mod vercel;
 pub mod s3;
+pub mod local;
+pub mod ssh;
+pub mod verify;
 
 pub use github::GitHubDeployer;
 pub use netlify::NetlifyDeployer;
 pub use vercel::VercelDeployer;
-pub use s3::S3Deployer;
\ No newline at end of file
+pub use s3::S3Deployer;
+pub use local::LocalDeployer;
+pub use ssh::SshDeployer;
+pub use verify::{SiteVerifier, VerificationReport};
+
+use crate::core::config::DeployConfig;
+use crate::core::{Error, Result, Site, SiteConfig};
+use std::collections::{HashMap, HashSet};
+
+/// Names of all deploy targets, in the order `peta deploy --list` shows them
+pub const DEPLOY_TARGETS: &[&str] = &["github", "netlify", "vercel", "s3", "local", "ssh"];
+
+/// A deployment target
+#[async_trait::async_trait]
+pub trait Deployer: Send + Sync {
+    /// Target name as used in `deploy.target`
+    fn name(&self) -> &'static str;
+
+    /// Deploy the built output directory
+    async fn deploy(&self, site: &Site) -> Result<DeployReport>;
+}
+
+/// Create the deployer for `target` from the `[deploy]` settings
+pub fn create_deployer(config: &SiteConfig, target: &str) -> Result<Box<dyn Deployer>> {
+    let deploy = &config.deploy;
+    let output_dir = &config.build.output_dir;
+    let missing = || Error::deploy(format!("Missing [deploy.{}] section in peta.toml", target));
+
+    let deployer: Box<dyn Deployer> = match target {
+        "github" => Box::new(GitHubDeployer::with_config(github::GitHubConfig {
+            repository: String::new(),
+            branch: deploy.branch.clone(),
+            token: String::new(),
+            output_dir: output_dir.clone(),
+        })),
+        "netlify" => {
+            let settings = deploy.netlify.as_ref().ok_or_else(missing)?;
+            Box::new(NetlifyDeployer::with_config(netlify::NetlifyConfig::from_settings(
+                settings,
+                &deploy.redirects,
+                &deploy.headers,
+                output_dir,
+            )?))
+        }
+        "vercel" => {
+            let settings = deploy.vercel.as_ref().ok_or_else(missing)?;
+            Box::new(VercelDeployer::with_config(vercel::VercelConfig::from_settings(
+                settings,
+                &deploy.redirects,
+                &deploy.headers,
+                output_dir,
+            )?))
+        }
+        "s3" => {
+            let settings = deploy.s3.as_ref().ok_or_else(missing)?;
+            Box::new(S3Deployer::with_config(s3::S3Config::from_settings(settings, output_dir)?))
+        }
+        "local" => {
+            let settings = deploy.local.as_ref().ok_or_else(missing)?;
+            Box::new(LocalDeployer::with_config(local::LocalConfig::from_settings(settings, output_dir)?))
+        }
+        "ssh" => {
+            let settings = deploy.ssh.as_ref().ok_or_else(missing)?;
+            Box::new(SshDeployer::with_config(ssh::SshConfig::from_settings(settings, output_dir)?))
+        }
+        _ => {
+            return Err(Error::deploy(format!(
+                "Unknown deploy target '{}' (available: {})",
+                target,
+                DEPLOY_TARGETS.join(", ")
+            )));
+        }
+    };
+
+    Ok(deployer)
+}
+
+/// Targets that have settings in `[deploy]`, with a short description of where they deploy to
+pub fn configured_targets(deploy: &DeployConfig) -> Vec<(&'static str, String)> {
+    let mut targets = Vec::new();
+
+    if deploy.target == "github" {
+        targets.push(("github", format!("{} ({})", deploy.domain, deploy.branch)));
+    }
+    if let Some(netlify) = &deploy.netlify {
+        targets.push(("netlify", format!("site {}", netlify.site_id)));
+    }
+    if let Some(vercel) = &deploy.vercel {
+        targets.push(("vercel", format!("project {}", vercel.project)));
+    }
+    if let Some(s3) = &deploy.s3 {
+        targets.push(("s3", format!("s3://{}/{}", s3.bucket, s3.prefix.trim_matches('/'))));
+    }
+    if let Some(local) = &deploy.local {
+        targets.push(("local", local.path.clone()));
+    }
+    if let Some(ssh) = &deploy.ssh {
+        let user = ssh.user.as_ref().map(|u| format!("{}@", u)).unwrap_or_default();
+        targets.push(("ssh", format!("{}{}:{}", user, ssh.host, ssh.path)));
+    }
+
+    targets
+}
+
+/// Summary of a completed deployment
+#[derive(Debug, Clone, Default)]
+pub struct DeployReport {
+    /// Files uploaded or copied
+    pub uploaded: usize,
+    /// Files left untouched because the remote copy is identical
+    pub unchanged: usize,
+    /// Remote files removed
+    pub deleted: usize,
+    /// Public URL of the deployment, if the target reports one
+    pub url: Option<String>,
+}
+
+/// A local file that can be compared against a remote listing
+pub trait SyncEntry: Clone {
+    /// Key the file is stored under remotely
+    fn key(&self) -> &str;
+
+    /// Content hash comparable with the remote listing
+    fn digest(&self) -> &str;
+}
+
+/// Upload and delete operations needed to bring a remote in sync
+#[derive(Debug)]
+pub struct SyncPlan<T> {
+    pub uploads: Vec<T>,
+    pub deletes: Vec<String>,
+    pub unchanged: usize,
+}
+
+impl<T: SyncEntry> SyncPlan<T> {
+    /// Compare local entries against a remote `key -> digest` listing
+    pub fn new(local: Vec<T>, remote: &HashMap<String, String>, delete_stale: bool) -> Self {
+        let mut plan = SyncPlan {
+            uploads: Vec::new(),
+            deletes: Vec::new(),
+            unchanged: 0,
+        };
+
+        for entry in &local {
+            match remote.get(entry.key()) {
+                Some(digest) if digest == entry.digest() => plan.unchanged += 1,
+                _ => plan.uploads.push(entry.clone()),
+            }
+        }
+
+        if delete_stale {
+            let local_keys: HashSet<&str> = local.iter().map(|e| e.key()).collect();
+            plan.deletes = remote
+                .keys()
+                .filter(|key| !local_keys.contains(key.as_str()))
+                .cloned()
+                .collect();
+            plan.deletes.sort();
+        }
+
+        plan
+    }
+}
+
+/// A file in the output directory with its content digest
+#[derive(Debug, Clone)]
+pub struct FileDigest {
+    /// Path relative to the output directory, `/`-separated
+    pub path: String,
+    /// Absolute location on disk
+    pub file: std::path::PathBuf,
+    /// Hex-encoded SHA1 of the content
+    pub sha1: String,
+    /// Size in bytes
+    pub size: u64,
+}
+
+impl SyncEntry for FileDigest {
+    fn key(&self) -> &str {
+        &self.path
+    }
+
+    fn digest(&self) -> &str {
+        &self.sha1
+    }
+}
+
+/// Hash every file below `output_dir`, sorted by path
+pub fn collect_file_digests(output_dir: &std::path::Path) -> Result<Vec<FileDigest>> {
+    use sha1::{Digest, Sha1};
+
+    if !output_dir.exists() {
+        return Err(Error::deploy(format!(
+            "Output directory {} does not exist",
+            output_dir.display()
+        )));
+    }
+
+    let mut digests = Vec::new();
+    for entry in walkdir::WalkDir::new(output_dir).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(output_dir)
+            .map_err(|e| Error::deploy(format!("Failed to get relative path: {}", e)))?;
+        let path = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let content = std::fs::read(entry.path())?;
+
+        digests.push(FileDigest {
+            path,
+            file: entry.path().to_path_buf(),
+            sha1: hex::encode(Sha1::digest(&content)),
+            size: content.len() as u64,
+        });
+    }
+
+    digests.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(digests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_file_digests() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("css")).unwrap();
+        std::fs::write(dir.path().join("index.html"), "hello").unwrap();
+        std::fs::write(dir.path().join("css/main.css"), "").unwrap();
+
+        let digests = collect_file_digests(dir.path()).unwrap();
+        let paths: Vec<&str> = digests.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, vec!["css/main.css", "index.html"]);
+        assert_eq!(digests[0].sha1, "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(digests[1].size, 5);
+    }
+
+    fn digest(path: &str, sha1: &str) -> FileDigest {
+        FileDigest {
+            path: path.to_string(),
+            file: std::path::PathBuf::from(path),
+            sha1: sha1.to_string(),
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn test_sync_plan_uploads_only_changed_files() {
+        let mut remote = HashMap::new();
+        remote.insert("index.html".to_string(), "aaa".to_string());
+        remote.insert("old.html".to_string(), "ccc".to_string());
+
+        let plan = SyncPlan::new(vec![digest("index.html", "aaa"), digest("new.html", "bbb")], &remote, false);
+        assert_eq!(plan.unchanged, 1);
+        assert_eq!(plan.uploads.len(), 1);
+        assert_eq!(plan.uploads[0].path, "new.html");
+        assert!(plan.deletes.is_empty());
+
+        let plan = SyncPlan::new(vec![digest("index.html", "changed")], &remote, true);
+        assert_eq!(plan.uploads.len(), 1);
+        assert_eq!(plan.deletes, vec!["old.html".to_string()]);
+    }
+
+    #[test]
+    fn test_create_deployer_requires_target_section() {
+        let config = SiteConfig::default();
+        assert_eq!(create_deployer(&config, "github").unwrap().name(), "github");
+        assert!(create_deployer(&config, "local").is_err());
+        assert!(create_deployer(&config, "ftp").is_err());
+    }
+}
@@ -1,11 +1,22 @@
 //! Netlify deployment
+//!
+//! Uses Netlify's file digest API: the deploy is created with a manifest of
+//! SHA1 digests and only the files Netlify reports as `required` are uploaded.
 
-use crate::core::{Site, Result};
+use crate::core::config::{HeaderRule, NetlifyDeployConfig, RedirectRule};
+use crate::core::{Error, Result, Site};
+use crate::deploy::{collect_file_digests, DeployReport, Deployer};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Default Netlify API base URL
+pub const NETLIFY_API_URL: &str = "https://api.netlify.com";
 
 /// Netlify deployer
 pub struct NetlifyDeployer {
-    #[allow(dead_code)]
     config: NetlifyConfig,
+    client: reqwest::Client,
 }
 
 /// Netlify deployment configuration
@@ -14,30 +25,212 @@ pub struct NetlifyConfig {
     pub site_id: String,
     pub token: String,
     pub output_dir: String,
+    pub api_url: String,
+    pub redirects: Vec<RedirectRule>,
+    pub headers: Vec<HeaderRule>,
+}
+
+impl NetlifyConfig {
+    /// Build a runtime config from `[deploy.netlify]`, reading the token from `NETLIFY_AUTH_TOKEN`
+    pub fn from_settings(
+        settings: &NetlifyDeployConfig,
+        redirects: &[RedirectRule],
+        headers: &[HeaderRule],
+        output_dir: &str,
+    ) -> Result<Self> {
+        let token = std::env::var("NETLIFY_AUTH_TOKEN")
+            .map_err(|_| Error::deploy("NETLIFY_AUTH_TOKEN is not set"))?;
+
+        Ok(Self {
+            site_id: settings.site_id.clone(),
+            token,
+            output_dir: output_dir.to_string(),
+            api_url: settings.api_url.clone(),
+            redirects: redirects.to_vec(),
+            headers: headers.to_vec(),
+        })
+    }
+}
+
+/// Response to deploy creation
+#[derive(Debug, Deserialize)]
+struct NetlifyDeploy {
+    id: String,
+    #[serde(default)]
+    required: Vec<String>,
+    #[serde(default)]
+    deploy_ssl_url: Option<String>,
+    #[serde(default)]
+    ssl_url: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
 }
 
 impl NetlifyDeployer {
     /// Create a new Netlify deployer with default config
     pub fn new() -> Self {
-        Self { 
-            config: NetlifyConfig {
-                site_id: String::new(),
-                token: String::new(),
-                output_dir: "_dist".to_string(),
-            }
-        }
+        Self::with_config(NetlifyConfig {
+            site_id: String::new(),
+            token: String::new(),
+            output_dir: "_dist".to_string(),
+            api_url: NETLIFY_API_URL.to_string(),
+            redirects: Vec::new(),
+            headers: Vec::new(),
+        })
     }
-    
+
     /// Create a new Netlify deployer
     pub fn with_config(config: NetlifyConfig) -> Self {
-        Self { config }
-    }
-    
-    /// Deploy to Netlify
-    pub async fn deploy(&self, _site: &Site) -> Result<()> {
-        // For now, this is a placeholder
-        // In a real implementation, you would use Netlify API
-        println!("Deploying to Netlify...");
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Write `_redirects` and `_headers` from the site settings
+    pub fn write_platform_files(&self, output_dir: &Path) -> Result<()> {
+        if !self.config.redirects.is_empty() {
+            std::fs::write(output_dir.join("_redirects"), render_redirects(&self.config.redirects))?;
+        }
+        if !self.config.headers.is_empty() {
+            std::fs::write(output_dir.join("_headers"), render_headers(&self.config.headers))?;
+        }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[async_trait::async_trait]
+impl Deployer for NetlifyDeployer {
+    fn name(&self) -> &'static str {
+        "netlify"
+    }
+
+    async fn deploy(&self, _site: &Site) -> Result<DeployReport> {
+        if self.config.site_id.is_empty() {
+            return Err(Error::deploy("deploy.netlify.site_id is not set"));
+        }
+
+        let output_dir = Path::new(&self.config.output_dir);
+        self.write_platform_files(output_dir)?;
+
+        let digests = collect_file_digests(output_dir)?;
+        let files: HashMap<String, String> = digests
+            .iter()
+            .map(|d| (format!("/{}", d.path), d.sha1.clone()))
+            .collect();
+
+        let api_url = self.config.api_url.trim_end_matches('/');
+        let response = self.client
+            .post(format!("{}/api/v1/sites/{}/deploys", api_url, self.config.site_id))
+            .bearer_auth(&self.config.token)
+            .json(&serde_json::json!({ "files": files }))
+            .send()
+            .await
+            .map_err(|e| Error::deploy(format!("Failed to create Netlify deploy: {}", e)))?;
+        let deploy: NetlifyDeploy = parse_response(response, "create Netlify deploy").await?;
+
+        // Netlify asks for each missing digest once, even if several paths share it
+        let required: HashSet<&str> = deploy.required.iter().map(|s| s.as_str()).collect();
+        let mut uploaded = HashSet::new();
+        for digest in &digests {
+            if !required.contains(digest.sha1.as_str()) || !uploaded.insert(digest.sha1.clone()) {
+                continue;
+            }
+
+            let body = std::fs::read(&digest.file)?;
+            let response = self.client
+                .put(file_upload_url(api_url, &deploy.id, &digest.path)?)
+                .bearer_auth(&self.config.token)
+                .header("content-type", "application/octet-stream")
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| Error::deploy(format!("Failed to upload {}: {}", digest.path, e)))?;
+            parse_response::<serde_json::Value>(response, &format!("upload {}", digest.path)).await?;
+        }
+
+        Ok(DeployReport {
+            uploaded: uploaded.len(),
+            unchanged: digests.len() - uploaded.len(),
+            deleted: 0,
+            url: deploy.deploy_ssl_url.or(deploy.ssl_url).or(deploy.url),
+        })
+    }
+}
+
+impl Default for NetlifyDeployer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// URL uploading `path` to a deploy, with each path segment percent-encoded
+fn file_upload_url(api_url: &str, deploy_id: &str, path: &str) -> Result<url::Url> {
+    let mut url = url::Url::parse(api_url)
+        .map_err(|e| Error::deploy(format!("Invalid Netlify API URL {}: {}", api_url, e)))?;
+    url.path_segments_mut()
+        .map_err(|_| Error::deploy(format!("Invalid Netlify API URL {}", api_url)))?
+        .pop_if_empty()
+        .extend(["api", "v1", "deploys", deploy_id, "files"])
+        .extend(path.split('/'));
+    Ok(url)
+}
+
+/// Render redirect rules in Netlify's `_redirects` format
+fn render_redirects(rules: &[RedirectRule]) -> String {
+    rules
+        .iter()
+        .map(|rule| format!("{} {} {}\n", rule.from, rule.to, rule.status))
+        .collect()
+}
+
+/// Render header rules in Netlify's `_headers` format
+fn render_headers(rules: &[HeaderRule]) -> String {
+    let mut output = String::new();
+    for rule in rules {
+        output.push_str(&rule.path);
+        output.push('\n');
+        for (name, value) in &rule.values {
+            output.push_str(&format!("  {}: {}\n", name, value));
+        }
+    }
+    output
+}
+
+/// Check the status of an API response and decode its JSON body
+async fn parse_response<T: serde::de::DeserializeOwned>(response: reqwest::Response, action: &str) -> Result<T> {
+    let status = response.status();
+    let body = response.text().await
+        .map_err(|e| Error::deploy(format!("Failed to {}: {}", action, e)))?;
+
+    if !status.is_success() {
+        return Err(Error::deploy(format!("Failed to {}: {} {}", action, status, body.trim())));
+    }
+
+    serde_json::from_str(&body)
+        .map_err(|e| Error::deploy(format!("Failed to {}: invalid response: {}", action, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_render_platform_files() {
+        let redirects = vec![RedirectRule {
+            from: "/old".to_string(),
+            to: "/new.html".to_string(),
+            status: 301,
+        }];
+        assert_eq!(render_redirects(&redirects), "/old /new.html 301\n");
+
+        let mut values = BTreeMap::new();
+        values.insert("Cache-Control".to_string(), "no-cache".to_string());
+        let headers = vec![HeaderRule {
+            path: "/*.json".to_string(),
+            values,
+        }];
+        assert_eq!(render_headers(&headers), "/*.json\n  Cache-Control: no-cache\n");
+    }
+}
@@ -0,0 +1,247 @@
+//! SSH deployment
+//!
+//! Uploads the output directory over SFTP using the system `sftp` client in
+//! batch mode. A manifest of content hashes is kept next to the deployed
+//! files (`.peta-manifest.json`), so like `rsync` only changed files are sent.
+
+use crate::core::config::SshDeployConfig;
+use crate::core::{Error, Result, Site};
+use crate::deploy::{collect_file_digests, DeployReport, Deployer, FileDigest, SyncPlan};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Name of the remote manifest file
+pub const MANIFEST_FILE: &str = ".peta-manifest.json";
+
+/// SSH deployer
+pub struct SshDeployer {
+    config: SshConfig,
+}
+
+/// SSH deployment configuration
+#[derive(Debug, Clone)]
+pub struct SshConfig {
+    pub host: String,
+    pub user: Option<String>,
+    pub port: u16,
+    pub path: String,
+    pub identity_file: Option<String>,
+    pub delete_stale: bool,
+    pub output_dir: String,
+}
+
+impl SshConfig {
+    /// Build a runtime config from `[deploy.ssh]`
+    pub fn from_settings(settings: &SshDeployConfig, output_dir: &str) -> Result<Self> {
+        if settings.host.is_empty() {
+            return Err(Error::deploy("deploy.ssh.host is not set"));
+        }
+        if settings.path.is_empty() {
+            return Err(Error::deploy("deploy.ssh.path is not set"));
+        }
+
+        Ok(Self {
+            host: settings.host.clone(),
+            user: settings.user.clone(),
+            port: settings.port,
+            path: settings.path.trim_end_matches('/').to_string(),
+            identity_file: settings.identity_file.clone(),
+            delete_stale: settings.delete_stale,
+            output_dir: output_dir.to_string(),
+        })
+    }
+}
+
+impl SshDeployer {
+    /// Create a new SSH deployer
+    pub fn with_config(config: SshConfig) -> Self {
+        Self { config }
+    }
+
+    /// `user@host` or just `host`
+    fn destination(&self) -> String {
+        match &self.config.user {
+            Some(user) => format!("{}@{}", user, self.config.host),
+            None => self.config.host.clone(),
+        }
+    }
+
+    /// Remote path for a file relative to the deploy root
+    fn remote_path(&self, relative: &str) -> String {
+        format!("{}/{}", self.config.path, relative)
+    }
+
+    /// Run `sftp` with the given batch script
+    async fn run_batch(&self, script: &str) -> Result<()> {
+        let batch_file = std::env::temp_dir().join(format!("peta-sftp-{}.batch", uuid::Uuid::new_v4()));
+        std::fs::write(&batch_file, script)?;
+
+        let mut command = tokio::process::Command::new("sftp");
+        command
+            .arg("-b")
+            .arg(&batch_file)
+            .arg("-P")
+            .arg(self.config.port.to_string())
+            .arg("-o")
+            .arg("BatchMode=yes");
+        if let Some(identity_file) = &self.config.identity_file {
+            command.arg("-i").arg(identity_file);
+        }
+        command.arg(self.destination());
+
+        let output = command.output().await;
+        let _ = std::fs::remove_file(&batch_file);
+        let output = output.map_err(|e| Error::deploy(format!("Failed to run sftp: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(Error::deploy(format!(
+                "sftp to {} failed: {}",
+                self.destination(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Download the remote manifest, or an empty one on first deploy
+    async fn fetch_manifest(&self) -> Result<HashMap<String, String>> {
+        let local = std::env::temp_dir().join(format!("peta-manifest-{}.json", uuid::Uuid::new_v4()));
+
+        // A leading `-` tells sftp to carry on if the manifest does not exist yet
+        let script = format!(
+            "-get {} {}\n",
+            quote(&self.remote_path(MANIFEST_FILE)),
+            quote(&local.to_string_lossy())
+        );
+        self.run_batch(&script).await?;
+
+        if !local.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = std::fs::read_to_string(&local)?;
+        let _ = std::fs::remove_file(&local);
+        serde_json::from_str(&content)
+            .map_err(|e| Error::deploy(format!("Invalid remote manifest {}: {}", MANIFEST_FILE, e)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Deployer for SshDeployer {
+    fn name(&self) -> &'static str {
+        "ssh"
+    }
+
+    async fn deploy(&self, _site: &Site) -> Result<DeployReport> {
+        let digests = collect_file_digests(Path::new(&self.config.output_dir))?;
+        let manifest: BTreeMap<String, String> = digests
+            .iter()
+            .map(|d| (d.path.clone(), d.sha1.clone()))
+            .collect();
+
+        let remote = self.fetch_manifest().await?;
+        let plan = SyncPlan::new(digests, &remote, self.config.delete_stale);
+
+        let manifest_file = std::env::temp_dir().join(format!("peta-manifest-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&manifest_file, serde_json::to_string_pretty(&manifest)?)?;
+
+        let script = batch_script(&self.config.path, &plan, &manifest_file);
+        let result = self.run_batch(&script).await;
+        let _ = std::fs::remove_file(&manifest_file);
+        result?;
+
+        Ok(DeployReport {
+            uploaded: plan.uploads.len(),
+            unchanged: plan.unchanged,
+            deleted: plan.deletes.len(),
+            url: Some(format!("sftp://{}{}", self.destination(), self.config.path)),
+        })
+    }
+}
+
+/// Build the `sftp` batch script that applies a sync plan
+///
+/// Directories are created parents first; `-mkdir` ignores directories that
+/// already exist. The manifest is uploaded last so an interrupted deploy is
+/// retried in full.
+fn batch_script(root: &str, plan: &SyncPlan<FileDigest>, manifest_file: &Path) -> String {
+    let mut dirs = BTreeSet::new();
+    dirs.insert(root.to_string());
+    for digest in &plan.uploads {
+        let mut dir = PathBuf::from(&digest.path);
+        while dir.pop() && !dir.as_os_str().is_empty() {
+            dirs.insert(format!("{}/{}", root, dir.to_string_lossy()));
+        }
+    }
+
+    let mut script = String::new();
+    for dir in &dirs {
+        script.push_str(&format!("-mkdir {}\n", quote(dir)));
+    }
+    for digest in &plan.uploads {
+        script.push_str(&format!(
+            "put {} {}\n",
+            quote(&digest.file.to_string_lossy()),
+            quote(&format!("{}/{}", root, digest.path))
+        ));
+    }
+    for key in &plan.deletes {
+        script.push_str(&format!("-rm {}\n", quote(&format!("{}/{}", root, key))));
+    }
+    script.push_str(&format!(
+        "put {} {}\n",
+        quote(&manifest_file.to_string_lossy()),
+        quote(&format!("{}/{}", root, MANIFEST_FILE))
+    ));
+
+    script
+}
+
+/// Quote an argument for an sftp batch file
+fn quote(arg: &str) -> String {
+    format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(path: &str) -> FileDigest {
+        FileDigest {
+            path: path.to_string(),
+            file: PathBuf::from("/build").join(path),
+            sha1: "new".to_string(),
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn test_batch_script() {
+        let mut remote = HashMap::new();
+        remote.insert("index.html".to_string(), "new".to_string());
+        remote.insert("old.html".to_string(), "old".to_string());
+
+        let plan = SyncPlan::new(
+            vec![digest("index.html"), digest("css/vendor/main.css")],
+            &remote,
+            true,
+        );
+        let script = batch_script("/var/www", &plan, Path::new("/tmp/manifest.json"));
+
+        assert_eq!(
+            script,
+            "-mkdir \"/var/www\"\n\
+             -mkdir \"/var/www/css\"\n\
+             -mkdir \"/var/www/css/vendor\"\n\
+             put \"/build/css/vendor/main.css\" \"/var/www/css/vendor/main.css\"\n\
+             -rm \"/var/www/old.html\"\n\
+             put \"/tmp/manifest.json\" \"/var/www/.peta-manifest.json\"\n"
+        );
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("my \"site\""), "\"my \\\"site\\\"\"");
+    }
+}
@@ -1,11 +1,23 @@
 //! Vercel deployment
+//!
+//! Uses Vercel's file digest flow: the deployment is created with a list of
+//! SHA1 digests, Vercel answers `missing_files` for content it does not have,
+//! those files are uploaded and the deployment is created again.
 
-use crate::core::{Site, Result};
+use crate::core::config::{HeaderRule, RedirectRule, VercelDeployConfig};
+use crate::core::{Error, Result, Site};
+use crate::deploy::{collect_file_digests, DeployReport, Deployer, FileDigest};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Default Vercel API base URL
+pub const VERCEL_API_URL: &str = "https://api.vercel.com";
 
 /// Vercel deployer
 pub struct VercelDeployer {
-    #[allow(dead_code)]
     config: VercelConfig,
+    client: reqwest::Client,
 }
 
 /// Vercel deployment configuration
@@ -14,30 +26,305 @@ pub struct VercelConfig {
     pub project_id: String,
     pub token: String,
     pub output_dir: String,
+    pub team_id: Option<String>,
+    pub production: bool,
+    pub api_url: String,
+    pub redirects: Vec<RedirectRule>,
+    pub headers: Vec<HeaderRule>,
+}
+
+impl VercelConfig {
+    /// Build a runtime config from `[deploy.vercel]`, reading the token from `VERCEL_TOKEN`
+    pub fn from_settings(
+        settings: &VercelDeployConfig,
+        redirects: &[RedirectRule],
+        headers: &[HeaderRule],
+        output_dir: &str,
+    ) -> Result<Self> {
+        let token = std::env::var("VERCEL_TOKEN")
+            .map_err(|_| Error::deploy("VERCEL_TOKEN is not set"))?;
+
+        Ok(Self {
+            project_id: settings.project.clone(),
+            token,
+            output_dir: output_dir.to_string(),
+            team_id: settings.team_id.clone(),
+            production: settings.production,
+            api_url: settings.api_url.clone(),
+            redirects: redirects.to_vec(),
+            headers: headers.to_vec(),
+        })
+    }
+}
+
+/// Successful deployment response
+#[derive(Debug, Deserialize)]
+struct VercelDeployment {
+    url: String,
+}
+
+/// Error response body
+#[derive(Debug, Deserialize)]
+struct VercelErrorBody {
+    error: VercelError,
+}
+
+#[derive(Debug, Deserialize)]
+struct VercelError {
+    code: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    missing: Vec<String>,
+}
+
+/// Outcome of a deployment creation attempt
+enum CreateOutcome {
+    Created(VercelDeployment),
+    MissingFiles(Vec<String>),
 }
 
 impl VercelDeployer {
     /// Create a new Vercel deployer with default config
     pub fn new() -> Self {
-        Self { 
-            config: VercelConfig {
-                project_id: String::new(),
-                token: String::new(),
-                output_dir: "_dist".to_string(),
-            }
-        }
+        Self::with_config(VercelConfig {
+            project_id: String::new(),
+            token: String::new(),
+            output_dir: "_dist".to_string(),
+            team_id: None,
+            production: true,
+            api_url: VERCEL_API_URL.to_string(),
+            redirects: Vec::new(),
+            headers: Vec::new(),
+        })
     }
-    
+
     /// Create a new Vercel deployer
     pub fn with_config(config: VercelConfig) -> Self {
-        Self { config }
-    }
-    
-    /// Deploy to Vercel
-    pub async fn deploy(&self, _site: &Site) -> Result<()> {
-        // For now, this is a placeholder
-        // In a real implementation, you would use Vercel CLI or API
-        println!("Deploying to Vercel...");
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Build an API URL, adding the team scope when configured
+    fn api(&self, path: &str) -> Result<url::Url> {
+        api_url(&self.config.api_url, path, self.config.team_id.as_deref())
+    }
+
+    /// Create the deployment from the file manifest
+    async fn create_deployment(&self, digests: &[FileDigest]) -> Result<CreateOutcome> {
+        let files: Vec<serde_json::Value> = digests
+            .iter()
+            .map(|d| serde_json::json!({ "file": d.path, "sha": d.sha1, "size": d.size }))
+            .collect();
+
+        let mut body = serde_json::json!({
+            "name": self.config.project_id,
+            "project": self.config.project_id,
+            "files": files,
+            "projectSettings": { "framework": null },
+        });
+        if self.config.production {
+            body["target"] = serde_json::Value::String("production".to_string());
+        }
+
+        let response = self.client
+            .post(self.api("/v13/deployments")?)
+            .bearer_auth(&self.config.token)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| Error::deploy(format!("Failed to create Vercel deployment: {}", e)))?;
+
+        let status = response.status();
+        let text = response.text().await
+            .map_err(|e| Error::deploy(format!("Failed to read Vercel response: {}", e)))?;
+
+        if status.is_success() {
+            let deployment = serde_json::from_str(&text)
+                .map_err(|e| Error::deploy(format!("Invalid Vercel deployment response: {}", e)))?;
+            return Ok(CreateOutcome::Created(deployment));
+        }
+
+        match serde_json::from_str::<VercelErrorBody>(&text) {
+            Ok(body) if body.error.code == "missing_files" => Ok(CreateOutcome::MissingFiles(body.error.missing)),
+            Ok(body) => Err(Error::deploy(format!("Vercel deployment failed: {} ({})", body.error.message, body.error.code))),
+            Err(_) => Err(Error::deploy(format!("Vercel deployment failed: {} {}", status, text.trim()))),
+        }
+    }
+
+    /// Upload one file by digest
+    async fn upload_file(&self, digest: &FileDigest) -> Result<()> {
+        let body = std::fs::read(&digest.file)?;
+        let response = self.client
+            .post(self.api("/v2/files")?)
+            .bearer_auth(&self.config.token)
+            .header("content-type", "application/octet-stream")
+            .header("x-vercel-digest", &digest.sha1)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| Error::deploy(format!("Failed to upload {}: {}", digest.path, e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(Error::deploy(format!("Failed to upload {}: {} {}", digest.path, status, text.trim())));
+        }
+
+        Ok(())
+    }
+
+    /// Write `vercel.json` from the site settings
+    pub fn write_platform_files(&self, output_dir: &Path) -> Result<()> {
+        if self.config.redirects.is_empty() && self.config.headers.is_empty() {
+            return Ok(());
+        }
+
+        let json = serde_json::to_string_pretty(&render_vercel_json(&self.config.redirects, &self.config.headers))?;
+        std::fs::write(output_dir.join("vercel.json"), json)?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[async_trait::async_trait]
+impl Deployer for VercelDeployer {
+    fn name(&self) -> &'static str {
+        "vercel"
+    }
+
+    async fn deploy(&self, _site: &Site) -> Result<DeployReport> {
+        if self.config.project_id.is_empty() {
+            return Err(Error::deploy("deploy.vercel.project is not set"));
+        }
+
+        let output_dir = Path::new(&self.config.output_dir);
+        self.write_platform_files(output_dir)?;
+        let digests = collect_file_digests(output_dir)?;
+
+        let mut uploaded = HashSet::new();
+        let deployment = match self.create_deployment(&digests).await? {
+            CreateOutcome::Created(deployment) => deployment,
+            CreateOutcome::MissingFiles(missing) => {
+                let missing: HashSet<String> = missing.into_iter().collect();
+                for digest in &digests {
+                    if missing.contains(&digest.sha1) && uploaded.insert(digest.sha1.clone()) {
+                        self.upload_file(digest).await?;
+                    }
+                }
+
+                match self.create_deployment(&digests).await? {
+                    CreateOutcome::Created(deployment) => deployment,
+                    CreateOutcome::MissingFiles(missing) => {
+                        return Err(Error::deploy(format!(
+                            "Vercel still reports {} missing file(s) after upload",
+                            missing.len()
+                        )));
+                    }
+                }
+            }
+        };
+
+        let url = if deployment.url.starts_with("http") {
+            deployment.url
+        } else {
+            format!("https://{}", deployment.url)
+        };
+
+        Ok(DeployReport {
+            uploaded: uploaded.len(),
+            unchanged: digests.len() - uploaded.len(),
+            deleted: 0,
+            url: Some(url),
+        })
+    }
+}
+
+impl Default for VercelDeployer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// API URL for `path`, with the team id as an encoded `teamId` parameter
+fn api_url(api_url: &str, path: &str, team_id: Option<&str>) -> Result<url::Url> {
+    let mut url = url::Url::parse(api_url)
+        .map_err(|e| Error::deploy(format!("Invalid Vercel API URL {}: {}", api_url, e)))?;
+    url.path_segments_mut()
+        .map_err(|_| Error::deploy(format!("Invalid Vercel API URL {}", api_url)))?
+        .pop_if_empty()
+        .extend(path.trim_start_matches('/').split('/'));
+    if let Some(team_id) = team_id {
+        url.query_pairs_mut().append_pair("teamId", team_id);
+    }
+    Ok(url)
+}
+
+/// Render redirect and header rules as a `vercel.json` document
+fn render_vercel_json(redirects: &[RedirectRule], headers: &[HeaderRule]) -> serde_json::Value {
+    let redirects: Vec<serde_json::Value> = redirects
+        .iter()
+        .map(|rule| {
+            serde_json::json!({
+                "source": to_vercel_pattern(&rule.from),
+                "destination": rule.to,
+                "statusCode": rule.status,
+            })
+        })
+        .collect();
+
+    let headers: Vec<serde_json::Value> = headers
+        .iter()
+        .map(|rule| {
+            let values: Vec<serde_json::Value> = rule.values
+                .iter()
+                .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+                .collect();
+            serde_json::json!({ "source": to_vercel_pattern(&rule.path), "headers": values })
+        })
+        .collect();
+
+    serde_json::json!({ "redirects": redirects, "headers": headers })
+}
+
+/// Translate a `*` wildcard path into Vercel's path-to-regexp syntax
+fn to_vercel_pattern(path: &str) -> String {
+    path.replace('*', "(.*)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_render_vercel_json() {
+        let redirects = vec![RedirectRule {
+            from: "/blog/*".to_string(),
+            to: "/articles.html".to_string(),
+            status: 302,
+        }];
+        let mut values = BTreeMap::new();
+        values.insert("Cache-Control".to_string(), "no-cache".to_string());
+        let headers = vec![HeaderRule {
+            path: "/*.json".to_string(),
+            values,
+        }];
+
+        let json = render_vercel_json(&redirects, &headers);
+        assert_eq!(json["redirects"][0]["source"], "/blog/(.*)");
+        assert_eq!(json["redirects"][0]["statusCode"], 302);
+        assert_eq!(json["headers"][0]["source"], "/(.*).json");
+        assert_eq!(json["headers"][0]["headers"][0]["key"], "Cache-Control");
+    }
+
+    #[test]
+    fn test_api_url_encodes_team_id() {
+        let url = api_url("https://api.vercel.com/", "/v2/files", Some("team a&b=c")).unwrap();
+        assert_eq!(url.as_str(), "https://api.vercel.com/v2/files?teamId=team+a%26b%3Dc");
+
+        let url = api_url("http://127.0.0.1:3000", "/v13/deployments", None).unwrap();
+        assert_eq!(url.as_str(), "http://127.0.0.1:3000/v13/deployments");
+    }
+}
@@ -0,0 +1,677 @@
+//! Verification of the built output before deployment
+//!
+//! Walks the output directory and reports broken internal links and anchors,
+//! missing assets, unresolved snippet references, duplicate URLs, unbalanced
+//! HTML and missing required files.
+
+use crate::core::{Result, Site, SiteConfig};
+use crate::core::config::SearchBackend;
+use crate::search::client::{CLIENT_INDEX_DIR, MANIFEST_FILE};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+static LINK_TAG_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<(a|area|link|img|script|source|iframe|video|audio)\b[^>]*>").unwrap());
+static LINK_ATTR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?is)\s(href|src)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+static ID_ATTR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?is)\s(?:id|name)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+static TAG_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<(/?)([a-zA-Z][a-zA-Z0-9-]*)\b(?:[^>\x22']|\x22[^\x22]*\x22|'[^']*')*?(/?)>").unwrap());
+static RAW_TEXT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<!--.*?-->|(<script\b[^>]*>).*?(</script\s*>)|(<style\b[^>]*>).*?(</style\s*>)").unwrap());
+static SNIPPET_PLACEHOLDER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"<div class="embedded-snippet-card(?: error)?" data-snippet="([^"]*)""#).unwrap());
+static SNIPPET_ERROR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Snippet Not Found: ([^<]*)<").unwrap());
+
+/// Elements that never have a closing tag
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose closing tag may be omitted
+const OPTIONAL_CLOSE_ELEMENTS: &[&str] = &[
+    "p", "li", "dt", "dd", "tr", "td", "th", "thead", "tbody", "tfoot", "option", "optgroup",
+    "colgroup", "rt", "rp",
+];
+
+/// Kind of verification failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IssueKind {
+    MissingFile,
+    EmptySearchIndex,
+    BrokenLink,
+    BrokenAnchor,
+    MissingAsset,
+    MissingSnippet,
+    DuplicateUrl,
+    MalformedHtml,
+}
+
+impl IssueKind {
+    /// Human readable label
+    pub fn label(&self) -> &'static str {
+        match self {
+            IssueKind::MissingFile => "missing file",
+            IssueKind::EmptySearchIndex => "empty search index",
+            IssueKind::BrokenLink => "broken link",
+            IssueKind::BrokenAnchor => "broken anchor",
+            IssueKind::MissingAsset => "missing asset",
+            IssueKind::MissingSnippet => "missing snippet",
+            IssueKind::DuplicateUrl => "duplicate URL",
+            IssueKind::MalformedHtml => "malformed HTML",
+        }
+    }
+}
+
+/// A single verification failure
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// File relative to the output directory
+    pub file: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Result of verifying the output directory
+#[derive(Debug, Clone, Default)]
+pub struct VerificationReport {
+    pub issues: Vec<Issue>,
+    /// Number of HTML files checked
+    pub files_checked: usize,
+}
+
+impl VerificationReport {
+    /// Whether no issues were found
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Number of issues of each kind
+    pub fn counts(&self) -> Vec<(IssueKind, usize)> {
+        let mut counts: HashMap<IssueKind, usize> = HashMap::new();
+        for issue in &self.issues {
+            *counts.entry(issue.kind).or_insert(0) += 1;
+        }
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort();
+        counts
+    }
+
+    fn push(&mut self, kind: IssueKind, file: &str, line: Option<usize>, message: String) {
+        self.issues.push(Issue {
+            kind,
+            file: file.to_string(),
+            line,
+            message,
+        });
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "Checked {} HTML file(s), no problems found", self.files_checked);
+        }
+
+        writeln!(f, "Checked {} HTML file(s), found {} problem(s):", self.files_checked, self.issues.len())?;
+        for issue in &self.issues {
+            let location = match issue.line {
+                Some(line) => format!("{}:{}", issue.file, line),
+                None => issue.file.clone(),
+            };
+            writeln!(f, "  [{}] {}: {}", issue.kind.label(), location, issue.message)?;
+        }
+
+        let summary: Vec<String> = self
+            .counts()
+            .iter()
+            .map(|(kind, count)| format!("{} {}", count, kind.label()))
+            .collect();
+        write!(f, "Summary: {}", summary.join(", "))
+    }
+}
+
+/// Verifies a built site
+pub struct SiteVerifier {
+    output_dir: PathBuf,
+    base_path: String,
+    base_origin: Option<String>,
+    search_enabled: bool,
+}
+
+impl SiteVerifier {
+    /// Create a verifier for the configured output directory
+    pub fn new(config: &SiteConfig) -> Self {
+        Self {
+            output_dir: PathBuf::from(&config.build.output_dir),
+            base_path: base_path(&config.site.base_url),
+            base_origin: url::Url::parse(&config.site.base_url)
+                .ok()
+                .map(|url| url.origin().ascii_serialization()),
+            // The server backend's index is not part of the static output
+            search_enabled: config.search.enabled && config.search.backend == SearchBackend::Static,
+        }
+    }
+
+    /// Override the directory to verify
+    pub fn with_output_dir<P: AsRef<Path>>(mut self, output_dir: P) -> Self {
+        self.output_dir = output_dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Run all checks
+    pub fn verify(&self, site: &Site) -> Result<VerificationReport> {
+        let mut report = VerificationReport::default();
+
+        self.check_required_files(&mut report)?;
+        check_duplicate_urls(site, &mut report);
+
+        let pages = self.collect_html_files();
+        report.files_checked = pages.len();
+
+        let mut anchors: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+        for page in &pages {
+            let html = std::fs::read_to_string(page)?;
+            let relative = self.relative(page);
+
+            check_well_formed(&html, &relative, &mut report);
+            check_snippets(&html, &relative, &mut report);
+            self.check_links(page, &html, &relative, &mut anchors, &mut report)?;
+        }
+
+        Ok(report)
+    }
+
+    /// `index.html`, `404.html` and, with search enabled, a non-empty search index
+    fn check_required_files(&self, report: &mut VerificationReport) -> Result<()> {
+        for required in ["index.html", "404.html"] {
+            if !self.output_dir.join(required).is_file() {
+                report.push(IssueKind::MissingFile, required, None, "required file was not generated".to_string());
+            }
+        }
+
+        if self.search_enabled {
+            let manifest = format!("{}/{}", CLIENT_INDEX_DIR, MANIFEST_FILE);
+            let index = self.output_dir.join(&manifest);
+            if !index.is_file() {
+                report.push(IssueKind::MissingFile, &manifest, None, "search is enabled but no index was generated".to_string());
+            } else {
+                let data: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&index)?)?;
+                let documents = data["documents"].as_array().map(|d| d.len()).unwrap_or(0);
+                if documents == 0 {
+                    report.push(IssueKind::EmptySearchIndex, &manifest, None, "search index contains no documents".to_string());
+                }
+                for key in data["shards"].as_array().into_iter().flatten().filter_map(|k| k.as_str()) {
+                    for kind in ["terms", "positions"] {
+                        let shard = format!("{}/{}-{}.json", CLIENT_INDEX_DIR, kind, key);
+                        if !self.output_dir.join(&shard).is_file() {
+                            report.push(IssueKind::MissingFile, &shard, None, "search index shard listed in the manifest is missing".to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// All HTML files in the output directory, sorted
+    fn collect_html_files(&self) -> Vec<PathBuf> {
+        let mut pages: Vec<PathBuf> = WalkDir::new(&self.output_dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("html"))
+            .collect();
+        pages.sort();
+        pages
+    }
+
+    /// Path relative to the output directory, `/`-separated
+    fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.output_dir)
+            .unwrap_or(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Check every `href`/`src` that points inside the site
+    fn check_links(
+        &self,
+        page: &Path,
+        html: &str,
+        relative: &str,
+        anchors: &mut HashMap<PathBuf, HashSet<String>>,
+        report: &mut VerificationReport,
+    ) -> Result<()> {
+        let scannable = blank_raw_text(html);
+        let page_url = url::Url::parse("http://peta.invalid/")
+            .and_then(|base| base.join(relative))
+            .map_err(|e| crate::core::Error::deploy(format!("Invalid page path {}: {}", relative, e)))?;
+
+        for tag in LINK_TAG_RE.captures_iter(&scannable) {
+            let tag_name = tag[1].to_lowercase();
+            let whole = tag.get(0).unwrap();
+
+            for attr in LINK_ATTR_RE.captures_iter(whole.as_str()) {
+                let value = decode_entities(attr.get(2).or_else(|| attr.get(3)).map(|m| m.as_str()).unwrap_or(""));
+                let Some(link) = self.internal_link(&value) else {
+                    continue;
+                };
+                let line = Some(line_of(html, whole.start()));
+
+                let target = match page_url.join(&link) {
+                    Ok(url) => url,
+                    Err(_) => {
+                        report.push(IssueKind::BrokenLink, relative, line, format!("invalid URL {}", value));
+                        continue;
+                    }
+                };
+
+                let is_asset = match tag_name.as_str() {
+                    "a" | "area" => false,
+                    "link" => loads_resource(whole.as_str()),
+                    _ => true,
+                };
+                let Some(file) = self.resolve(&percent_decode(target.path())) else {
+                    let kind = if is_asset { IssueKind::MissingAsset } else { IssueKind::BrokenLink };
+                    report.push(kind, relative, line, format!("{} does not exist", value));
+                    continue;
+                };
+
+                let Some(fragment) = target.fragment() else {
+                    continue;
+                };
+                let fragment = percent_decode(fragment);
+                if fragment.is_empty() || fragment == "top" || file.extension().and_then(|e| e.to_str()) != Some("html") {
+                    continue;
+                }
+
+                if !anchors.contains_key(&file) {
+                    let target_html = if file == page { html.to_string() } else { std::fs::read_to_string(&file)? };
+                    anchors.insert(file.clone(), collect_ids(&target_html));
+                }
+                if !anchors[&file].contains(&fragment) {
+                    report.push(IssueKind::BrokenAnchor, relative, line, format!("{} has no element with id \"{}\"", value, fragment));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Turn a link into a site-relative reference, or `None` for external links
+    fn internal_link(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        if value.is_empty() || value.contains("{{") || value.contains("{%") || value.contains("${") {
+            return None;
+        }
+
+        let (path, suffix) = split_suffix(value);
+        if path.starts_with("//") {
+            return None;
+        }
+
+        // Any scheme (http:, mailto:, data:, javascript:, ...) makes a link absolute;
+        // only absolute links back to the site itself are checked
+        if let Some(colon) = path.find(':') {
+            if !path[..colon].contains('/') {
+                let url = url::Url::parse(value).ok()?;
+                if self.base_origin.as_deref() != Some(url.origin().ascii_serialization().as_str()) {
+                    return None;
+                }
+                let path = self.strip_base(url.path())?;
+                return Some(match url.fragment() {
+                    Some(fragment) => format!("{}#{}", path, fragment),
+                    None => path,
+                });
+            }
+        }
+
+        if path.starts_with('/') {
+            return Some(format!("{}{}", self.strip_base(path).unwrap_or_else(|| path.to_string()), suffix));
+        }
+
+        Some(value.to_string())
+    }
+
+    /// Remove the base URL path prefix from an absolute path
+    fn strip_base(&self, path: &str) -> Option<String> {
+        if self.base_path.is_empty() {
+            return Some(path.to_string());
+        }
+        path.strip_prefix(&self.base_path)
+            .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            .map(|rest| if rest.is_empty() { "/".to_string() } else { rest.to_string() })
+    }
+
+    /// Map a URL path to a file in the output directory
+    fn resolve(&self, url_path: &str) -> Option<PathBuf> {
+        let relative = url_path.trim_start_matches('/');
+        let candidate = self.output_dir.join(relative);
+
+        if relative.is_empty() || url_path.ends_with('/') || candidate.is_dir() {
+            let index = candidate.join("index.html");
+            return index.is_file().then_some(index);
+        }
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+
+        let with_html = self.output_dir.join(format!("{}.html", relative));
+        with_html.is_file().then_some(with_html)
+    }
+}
+
+/// Report content items that would be written to the same URL
+fn check_duplicate_urls(site: &Site, report: &mut VerificationReport) {
+    let mut by_url: HashMap<&str, Vec<&str>> = HashMap::new();
+    for content in &site.content {
+        by_url.entry(content.metadata.url.as_str()).or_default().push(content.metadata.id.as_str());
+    }
+
+    let mut duplicates: Vec<_> = by_url.into_iter().filter(|(_, ids)| ids.len() > 1).collect();
+    duplicates.sort();
+    for (url, ids) in duplicates {
+        report.push(IssueKind::DuplicateUrl, url, None, format!("generated by {}", ids.join(", ")));
+    }
+}
+
+/// Report snippet references that were never resolved
+fn check_snippets(html: &str, relative: &str, report: &mut VerificationReport) {
+    for caps in SNIPPET_PLACEHOLDER_RE.captures_iter(html) {
+        let line = Some(line_of(html, caps.get(0).unwrap().start()));
+        report.push(IssueKind::MissingSnippet, relative, line, format!("snippet \"{}\" was not found", &caps[1]));
+    }
+    for caps in SNIPPET_ERROR_RE.captures_iter(html) {
+        let line = Some(line_of(html, caps.get(0).unwrap().start()));
+        report.push(IssueKind::MissingSnippet, relative, line, format!("snippet \"{}\" was not found", caps[1].trim()));
+    }
+}
+
+/// Check that elements are properly nested and closed
+fn check_well_formed(html: &str, relative: &str, report: &mut VerificationReport) {
+    let scannable = blank_raw_text(html);
+    let mut stack: Vec<(String, usize)> = Vec::new();
+
+    for caps in TAG_RE.captures_iter(&scannable) {
+        let offset = caps.get(0).unwrap().start();
+        let closing = !caps[1].is_empty();
+        let self_closing = !caps[3].is_empty();
+        let name = caps[2].to_ascii_lowercase();
+
+        if VOID_ELEMENTS.contains(&name.as_str()) {
+            continue;
+        }
+        if !closing {
+            if !self_closing {
+                stack.push((name, offset));
+            }
+            continue;
+        }
+
+        match stack.iter().rposition(|(open, _)| *open == name) {
+            Some(position) => {
+                for (unclosed, open_offset) in stack.drain(position..).skip(1) {
+                    if !OPTIONAL_CLOSE_ELEMENTS.contains(&unclosed.as_str()) {
+                        report.push(
+                            IssueKind::MalformedHtml,
+                            relative,
+                            Some(line_of(html, open_offset)),
+                            format!("<{}> is not closed before </{}>", unclosed, name),
+                        );
+                    }
+                }
+            }
+            None => report.push(
+                IssueKind::MalformedHtml,
+                relative,
+                Some(line_of(html, offset)),
+                format!("unexpected </{}>", name),
+            ),
+        }
+    }
+
+    for (unclosed, offset) in stack {
+        if !OPTIONAL_CLOSE_ELEMENTS.contains(&unclosed.as_str()) {
+            report.push(IssueKind::MalformedHtml, relative, Some(line_of(html, offset)), format!("<{}> is never closed", unclosed));
+        }
+    }
+}
+
+/// Collect all `id` and `name` attribute values
+fn collect_ids(html: &str) -> HashSet<String> {
+    ID_ATTR_RE
+        .captures_iter(html)
+        .filter_map(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .map(|m| decode_entities(m.as_str()))
+        .collect()
+}
+
+/// Replace comments and script/style bodies with spaces, keeping offsets and newlines
+fn blank_raw_text(html: &str) -> String {
+    let mut output = html.to_string();
+    for caps in RAW_TEXT_RE.captures_iter(html) {
+        let whole = caps.get(0).unwrap();
+        let (start, end) = match (caps.get(1).or_else(|| caps.get(3)), caps.get(2).or_else(|| caps.get(4))) {
+            // Keep the <script>/<style> tags themselves for the nesting check
+            (Some(open), Some(close)) => (open.end(), close.start()),
+            _ => (whole.start(), whole.end()),
+        };
+        // Same byte length as the original so offsets stay valid
+        let blank: String = html[start..end]
+            .chars()
+            .map(|c| if c == '\n' { "\n".to_string() } else { " ".repeat(c.len_utf8()) })
+            .collect();
+        output.replace_range(start..end, &blank);
+    }
+    output
+}
+
+/// 1-based line number of a byte offset
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].bytes().filter(|&b| b == b'\n').count() + 1
+}
+
+/// Split a link into its path and `?query#fragment` suffix
+fn split_suffix(link: &str) -> (&str, &str) {
+    match link.find(['?', '#']) {
+        Some(position) => link.split_at(position),
+        None => (link, ""),
+    }
+}
+
+/// Whether a `<link>` tag loads a resource (as opposed to e.g. `rel="canonical"`)
+fn loads_resource(tag: &str) -> bool {
+    let lower = tag.to_ascii_lowercase();
+    lower.contains("stylesheet") || lower.contains("preload") || lower.contains("icon") || lower.contains("manifest")
+}
+
+/// Path component of the base URL, without a trailing slash
+fn base_path(base_url: &str) -> String {
+    let path = match url::Url::parse(base_url) {
+        Ok(url) => url.path().to_string(),
+        Err(_) => base_url.to_string(),
+    };
+    path.trim_end_matches('/').to_string()
+}
+
+/// Decode the HTML character references that templates emit in attribute values
+fn decode_entities(input: &str) -> String {
+    if !input.contains('&') {
+        return input.to_string();
+    }
+
+    static ENTITY_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"&(#[xX][0-9a-fA-F]+|#[0-9]+|amp|lt|gt|quot|apos);").unwrap());
+    ENTITY_RE
+        .replace_all(input, |caps: &regex::Captures| {
+            let entity = &caps[1];
+            let code = match entity {
+                "amp" => Some('&' as u32),
+                "lt" => Some('<' as u32),
+                "gt" => Some('>' as u32),
+                "quot" => Some('"' as u32),
+                "apos" => Some('\'' as u32),
+                _ if entity[1..].starts_with(['x', 'X']) => u32::from_str_radix(&entity[2..], 16).ok(),
+                _ => entity[1..].parse().ok(),
+            };
+            code.and_then(char::from_u32).map(String::from).unwrap_or_else(|| caps[0].to_string())
+        })
+        .to_string()
+}
+
+/// Decode `%XX` escapes in a URL path or fragment
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let high = (bytes[i + 1] as char).to_digit(16);
+            let low = (bytes[i + 2] as char).to_digit(16);
+            if let (Some(high), Some(low)) = (high, low) {
+                output.push((high * 16 + low) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        output.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&output).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let file = dir.join(path);
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(file, content).unwrap();
+    }
+
+    fn verifier(dir: &Path) -> SiteVerifier {
+        let mut config = SiteConfig::default();
+        config.search.enabled = false;
+        SiteVerifier::new(&config).with_output_dir(dir)
+    }
+
+    fn kinds(report: &VerificationReport) -> Vec<IssueKind> {
+        report.issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn test_valid_site_passes() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "index.html", r##"<html><body><a href="articles/post.html#intro">Post</a><a href="#top">Top</a></body></html>"##);
+        write(dir.path(), "404.html", "<html><body><p>Not found</body></html>");
+        write(dir.path(), "articles/post.html", r#"<html><head><link rel="stylesheet" href="../css/main.css"></head><body><h2 id="intro">Intro</h2><a href="/">Home</a><a href="https://example.org/x">Ext</a></body></html>"#);
+        write(dir.path(), "css/main.css", "body{}");
+
+        let report = verifier(dir.path()).verify(&Site::new(SiteConfig::default())).unwrap();
+        assert!(report.is_ok(), "{}", report);
+        assert_eq!(report.files_checked, 3);
+    }
+
+    #[test]
+    fn test_reports_broken_links_assets_and_anchors() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "404.html", "<p>Not found</p>");
+        write(
+            dir.path(),
+            "index.html",
+            "<div>\n<a href=\"missing.html\">x</a>\n<img src=\"/images/logo.png\">\n<a href=\"404.html#nope\">y</a>\n</div>",
+        );
+
+        let report = verifier(dir.path()).verify(&Site::new(SiteConfig::default())).unwrap();
+        assert_eq!(kinds(&report), vec![IssueKind::BrokenLink, IssueKind::MissingAsset, IssueKind::BrokenAnchor]);
+        assert_eq!(report.issues[0].line, Some(2));
+        assert_eq!(report.issues[1].line, Some(3));
+    }
+
+    #[test]
+    fn test_reports_missing_files_and_snippets() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "index.html", r#"<div class="embedded-snippet-card" data-snippet="gone"></div>"#);
+
+        let mut config = SiteConfig::default();
+        config.search.enabled = true;
+        let report = SiteVerifier::new(&config)
+            .with_output_dir(dir.path())
+            .verify(&Site::new(config.clone()))
+            .unwrap();
+
+        assert_eq!(kinds(&report), vec![IssueKind::MissingFile, IssueKind::MissingFile, IssueKind::MissingSnippet]);
+        assert!(report.to_string().contains("snippet \"gone\" was not found"));
+    }
+
+    #[tokio::test]
+    async fn test_no_build_reports_duplicate_urls() {
+        // `peta check --no-build`: the output exists, the content is only loaded
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("_out/dist");
+        write(&output, "index.html", "<p>Home</p>");
+        write(&output, "404.html", "<p>Not found</p>");
+        write(&output, "articles/guide/setup.html", "<p>Setup</p>");
+        let content = dir.path().join("_content");
+        write(&content, "articles/Guide/setup.rst", "Setup\n=====\n\nFirst.\n");
+        write(&content, "articles/guide/setup.rst", "Setup Again\n===========\n\nSecond.\n");
+
+        let mut config = SiteConfig::default();
+        config.search.enabled = false;
+        config.build.content_dir = content.to_string_lossy().into_owned();
+        config.build.output_dir = output.to_string_lossy().into_owned();
+        let site = crate::core::SiteBuilder::new(config.clone()).load_site().await.unwrap();
+        let report = SiteVerifier::new(&config).verify(&site).unwrap();
+
+        assert_eq!(kinds(&report), vec![IssueKind::DuplicateUrl]);
+        assert_eq!(report.issues[0].file, "articles/guide/setup.html");
+    }
+
+    #[test]
+    fn test_well_formedness() {
+        let mut report = VerificationReport::default();
+        check_well_formed("<ul><li>one<li>two</ul><br><img src=x />", "a.html", &mut report);
+        check_well_formed("<script>if (a < b) { x = '</div>'; }</script><!-- <div> -->", "b.html", &mut report);
+        assert!(report.is_ok(), "{}", report);
+
+        check_well_formed("<div><span>text</div>\n</section>", "c.html", &mut report);
+        let messages: Vec<&str> = report.issues.iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(messages, vec!["<span> is not closed before </div>", "unexpected </section>"]);
+        assert_eq!(report.issues[1].line, Some(2));
+    }
+
+    #[test]
+    fn test_internal_link_with_base_url() {
+        let mut config = SiteConfig::default();
+        config.site.base_url = "https://example.com/blog".to_string();
+        let verifier = SiteVerifier::new(&config);
+
+        assert_eq!(verifier.internal_link("/blog/a.html#x").as_deref(), Some("/a.html#x"));
+        assert_eq!(verifier.internal_link("https://example.com/blog/a.html").as_deref(), Some("/a.html"));
+        assert_eq!(verifier.internal_link("https://other.com/a.html"), None);
+        assert_eq!(verifier.internal_link("mailto:me@example.com"), None);
+        assert_eq!(verifier.internal_link("{{ url }}"), None);
+        assert_eq!(verifier.internal_link("img/a.png").as_deref(), Some("img/a.png"));
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("&#x2F;css&#x2F;main.css"), "/css/main.css");
+        assert_eq!(decode_entities("a.html?x=1&amp;y=2"), "a.html?x=1&y=2");
+        assert_eq!(decode_entities("&#47;&unknown;"), "/&unknown;");
+    }
+}
@@ -24,13 +24,35 @@ async fn main() -> Result<()> {
             }
         }
         Commands::Build { content_dir, output: output_dir, theme, base_url, draft } => {
-            commands::build_site(content_dir, output_dir, theme, base_url, draft, &mut output).await?;
+            commands::build_site(content_dir, output_dir, theme, base_url, draft, peta::core::BuildMode::Build, &mut output).await?;
         }
         Commands::Serve { content_dir, port, host, open, draft } => {
             commands::serve_site(content_dir, port, &host, open, draft, &mut output).await?;
         }
-        Commands::Deploy { target } => {
-            commands::deploy_site(&target, &mut output).await?;
+        Commands::Search { query, types, tags, sort, limit, json, rebuild } => {
+            let options = peta::search::query::SearchOptions {
+                content_types: types,
+                tags,
+                sort: sort.parse()?,
+                limit: Some(limit),
+            };
+            commands::search(&query, &options, json, rebuild, &mut output).await?;
+        }
+        Commands::SearchServer { port, host, index } => {
+            commands::search_server(port, host, index, &mut output).await?;
+        }
+        Commands::Deploy { target, list, no_verify } => {
+            if list {
+                commands::list_deploy_targets(&mut output)?;
+            } else {
+                commands::deploy_site(target.as_deref(), no_verify, &mut output).await?;
+            }
+        }
+        Commands::Check { no_build } => {
+            commands::check_site(no_build, &mut output).await?;
+        }
+        Commands::TestSnippets { filter, content_dir, languages } => {
+            commands::test_snippets(filter.as_deref(), content_dir, &languages, &mut output)?;
         }
         Commands::Clean { all } => {
             commands::clean_site(all, &mut output)?;
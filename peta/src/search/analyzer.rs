@@ -0,0 +1,386 @@
+//! Language-aware text analysis for the search index
+//!
+//! Documents are analyzed with the analyzer for their language: lowercase
+//! word tokens, CJK runs split into overlapping bigrams, per-language stop
+//! words removed and a Snowball stemmer applied.
+//!
+//! Queries are tokenized (`query_tokens`) and resolved through the surface
+//! forms the index recorded (`SearchIndex::forms`); words never seen while
+//! indexing are stemmed with the index's stemmers (`stem`). The browser runs
+//! the same Snowball algorithms, generated into `stemmers/` from the code of
+//! rust-stemmers, so both sides produce exactly the terms the indexer did.
+//! `tokenize` is mirrored by `PetaSearch.tokenize` in `search_bar.js`.
+
+use crate::search::indexer::IndexerConfig;
+use rust_stemmers::{Algorithm, Stemmer};
+use std::collections::HashSet;
+
+/// Snowball stemmers by the name recorded in `SearchIndex::stemmers`, which is
+/// also the name of their generated JavaScript in `stemmers/`
+pub const STEMMERS: [(&str, Algorithm); 18] = [
+    ("arabic", Algorithm::Arabic),
+    ("danish", Algorithm::Danish),
+    ("dutch", Algorithm::Dutch),
+    ("english", Algorithm::English),
+    ("finnish", Algorithm::Finnish),
+    ("french", Algorithm::French),
+    ("german", Algorithm::German),
+    ("greek", Algorithm::Greek),
+    ("hungarian", Algorithm::Hungarian),
+    ("italian", Algorithm::Italian),
+    ("norwegian", Algorithm::Norwegian),
+    ("portuguese", Algorithm::Portuguese),
+    ("romanian", Algorithm::Romanian),
+    ("russian", Algorithm::Russian),
+    ("spanish", Algorithm::Spanish),
+    ("swedish", Algorithm::Swedish),
+    ("tamil", Algorithm::Tamil),
+    ("turkish", Algorithm::Turkish),
+];
+
+/// Languages with a dedicated analyzer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Arabic,
+    Chinese,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Japanese,
+    Korean,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+impl Language {
+    /// Parse a language tag such as `en`, `de-AT` or `zh_Hans`
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let language = match primary.as_str() {
+            "ar" => Language::Arabic,
+            "zh" => Language::Chinese,
+            "da" => Language::Danish,
+            "nl" => Language::Dutch,
+            "en" => Language::English,
+            "fi" => Language::Finnish,
+            "fr" => Language::French,
+            "de" => Language::German,
+            "el" => Language::Greek,
+            "hu" => Language::Hungarian,
+            "it" => Language::Italian,
+            "ja" => Language::Japanese,
+            "ko" => Language::Korean,
+            "no" | "nb" | "nn" => Language::Norwegian,
+            "pt" => Language::Portuguese,
+            "ro" => Language::Romanian,
+            "ru" => Language::Russian,
+            "es" => Language::Spanish,
+            "sv" => Language::Swedish,
+            "ta" => Language::Tamil,
+            "tr" => Language::Turkish,
+            _ => return None,
+        };
+        Some(language)
+    }
+
+    /// Snowball algorithm, if the language has one
+    fn algorithm(&self) -> Option<Algorithm> {
+        match self {
+            Language::Arabic => Some(Algorithm::Arabic),
+            Language::Danish => Some(Algorithm::Danish),
+            Language::Dutch => Some(Algorithm::Dutch),
+            Language::English => Some(Algorithm::English),
+            Language::Finnish => Some(Algorithm::Finnish),
+            Language::French => Some(Algorithm::French),
+            Language::German => Some(Algorithm::German),
+            Language::Greek => Some(Algorithm::Greek),
+            Language::Hungarian => Some(Algorithm::Hungarian),
+            Language::Italian => Some(Algorithm::Italian),
+            Language::Norwegian => Some(Algorithm::Norwegian),
+            Language::Portuguese => Some(Algorithm::Portuguese),
+            Language::Romanian => Some(Algorithm::Romanian),
+            Language::Russian => Some(Algorithm::Russian),
+            Language::Spanish => Some(Algorithm::Spanish),
+            Language::Swedish => Some(Algorithm::Swedish),
+            Language::Tamil => Some(Algorithm::Tamil),
+            Language::Turkish => Some(Algorithm::Turkish),
+            Language::Chinese | Language::Japanese | Language::Korean => None,
+        }
+    }
+
+    /// Built-in stop words
+    pub fn stop_words(&self) -> &'static [&'static str] {
+        match self {
+            Language::English => &[
+                "a", "an", "and", "are", "as", "at", "be", "been", "being", "but", "by", "did", "do", "does",
+                "for", "from", "had", "has", "have", "if", "in", "into", "is", "it", "its", "no", "not", "of",
+                "on", "or", "such", "that", "the", "their", "then", "there", "these", "they", "this", "those",
+                "to", "was", "were", "will", "with",
+            ],
+            Language::German => &[
+                "aber", "als", "am", "an", "auch", "auf", "aus", "bei", "bin", "bis", "das", "dass", "dem",
+                "den", "der", "des", "die", "ein", "eine", "einem", "einen", "einer", "eines", "es", "für",
+                "hat", "ich", "im", "in", "ist", "mit", "nach", "nicht", "noch", "oder", "sich", "sie", "sind",
+                "so", "über", "um", "und", "von", "vor", "war", "wie", "wir", "wird", "zu", "zum", "zur",
+            ],
+            Language::French => &[
+                "au", "aux", "avec", "ce", "ces", "dans", "de", "des", "du", "elle", "en", "est", "et", "il",
+                "ils", "je", "la", "le", "les", "leur", "lui", "ma", "mais", "me", "mes", "ne", "nous", "on",
+                "ou", "par", "pas", "pour", "qu", "que", "qui", "sa", "se", "ses", "son", "sont", "sur", "ta",
+                "te", "tu", "un", "une", "vous",
+            ],
+            Language::Spanish => &[
+                "al", "como", "con", "de", "del", "el", "en", "es", "esta", "este", "la", "las", "lo", "los",
+                "más", "no", "o", "para", "pero", "por", "que", "se", "si", "sin", "sobre", "su", "sus", "un",
+                "una", "uno", "y", "ya",
+            ],
+            Language::Italian => &[
+                "a", "al", "alla", "anche", "che", "con", "da", "del", "della", "di", "e", "è", "gli", "i",
+                "il", "in", "la", "le", "lo", "ma", "nel", "nella", "non", "per", "più", "si", "sono", "su",
+                "un", "una", "uno",
+            ],
+            Language::Portuguese => &[
+                "a", "ao", "as", "com", "da", "das", "de", "do", "dos", "e", "é", "em", "na", "nas", "no",
+                "nos", "o", "os", "para", "por", "que", "se", "sem", "um", "uma",
+            ],
+            Language::Dutch => &[
+                "aan", "als", "bij", "dat", "de", "den", "der", "die", "dit", "een", "en", "er", "het", "hij",
+                "in", "is", "je", "maar", "met", "niet", "of", "om", "op", "te", "tot", "uit", "van", "voor",
+                "was", "wat", "ze", "zijn",
+            ],
+            Language::Swedish => &[
+                "att", "av", "de", "den", "det", "en", "ett", "för", "har", "i", "inte", "jag", "med", "men",
+                "och", "om", "på", "som", "till", "var", "är",
+            ],
+            Language::Danish | Language::Norwegian => &[
+                "af", "at", "av", "de", "den", "det", "en", "er", "et", "for", "har", "i", "ikke", "med",
+                "og", "om", "på", "som", "til", "var",
+            ],
+            Language::Russian => &[
+                "а", "в", "во", "да", "для", "до", "его", "же", "за", "и", "из", "к", "как", "на", "не", "но",
+                "о", "об", "от", "по", "с", "со", "то", "что", "это",
+            ],
+            _ => &[],
+        }
+    }
+}
+
+/// A token before stop word removal and stemming
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Lowercased text as it appears in the source
+    pub text: String,
+    /// Part of a CJK run, never stemmed or length-filtered
+    pub cjk: bool,
+}
+
+/// A token after analysis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzedToken {
+    /// Lowercased text as it appears in the source
+    pub surface: String,
+    /// Indexed term, `None` for stop words
+    pub term: Option<String>,
+}
+
+/// Whether `c` is a Han, kana or Hangul character, segmented as bigrams
+pub fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x11FF      // Hangul Jamo
+        | 0x3040..=0x30FF    // Hiragana, Katakana
+        | 0x3130..=0x318F    // Hangul compatibility Jamo
+        | 0x31F0..=0x31FF    // Katakana phonetic extensions
+        | 0x3400..=0x4DBF    // CJK extension A
+        | 0x4E00..=0x9FFF    // CJK unified ideographs
+        | 0xAC00..=0xD7AF    // Hangul syllables
+        | 0xF900..=0xFAFF    // CJK compatibility ideographs
+        | 0xFF66..=0xFF9F    // Halfwidth Katakana
+        | 0x20000..=0x2FA1F  // CJK extensions B and later
+    )
+}
+
+/// Split text into lowercase word tokens, with CJK runs as overlapping bigrams
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let lowered = text.to_lowercase();
+
+    for word in lowered.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        let chars: Vec<char> = word.chars().collect();
+        let mut start = 0;
+        while start < chars.len() {
+            let cjk = is_cjk(chars[start]);
+            let mut end = start + 1;
+            while end < chars.len() && is_cjk(chars[end]) == cjk {
+                end += 1;
+            }
+
+            let run = &chars[start..end];
+            if !cjk {
+                tokens.push(Token { text: run.iter().collect(), cjk });
+            } else if run.len() == 1 {
+                tokens.push(Token { text: run[0].to_string(), cjk });
+            } else {
+                for pair in run.windows(2) {
+                    tokens.push(Token { text: pair.iter().collect(), cjk });
+                }
+            }
+            start = end;
+        }
+    }
+
+    tokens
+}
+
+/// Whether a non-CJK token passes the configured length limits
+fn within_length(token: &Token, config: &IndexerConfig) -> bool {
+    if token.cjk {
+        return true;
+    }
+    let length = token.text.chars().count();
+    length >= config.min_term_length && length <= config.max_term_length
+}
+
+/// Query tokens, to be resolved into terms by `fuzzy::resolve`
+pub fn query_tokens(text: &str, config: &IndexerConfig) -> Vec<String> {
+    tokenize(text)
+        .into_iter()
+        .filter(|token| within_length(token, config))
+        .map(|token| token.text)
+        .collect()
+}
+
+/// Stem a query word with the stemmer named `name` in `STEMMERS`
+pub fn stem(name: &str, word: &str) -> Option<String> {
+    let (_, algorithm) = STEMMERS.iter().find(|(n, _)| *n == name)?;
+    Some(Stemmer::create(*algorithm).stem(word).into_owned())
+}
+
+/// Analyzer for one language
+pub struct Analyzer {
+    language: Option<Language>,
+    stemmer: Option<Stemmer>,
+    stop_words: HashSet<String>,
+}
+
+impl Analyzer {
+    /// Create the analyzer for `language`; unknown languages only tokenize
+    pub fn new(language: Option<Language>, config: &IndexerConfig) -> Self {
+        let stemmer = if config.stemming {
+            language.and_then(|l| l.algorithm()).map(Stemmer::create)
+        } else {
+            None
+        };
+        let stop_words = language
+            .map(|l| l.stop_words())
+            .unwrap_or_default()
+            .iter()
+            .map(|w| w.to_string())
+            .chain(config.stop_words.iter().map(|w| w.to_lowercase()))
+            .collect();
+
+        Self { language, stemmer, stop_words }
+    }
+
+    /// Analyzer language
+    pub fn language(&self) -> Option<Language> {
+        self.language
+    }
+
+    /// Name of the stemmer applied, as listed in `STEMMERS`
+    pub fn stemmer_name(&self) -> Option<&'static str> {
+        self.stemmer.as_ref()?;
+        let algorithm = self.language?.algorithm()?;
+        STEMMERS.iter().find(|(_, a)| *a == algorithm).map(|(name, _)| *name)
+    }
+
+    /// Analyze text into `(surface form, indexed term)` pairs
+    pub fn analyze(&self, text: &str, config: &IndexerConfig) -> Vec<(String, String)> {
+        self.analyze_positions(text, config)
+            .into_iter()
+            .filter_map(|token| Some((token.surface, token.term?)))
+            .collect()
+    }
+
+    /// Analyze text keeping one entry per query token position, so stop
+    /// words leave a gap instead of joining their neighbours into a phrase
+    pub fn analyze_positions(&self, text: &str, config: &IndexerConfig) -> Vec<AnalyzedToken> {
+        tokenize(text)
+            .into_iter()
+            .filter(|token| within_length(token, config))
+            .map(|token| {
+                let term = if self.stop_words.contains(&token.text) {
+                    None
+                } else {
+                    match (&self.stemmer, token.cjk) {
+                        (Some(stemmer), false) => Some(stemmer.stem(&token.text).into_owned()),
+                        _ => Some(token.text.clone()),
+                    }
+                };
+                AnalyzedToken { surface: token.text, term }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(language: &str, text: &str) -> Vec<String> {
+        let config = IndexerConfig::default();
+        Analyzer::new(Language::from_code(language), &config)
+            .analyze(text, &config)
+            .into_iter()
+            .map(|(_, term)| term)
+            .collect()
+    }
+
+    #[test]
+    fn test_language_codes() {
+        assert_eq!(Language::from_code("en-US"), Some(Language::English));
+        assert_eq!(Language::from_code("de_AT"), Some(Language::German));
+        assert_eq!(Language::from_code("zh-Hans"), Some(Language::Chinese));
+        assert_eq!(Language::from_code("xx"), None);
+    }
+
+    #[test]
+    fn test_snowball_stemming_and_stop_words() {
+        assert_eq!(terms("en", "The runners were running quickly"), vec!["runner", "run", "quick"]);
+        assert_eq!(terms("de", "Die Häuser und der Garten"), vec!["haus", "gart"]);
+        assert_eq!(terms("fr", "les maisons de la ville"), vec!["maison", "vill"]);
+        // Unknown languages are tokenized but not stemmed
+        assert_eq!(terms("xx", "the runners"), vec!["the", "runners"]);
+    }
+
+    #[test]
+    fn test_query_stemmers_by_name() {
+        let config = IndexerConfig::default();
+        assert_eq!(Analyzer::new(Some(Language::German), &config).stemmer_name(), Some("german"));
+        assert_eq!(Analyzer::new(Some(Language::Japanese), &config).stemmer_name(), None);
+        assert_eq!(stem("english", "deploying").as_deref(), Some("deploy"));
+        assert_eq!(stem("klingon", "deploying"), None);
+    }
+
+    #[test]
+    fn test_cjk_bigrams() {
+        let tokens: Vec<String> = tokenize("検索エンジン Rust入門 中").into_iter().map(|t| t.text).collect();
+        assert_eq!(tokens, vec!["検索", "索エ", "エン", "ンジ", "ジン", "rust", "入門", "中"]);
+        assert_eq!(terms("ja", "中文"), vec!["中文"]);
+    }
+}
@@ -0,0 +1,469 @@
+//! Sharded client-side search index
+//!
+//! The browser index is split so a query only downloads what it needs:
+//!
+//! - `search/manifest.json`: document metadata (no bodies), field lengths,
+//!   tag and content type maps, analyzer and ranking configuration
+//! - `search/terms-<key>.json`: the postings of every term sharing a prefix,
+//!   and the stemmed forms of every surface form sharing it, where `<key>`
+//!   is the hex-encoded UTF-8 of the first characters
+//! - `search/positions-<key>.json`: token positions of the same terms,
+//!   fetched only for phrases
+//! - `search/docs-<n>.json`: document bodies in fixed-size chunks, fetched
+//!   only to build snippets for displayed results
+//! - `search/vocab.json`: every surface form, sorted, fetched only when a
+//!   query token needs typo tolerance or a "did you mean" suggestion
+//! - `search/stemmers/<name>.js`: the Snowball stemmers listed in the
+//!   manifest, fetched to stem query words no document contains
+//!
+//! Postings are flattened to `[doc_delta, title, tags, headings, body, ...]`
+//! with document indices delta-encoded against the previous posting.
+//! Positions follow the same document order as `[count, delta, ...]`, each
+//! position delta-encoded against the previous one in its document.
+
+use crate::core::config::SearchConfig;
+use crate::core::{Error, Result};
+use crate::search::indexer::{IndexerConfig, Posting, SearchDocument, SearchIndex, SearchMetadata};
+use crate::search::ranking::FIELD_COUNT;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Directory, relative to the output directory, holding the client index
+pub const CLIENT_INDEX_DIR: &str = "search";
+
+/// Manifest file name inside `CLIENT_INDEX_DIR`
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Vocabulary file name inside `CLIENT_INDEX_DIR`
+pub const VOCABULARY_FILE: &str = "vocab.json";
+
+/// Directory inside `CLIENT_INDEX_DIR` holding the query stemmers
+pub const STEMMERS_DIR: &str = "stemmers";
+
+/// Runtime the generated stemmers in `stemmers/` run on
+const SNOWBALL_RUNTIME: &str = include_str!("stemmers/snowball.js");
+
+/// Layout options for the client index
+#[derive(Debug, Clone)]
+pub struct ClientIndexOptions {
+    /// Number of leading characters of a term that select its shard
+    pub shard_prefix_length: usize,
+    /// Number of document bodies per chunk file
+    pub chunk_size: usize,
+}
+
+impl ClientIndexOptions {
+    /// Read the options from `[search]`
+    pub fn from_config(config: &SearchConfig) -> Self {
+        Self {
+            shard_prefix_length: config.shard_prefix_length.max(1),
+            chunk_size: config.chunk_size.max(1),
+        }
+    }
+}
+
+impl Default for ClientIndexOptions {
+    fn default() -> Self {
+        Self::from_config(&SearchConfig::default())
+    }
+}
+
+/// Document metadata shipped in the manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientDocument {
+    pub id: String,
+    pub title: String,
+    pub excerpt: String,
+    pub url: String,
+    pub content_type: String,
+    pub tags: Vec<String>,
+    pub date: String,
+    pub author: Option<String>,
+    pub word_count: usize,
+    pub reading_time: usize,
+    pub lang: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub section: Option<String>,
+    #[serde(default)]
+    pub parent: Option<usize>,
+}
+
+impl From<&SearchDocument> for ClientDocument {
+    fn from(doc: &SearchDocument) -> Self {
+        Self {
+            id: doc.id.clone(),
+            title: doc.title.clone(),
+            excerpt: doc.excerpt.clone(),
+            url: doc.url.clone(),
+            content_type: doc.content_type.clone(),
+            tags: doc.tags.clone(),
+            date: doc.date.clone(),
+            author: doc.author.clone(),
+            word_count: doc.word_count,
+            reading_time: doc.reading_time,
+            lang: doc.lang.clone(),
+            language: doc.language.clone(),
+            section: doc.section.clone(),
+            parent: doc.parent,
+        }
+    }
+}
+
+/// Contents of one `terms-<key>.json` file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientShard {
+    /// Encoded postings by term
+    pub terms: BTreeMap<String, Vec<u32>>,
+    /// Indexed terms by surface form, see `SearchIndex::forms`
+    pub forms: BTreeMap<String, Vec<String>>,
+}
+
+/// Entry point of the client index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientManifest {
+    pub metadata: SearchMetadata,
+    pub config: IndexerConfig,
+    pub shard_prefix_length: usize,
+    /// Keys of the term shards that exist
+    pub shards: Vec<String>,
+    pub chunk_size: usize,
+    pub chunks: usize,
+    pub documents: Vec<ClientDocument>,
+    pub field_lengths: Vec<[u32; FIELD_COUNT]>,
+    pub tags: HashMap<String, Vec<usize>>,
+    pub content_types: HashMap<String, Vec<usize>>,
+    /// See `SearchIndex::stop_words`
+    #[serde(default)]
+    pub stop_words: Vec<String>,
+    /// See `SearchIndex::stemmers`
+    #[serde(default)]
+    pub stemmers: Vec<String>,
+}
+
+/// Summary of a written client index
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientIndexStats {
+    pub shards: usize,
+    pub chunks: usize,
+    pub bytes: usize,
+}
+
+/// Shard key of a term: hex-encoded UTF-8 of its first `prefix_length` characters
+pub fn shard_key(term: &str, prefix_length: usize) -> String {
+    let prefix: String = term.chars().take(prefix_length).collect();
+    prefix.bytes().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Flatten postings into `[doc_delta, tf..., doc_delta, tf..., ...]`
+pub fn encode_postings(postings: &[Posting]) -> Vec<u32> {
+    let mut sorted: Vec<&Posting> = postings.iter().collect();
+    sorted.sort_by_key(|p| p.doc);
+
+    let mut encoded = Vec::with_capacity(sorted.len() * (FIELD_COUNT + 1));
+    let mut previous = 0;
+    for posting in sorted {
+        encoded.push((posting.doc - previous) as u32);
+        encoded.extend_from_slice(&posting.tf);
+        previous = posting.doc;
+    }
+    encoded
+}
+
+/// Inverse of `encode_postings`
+pub fn decode_postings(encoded: &[u32]) -> Result<Vec<Posting>> {
+    if !encoded.len().is_multiple_of(FIELD_COUNT + 1) {
+        return Err(Error::search(format!("Malformed postings list of length {}", encoded.len())));
+    }
+
+    let mut doc = 0;
+    Ok(encoded
+        .chunks(FIELD_COUNT + 1)
+        .map(|entry| {
+            doc += entry[0] as usize;
+            let mut tf = [0; FIELD_COUNT];
+            tf.copy_from_slice(&entry[1..]);
+            Posting { doc, tf, positions: Vec::new() }
+        })
+        .collect())
+}
+
+/// Flatten the positions of `postings`, in document order, into
+/// `[count, delta, ..., count, delta, ...]`
+pub fn encode_positions(postings: &[Posting]) -> Vec<u32> {
+    let mut sorted: Vec<&Posting> = postings.iter().collect();
+    sorted.sort_by_key(|p| p.doc);
+
+    let mut encoded = Vec::new();
+    for posting in sorted {
+        encoded.push(posting.positions.len() as u32);
+        let mut previous = 0;
+        for &position in &posting.positions {
+            encoded.push(position - previous);
+            previous = position;
+        }
+    }
+    encoded
+}
+
+/// Inverse of `encode_positions`, filling in postings decoded by `decode_postings`
+pub fn decode_positions(encoded: &[u32], postings: &mut [Posting]) -> Result<()> {
+    let mut values = encoded.iter().copied();
+    for posting in postings.iter_mut() {
+        let count = values.next().ok_or_else(|| Error::search("Positions list is shorter than its postings"))?;
+        let mut position = 0;
+        posting.positions = (0..count)
+            .map(|_| {
+                position += values.next()?;
+                Some(position)
+            })
+            .collect::<Option<Vec<u32>>>()
+            .ok_or_else(|| Error::search("Positions list ends inside a posting"))?;
+    }
+    if values.next().is_some() {
+        return Err(Error::search("Positions list is longer than its postings"));
+    }
+    Ok(())
+}
+
+/// Write the sharded client index for `index` into `dir`
+pub fn write_client_index(index: &SearchIndex, dir: &Path, options: &ClientIndexOptions) -> Result<ClientIndexStats> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::create_dir_all(dir)?;
+    let mut stats = ClientIndexStats::default();
+
+    // Term shards, sorted for reproducible output
+    let mut shards: BTreeMap<String, ClientShard> = BTreeMap::new();
+    let mut positions: BTreeMap<String, BTreeMap<&str, Vec<u32>>> = BTreeMap::new();
+    for (term, postings) in &index.terms {
+        let key = shard_key(term, options.shard_prefix_length);
+        positions.entry(key.clone()).or_default().insert(term, encode_positions(postings));
+        shards.entry(key).or_default().terms.insert(term.clone(), encode_postings(postings));
+    }
+    for (surface, terms) in &index.forms {
+        shards
+            .entry(shard_key(surface, options.shard_prefix_length))
+            .or_default()
+            .forms
+            .insert(surface.clone(), terms.clone());
+    }
+    for (key, shard) in &shards {
+        stats.bytes += write_json(&dir.join(format!("terms-{}.json", key)), shard)?;
+    }
+    for (key, shard) in &positions {
+        stats.bytes += write_json(&dir.join(format!("positions-{}.json", key)), shard)?;
+    }
+    stats.shards = shards.len();
+
+    // Document body chunks
+    for (n, chunk) in index.documents.chunks(options.chunk_size).enumerate() {
+        let bodies: Vec<&str> = chunk.iter().map(|d| d.content.as_str()).collect();
+        stats.bytes += write_json(&dir.join(format!("docs-{}.json", n)), &bodies)?;
+        stats.chunks += 1;
+    }
+
+    if index.config.fuzzy_search {
+        stats.bytes += write_json(&dir.join(VOCABULARY_FILE), &index.vocabulary)?;
+    }
+
+    // Query stemmers, each evaluated by the client as a function body
+    // returning `word => stem`
+    if !index.stemmers.is_empty() {
+        fs::create_dir_all(dir.join(STEMMERS_DIR))?;
+    }
+    for name in &index.stemmers {
+        let algorithm = stemmer_script(name)
+            .ok_or_else(|| Error::search(format!("No client stemmer for '{}'", name)))?;
+        let script = format!("{}\n{}\nreturn snowballStemmer(stem);\n", SNOWBALL_RUNTIME, algorithm);
+        fs::write(dir.join(STEMMERS_DIR).join(format!("{}.js", name)), &script)?;
+        stats.bytes += script.len();
+    }
+
+    let manifest = ClientManifest {
+        metadata: index.metadata.clone(),
+        config: index.config.clone(),
+        shard_prefix_length: options.shard_prefix_length,
+        shards: shards.into_keys().collect(),
+        chunk_size: options.chunk_size,
+        chunks: stats.chunks,
+        documents: index.documents.iter().map(ClientDocument::from).collect(),
+        field_lengths: index.field_lengths.clone(),
+        tags: index.tags.clone(),
+        content_types: index.content_types.clone(),
+        stop_words: index.stop_words.clone(),
+        stemmers: index.stemmers.clone(),
+    };
+    stats.bytes += write_json(&dir.join(MANIFEST_FILE), &manifest)?;
+
+    Ok(stats)
+}
+
+/// Load a client index written by `write_client_index` back into a `SearchIndex`
+pub fn read_client_index(dir: &Path) -> Result<SearchIndex> {
+    let manifest: ClientManifest = read_json(&dir.join(MANIFEST_FILE))?;
+
+    let mut terms = HashMap::new();
+    let mut forms = HashMap::new();
+    for key in &manifest.shards {
+        let shard: ClientShard = read_json(&dir.join(format!("terms-{}.json", key)))?;
+        let positions: HashMap<String, Vec<u32>> = read_json(&dir.join(format!("positions-{}.json", key)))?;
+        for (term, encoded) in shard.terms {
+            let mut postings = decode_postings(&encoded)?;
+            if let Some(encoded) = positions.get(&term) {
+                decode_positions(encoded, &mut postings)?;
+            }
+            terms.insert(term, postings);
+        }
+        forms.extend(shard.forms);
+    }
+
+    let mut documents = Vec::with_capacity(manifest.documents.len());
+    for n in 0..manifest.chunks {
+        let bodies: Vec<String> = read_json(&dir.join(format!("docs-{}.json", n)))?;
+        for body in bodies {
+            let doc = manifest.documents.get(documents.len()).ok_or_else(|| {
+                Error::search("Client index has more document bodies than documents")
+            })?;
+            documents.push(SearchDocument {
+                id: doc.id.clone(),
+                title: doc.title.clone(),
+                excerpt: doc.excerpt.clone(),
+                url: doc.url.clone(),
+                content_type: doc.content_type.clone(),
+                tags: doc.tags.clone(),
+                date: doc.date.clone(),
+                author: doc.author.clone(),
+                content: body,
+                word_count: doc.word_count,
+                reading_time: doc.reading_time,
+                lang: doc.lang.clone(),
+                language: doc.language.clone(),
+                section: doc.section.clone(),
+                parent: doc.parent,
+            });
+        }
+    }
+    if documents.len() != manifest.documents.len() {
+        return Err(Error::search("Client index is missing document bodies"));
+    }
+
+    let vocabulary_file = dir.join(VOCABULARY_FILE);
+    let vocabulary = if vocabulary_file.is_file() {
+        read_json(&vocabulary_file)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(SearchIndex {
+        documents,
+        terms,
+        field_lengths: manifest.field_lengths,
+        forms,
+        vocabulary,
+        stop_words: manifest.stop_words,
+        stemmers: manifest.stemmers,
+        tags: manifest.tags,
+        content_types: manifest.content_types,
+        metadata: manifest.metadata,
+        config: manifest.config,
+    })
+}
+
+/// Generated JavaScript of the stemmer named `name` in `analyzer::STEMMERS`
+fn stemmer_script(name: &str) -> Option<&'static str> {
+    let script = match name {
+        "arabic" => include_str!("stemmers/arabic.js"),
+        "danish" => include_str!("stemmers/danish.js"),
+        "dutch" => include_str!("stemmers/dutch.js"),
+        "english" => include_str!("stemmers/english.js"),
+        "finnish" => include_str!("stemmers/finnish.js"),
+        "french" => include_str!("stemmers/french.js"),
+        "german" => include_str!("stemmers/german.js"),
+        "greek" => include_str!("stemmers/greek.js"),
+        "hungarian" => include_str!("stemmers/hungarian.js"),
+        "italian" => include_str!("stemmers/italian.js"),
+        "norwegian" => include_str!("stemmers/norwegian.js"),
+        "portuguese" => include_str!("stemmers/portuguese.js"),
+        "romanian" => include_str!("stemmers/romanian.js"),
+        "russian" => include_str!("stemmers/russian.js"),
+        "spanish" => include_str!("stemmers/spanish.js"),
+        "swedish" => include_str!("stemmers/swedish.js"),
+        "tamil" => include_str!("stemmers/tamil.js"),
+        "turkish" => include_str!("stemmers/turkish.js"),
+        _ => return None,
+    };
+    Some(script)
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<usize> {
+    let json = serde_json::to_string(value)
+        .map_err(|e| Error::search(format!("Failed to serialize {}: {}", path.display(), e)))?;
+    fs::write(path, &json)
+        .map_err(|e| Error::search(format!("Failed to write {}: {}", path.display(), e)))?;
+    Ok(json.len())
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| Error::search(format!("Failed to read {}: {}", path.display(), e)))?;
+    serde_json::from_str(&json)
+        .map_err(|e| Error::search(format!("Failed to parse {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postings_roundtrip_delta_encoded() {
+        let postings = vec![
+            Posting { doc: 7, tf: [0, 0, 1, 3], positions: vec![] },
+            Posting { doc: 2, tf: [1, 0, 0, 0], positions: vec![] },
+            Posting { doc: 12, tf: [0, 2, 0, 0], positions: vec![] },
+        ];
+
+        let encoded = encode_postings(&postings);
+        assert_eq!(encoded, vec![2, 1, 0, 0, 0, 5, 0, 0, 1, 3, 5, 0, 2, 0, 0]);
+
+        let decoded = decode_postings(&encoded).unwrap();
+        assert_eq!(decoded.iter().map(|p| p.doc).collect::<Vec<_>>(), vec![2, 7, 12]);
+        assert_eq!(decoded[1].tf, [0, 0, 1, 3]);
+        assert!(decode_postings(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_positions_roundtrip_delta_encoded() {
+        let postings = vec![
+            Posting { doc: 4, tf: [0, 0, 0, 2], positions: vec![3, 40] },
+            Posting { doc: 1, tf: [1, 0, 0, 0], positions: vec![0] },
+        ];
+
+        let encoded = encode_positions(&postings);
+        assert_eq!(encoded, vec![1, 0, 2, 3, 37]);
+
+        let mut decoded = decode_postings(&encode_postings(&postings)).unwrap();
+        decode_positions(&encoded, &mut decoded).unwrap();
+        assert_eq!(decoded[0].positions, vec![0]);
+        assert_eq!(decoded[1].positions, vec![3, 40]);
+        assert!(decode_positions(&[2, 0], &mut decoded).is_err());
+    }
+
+    #[test]
+    fn test_every_stemmer_ships_to_the_client() {
+        for (name, _) in crate::search::analyzer::STEMMERS {
+            assert!(stemmer_script(name).is_some(), "no client stemmer for {}", name);
+        }
+    }
+
+    #[test]
+    fn test_shard_key_uses_characters() {
+        assert_eq!(shard_key("rust", 2), "7275");
+        assert_eq!(shard_key("r", 2), "72");
+        assert_eq!(shard_key("über", 2), "c3bc62");
+    }
+}
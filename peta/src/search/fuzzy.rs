@@ -0,0 +1,363 @@
+//! Prefix and typo-tolerant query expansion
+//!
+//! Each query token expands to the terms it resolves to exactly, plus, with
+//! `fuzzy_search` enabled, prefix completions of the last token and terms
+//! within a small edit distance of tokens that match nothing exactly.
+//! Expanded terms carry a weight below 1 and `RankingAlgorithm` ranks
+//! documents matching more tokens exactly first.
+//!
+//! `PetaSearch.expandQuery` and `PetaSearch.suggest` in `search_bar.js`
+//! implement the same rules; keep them in sync.
+
+use crate::search::analyzer;
+use crate::search::indexer::{IndexerConfig, SearchIndex};
+use crate::search::syntax::{self, Clause};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// A term searched for on behalf of one query token
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryTerm {
+    /// Indexed term
+    pub term: String,
+    /// Position of the query token it was expanded from
+    pub token: usize,
+    /// Multiplier applied to the term's score
+    pub weight: f64,
+    /// Whether the token resolves to this term without expansion
+    pub exact: bool,
+}
+
+impl QueryTerm {
+    /// Unexpanded term for token `token`
+    pub fn exact(term: &str, token: usize) -> Self {
+        Self { term: term.to_string(), token, weight: 1.0, exact: true }
+    }
+}
+
+/// The terms and surface forms queries are resolved and expanded against.
+///
+/// Implemented by `SearchIndex` and by the lexicon kept next to a tantivy
+/// index, so both backends expand queries identically.
+pub trait Lexicon {
+    /// Configuration the index was built with
+    fn config(&self) -> &IndexerConfig;
+
+    /// Surface forms whose indexed terms differ from the form itself
+    fn forms(&self) -> &HashMap<String, Vec<String>>;
+
+    /// Every surface form seen while indexing, sorted
+    fn vocabulary(&self) -> &[String];
+
+    /// Names of the stemmers the documents were analyzed with
+    fn stemmers(&self) -> &[String];
+
+    /// Every indexed term
+    fn terms(&self) -> Box<dyn Iterator<Item = &str> + '_>;
+
+    /// Number of documents containing `term`
+    fn doc_freq(&self, term: &str) -> usize;
+}
+
+impl Lexicon for SearchIndex {
+    fn config(&self) -> &IndexerConfig {
+        &self.config
+    }
+
+    fn forms(&self) -> &HashMap<String, Vec<String>> {
+        &self.forms
+    }
+
+    fn vocabulary(&self) -> &[String] {
+        &self.vocabulary
+    }
+
+    fn stemmers(&self) -> &[String] {
+        &self.stemmers
+    }
+
+    fn terms(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.terms.keys().map(String::as_str))
+    }
+
+    fn doc_freq(&self, term: &str) -> usize {
+        self.terms.get(term).map(|p| p.len()).unwrap_or(0)
+    }
+}
+
+/// Largest edit distance tolerated for a token of `length` characters
+pub fn max_edits(length: usize) -> usize {
+    match length {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    }
+}
+
+/// Levenshtein distance between `a` and `b` in characters, if at most `max`
+pub fn levenshtein(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        let mut row_min = current[0];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            row_min = row_min.min(current[j + 1]);
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[b.len()]).filter(|d| *d <= max)
+}
+
+/// Terms a token or surface form resolves to without expansion.
+///
+/// Surface forms seen while indexing resolve to the terms they were indexed
+/// as. Any other word is stemmed with each of the index's stemmers, so an
+/// inflection no document contains still finds the documents of its stem.
+pub fn resolve<'a>(index: &'a impl Lexicon, token: &'a str) -> Vec<Cow<'a, str>> {
+    if let Some(terms) = index.forms().get(token) {
+        return terms.iter().map(|t| Cow::Borrowed(t.as_str())).collect();
+    }
+
+    let mut terms = vec![Cow::Borrowed(token)];
+    if token.chars().any(analyzer::is_cjk) {
+        return terms;
+    }
+    for stem in index.stemmers().iter().filter_map(|name| analyzer::stem(name, token)) {
+        if !terms.iter().any(|term| *term == stem) && index.doc_freq(&stem) > 0 {
+            terms.push(Cow::Owned(stem));
+        }
+    }
+    terms
+}
+
+/// Expand a query into weighted terms
+pub fn expand_query(index: &impl Lexicon, query: &str) -> Vec<QueryTerm> {
+    let config = index.config();
+    let tokens = analyzer::query_tokens(query, config);
+    let completes_last = config.fuzzy_search && !query.chars().last().is_some_and(char::is_whitespace);
+    let mut expanded = Vec::new();
+
+    for (position, token) in tokens.iter().enumerate() {
+        let exact = resolve(index, token);
+        expanded.extend(exact.iter().map(|term| QueryTerm::exact(term, position)));
+        if !config.fuzzy_search {
+            continue;
+        }
+
+        // Search-as-you-type completions of the last token
+        if completes_last && position + 1 == tokens.len() {
+            let mut candidates: HashSet<Cow<str>> = HashSet::new();
+            for word in index.vocabulary().iter().map(String::as_str).chain(index.terms()) {
+                if word.starts_with(token.as_str()) && word != token.as_str() {
+                    candidates.extend(resolve(index, word));
+                }
+            }
+            let mut candidates: Vec<(Cow<str>, usize)> = candidates
+                .into_iter()
+                .filter(|term| !exact.contains(term))
+                .map(|term| {
+                    let df = index.doc_freq(&term);
+                    (term, df)
+                })
+                .filter(|(_, df)| *df > 0)
+                .collect();
+            candidates.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            expanded.extend(candidates.into_iter().take(config.max_expansions).map(|(term, _)| QueryTerm {
+                term: term.into_owned(),
+                token: position,
+                weight: config.ranking.prefix_weight,
+                exact: false,
+            }));
+        }
+
+        // Typo tolerance for tokens that match nothing
+        if exact.iter().all(|term| index.doc_freq(term) == 0) {
+            let mut candidates = fuzzy_terms(index, token);
+            candidates.sort_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)).then(a.0.cmp(&b.0)));
+            expanded.extend(candidates.into_iter().take(config.max_expansions).map(|(term, distance, _)| QueryTerm {
+                term: term.into_owned(),
+                token: position,
+                weight: config.ranking.fuzzy_weight.powi(distance as i32),
+                exact: false,
+            }));
+        }
+    }
+
+    // A term keeps its first, strongest expansion
+    let mut seen = HashSet::new();
+    expanded.retain(|q| seen.insert(q.term.clone()));
+    expanded
+}
+
+/// Indexed terms within edit distance of `token`, as `(term, distance, doc freq)`
+fn fuzzy_terms<'a>(index: &'a impl Lexicon, token: &str) -> Vec<(Cow<'a, str>, usize, usize)> {
+    let max = max_edits(token.chars().count());
+    if max == 0 {
+        return Vec::new();
+    }
+
+    let mut best: HashMap<Cow<str>, usize> = HashMap::new();
+    for word in index.vocabulary() {
+        let distance = match levenshtein(token, word, max) {
+            Some(distance) if distance > 0 => distance,
+            _ => continue,
+        };
+        for term in resolve(index, word) {
+            let entry = best.entry(term).or_insert(distance);
+            *entry = (*entry).min(distance);
+        }
+    }
+
+    best.into_iter()
+        .map(|(term, distance)| {
+            let df = index.doc_freq(&term);
+            (term, distance, df)
+        })
+        .filter(|(_, _, df)| *df > 0)
+        .collect()
+}
+
+/// A corrected query when some words match nothing, e.g. "did you mean".
+///
+/// Only the plain words of the query are corrected; phrases, exclusions and
+/// filters are kept as typed.
+pub fn suggest(index: &impl Lexicon, query: &str) -> Option<String> {
+    let query = syntax::parse(query);
+    let mut changed = false;
+    let words: Vec<String> = query
+        .clauses
+        .iter()
+        .filter_map(|c| match (&c.clause, c.negated) {
+            (Clause::Word(word), false) => Some(word),
+            _ => None,
+        })
+        .map(|word| match correct(index, word) {
+            Some(corrected) => {
+                changed = true;
+                corrected
+            }
+            None => word.clone(),
+        })
+        .collect();
+
+    changed.then(|| query.with_words(&words).to_string())
+}
+
+/// `text` with every token that matches nothing replaced by its closest
+/// indexed word, if any was replaced
+fn correct(index: &impl Lexicon, text: &str) -> Option<String> {
+    let tokens = analyzer::query_tokens(text, index.config());
+    let mut corrected = Vec::with_capacity(tokens.len());
+    let mut changed = false;
+
+    for token in tokens {
+        if resolve(index, &token).iter().any(|term| index.doc_freq(term) > 0) {
+            corrected.push(token);
+            continue;
+        }
+
+        let max = max_edits(token.chars().count());
+        let best = index
+            .vocabulary()
+            .iter()
+            .filter(|word| **word != token)
+            .filter_map(|word| {
+                let distance = levenshtein(&token, word, max)?;
+                let df = resolve(index, word).iter().map(|t| index.doc_freq(t)).max().unwrap_or(0);
+                (df > 0).then_some((word, distance, df))
+            })
+            .min_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)).then(a.0.cmp(b.0)));
+
+        match best {
+            Some((word, _, _)) => {
+                corrected.push(word.clone());
+                changed = true;
+            }
+            _ => corrected.push(token),
+        }
+    }
+
+    changed.then(|| corrected.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::indexer::Posting;
+
+    fn index(words: &[(&str, &str, usize)]) -> SearchIndex {
+        let mut index = SearchIndex::new();
+        for (surface, term, docs) in words {
+            let postings = (0..*docs).map(|doc| Posting { doc, tf: [0, 0, 0, 1], positions: vec![] }).collect();
+            index.terms.insert(term.to_string(), postings);
+            if surface != term {
+                index.forms.insert(surface.to_string(), vec![term.to_string()]);
+            }
+            index.vocabulary.push(surface.to_string());
+        }
+        index.vocabulary.sort();
+        index
+    }
+
+    fn expansions(index: &SearchIndex, query: &str) -> Vec<(String, bool)> {
+        expand_query(index, query).into_iter().map(|q| (q.term, q.exact)).collect()
+    }
+
+    #[test]
+    fn test_levenshtein_is_bounded() {
+        assert_eq!(levenshtein("python", "pythn", 2), Some(1));
+        assert_eq!(levenshtein("kitten", "sitting", 3), Some(3));
+        assert_eq!(levenshtein("kitten", "sitting", 2), None);
+        assert_eq!(levenshtein("straße", "strasse", 2), Some(2));
+        assert_eq!(max_edits(2), 0);
+        assert_eq!(max_edits(5), 1);
+        assert_eq!(max_edits(6), 2);
+    }
+
+    #[test]
+    fn test_only_last_token_completes_as_prefix() {
+        let index = index(&[("python", "python", 2), ("packaging", "packag", 1), ("pack", "pack", 1)]);
+
+        assert_eq!(
+            expansions(&index, "pack"),
+            vec![("pack".to_string(), true), ("packag".to_string(), false)]
+        );
+        assert_eq!(expansions(&index, "pack "), vec![("pack".to_string(), true)]);
+        assert!(expansions(&index, "pyth pack").iter().all(|(term, _)| term != "python"));
+    }
+
+    #[test]
+    fn test_unmatched_tokens_expand_to_close_terms() {
+        let index = index(&[("python", "python", 2), ("typhon", "typhon", 1)]);
+
+        let terms = expand_query(&index, "pythn ");
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms[1].term, "python");
+        assert_eq!(terms[1].weight, 0.5);
+        assert!(!terms[1].exact);
+        assert_eq!(suggest(&index, "pythn"), Some("python".to_string()));
+        assert_eq!(suggest(&index, "python"), None);
+    }
+
+    #[test]
+    fn test_disabled_fuzzy_search_matches_exactly() {
+        let mut index = index(&[("python", "python", 2)]);
+        index.config.fuzzy_search = false;
+
+        assert_eq!(expansions(&index, "pythn"), vec![("pythn".to_string(), true)]);
+        assert_eq!(expansions(&index, "pyth"), vec![("pyth".to_string(), true)]);
+    }
+}
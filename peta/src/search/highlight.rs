@@ -0,0 +1,364 @@
+//! Search result highlighting
+//!
+//! Matches are found word by word, split the way the analyzer tokenizes text,
+//! and widened to grapheme cluster boundaries so an accent written as a
+//! combining mark always stays with its letter. Highlights are spans of plain
+//! text with a `matched` flag: renderers escape every span and wrap matched
+//! ones in `<mark>`. `PetaSearch.highlighter` in `search_bar.js` implements
+//! the same rules; keep them in sync.
+
+use crate::search::analyzer::{self, is_cjk};
+use crate::search::fuzzy::{self, Lexicon};
+use crate::search::indexer::SearchDocument;
+use crate::search::syntax;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Graphemes of context kept on each side of a match
+pub const SNIPPET_CONTEXT: usize = 40;
+
+/// Snippets taken from one text
+pub const MAX_SNIPPETS: usize = 2;
+
+/// A piece of highlighted text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighlightSpan {
+    pub text: String,
+    pub matched: bool,
+}
+
+/// Text split into matched and unmatched spans
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snippet {
+    pub spans: Vec<HighlightSpan>,
+    /// Whether the source text continues before the snippet
+    pub truncated_start: bool,
+    /// Whether the source text continues after the snippet
+    pub truncated_end: bool,
+}
+
+impl Snippet {
+    /// Whether any span is a match
+    pub fn has_match(&self) -> bool {
+        self.spans.iter().any(|s| s.matched)
+    }
+
+    /// Plain text of the snippet
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    /// HTML with every span escaped and matches wrapped in `<mark>`
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        if self.truncated_start {
+            html.push('…');
+        }
+        for span in &self.spans {
+            if span.matched {
+                html.push_str("<mark>");
+                html.push_str(&escape_html(&span.text));
+                html.push_str("</mark>");
+            } else {
+                html.push_str(&escape_html(&span.text));
+            }
+        }
+        if self.truncated_end {
+            html.push('…');
+        }
+        html
+    }
+}
+
+/// Part of a document a highlight was taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightField {
+    Title,
+    Section,
+    Content,
+}
+
+/// A highlighted snippet of one document field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Highlight {
+    pub field: HighlightField,
+    pub snippet: Snippet,
+}
+
+/// Finds and marks the words of a query in text
+#[derive(Debug, Clone)]
+pub struct Highlighter {
+    words: HashSet<String>,
+    context: usize,
+    max_snippets: usize,
+}
+
+impl Highlighter {
+    /// Highlight the given lowercase words
+    pub fn new(words: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            words: words.into_iter().collect(),
+            context: SNIPPET_CONTEXT,
+            max_snippets: MAX_SNIPPETS,
+        }
+    }
+
+    /// Highlight the words of `query` and every indexed surface form of the
+    /// terms they expand to, so "run" also marks "running"
+    pub fn for_query(index: &impl Lexicon, query: &str) -> Self {
+        let text = syntax::parse(query).text();
+        let terms: HashSet<String> = fuzzy::expand_query(index, &text).into_iter().map(|q| q.term).collect();
+
+        let mut words: HashSet<String> = analyzer::query_tokens(&text, index.config()).into_iter().collect();
+        words.extend(terms.iter().cloned());
+        for (surface, resolved) in index.forms() {
+            if resolved.iter().any(|term| terms.contains(term)) {
+                words.insert(surface.clone());
+            }
+        }
+        Self::new(words)
+    }
+
+    /// Set the graphemes of context around matches and the snippet limit
+    pub fn with_context(mut self, context: usize, max_snippets: usize) -> Self {
+        self.context = context;
+        self.max_snippets = max_snippets;
+        self
+    }
+
+    /// Byte ranges of the matched words, on grapheme boundaries, merged
+    pub fn matches(&self, text: &str) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let byte = |i: usize| chars.get(i).map(|c| c.0).unwrap_or(text.len());
+        let mut ranges = Vec::new();
+
+        let mut i = 0;
+        while i < chars.len() {
+            if !chars[i].1.is_alphanumeric() {
+                i += 1;
+                continue;
+            }
+            let cjk = is_cjk(chars[i].1);
+            let mut end = i + 1;
+            while end < chars.len() && chars[end].1.is_alphanumeric() && is_cjk(chars[end].1) == cjk {
+                end += 1;
+            }
+
+            // CJK runs match by bigram, like the analyzer indexes them
+            let pieces: Vec<(usize, usize)> = if cjk && end - i > 1 {
+                (i..end - 1).map(|k| (k, k + 2)).collect()
+            } else {
+                vec![(i, end)]
+            };
+            for (start, stop) in pieces {
+                let range = byte(start)..byte(stop);
+                if self.words.contains(&text[range.clone()].to_lowercase()) {
+                    ranges.push(range);
+                }
+            }
+            i = end;
+        }
+
+        let bounds = grapheme_bounds(text);
+        let mut merged: Vec<Range<usize>> = Vec::new();
+        for range in ranges {
+            let start = bounds[bounds.partition_point(|&b| b <= range.start) - 1];
+            let end = bounds[bounds.partition_point(|&b| b < range.end)];
+            match merged.last_mut() {
+                Some(last) if start <= last.end => last.end = last.end.max(end),
+                _ => merged.push(start..end),
+            }
+        }
+        merged
+    }
+
+    /// The whole of `text`, with its matches marked
+    pub fn highlight(&self, text: &str) -> Snippet {
+        Snippet {
+            spans: spans(text, 0..text.len(), &self.matches(text)),
+            truncated_start: false,
+            truncated_end: false,
+        }
+    }
+
+    /// Highlights of a search result: its title and section heading when
+    /// they match, then snippets of its content, or of its excerpt when no
+    /// content was indexed
+    pub fn highlight_document(&self, document: &SearchDocument) -> Vec<Highlight> {
+        let mut highlights = Vec::new();
+
+        let title = self.highlight(&document.title);
+        if title.has_match() {
+            highlights.push(Highlight { field: HighlightField::Title, snippet: title });
+        }
+
+        if let Some(section) = &document.section {
+            let section = self.highlight(section);
+            if section.has_match() {
+                highlights.push(Highlight { field: HighlightField::Section, snippet: section });
+            }
+        }
+
+        let text = if document.content.is_empty() { &document.excerpt } else { &document.content };
+        for snippet in self.snippets(text) {
+            highlights.push(Highlight { field: HighlightField::Content, snippet });
+        }
+
+        highlights
+    }
+
+    /// Windows of context around the matches in `text`, overlapping windows
+    /// merged, at most `max_snippets` of them; empty when nothing matches
+    pub fn snippets(&self, text: &str) -> Vec<Snippet> {
+        let matches = self.matches(text);
+        let bounds = grapheme_bounds(text);
+        let last = bounds.len() - 1;
+
+        let mut windows: Vec<Range<usize>> = Vec::new();
+        for range in &matches {
+            let first = bounds.partition_point(|&b| b < range.start);
+            let after = bounds.partition_point(|&b| b < range.end);
+            let window = bounds[first.saturating_sub(self.context)]..bounds[(after + self.context).min(last)];
+            match windows.last_mut() {
+                Some(previous) if window.start <= previous.end => previous.end = window.end,
+                _ => windows.push(window),
+            }
+        }
+
+        windows
+            .into_iter()
+            .take(self.max_snippets)
+            .map(|window| {
+                let inside: Vec<Range<usize>> = matches
+                    .iter()
+                    .filter(|m| m.start >= window.start && m.end <= window.end)
+                    .cloned()
+                    .collect();
+                let window = trim_window(text, window, &inside);
+                Snippet {
+                    truncated_start: !text[..window.start].trim().is_empty(),
+                    truncated_end: !text[window.end..].trim().is_empty(),
+                    spans: spans(text, window, &inside),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Grapheme cluster boundaries of `text`, including its end
+fn grapheme_bounds(text: &str) -> Vec<usize> {
+    let mut bounds: Vec<usize> = text.grapheme_indices(true).map(|(i, _)| i).collect();
+    bounds.push(text.len());
+    bounds
+}
+
+/// Drop the partial words at the edges of a window, then its outer whitespace
+fn trim_window(text: &str, window: Range<usize>, matches: &[Range<usize>]) -> Range<usize> {
+    let (mut start, mut end) = (window.start, window.end);
+    let first = matches.first().map(|m| m.start).unwrap_or(end);
+    let last = matches.last().map(|m| m.end).unwrap_or(start);
+
+    if text[..start].chars().next_back().is_some_and(|c| !c.is_whitespace()) {
+        if let Some(space) = text[start..first].find(char::is_whitespace) {
+            start += space;
+        }
+    }
+    if text[end..].chars().next().is_some_and(|c| !c.is_whitespace()) {
+        if let Some(space) = text[last..end].rfind(char::is_whitespace) {
+            end = last + space;
+        }
+    }
+
+    let inner = &text[start..end];
+    let leading = inner.len() - inner.trim_start().len();
+    let trailing = inner.len() - inner.trim_end().len();
+    start + leading..(end - trailing).max(start + leading)
+}
+
+/// Spans of `text[window]`, marking `matches`
+fn spans(text: &str, window: Range<usize>, matches: &[Range<usize>]) -> Vec<HighlightSpan> {
+    let mut spans = Vec::new();
+    let mut position = window.start;
+    for range in matches.iter().filter(|m| m.start >= window.start && m.end <= window.end) {
+        if range.start > position {
+            spans.push(HighlightSpan { text: text[position..range.start].to_string(), matched: false });
+        }
+        spans.push(HighlightSpan { text: text[range.clone()].to_string(), matched: true });
+        position = range.end;
+    }
+    if position < window.end {
+        spans.push(HighlightSpan { text: text[position..window.end].to_string(), matched: false });
+    }
+    spans
+}
+
+/// Escape text for use in HTML content and attributes
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn highlighter(words: &[&str]) -> Highlighter {
+        Highlighter::new(words.iter().map(|w| w.to_string()))
+    }
+
+    fn marked(snippet: &Snippet) -> Vec<&str> {
+        snippet.spans.iter().filter(|s| s.matched).map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_matches_whole_words_case_insensitively() {
+        let snippet = highlighter(&["rust"]).highlight("Rust, rusty and RUST.");
+        assert_eq!(marked(&snippet), vec!["Rust", "RUST"]);
+        assert_eq!(snippet.text(), "Rust, rusty and RUST.");
+    }
+
+    #[test]
+    fn test_non_ascii_and_combining_marks() {
+        // İ and ẞ change byte length when lowercased; the analyzer splits a
+        // combining accent from "cafe", but the mark keeps the whole grapheme
+        let snippet = highlighter(&["straße", "cafe"]).highlight("İİİ STRAẞE Strasse cafe\u{301} café");
+        assert_eq!(marked(&snippet), vec!["STRAẞE", "cafe\u{301}"]);
+        assert!(snippet.to_html().contains("<mark>cafe\u{301}</mark>"));
+
+        let snippet = highlighter(&["検索"]).highlight("全文検索エンジン");
+        assert_eq!(marked(&snippet), vec!["検索"]);
+    }
+
+    #[test]
+    fn test_snippets_merge_overlapping_windows() {
+        let text = format!("{} alpha beta {} gamma", "lorem ipsum ".repeat(10), "dolor sit ".repeat(20));
+        let snippets = highlighter(&["alpha", "beta", "gamma"]).with_context(12, 5).snippets(&text);
+
+        assert_eq!(snippets.len(), 2);
+        assert_eq!(marked(&snippets[0]), vec!["alpha", "beta"]);
+        assert!(snippets[0].truncated_start && snippets[0].truncated_end);
+        assert_eq!(marked(&snippets[1]), vec!["gamma"]);
+        assert!(!snippets[1].truncated_end);
+        // Windows never start or end inside a word
+        assert!(snippets[0].text().starts_with("ipsum"));
+    }
+
+    #[test]
+    fn test_html_is_escaped() {
+        let snippet = highlighter(&["amp", "lt"]).highlight("<b>a & b</b> amp lt");
+        assert_eq!(snippet.to_html(), "&lt;b&gt;a &amp; b&lt;/b&gt; <mark>amp</mark> <mark>lt</mark>");
+    }
+}
@@ -1,14 +1,21 @@
 //! Enhanced search indexer for building client-side search index
 
-use crate::content::RstContent;
+use crate::content::{RstContent, TocEntry};
+use crate::core::config::SiteConfig;
 use crate::core::{Error, Result};
+use crate::search::analyzer::{Analyzer, Language};
+use crate::search::fuzzy;
+use crate::search::ranking::{Field, RankingAlgorithm, RankingParams, FIELD_COUNT};
+use crate::search::syntax::{self, QueryFilter};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
 /// Search document representing indexed content
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchDocument {
     /// Document ID
     pub id: String,
@@ -32,6 +39,18 @@ pub struct SearchDocument {
     pub word_count: usize,
     /// Reading time in minutes
     pub reading_time: usize,
+    /// Language the document was analyzed as
+    #[serde(default)]
+    pub lang: String,
+    /// Heading of the section this document covers, `None` for a whole page
+    #[serde(default)]
+    pub section: Option<String>,
+    /// Index of the page document a section belongs to
+    #[serde(default)]
+    pub parent: Option<usize>,
+    /// Programming language of a snippet, from its `language` metadata
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 /// Search index for client-side search
@@ -39,16 +58,71 @@ pub struct SearchDocument {
 pub struct SearchIndex {
     /// Indexed documents
     pub documents: Vec<SearchDocument>,
-    /// Term index for fast lookup
-    pub terms: HashMap<String, Vec<usize>>,
+    /// Term index with per-field term frequencies
+    pub terms: HashMap<String, Vec<Posting>>,
+    /// Per-document field lengths in terms, indexed by `Field`
+    #[serde(default)]
+    pub field_lengths: Vec<[u32; FIELD_COUNT]>,
     /// Tag index
     pub tags: HashMap<String, Vec<usize>>,
     /// Content type index
     pub content_types: HashMap<String, Vec<usize>>,
     /// Index metadata
     pub metadata: SearchMetadata,
+    /// Surface forms whose indexed terms differ from the form itself,
+    /// used to resolve query tokens without running a stemmer
+    #[serde(default)]
+    pub forms: HashMap<String, Vec<String>>,
+    /// Every surface form seen while indexing, sorted, for typo tolerance
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+    /// Surface forms dropped as stop words, sorted; they match any word
+    /// inside a phrase since their positions are not indexed
+    #[serde(default)]
+    pub stop_words: Vec<String>,
+    /// Snowball stemmers the documents were analyzed with, sorted, see
+    /// `analyzer::STEMMERS`; query words missing from `forms` are stemmed
+    /// with each of them
+    #[serde(default)]
+    pub stemmers: Vec<String>,
+    /// Configuration the index was built with, reused to analyze queries
+    #[serde(default)]
+    pub config: IndexerConfig,
 }
 
+/// Occurrences of a term in one document
+///
+/// Serialized as `[doc, [title, tags, headings, body], [positions]]` to keep
+/// the index small.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "PostingTuple", into = "PostingTuple")]
+pub struct Posting {
+    /// Document index
+    pub doc: usize,
+    /// Term frequency per field, indexed by `Field`
+    pub tf: [u32; FIELD_COUNT],
+    /// Ascending token positions in the document, for phrase queries
+    pub positions: Vec<u32>,
+}
+
+type PostingTuple = (usize, [u32; FIELD_COUNT], Vec<u32>);
+
+impl From<PostingTuple> for Posting {
+    fn from((doc, tf, positions): PostingTuple) -> Self {
+        Self { doc, tf, positions }
+    }
+}
+
+impl From<Posting> for PostingTuple {
+    fn from(posting: Posting) -> Self {
+        (posting.doc, posting.tf, posting.positions)
+    }
+}
+
+/// Positions skipped between the title, each tag, each heading and the body,
+/// so that a phrase never matches across two of them
+pub const POSITION_GAP: u32 = 16;
+
 /// Search metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchMetadata {
@@ -60,18 +134,24 @@ pub struct SearchMetadata {
     pub total_documents: usize,
     /// Total terms
     pub total_terms: usize,
-    /// Average document length
+    /// Average document length in terms, over all fields
     pub avg_document_length: f64,
+    /// Average length of each field in terms, indexed by `Field`
+    #[serde(default)]
+    pub avg_field_lengths: [f64; FIELD_COUNT],
 }
 
 /// Search indexer configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct IndexerConfig {
     /// Minimum term length to index
     pub min_term_length: usize,
     /// Maximum term length to index
     pub max_term_length: usize,
-    /// Stop words to exclude
+    /// Default language tag, overridden by a page's `lang` frontmatter
+    pub language: String,
+    /// Stop words excluded in every language, on top of the built-in lists
     pub stop_words: Vec<String>,
     /// Include content in index
     pub index_content: bool,
@@ -79,8 +159,29 @@ pub struct IndexerConfig {
     pub index_metadata: bool,
     /// Stemming enabled
     pub stemming: bool,
-    /// Fuzzy search enabled
+    /// Prefix completion and typo tolerance enabled
     pub fuzzy_search: bool,
+    /// Split pages into sections at their table of contents headings
+    pub sections: bool,
+    /// Maximum prefix or fuzzy expansions per query token
+    pub max_expansions: usize,
+    /// BM25F ranking parameters
+    pub ranking: RankingParams,
+}
+
+impl IndexerConfig {
+    /// Indexer configuration for the site's `[search]` section and language
+    pub fn from_config(config: &SiteConfig) -> Self {
+        Self {
+            language: config.site.lang.clone(),
+            index_content: config.search.index_content,
+            index_metadata: config.search.index_metadata,
+            fuzzy_search: config.search.fuzzy_search,
+            sections: config.search.sections,
+            ranking: RankingParams::from_config(&config.search),
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for IndexerConfig {
@@ -88,17 +189,15 @@ impl Default for IndexerConfig {
         Self {
             min_term_length: 2,
             max_term_length: 50,
-            stop_words: vec![
-                "a".to_string(), "an".to_string(), "and".to_string(), "are".to_string(), "as".to_string(), "at".to_string(), "be".to_string(), "but".to_string(), "by".to_string(), "for".to_string(), "if".to_string(),
-                "in".to_string(), "into".to_string(), "is".to_string(), "it".to_string(), "no".to_string(), "not".to_string(), "of".to_string(), "on".to_string(), "or".to_string(), "such".to_string(), "that".to_string(),
-                "the".to_string(), "their".to_string(), "then".to_string(), "there".to_string(), "these".to_string(), "they".to_string(), "this".to_string(), "to".to_string(), "was".to_string(),
-                "will".to_string(), "with".to_string(), "the".to_string(), "this".to_string(), "that".to_string(), "these".to_string(), "those".to_string(), "is".to_string(), "are".to_string(),
-                "was".to_string(), "were".to_string(), "been".to_string(), "being".to_string(), "have".to_string(), "has".to_string(), "had".to_string(), "do".to_string(), "does".to_string(), "did".to_string(),
-            ],
+            language: "en".to_string(),
+            stop_words: Vec::new(),
             index_content: true,
             index_metadata: true,
             stemming: true,
             fuzzy_search: true,
+            sections: true,
+            max_expansions: 10,
+            ranking: RankingParams::default(),
         }
     }
 }
@@ -109,15 +208,22 @@ impl SearchIndex {
         Self {
             documents: Vec::new(),
             terms: HashMap::new(),
+            field_lengths: Vec::new(),
+            forms: HashMap::new(),
+            vocabulary: Vec::new(),
+            stop_words: Vec::new(),
+            stemmers: Vec::new(),
             tags: HashMap::new(),
             content_types: HashMap::new(),
             metadata: SearchMetadata {
-                version: "1.0.0".to_string(),
+                version: "1.2.0".to_string(),
                 build_timestamp: chrono::Utc::now().to_rfc3339(),
                 total_documents: 0,
                 total_terms: 0,
                 avg_document_length: 0.0,
+                avg_field_lengths: [0.0; FIELD_COUNT],
             },
+            config: IndexerConfig::default(),
         }
     }
     
@@ -132,17 +238,79 @@ impl SearchIndex {
         // Clear existing index
         self.documents.clear();
         self.terms.clear();
+        self.field_lengths.clear();
+        self.forms.clear();
+        self.vocabulary.clear();
+        self.stop_words.clear();
+        self.stemmers.clear();
         self.tags.clear();
         self.content_types.clear();
-        
-        // Process each content item
-        for (idx, item) in content.iter().enumerate() {
-            let document = self.create_document(item, config)?;
-            self.documents.push(document);
+        self.config = config.clone();
+        
+        let mut analyzers: HashMap<Option<Language>, Analyzer> = HashMap::new();
+        let mut forms: HashMap<String, BTreeSet<String>> = HashMap::new();
+        
+        // Process each content item, and each of its sections
+        for item in content {
+            let mut page = self.create_document(item, config)?;
+            let language = Language::from_code(&page.lang);
+            let analyzer = analyzers
+                .entry(language)
+                .or_insert_with(|| Analyzer::new(language, config));
+            
+            let (intro, sections) = if config.sections && config.index_content {
+                self.split_sections(item)
+            } else {
+                (item.html.as_str(), Vec::new())
+            };
+            
+            let page_idx = self.documents.len();
+            if !sections.is_empty() {
+                page.content = self.html_to_text(intro);
+            }
+            let mut parts = vec![(page.clone(), intro)];
+            for section in sections {
+                let body = self.html_to_text(&HEADING_RE.replace_all(section.html, " "));
+                let word_count = body.split_whitespace().count();
+                let document = SearchDocument {
+                    id: format!("{}#{}", page.id, section.anchor),
+                    url: format!("{}#{}", page.url, section.anchor),
+                    excerpt: self.generate_excerpt(&body, 150),
+                    content: self.html_to_text(section.html),
+                    word_count,
+                    reading_time: word_count.div_ceil(200),
+                    section: Some(section.title),
+                    parent: Some(page_idx),
+                    ..page.clone()
+                };
+                parts.push((document, section.html));
+            }
             
-            // Index document
-            self.index_document(idx, config)?;
+            // Index documents
+            for (document, html) in parts {
+                let idx = self.documents.len();
+                self.documents.push(document);
+                let headings = self.extract_headings(html);
+                let body = self.html_to_text(&HEADING_RE.replace_all(html, " "));
+                self.index_document(idx, &headings, &body, analyzer, &mut forms, config)?;
+            }
+        }
+        
+        // Keep only the forms a query could not resolve by itself
+        for (surface, terms) in forms {
+            if terms.is_empty() {
+                self.stop_words.push(surface);
+                continue;
+            }
+            if terms.len() > 1 || !terms.contains(&surface) {
+                self.forms.insert(surface.clone(), terms.into_iter().collect());
+            }
+            self.vocabulary.push(surface);
         }
+        self.vocabulary.sort();
+        self.stop_words.sort();
+        self.stemmers = analyzers.values().filter_map(Analyzer::stemmer_name).map(String::from).collect();
+        self.stemmers.sort();
         
         // Update metadata
         self.update_metadata();
@@ -167,6 +335,12 @@ impl SearchIndex {
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
         
+        let lang = content.frontmatter
+            .get("lang")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&config.language)
+            .to_string();
+        
         Ok(SearchDocument {
             id: content.metadata.id.clone(),
             title: content.metadata.title.clone(),
@@ -181,21 +355,69 @@ impl SearchIndex {
             content: if config.index_content { text_content } else { String::new() },
             word_count,
             reading_time,
+            lang,
+            language: content.metadata.extra.get("language").cloned(),
+            section: None,
+            parent: None,
         })
     }
     
     /// Index a document
-    fn index_document(&mut self, doc_idx: usize, config: &IndexerConfig) -> Result<()> {
+    fn index_document(
+        &mut self,
+        doc_idx: usize,
+        headings: &[String],
+        body: &str,
+        analyzer: &Analyzer,
+        forms: &mut HashMap<String, BTreeSet<String>>,
+        config: &IndexerConfig,
+    ) -> Result<()> {
         let document = self.documents[doc_idx].clone();
+        let mut occurrences: HashMap<String, ([u32; FIELD_COUNT], Vec<u32>)> = HashMap::new();
+        let mut lengths = [0u32; FIELD_COUNT];
+        let mut position = 0;
+        
+        // Count the terms of `text` towards `field`
+        let mut index_text = |text: &str, field: Field| {
+            for token in analyzer.analyze_positions(text, config) {
+                let terms = forms.entry(token.surface).or_default();
+                if let Some(term) = token.term {
+                    terms.insert(term.clone());
+                    let (tf, positions) = occurrences.entry(term).or_default();
+                    tf[field as usize] += 1;
+                    positions.push(position);
+                    lengths[field as usize] += 1;
+                }
+                position += 1;
+            }
+            position += POSITION_GAP;
+        };
         
         // Index title (always indexed)
-        self.index_text(&document.title, doc_idx, config)?;
+        index_text(&document.title, Field::Title);
+        
+        if config.index_metadata {
+            for tag in &document.tags {
+                index_text(tag, Field::Tags);
+            }
+        }
         
         // Index content if enabled
         if config.index_content {
-            self.index_text(&document.content, doc_idx, config)?;
+            for heading in headings {
+                index_text(heading, Field::Headings);
+            }
+            index_text(body, Field::Body);
         }
         
+        for (term, (tf, positions)) in occurrences {
+            self.terms
+                .entry(term)
+                .or_default()
+                .push(Posting { doc: doc_idx, tf, positions });
+        }
+        self.field_lengths.push(lengths);
+        
         // Index tags
         for tag in &document.tags {
             self.tags
@@ -213,74 +435,46 @@ impl SearchIndex {
         Ok(())
     }
     
-    /// Index text content
-    fn index_text(&mut self, text: &str, doc_idx: usize, config: &IndexerConfig) -> Result<()> {
-        // Tokenize text
-        let terms = self.tokenize(text, config);
-        
-        // Index each term
-        for term in terms {
-            self.terms
-                .entry(term.to_lowercase())
-                .or_insert_with(Vec::new)
-                .push(doc_idx);
-        }
-        
-        Ok(())
-    }
-    
-    /// Tokenize text into terms
-    fn tokenize(&self, text: &str, config: &IndexerConfig) -> Vec<String> {
-        let mut terms = Vec::new();
-        
-        // Split on whitespace and punctuation
-        let raw_terms: Vec<String> = text
-            .to_lowercase()
-            .split(|c: char| !c.is_alphanumeric())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect();
-        
-        for term in raw_terms {
-            // Filter by length
-            if term.len() < config.min_term_length || term.len() > config.max_term_length {
-                continue;
+    /// Split a page at the headings listed in its table of contents,
+    /// returning the HTML before the first of them and the sections
+    fn split_sections<'a>(&self, item: &'a RstContent) -> (&'a str, Vec<Section<'a>>) {
+        fn flatten(entries: &[TocEntry], titles: &mut HashMap<String, String>) {
+            for entry in entries {
+                titles.entry(entry.anchor.clone()).or_insert_with(|| entry.title.clone());
+                flatten(&entry.children, titles);
             }
-            
-            // Filter stop words
-            if config.stop_words.contains(&term.to_string()) {
-                continue;
+        }
+        let mut titles = HashMap::new();
+        flatten(&item.toc, &mut titles);
+        
+        // Start of each listed heading, in document order
+        let mut starts: Vec<(usize, String, String)> = Vec::new();
+        for captures in HEADING_ID_RE.captures_iter(&item.html) {
+            if let Some(title) = titles.remove(&captures[1]) {
+                let start = captures.get(0).map(|m| m.start()).unwrap_or(0);
+                starts.push((start, captures[1].to_string(), self.html_to_text(&title)));
             }
-            
-            // Apply stemming if enabled
-            let processed_term = if config.stemming {
-                self.stem_term(&term)
-            } else {
-                term.to_string()
-            };
-            
-            terms.push(processed_term);
         }
         
-        terms
+        let intro = &item.html[..starts.first().map(|s| s.0).unwrap_or(item.html.len())];
+        let sections = starts
+            .iter()
+            .enumerate()
+            .map(|(i, (start, anchor, title))| {
+                let end = starts.get(i + 1).map(|s| s.0).unwrap_or(item.html.len());
+                Section { title: title.clone(), anchor: anchor.clone(), html: &item.html[*start..end] }
+            })
+            .collect();
+        (intro, sections)
     }
     
-    /// Simple stemming (placeholder implementation)
-    fn stem_term(&self, term: &str) -> String {
-        // This is a very basic stemming implementation
-        // In a real implementation, you'd use a proper stemming library
-        let mut stemmed = term.to_string();
-        
-        // Remove common suffixes
-        let suffixes = ["ing", "ed", "er", "est", "ly", "s"];
-        for suffix in &suffixes {
-            if stemmed.ends_with(suffix) && stemmed.len() > suffix.len() + 2 {
-                stemmed.truncate(stemmed.len() - suffix.len());
-                break;
-            }
-        }
-        
-        stemmed
+    /// Plain text of the headings in rendered HTML
+    fn extract_headings(&self, html: &str) -> Vec<String> {
+        HEADING_RE
+            .captures_iter(html)
+            .map(|c| self.html_to_text(&c[1]))
+            .filter(|h| !h.is_empty())
+            .collect()
     }
     
     /// Convert HTML to plain text
@@ -343,12 +537,16 @@ impl SearchIndex {
         self.metadata.total_documents = self.documents.len();
         self.metadata.total_terms = self.terms.len();
         
-        if !self.documents.is_empty() {
-            let total_length: usize = self.documents
-                .iter()
-                .map(|d| d.content.len())
-                .sum();
-            self.metadata.avg_document_length = total_length as f64 / self.documents.len() as f64;
+        self.metadata.avg_document_length = 0.0;
+        self.metadata.avg_field_lengths = [0.0; FIELD_COUNT];
+        if !self.field_lengths.is_empty() {
+            let count = self.field_lengths.len() as f64;
+            for lengths in &self.field_lengths {
+                for (field, length) in lengths.iter().enumerate() {
+                    self.metadata.avg_field_lengths[field] += *length as f64 / count;
+                }
+            }
+            self.metadata.avg_document_length = self.metadata.avg_field_lengths.iter().sum();
         }
         
         self.metadata.build_timestamp = chrono::Utc::now().to_rfc3339();
@@ -359,9 +557,14 @@ impl SearchIndex {
         let search_data = ClientSearchData {
             documents: self.documents.clone(),
             terms: self.terms.clone(),
+            field_lengths: self.field_lengths.clone(),
+            forms: self.forms.clone(),
+            vocabulary: self.vocabulary.clone(),
+            stop_words: self.stop_words.clone(),
             tags: self.tags.clone(),
             content_types: self.content_types.clone(),
             metadata: self.metadata.clone(),
+            config: self.config.clone(),
         };
         
         serde_json::to_string(&search_data)
@@ -385,32 +588,53 @@ impl SearchIndex {
             .map_err(|e| Error::search(format!("Failed to parse search index: {}", e)))
     }
     
-    /// Search the index
-    pub fn search(&self, query: &str, limit: Option<usize>) -> Vec<&SearchDocument> {
-        if query.is_empty() {
-            return Vec::new();
-        }
-        
-        let terms = self.tokenize(query, &IndexerConfig::default());
-        let mut document_scores: HashMap<usize, f64> = HashMap::new();
-        
-        // Score documents based on term matches
-        for term in terms {
-            if let Some(document_indices) = self.terms.get(&term) {
-                for &doc_idx in document_indices {
-                    let score = document_scores.entry(doc_idx).or_insert(0.0);
-                    *score += 1.0; // Simple scoring: each term match adds 1 point
-                }
+    /// Terms the query resolves to exactly, dropping repeated terms
+    pub fn query_terms(&self, query: &str) -> Vec<String> {
+        fuzzy::expand_query(self, &syntax::parse(query).text())
+            .into_iter()
+            .filter(|q| q.exact)
+            .map(|q| q.term)
+            .collect()
+    }
+    
+    /// Rank documents for `query` with BM25F, as `(document index, score)`.
+    ///
+    /// See `syntax` for phrases, exclusions and filters. A query of filters
+    /// alone lists every document it accepts, newest first, with score 0.
+    /// Each page appears once, as its best matching section or as itself.
+    pub fn rank(&self, query: &str) -> Vec<(usize, f64)> {
+        let query = syntax::parse(query);
+        let filter = QueryFilter::new(self, &query);
+        let terms = fuzzy::expand_query(self, &query.text());
+        
+        let ranked = if terms.is_empty() {
+            if !query.has_constraints() {
+                return Vec::new();
             }
-        }
-        
-        // Sort documents by score
-        let mut scored_docs: Vec<(usize, f64)> = document_scores.into_iter().collect();
-        scored_docs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            let mut docs: Vec<usize> = (0..self.documents.len()).collect();
+            docs.sort_by(|a, b| self.documents[*b].date.cmp(&self.documents[*a].date).then(a.cmp(b)));
+            docs.into_iter().map(|doc| (doc, 0.0)).collect()
+        } else {
+            RankingAlgorithm::with_params(self.config.ranking.clone()).rank(self, &terms)
+        };
         
-        // Return documents up to limit
+        let mut pages = HashSet::new();
+        ranked
+            .into_iter()
+            .filter(|(doc, _)| filter.accepts(*doc))
+            .filter(|(doc, _)| pages.insert(self.documents[*doc].parent.unwrap_or(*doc)))
+            .collect()
+    }
+    
+    /// Corrected query to offer when some query tokens match nothing
+    pub fn suggest(&self, query: &str) -> Option<String> {
+        fuzzy::suggest(self, query)
+    }
+    
+    /// Search the index
+    pub fn search(&self, query: &str, limit: Option<usize>) -> Vec<&SearchDocument> {
         let limit = limit.unwrap_or(10);
-        scored_docs
+        self.rank(query)
             .into_iter()
             .take(limit)
             .filter_map(|(idx, _)| self.documents.get(idx))
@@ -469,12 +693,30 @@ impl SearchIndex {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientSearchData {
     pub documents: Vec<SearchDocument>,
-    pub terms: HashMap<String, Vec<usize>>,
+    pub terms: HashMap<String, Vec<Posting>>,
+    pub field_lengths: Vec<[u32; FIELD_COUNT]>,
+    pub forms: HashMap<String, Vec<String>>,
+    pub vocabulary: Vec<String>,
+    pub stop_words: Vec<String>,
     pub tags: HashMap<String, Vec<usize>>,
     pub content_types: HashMap<String, Vec<usize>>,
     pub metadata: SearchMetadata,
+    pub config: IndexerConfig,
+}
+
+/// A part of a page, from one of its table of contents headings to the next
+struct Section<'a> {
+    title: String,
+    anchor: String,
+    html: &'a str,
 }
 
+/// Matches the opening tag of a heading with an id, capturing the id
+static HEADING_ID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?is)<h[1-6]\b[^>]*\bid\s*=\s*"([^"]*)"[^>]*>"#).unwrap());
+
+/// Matches a rendered heading, capturing its inner HTML
+static HEADING_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<h[1-6][^>]*>(.*?)</h[1-6]>").unwrap());
+
 impl Default for SearchIndex {
     fn default() -> Self {
         Self::new()
@@ -539,6 +781,93 @@ mod tests {
         assert_eq!(results[0].title, "Rust Programming Guide");
     }
     
+    #[test]
+    fn test_bm25f_field_weights() {
+        let mut index = SearchIndex::new();
+        
+        let content = vec![
+            create_test_content(
+                "body",
+                "Language Notes",
+                "Some notes that mention compilers once",
+                "/articles/body",
+                ContentType::Article,
+                vec![],
+            ),
+            create_test_content(
+                "title",
+                "Compilers Explained",
+                "An overview of parsing and code generation",
+                "/articles/title",
+                ContentType::Article,
+                vec![],
+            ),
+        ];
+        
+        index.build(&content).unwrap();
+        
+        let posting = &index.terms["compil"][0];
+        assert_eq!(posting.doc, 0);
+        assert_eq!(posting.tf, [0, 0, 0, 1]);
+        
+        let ranked = index.rank("compilers");
+        assert_eq!(ranked.iter().map(|r| r.0).collect::<Vec<_>>(), vec![1, 0]);
+        
+        // Boosting the body field above the title flips the order
+        let mut config = IndexerConfig::default();
+        config.ranking.boosts = [1.0, 1.0, 1.0, 10.0];
+        index.build_with_config(&content, &config).unwrap();
+        assert_eq!(index.rank("compilers")[0].0, 0);
+    }
+    
+    #[test]
+    fn test_client_search_postings_format() {
+        let mut index = SearchIndex::new();
+        index.build(&[create_test_content(
+            "test-1",
+            "Rust",
+            "<h2>Rust tooling</h2>rust",
+            "/articles/test-1",
+            ContentType::Article,
+            vec!["rust".to_string()],
+        )]).unwrap();
+        
+        let json: serde_json::Value = serde_json::from_str(&index.generate_client_search().unwrap()).unwrap();
+        // Positions skip POSITION_GAP between the title, tag, heading and body
+        assert_eq!(json["terms"]["rust"], serde_json::json!([[0, [1, 1, 1, 1], [0, 17, 34, 52]]]));
+        assert_eq!(json["field_lengths"], serde_json::json!([[1, 1, 2, 1]]));
+        assert_eq!(json["config"]["ranking"]["boosts"], serde_json::json!([5.0, 3.0, 2.0, 1.0]));
+    }
+    
+    #[test]
+    fn test_sections_split_at_toc_headings() {
+        let mut content = create_test_content(
+            "guide",
+            "Guide",
+            "Overview</p><h2 id=\"install\">Install</h2><p>Run cargo install.</p><h2 id=\"usage\">Usage</h2><p>Run peta build.",
+            "/articles/guide.html",
+            ContentType::Article,
+            vec![],
+        );
+        content.toc = ["Install", "Usage"]
+            .iter()
+            .map(|title| TocEntry { level: 2, title: title.to_string(), anchor: title.to_lowercase(), children: vec![] })
+            .collect();
+        
+        let mut index = SearchIndex::new();
+        index.build(&[content.clone()]).unwrap();
+        assert_eq!(index.documents.len(), 3);
+        assert_eq!(index.documents[1].url, "/articles/guide.html#install");
+        assert_eq!(index.documents[2].excerpt, "Run peta build.");
+        assert_eq!(index.rank("peta").iter().map(|r| r.0).collect::<Vec<_>>(), vec![2]);
+        // Both sections match, the page is listed once
+        assert_eq!(index.rank("run").len(), 1);
+        
+        let config = IndexerConfig { sections: false, ..IndexerConfig::default() };
+        index.build_with_config(&[content], &config).unwrap();
+        assert_eq!(index.documents.len(), 1);
+    }
+    
     fn create_test_content(
         id: &str,
         title: &str,
@@ -553,6 +882,7 @@ mod tests {
                 title: title.to_string(),
                 content_type,
                 date: "2023-01-01".to_string(),
+                date_time: None,
                 tags,
                 author: None,
                 excerpt: None,
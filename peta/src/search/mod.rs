@@ -1,9 +1,16 @@
 //! Search functionality module
 
+pub mod analyzer;
+pub mod client;
+pub mod fuzzy;
+pub mod highlight;
 pub mod indexer;
 pub mod query;
 pub mod ranking;
+pub mod related;
+pub mod syntax;
+pub mod tantivy_index;
 
 pub use indexer::SearchIndex;
 pub use query::QueryProcessor;
-pub use ranking::RankingAlgorithm;
\ No newline at end of file
+pub use ranking::RankingAlgorithm;pub use related::RelatedContent;
@@ -1,7 +1,9 @@
 //! Search query processor
 
-use crate::search::indexer::SearchDocument;
+use crate::search::highlight::{Highlight, Highlighter};
+use crate::search::indexer::{SearchDocument, SearchIndex};
 use crate::core::Result;
+use serde::Serialize;
 
 /// Query processor
 pub struct QueryProcessor;
@@ -12,111 +14,168 @@ impl QueryProcessor {
         Self
     }
     
-    /// Process search query
-    pub fn process(&self, query: &str, documents: &[SearchDocument]) -> Result<Vec<SearchResult>> {
+    /// Process search query, ranking documents with the index's BM25F scoring
+    /// and applying its phrases, exclusions and filters
+    pub fn process(&self, query: &str, index: &SearchIndex) -> Result<Vec<SearchResult>> {
+        self.search(query, index, &SearchOptions::default())
+    }
+    
+    /// Process search query, then filter, order and limit the results like
+    /// the search page's options; only the returned results are highlighted
+    pub fn search(&self, query: &str, index: &SearchIndex, options: &SearchOptions) -> Result<Vec<SearchResult>> {
         if query.trim().is_empty() {
             return Ok(Vec::new());
         }
         
-        let terms = self.tokenize(query);
-        let mut results = Vec::new();
+        let mut ranked: Vec<(&SearchDocument, f64)> = index
+            .rank(query)
+            .into_iter()
+            .filter_map(|(idx, score)| index.documents.get(idx).map(|document| (document, score)))
+            .filter(|(document, _)| options.content_types.is_empty() || options.content_types.contains(&document.content_type))
+            .filter(|(document, _)| options.tags.is_empty() || options.tags.iter().any(|tag| document.tags.contains(tag)))
+            .collect();
         
-        for (_idx, document) in documents.iter().enumerate() {
-            let score = self.calculate_score(document, &terms);
-            
-            if score > 0.0 {
-                results.push(SearchResult {
-                    document: document.clone(),
-                    score,
-                    highlights: self.generate_highlights(document, &terms),
-                });
-            }
+        match options.sort {
+            SortOrder::Relevance => {}
+            SortOrder::Date => ranked.sort_by(|a, b| b.0.date.cmp(&a.0.date)),
+            SortOrder::Title => ranked.sort_by_key(|(document, _)| document.title.to_lowercase()),
         }
+        ranked.truncate(options.limit.unwrap_or(usize::MAX));
         
-        // Sort by score
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        let highlighter = Highlighter::for_query(index, query);
+        let results = ranked
+            .into_iter()
+            .map(|(document, score)| SearchResult {
+                document: document.clone(),
+                score,
+                highlights: highlighter.highlight_document(document),
+            })
+            .collect();
         
         Ok(results)
     }
+}
+
+/// Filters, order and limit applied to ranked results
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    /// Keep results of these content types, all when empty
+    pub content_types: Vec<String>,
+    /// Keep results with any of these tags, all when empty
+    pub tags: Vec<String>,
+    pub sort: SortOrder,
+    pub limit: Option<usize>,
+}
+
+/// Order of search results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Best match first
+    #[default]
+    Relevance,
+    /// Newest first
+    Date,
+    /// Alphabetical by title
+    Title,
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = crate::core::Error;
     
-    /// Tokenize query
-    fn tokenize(&self, query: &str) -> Vec<String> {
-        query.to_lowercase()
-            .split_whitespace()
-            .map(|s| s.trim_matches(&['.', ',', ';', ':', '!', '?', '(', ')', '[', ']', '{', '}'][..]))
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect()
-    }
-    
-    /// Calculate relevance score
-    fn calculate_score(&self, document: &SearchDocument, terms: &[String]) -> f32 {
-        let mut score = 0.0;
-        let content = format!("{} {} {}", document.title, document.excerpt, document.tags.join(" "));
-        let content_lower = content.to_lowercase();
-        
-        for term in terms {
-            // Title matches are worth more
-            if document.title.to_lowercase().contains(term) {
-                score += 10.0;
-            }
-            
-            // Tag matches
-            if document.tags.iter().any(|tag| tag.to_lowercase().contains(term)) {
-                score += 5.0;
-            }
-            
-            // Content matches
-            if content_lower.contains(term) {
-                score += 1.0;
-            }
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "relevance" => Ok(SortOrder::Relevance),
+            "date" => Ok(SortOrder::Date),
+            "title" => Ok(SortOrder::Title),
+            _ => Err(crate::core::Error::search(format!("Unknown sort order: {}", s))),
         }
-        
-        score
-    }
-    
-    /// Generate highlights
-    fn generate_highlights(&self, document: &SearchDocument, terms: &[String]) -> Vec<String> {
-        let mut highlights = Vec::new();
-        
-        // Highlight title matches
-        for term in terms {
-            if document.title.to_lowercase().contains(term) {
-                highlights.push(format!("Title: {}", document.title));
-                break;
-            }
-        }
-        
-        // Highlight content matches
-        let excerpt_lower = document.excerpt.to_lowercase();
-        for term in terms {
-            if let Some(pos) = excerpt_lower.find(term) {
-                let start = if pos > 50 { pos - 50 } else { 0 };
-                let end = if pos + term.len() + 50 < document.excerpt.len() {
-                    pos + term.len() + 50
-                } else {
-                    document.excerpt.len()
-                };
-                
-                highlights.push(format!("...{}...", &document.excerpt[start..end]));
-                break;
-            }
-        }
-        
-        highlights
     }
 }
 
 /// Search result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SearchResult {
     pub document: SearchDocument,
-    pub score: f32,
-    pub highlights: Vec<String>,
+    pub score: f64,
+    pub highlights: Vec<Highlight>,
 }
 
 impl Default for QueryProcessor {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::highlight::HighlightField;
+    use crate::content::{ContentMetadata, ContentType, RstContent};
+    use std::collections::HashMap;
+
+    fn content(id: &str, title: &str, content_type: ContentType, tags: &[&str], date: &str, text: &str) -> RstContent {
+        RstContent::new(
+            ContentMetadata {
+                id: id.to_string(),
+                title: title.to_string(),
+                content_type,
+                date: date.to_string(),
+                date_time: None,
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                author: None,
+                excerpt: None,
+                url: format!("/{}", id),
+                extra: HashMap::new(),
+            },
+            format!("<p>{}</p>", text),
+            Vec::new(),
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn test_highlights_non_ascii_text() {
+        let text = format!("{} Übergrößen für Straße und Café — straße", "Ähnliche Wörter ".repeat(8));
+        let mut index = SearchIndex::new();
+        index.build(&[content("strasse", "Die Straße", ContentType::Article, &[], "2023-01-01", &text)]).unwrap();
+
+        let results = QueryProcessor::new().process("STRASSE straße", &index).unwrap();
+        assert_eq!(results.len(), 1);
+
+        let highlights = &results[0].highlights;
+        assert_eq!(highlights[0].field, HighlightField::Title);
+        assert_eq!(highlights[0].snippet.to_html(), "Die <mark>Straße</mark>");
+        assert_eq!(highlights[1].field, HighlightField::Content);
+        let html = highlights[1].snippet.to_html();
+        assert!(html.starts_with('…'));
+        assert!(html.ends_with("<mark>Straße</mark> und Café — <mark>straße</mark>"));
+    }
+
+    #[test]
+    fn test_search_options_filter_sort_and_limit() {
+        let mut index = SearchIndex::new();
+        index
+            .build(&[
+                content("a", "Borrowing", ContentType::Article, &["rust"], "2023-01-01", "rust borrowing rules"),
+                content("b", "Arenas", ContentType::Snippet, &["rust"], "2024-01-01", "rust arena allocation"),
+                content("c", "Closures", ContentType::Snippet, &["python"], "2022-01-01", "rust closures compared"),
+            ])
+            .unwrap();
+        let processor = QueryProcessor::new();
+        let titles = |options: SearchOptions| -> Vec<String> {
+            processor
+                .search("rust", &index, &options)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.document.title)
+                .collect()
+        };
+
+        let snippets = SearchOptions { content_types: vec!["snippet".to_string()], sort: SortOrder::Title, ..Default::default() };
+        assert_eq!(titles(snippets), vec!["Arenas", "Closures"]);
+        let tagged = SearchOptions { tags: vec!["rust".to_string()], sort: SortOrder::Date, ..Default::default() };
+        assert_eq!(titles(tagged), vec!["Arenas", "Borrowing"]);
+        assert_eq!(titles(SearchOptions { limit: Some(1), ..Default::default() }).len(), 1);
+        assert_eq!("date".parse::<SortOrder>().unwrap(), SortOrder::Date);
+    }
+}
@@ -1,64 +1,152 @@
 //! Search ranking algorithm
+//!
+//! BM25F over the title, tags, headings and body fields. Documents matching
+//! more query tokens exactly rank above those matched only through prefix or
+//! fuzzy expansion. The client-side search script implements the same formula
+//! against the same index data, so keep `PetaSearch.rank` in `search_bar.js`
+//! in sync with this file.
 
-use crate::search::indexer::SearchDocument;
+use crate::core::config::SearchConfig;
+use crate::search::fuzzy::QueryTerm;
+use crate::search::indexer::SearchIndex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Number of scored fields
+pub const FIELD_COUNT: usize = 4;
+
+/// Scored document fields, in the order used by postings and field lengths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title = 0,
+    Tags = 1,
+    Headings = 2,
+    Body = 3,
+}
+
+/// BM25F parameters, shipped with the client index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankingParams {
+    /// Term frequency saturation
+    pub k1: f64,
+    /// Length normalization
+    pub b: f64,
+    /// Field weights indexed by `Field`
+    pub boosts: [f64; FIELD_COUNT],
+    /// Weight of prefix completions of the last query token
+    #[serde(default = "default_prefix_weight")]
+    pub prefix_weight: f64,
+    /// Weight of a fuzzy match, raised to the power of its edit distance
+    #[serde(default = "default_fuzzy_weight")]
+    pub fuzzy_weight: f64,
+}
+
+fn default_prefix_weight() -> f64 {
+    0.8
+}
+
+fn default_fuzzy_weight() -> f64 {
+    0.5
+}
+
+impl RankingParams {
+    /// Read the parameters from `[search]`
+    pub fn from_config(config: &SearchConfig) -> Self {
+        let boosts = &config.boosts;
+        Self {
+            k1: config.k1,
+            b: config.b,
+            boosts: [boosts.title, boosts.tags, boosts.headings, boosts.body],
+            prefix_weight: default_prefix_weight(),
+            fuzzy_weight: default_fuzzy_weight(),
+        }
+    }
+}
+
+impl Default for RankingParams {
+    fn default() -> Self {
+        Self::from_config(&SearchConfig::default())
+    }
+}
 
 /// Ranking algorithm
-pub struct RankingAlgorithm;
+pub struct RankingAlgorithm {
+    params: RankingParams,
+}
 
 impl RankingAlgorithm {
-    /// Create a new ranking algorithm
+    /// Create a new ranking algorithm with default parameters
     pub fn new() -> Self {
-        Self
+        Self::with_params(RankingParams::default())
     }
-    
-    /// Rank search results
-    pub fn rank(&self, documents: &[SearchDocument], query: &str) -> Vec<f32> {
-        documents
-            .iter()
-            .map(|doc| self.calculate_rank(doc, query))
-            .collect()
+
+    /// Create a ranking algorithm with custom parameters
+    pub fn with_params(params: RankingParams) -> Self {
+        Self { params }
     }
-    
-    /// Calculate rank for a document
-    fn calculate_rank(&self, document: &SearchDocument, query: &str) -> f32 {
-        let mut score = 0.0;
-        let binding = query.to_lowercase();
-        let query_terms = binding.split_whitespace().collect::<Vec<_>>();
-        
-        // Title relevance (highest weight)
-        let title_lower = document.title.to_lowercase();
-        for term in &query_terms {
-            if title_lower.contains(term) {
-                score += 20.0;
-            }
-        }
-        
-        // Tag relevance (high weight)
-        for term in &query_terms {
-            if document.tags.iter().any(|tag| tag.to_lowercase().contains(term)) {
-                score += 10.0;
-            }
-        }
-        
-        // Content relevance (medium weight)
-        let content_lower = format!("{} {}", document.excerpt, document.tags.join(" ")).to_lowercase();
-        for term in &query_terms {
-            if content_lower.contains(term) {
-                score += 5.0;
+
+    /// Score every document matching at least one of the query `terms`.
+    ///
+    /// Results are sorted by the number of query tokens matched exactly, then
+    /// by descending score, ties broken by document index.
+    pub fn rank(&self, index: &SearchIndex, terms: &[QueryTerm]) -> Vec<(usize, f64)> {
+        let total_docs = index.documents.len() as f64;
+        let avg_lengths = &index.metadata.avg_field_lengths;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        let mut exact_tokens: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+        for query_term in terms {
+            let postings = match index.terms.get(&query_term.term) {
+                Some(postings) if !postings.is_empty() => postings,
+                _ => continue,
+            };
+            let idf = self.idf(total_docs, postings.len() as f64);
+
+            for posting in postings {
+                let lengths = match index.field_lengths.get(posting.doc) {
+                    Some(lengths) => lengths,
+                    None => continue,
+                };
+                let tf = self.weighted_tf(&posting.tf, lengths, avg_lengths);
+                if tf > 0.0 {
+                    *scores.entry(posting.doc).or_insert(0.0) += query_term.weight * idf * tf / (self.params.k1 + tf);
+                    if query_term.exact {
+                        exact_tokens.entry(posting.doc).or_default().insert(query_term.token);
+                    }
+                }
             }
         }
-        
-        // Recency bonus (smaller weight)
-        if let Ok(date) = chrono::NaiveDate::parse_from_str(&document.date, "%Y-%m-%d") {
-            let days_old = (chrono::Utc::now().date_naive() - date).num_days();
-            if days_old < 30 {
-                score += 2.0;
-            } else if days_old < 365 {
-                score += 1.0;
+
+        let exact = |doc: usize| exact_tokens.get(&doc).map(|t| t.len()).unwrap_or(0);
+        let mut ranked: Vec<(usize, f64)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| {
+            exact(b.0).cmp(&exact(a.0))
+                .then(b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal))
+                .then(a.0.cmp(&b.0))
+        });
+        ranked
+    }
+
+    /// Inverse document frequency, never negative
+    fn idf(&self, total_docs: f64, doc_freq: f64) -> f64 {
+        (1.0 + (total_docs - doc_freq + 0.5) / (doc_freq + 0.5)).ln()
+    }
+
+    /// Boosted, length-normalized term frequency summed over the fields
+    fn weighted_tf(&self, tf: &[u32; FIELD_COUNT], lengths: &[u32; FIELD_COUNT], avg_lengths: &[f64; FIELD_COUNT]) -> f64 {
+        let mut total = 0.0;
+        for field in 0..FIELD_COUNT {
+            if tf[field] == 0 {
+                continue;
             }
+            let norm = if avg_lengths[field] > 0.0 {
+                1.0 - self.params.b + self.params.b * lengths[field] as f64 / avg_lengths[field]
+            } else {
+                1.0
+            };
+            total += self.params.boosts[field] * tf[field] as f64 / norm;
         }
-        
-        score
+        total
     }
 }
 
@@ -66,4 +154,72 @@ impl Default for RankingAlgorithm {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::indexer::Posting;
+
+    fn index(postings: Vec<Posting>, field_lengths: Vec<[u32; FIELD_COUNT]>) -> SearchIndex {
+        let mut index = SearchIndex::new();
+        index.documents = field_lengths.iter().map(|_| Default::default()).collect();
+        index.terms.insert("rust".to_string(), postings);
+        index.field_lengths = field_lengths;
+        index.metadata.avg_field_lengths = [4.0, 2.0, 4.0, 100.0];
+        index
+    }
+
+    #[test]
+    fn test_title_match_outranks_body_match() {
+        let index = index(
+            vec![
+                Posting { doc: 0, tf: [0, 0, 0, 1], positions: vec![] },
+                Posting { doc: 1, tf: [1, 0, 0, 0], positions: vec![] },
+                Posting { doc: 2, tf: [0, 0, 0, 0], positions: vec![] },
+            ],
+            vec![[4, 2, 4, 100]; 4],
+        );
+
+        let ranked = RankingAlgorithm::new().rank(&index, &[QueryTerm::exact("rust", 0)]);
+        assert_eq!(ranked.iter().map(|r| r.0).collect::<Vec<_>>(), vec![1, 0]);
+        assert!(ranked[0].1 > ranked[1].1);
+    }
+
+    #[test]
+    fn test_longer_field_scores_lower() {
+        let index = index(
+            vec![
+                Posting { doc: 0, tf: [0, 0, 0, 2], positions: vec![] },
+                Posting { doc: 1, tf: [0, 0, 0, 2], positions: vec![] },
+            ],
+            vec![[4, 2, 4, 400], [4, 2, 4, 50], [4, 2, 4, 100]],
+        );
+
+        let ranked = RankingAlgorithm::new().rank(&index, &[QueryTerm::exact("rust", 0)]);
+        assert_eq!(ranked[0].0, 1);
+
+        // Without length normalization the two documents tie and index order wins
+        let flat = RankingParams { b: 0.0, ..RankingParams::default() };
+        let ranked = RankingAlgorithm::with_params(flat).rank(&index, &[QueryTerm::exact("rust", 0)]);
+        assert_eq!(ranked[0].1, ranked[1].1);
+        assert_eq!(ranked[0].0, 0);
+    }
+
+    #[test]
+    fn test_exact_matches_rank_above_expansions() {
+        let mut index = index(
+            vec![Posting { doc: 0, tf: [0, 0, 0, 1], positions: vec![] }],
+            vec![[4, 2, 4, 100]; 2],
+        );
+        index.terms.insert("rusty".to_string(), vec![Posting { doc: 1, tf: [3, 0, 0, 0], positions: vec![] }]);
+
+        let terms = [
+            QueryTerm::exact("rust", 0),
+            QueryTerm { term: "rusty".to_string(), token: 0, weight: 0.8, exact: false },
+        ];
+        let ranked = RankingAlgorithm::new().rank(&index, &terms);
+        assert_eq!(ranked.iter().map(|r| r.0).collect::<Vec<_>>(), vec![0, 1]);
+        assert!(ranked[1].1 > ranked[0].1);
+    }
+}
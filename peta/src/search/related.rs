@@ -0,0 +1,319 @@
+//! Related content recommendations computed from the search index
+//!
+//! Pages are compared by the TF-IDF cosine similarity of their indexed terms,
+//! the share of tags they have in common and whether they belong to the same
+//! book. Frontmatter may pin items with `related` and hide them with
+//! `related_exclude`, both lists of page ids, slugs or URLs.
+
+use crate::content::RstContent;
+use crate::core::config::RelatedConfig;
+use crate::search::indexer::{SearchDocument, SearchIndex};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// A page recommended from another one, as exposed to templates
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedItem {
+    pub title: String,
+    pub url: String,
+    pub content_type: String,
+    pub excerpt: String,
+    pub tags: Vec<String>,
+    pub date: String,
+    /// Combined similarity score
+    pub score: f64,
+    /// Whether the page's frontmatter pinned this item
+    pub pinned: bool,
+}
+
+/// Related items of every page, keyed by page URL
+#[derive(Debug, Clone, Default)]
+pub struct RelatedContent {
+    items: HashMap<String, Vec<RelatedItem>>,
+    /// Frontmatter references that match no page
+    pub warnings: Vec<String>,
+}
+
+impl RelatedContent {
+    /// Create an empty set of recommendations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compute the related items of every page in `index`, reading pins and
+    /// exclusions from the frontmatter of `content`
+    pub fn compute(index: &SearchIndex, content: &[RstContent], config: &RelatedConfig) -> Self {
+        let mut related = Self::new();
+        let pages: Vec<usize> = (0..index.documents.len())
+            .filter(|&doc| index.documents[doc].parent.is_none())
+            .collect();
+        if pages.is_empty() {
+            return related;
+        }
+
+        let vectors = term_vectors(index, &pages);
+        let tags: Vec<HashSet<String>> = pages
+            .iter()
+            .map(|&doc| index.documents[doc].tags.iter().map(|tag| tag.to_lowercase()).collect())
+            .collect();
+        let books: Vec<Option<&str>> = pages.iter().map(|&doc| book_of(&index.documents[doc])).collect();
+        let frontmatter: HashMap<&str, &RstContent> = content
+            .iter()
+            .map(|item| (item.metadata.url.as_str(), item))
+            .collect();
+
+        for (a, &doc) in pages.iter().enumerate() {
+            let document = &index.documents[doc];
+            let score = |b: usize| {
+                let same_book = matches!((books[a], books[b]), (Some(x), Some(y)) if x == y);
+                config.text_weight * cosine(&vectors[a], &vectors[b])
+                    + config.tag_weight * jaccard(&tags[a], &tags[b])
+                    + if same_book { config.book_weight } else { 0.0 }
+            };
+
+            let (pinned, excluded) = match frontmatter.get(document.url.as_str()) {
+                Some(item) => (references(item, "related"), references(item, "related_exclude")),
+                None => (Vec::new(), Vec::new()),
+            };
+            let mut find = |reference: &str, key: &str| {
+                let found = (0..pages.len()).find(|&b| matches_reference(&index.documents[pages[b]], reference));
+                if found.is_none() {
+                    related.warnings.push(format!(
+                        "{}: `{}` entry '{}' matches no page", document.url, key, reference
+                    ));
+                }
+                found
+            };
+            let excluded: HashSet<usize> = excluded.iter().filter_map(|r| find(r, "related_exclude")).collect();
+            let mut chosen: Vec<usize> = Vec::new();
+            for reference in &pinned {
+                if let Some(b) = find(reference, "related") {
+                    if b != a && !chosen.contains(&b) {
+                        chosen.push(b);
+                    }
+                }
+            }
+            let pinned_count = chosen.len();
+
+            let mut candidates: Vec<(usize, f64)> = (0..pages.len())
+                .filter(|&b| b != a && !excluded.contains(&b) && !chosen.contains(&b))
+                .map(|b| (b, score(b)))
+                .filter(|&(_, score)| score > 0.0 && score >= config.min_score)
+                .collect();
+            candidates.sort_by(|x, y| {
+                y.1.total_cmp(&x.1)
+                    .then_with(|| index.documents[pages[x.0]].url.cmp(&index.documents[pages[y.0]].url))
+            });
+            chosen.extend(
+                candidates
+                    .into_iter()
+                    .map(|(b, _)| b)
+                    .take(config.count.saturating_sub(pinned_count)),
+            );
+
+            let items = chosen
+                .into_iter()
+                .enumerate()
+                .map(|(rank, b)| {
+                    let other = &index.documents[pages[b]];
+                    RelatedItem {
+                        title: other.title.clone(),
+                        url: other.url.clone(),
+                        content_type: other.content_type.clone(),
+                        excerpt: other.excerpt.clone(),
+                        tags: other.tags.clone(),
+                        date: other.date.clone(),
+                        score: score(b),
+                        pinned: rank < pinned_count,
+                    }
+                })
+                .collect();
+            related.items.insert(document.url.clone(), items);
+        }
+
+        related
+    }
+
+    /// Related items of the page at `url`
+    pub fn get(&self, url: &str) -> &[RelatedItem] {
+        self.items.get(url).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+/// Normalized TF-IDF vectors of each page over all its documents, as
+/// `(term, weight)` pairs sorted by term
+fn term_vectors(index: &SearchIndex, pages: &[usize]) -> Vec<Vec<(usize, f64)>> {
+    let slot: HashMap<usize, usize> = pages.iter().enumerate().map(|(slot, &doc)| (doc, slot)).collect();
+    let page_of = |doc: usize| {
+        let page = index.documents[doc].parent.unwrap_or(doc);
+        slot.get(&page).copied()
+    };
+
+    let mut terms: Vec<&String> = index.terms.keys().collect();
+    terms.sort();
+    let mut vectors: Vec<Vec<(usize, f64)>> = vec![Vec::new(); pages.len()];
+    for (term, key) in terms.into_iter().enumerate() {
+        let mut frequencies: HashMap<usize, u32> = HashMap::new();
+        for posting in &index.terms[key] {
+            if let Some(page) = page_of(posting.doc) {
+                *frequencies.entry(page).or_default() += posting.tf.iter().sum::<u32>();
+            }
+        }
+        let idf = (pages.len() as f64 / frequencies.len() as f64).ln();
+        if idf <= 0.0 {
+            continue;
+        }
+        for (page, tf) in frequencies {
+            if tf > 0 {
+                vectors[page].push((term, (1.0 + (tf as f64).ln()) * idf));
+            }
+        }
+    }
+
+    for vector in &mut vectors {
+        vector.sort_by_key(|&(term, _)| term);
+        let norm = vector.iter().map(|(_, weight)| weight * weight).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|(_, weight)| *weight /= norm);
+        }
+    }
+    vectors
+}
+
+/// Cosine similarity of two normalized sparse vectors
+fn cosine(a: &[(usize, f64)], b: &[(usize, f64)]) -> f64 {
+    let (mut i, mut j, mut dot) = (0, 0, 0.0);
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                dot += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    dot
+}
+
+/// Share of tags two pages have in common
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Directory of the book a page belongs to, from its `books/<book>/` URL
+fn book_of(document: &SearchDocument) -> Option<&str> {
+    let mut parts = document.url.trim_start_matches('/').split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some("books"), Some(book), Some(_)) => Some(book),
+        _ => None,
+    }
+}
+
+/// Page references listed under a frontmatter key, as a list or a single string
+fn references(content: &RstContent, key: &str) -> Vec<String> {
+    match content.frontmatter.get(key) {
+        Some(serde_json::Value::String(reference)) => vec![reference.clone()],
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .filter_map(|value| value.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether a frontmatter reference names a page, by id, URL or slug
+fn matches_reference(document: &SearchDocument, reference: &str) -> bool {
+    let reference = reference.trim().trim_start_matches('/').trim_end_matches(".html");
+    let url = document.url.trim_start_matches('/').trim_end_matches(".html");
+    let mut segments = url.rsplit('/');
+    let slug = match segments.next() {
+        Some("index") => segments.next(),
+        slug => slug,
+    };
+    reference == document.id || reference == url || Some(reference) == slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{ContentMetadata, ContentType};
+
+    fn content(url: &str, title: &str, tags: &[&str], html: &str, frontmatter: &[(&str, &[&str])]) -> RstContent {
+        let slug = url.rsplit('/').next().unwrap().trim_end_matches(".html");
+        RstContent::new(
+            ContentMetadata {
+                id: slug.to_string(),
+                title: title.to_string(),
+                content_type: ContentType::Article,
+                date: "2024-01-01".to_string(),
+                date_time: None,
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                author: None,
+                excerpt: None,
+                url: url.to_string(),
+                extra: HashMap::new(),
+            },
+            html.to_string(),
+            Vec::new(),
+            frontmatter
+                .iter()
+                .map(|(key, values)| (key.to_string(), serde_json::json!(values)))
+                .collect(),
+        )
+    }
+
+    fn related(content: &[RstContent], config: &RelatedConfig) -> RelatedContent {
+        let mut index = SearchIndex::new();
+        index.build(content).unwrap();
+        RelatedContent::compute(&index, content, config)
+    }
+
+    fn urls(items: &[RelatedItem]) -> Vec<&str> {
+        items.iter().map(|item| item.url.as_str()).collect()
+    }
+
+    #[test]
+    fn test_ranks_by_text_tags_and_book() {
+        let content = vec![
+            content("articles/borrowing.html", "Borrowing", &["rust"],
+                "<p>The borrow checker tracks ownership and lifetimes of references.</p>", &[]),
+            content("articles/lifetimes.html", "Lifetimes", &["rust"],
+                "<p>Lifetimes let the borrow checker validate references and ownership.</p>", &[]),
+            content("articles/gardening.html", "Gardening", &["outdoors"],
+                "<p>Tomatoes need sunlight, water and patience.</p>", &[]),
+            content("books/guide/setup.html", "Setup", &[], "<p>Install the toolchain.</p>", &[]),
+            content("books/guide/usage.html", "Usage", &[], "<p>Run the program.</p>", &[]),
+        ];
+        let related = related(&content, &RelatedConfig::default());
+
+        assert_eq!(urls(related.get("articles/borrowing.html")), vec!["articles/lifetimes.html"]);
+        assert_eq!(urls(related.get("books/guide/setup.html")), vec!["books/guide/usage.html"]);
+        assert!(related.get("articles/gardening.html").is_empty());
+        assert!(related.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_frontmatter_pins_and_excludes() {
+        let content = vec![
+            content("articles/borrowing.html", "Borrowing", &["rust"], "<p>Ownership and borrowing.</p>",
+                &[("related", &["gardening", "/articles/missing.html"]), ("related_exclude", &["articles/lifetimes"])]),
+            content("articles/lifetimes.html", "Lifetimes", &["rust"], "<p>Ownership and lifetimes.</p>", &[]),
+            content("articles/traits.html", "Traits", &["rust"], "<p>Traits and generics.</p>", &[]),
+            content("articles/gardening.html", "Gardening", &["outdoors"], "<p>Tomatoes and sunlight.</p>", &[]),
+        ];
+        let config = RelatedConfig { count: 2, ..RelatedConfig::default() };
+        let related = related(&content, &config);
+
+        let items = related.get("articles/borrowing.html");
+        assert_eq!(urls(items), vec!["articles/gardening.html", "articles/traits.html"]);
+        assert!(items[0].pinned && !items[1].pinned);
+        assert_eq!(related.warnings.len(), 1);
+        assert!(related.warnings[0].contains("/articles/missing.html"));
+    }
+}
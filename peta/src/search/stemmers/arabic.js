@@ -0,0 +1,1510 @@
+// Snowball stemmer for Arabic, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/arabic.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["!", -1, 3, null],
+    ["\"", -1, 3, null],
+    ["%", -1, 3, null],
+    ["*", -1, 3, null],
+    [",", -1, 3, null],
+    [".", -1, 3, null],
+    ["/", -1, 3, null],
+    [":", -1, 3, null],
+    [";", -1, 3, null],
+    ["?", -1, 3, null],
+    ["\\", -1, 3, null],
+    ["\u{060C}", -1, 4, null],
+    ["\u{061B}", -1, 4, null],
+    ["\u{061F}", -1, 4, null],
+    ["\u{0640}", -1, 2, null],
+    ["\u{064B}", -1, 1, null],
+    ["\u{064C}", -1, 1, null],
+    ["\u{064D}", -1, 1, null],
+    ["\u{064E}", -1, 1, null],
+    ["\u{064F}", -1, 1, null],
+    ["\u{0650}", -1, 1, null],
+    ["\u{0651}", -1, 1, null],
+    ["\u{0652}", -1, 1, null],
+    ["\u{0660}", -1, 5, null],
+    ["\u{0661}", -1, 6, null],
+    ["\u{0662}", -1, 7, null],
+    ["\u{0663}", -1, 8, null],
+    ["\u{0664}", -1, 9, null],
+    ["\u{0665}", -1, 10, null],
+    ["\u{0666}", -1, 11, null],
+    ["\u{0667}", -1, 12, null],
+    ["\u{0668}", -1, 13, null],
+    ["\u{0669}", -1, 14, null],
+    ["\u{066A}", -1, 15, null],
+    ["\u{066B}", -1, 15, null],
+    ["\u{066C}", -1, 15, null],
+    ["\u{FE80}", -1, 16, null],
+    ["\u{FE81}", -1, 20, null],
+    ["\u{FE82}", -1, 20, null],
+    ["\u{FE83}", -1, 17, null],
+    ["\u{FE84}", -1, 17, null],
+    ["\u{FE85}", -1, 21, null],
+    ["\u{FE86}", -1, 21, null],
+    ["\u{FE87}", -1, 18, null],
+    ["\u{FE88}", -1, 18, null],
+    ["\u{FE89}", -1, 19, null],
+    ["\u{FE8A}", -1, 19, null],
+    ["\u{FE8B}", -1, 19, null],
+    ["\u{FE8C}", -1, 19, null],
+    ["\u{FE8D}", -1, 22, null],
+    ["\u{FE8E}", -1, 22, null],
+    ["\u{FE8F}", -1, 23, null],
+    ["\u{FE90}", -1, 23, null],
+    ["\u{FE91}", -1, 23, null],
+    ["\u{FE92}", -1, 23, null],
+    ["\u{FE93}", -1, 24, null],
+    ["\u{FE94}", -1, 24, null],
+    ["\u{FE95}", -1, 25, null],
+    ["\u{FE96}", -1, 25, null],
+    ["\u{FE97}", -1, 25, null],
+    ["\u{FE98}", -1, 25, null],
+    ["\u{FE99}", -1, 26, null],
+    ["\u{FE9A}", -1, 26, null],
+    ["\u{FE9B}", -1, 26, null],
+    ["\u{FE9C}", -1, 26, null],
+    ["\u{FE9D}", -1, 27, null],
+    ["\u{FE9E}", -1, 27, null],
+    ["\u{FE9F}", -1, 27, null],
+    ["\u{FEA0}", -1, 27, null],
+    ["\u{FEA1}", -1, 28, null],
+    ["\u{FEA2}", -1, 28, null],
+    ["\u{FEA3}", -1, 28, null],
+    ["\u{FEA4}", -1, 28, null],
+    ["\u{FEA5}", -1, 29, null],
+    ["\u{FEA6}", -1, 29, null],
+    ["\u{FEA7}", -1, 29, null],
+    ["\u{FEA8}", -1, 29, null],
+    ["\u{FEA9}", -1, 30, null],
+    ["\u{FEAA}", -1, 30, null],
+    ["\u{FEAB}", -1, 31, null],
+    ["\u{FEAC}", -1, 31, null],
+    ["\u{FEAD}", -1, 32, null],
+    ["\u{FEAE}", -1, 32, null],
+    ["\u{FEAF}", -1, 33, null],
+    ["\u{FEB0}", -1, 33, null],
+    ["\u{FEB1}", -1, 34, null],
+    ["\u{FEB2}", -1, 34, null],
+    ["\u{FEB3}", -1, 34, null],
+    ["\u{FEB4}", -1, 34, null],
+    ["\u{FEB5}", -1, 35, null],
+    ["\u{FEB6}", -1, 35, null],
+    ["\u{FEB7}", -1, 35, null],
+    ["\u{FEB8}", -1, 35, null],
+    ["\u{FEB9}", -1, 36, null],
+    ["\u{FEBA}", -1, 36, null],
+    ["\u{FEBB}", -1, 36, null],
+    ["\u{FEBC}", -1, 36, null],
+    ["\u{FEBD}", -1, 37, null],
+    ["\u{FEBE}", -1, 37, null],
+    ["\u{FEBF}", -1, 37, null],
+    ["\u{FEC0}", -1, 37, null],
+    ["\u{FEC1}", -1, 38, null],
+    ["\u{FEC2}", -1, 38, null],
+    ["\u{FEC3}", -1, 38, null],
+    ["\u{FEC4}", -1, 38, null],
+    ["\u{FEC5}", -1, 39, null],
+    ["\u{FEC6}", -1, 39, null],
+    ["\u{FEC7}", -1, 39, null],
+    ["\u{FEC8}", -1, 39, null],
+    ["\u{FEC9}", -1, 40, null],
+    ["\u{FECA}", -1, 40, null],
+    ["\u{FECB}", -1, 40, null],
+    ["\u{FECC}", -1, 40, null],
+    ["\u{FECD}", -1, 41, null],
+    ["\u{FECE}", -1, 41, null],
+    ["\u{FECF}", -1, 41, null],
+    ["\u{FED0}", -1, 41, null],
+    ["\u{FED1}", -1, 42, null],
+    ["\u{FED2}", -1, 42, null],
+    ["\u{FED3}", -1, 42, null],
+    ["\u{FED4}", -1, 42, null],
+    ["\u{FED5}", -1, 43, null],
+    ["\u{FED6}", -1, 43, null],
+    ["\u{FED7}", -1, 43, null],
+    ["\u{FED8}", -1, 43, null],
+    ["\u{FED9}", -1, 44, null],
+    ["\u{FEDA}", -1, 44, null],
+    ["\u{FEDB}", -1, 44, null],
+    ["\u{FEDC}", -1, 44, null],
+    ["\u{FEDD}", -1, 45, null],
+    ["\u{FEDE}", -1, 45, null],
+    ["\u{FEDF}", -1, 45, null],
+    ["\u{FEE0}", -1, 45, null],
+    ["\u{FEE1}", -1, 46, null],
+    ["\u{FEE2}", -1, 46, null],
+    ["\u{FEE3}", -1, 46, null],
+    ["\u{FEE4}", -1, 46, null],
+    ["\u{FEE5}", -1, 47, null],
+    ["\u{FEE6}", -1, 47, null],
+    ["\u{FEE7}", -1, 47, null],
+    ["\u{FEE8}", -1, 47, null],
+    ["\u{FEE9}", -1, 48, null],
+    ["\u{FEEA}", -1, 48, null],
+    ["\u{FEEB}", -1, 48, null],
+    ["\u{FEEC}", -1, 48, null],
+    ["\u{FEED}", -1, 49, null],
+    ["\u{FEEE}", -1, 49, null],
+    ["\u{FEEF}", -1, 50, null],
+    ["\u{FEF0}", -1, 50, null],
+    ["\u{FEF1}", -1, 51, null],
+    ["\u{FEF2}", -1, 51, null],
+    ["\u{FEF3}", -1, 51, null],
+    ["\u{FEF4}", -1, 51, null],
+    ["\u{FEF5}", -1, 55, null],
+    ["\u{FEF6}", -1, 55, null],
+    ["\u{FEF7}", -1, 53, null],
+    ["\u{FEF8}", -1, 53, null],
+    ["\u{FEF9}", -1, 54, null],
+    ["\u{FEFA}", -1, 54, null],
+    ["\u{FEFB}", -1, 52, null],
+    ["\u{FEFC}", -1, 52, null],
+]);
+
+const A_1 = among([
+    ["\u{0622}", -1, 1, null],
+    ["\u{0623}", -1, 1, null],
+    ["\u{0624}", -1, 2, null],
+    ["\u{0625}", -1, 1, null],
+    ["\u{0626}", -1, 3, null],
+]);
+
+const A_2 = among([
+    ["\u{0622}", -1, 1, null],
+    ["\u{0623}", -1, 1, null],
+    ["\u{0624}", -1, 2, null],
+    ["\u{0625}", -1, 1, null],
+    ["\u{0626}", -1, 3, null],
+]);
+
+const A_3 = among([
+    ["\u{0627}\u{0644}", -1, 2, null],
+    ["\u{0628}\u{0627}\u{0644}", -1, 1, null],
+    ["\u{0643}\u{0627}\u{0644}", -1, 1, null],
+    ["\u{0644}\u{0644}", -1, 2, null],
+]);
+
+const A_4 = among([
+    ["\u{0623}\u{0622}", -1, 2, null],
+    ["\u{0623}\u{0623}", -1, 1, null],
+    ["\u{0623}\u{0624}", -1, 3, null],
+    ["\u{0623}\u{0625}", -1, 5, null],
+    ["\u{0623}\u{0627}", -1, 4, null],
+]);
+
+const A_5 = among([
+    ["\u{0641}", -1, 1, null],
+    ["\u{0648}", -1, 2, null],
+]);
+
+const A_6 = among([
+    ["\u{0627}\u{0644}", -1, 2, null],
+    ["\u{0628}\u{0627}\u{0644}", -1, 1, null],
+    ["\u{0643}\u{0627}\u{0644}", -1, 1, null],
+    ["\u{0644}\u{0644}", -1, 2, null],
+]);
+
+const A_7 = among([
+    ["\u{0628}", -1, 1, null],
+    ["\u{0628}\u{0628}", 0, 2, null],
+    ["\u{0643}\u{0643}", -1, 3, null],
+]);
+
+const A_8 = among([
+    ["\u{0633}\u{0623}", -1, 4, null],
+    ["\u{0633}\u{062A}", -1, 2, null],
+    ["\u{0633}\u{0646}", -1, 3, null],
+    ["\u{0633}\u{064A}", -1, 1, null],
+]);
+
+const A_9 = among([
+    ["\u{062A}\u{0633}\u{062A}", -1, 1, null],
+    ["\u{0646}\u{0633}\u{062A}", -1, 1, null],
+    ["\u{064A}\u{0633}\u{062A}", -1, 1, null],
+]);
+
+const A_10 = among([
+    ["\u{0643}", -1, 1, null],
+    ["\u{0643}\u{0645}", -1, 2, null],
+    ["\u{0647}\u{0645}", -1, 2, null],
+    ["\u{0647}\u{0646}", -1, 2, null],
+    ["\u{0647}", -1, 1, null],
+    ["\u{064A}", -1, 1, null],
+    ["\u{0643}\u{0645}\u{0627}", -1, 3, null],
+    ["\u{0647}\u{0645}\u{0627}", -1, 3, null],
+    ["\u{0646}\u{0627}", -1, 2, null],
+    ["\u{0647}\u{0627}", -1, 2, null],
+]);
+
+const A_11 = among([
+    ["\u{0646}", -1, 1, null],
+]);
+
+const A_12 = among([
+    ["\u{0648}", -1, 1, null],
+    ["\u{064A}", -1, 1, null],
+    ["\u{0627}", -1, 1, null],
+]);
+
+const A_13 = among([
+    ["\u{0627}\u{062A}", -1, 1, null],
+]);
+
+const A_14 = among([
+    ["\u{062A}", -1, 1, null],
+]);
+
+const A_15 = among([
+    ["\u{0629}", -1, 1, null],
+]);
+
+const A_16 = among([
+    ["\u{064A}", -1, 1, null],
+]);
+
+const A_17 = among([
+    ["\u{0643}", -1, 1, null],
+    ["\u{0643}\u{0645}", -1, 2, null],
+    ["\u{0647}\u{0645}", -1, 2, null],
+    ["\u{0643}\u{0646}", -1, 2, null],
+    ["\u{0647}\u{0646}", -1, 2, null],
+    ["\u{0647}", -1, 1, null],
+    ["\u{0643}\u{0645}\u{0648}", -1, 3, null],
+    ["\u{0646}\u{064A}", -1, 2, null],
+    ["\u{0643}\u{0645}\u{0627}", -1, 3, null],
+    ["\u{0647}\u{0645}\u{0627}", -1, 3, null],
+    ["\u{0646}\u{0627}", -1, 2, null],
+    ["\u{0647}\u{0627}", -1, 2, null],
+]);
+
+const A_18 = among([
+    ["\u{0646}", -1, 2, null],
+    ["\u{0648}\u{0646}", 0, 4, null],
+    ["\u{064A}\u{0646}", 0, 4, null],
+    ["\u{0627}\u{0646}", 0, 4, null],
+    ["\u{062A}\u{0646}", 0, 3, null],
+    ["\u{064A}", -1, 2, null],
+    ["\u{0627}", -1, 2, null],
+    ["\u{062A}\u{0645}\u{0627}", 6, 5, null],
+    ["\u{0646}\u{0627}", 6, 3, null],
+    ["\u{062A}\u{0627}", 6, 3, null],
+    ["\u{062A}", -1, 1, null],
+]);
+
+const A_19 = among([
+    ["\u{062A}\u{0645}", -1, 1, null],
+    ["\u{0648}\u{0627}", -1, 1, null],
+]);
+
+const A_20 = among([
+    ["\u{0648}", -1, 1, null],
+    ["\u{062A}\u{0645}\u{0648}", 0, 2, null],
+]);
+
+const A_21 = among([
+    ["\u{0649}", -1, 1, null],
+]);
+
+function r_Normalize_pre(env, context) {
+    var among_var;
+    for (let _i1 = 0, _n1 = env.char_count(); _i1 < _n1; _i1++) {
+        lab0: for (;;) {
+            var v_2 = env.cursor;
+            lab1: for (;;) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 5) {
+                    if (!env.slice_from("0")) {
+                        return false;
+                    }
+                } else if (among_var == 6) {
+                    if (!env.slice_from("1")) {
+                        return false;
+                    }
+                } else if (among_var == 7) {
+                    if (!env.slice_from("2")) {
+                        return false;
+                    }
+                } else if (among_var == 8) {
+                    if (!env.slice_from("3")) {
+                        return false;
+                    }
+                } else if (among_var == 9) {
+                    if (!env.slice_from("4")) {
+                        return false;
+                    }
+                } else if (among_var == 10) {
+                    if (!env.slice_from("5")) {
+                        return false;
+                    }
+                } else if (among_var == 11) {
+                    if (!env.slice_from("6")) {
+                        return false;
+                    }
+                } else if (among_var == 12) {
+                    if (!env.slice_from("7")) {
+                        return false;
+                    }
+                } else if (among_var == 13) {
+                    if (!env.slice_from("8")) {
+                        return false;
+                    }
+                } else if (among_var == 14) {
+                    if (!env.slice_from("9")) {
+                        return false;
+                    }
+                } else if (among_var == 15) {
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 16) {
+                    if (!env.slice_from("\u{0621}")) {
+                        return false;
+                    }
+                } else if (among_var == 17) {
+                    if (!env.slice_from("\u{0623}")) {
+                        return false;
+                    }
+                } else if (among_var == 18) {
+                    if (!env.slice_from("\u{0625}")) {
+                        return false;
+                    }
+                } else if (among_var == 19) {
+                    if (!env.slice_from("\u{0626}")) {
+                        return false;
+                    }
+                } else if (among_var == 20) {
+                    if (!env.slice_from("\u{0622}")) {
+                        return false;
+                    }
+                } else if (among_var == 21) {
+                    if (!env.slice_from("\u{0624}")) {
+                        return false;
+                    }
+                } else if (among_var == 22) {
+                    if (!env.slice_from("\u{0627}")) {
+                        return false;
+                    }
+                } else if (among_var == 23) {
+                    if (!env.slice_from("\u{0628}")) {
+                        return false;
+                    }
+                } else if (among_var == 24) {
+                    if (!env.slice_from("\u{0629}")) {
+                        return false;
+                    }
+                } else if (among_var == 25) {
+                    if (!env.slice_from("\u{062A}")) {
+                        return false;
+                    }
+                } else if (among_var == 26) {
+                    if (!env.slice_from("\u{062B}")) {
+                        return false;
+                    }
+                } else if (among_var == 27) {
+                    if (!env.slice_from("\u{062C}")) {
+                        return false;
+                    }
+                } else if (among_var == 28) {
+                    if (!env.slice_from("\u{062D}")) {
+                        return false;
+                    }
+                } else if (among_var == 29) {
+                    if (!env.slice_from("\u{062E}")) {
+                        return false;
+                    }
+                } else if (among_var == 30) {
+                    if (!env.slice_from("\u{062F}")) {
+                        return false;
+                    }
+                } else if (among_var == 31) {
+                    if (!env.slice_from("\u{0630}")) {
+                        return false;
+                    }
+                } else if (among_var == 32) {
+                    if (!env.slice_from("\u{0631}")) {
+                        return false;
+                    }
+                } else if (among_var == 33) {
+                    if (!env.slice_from("\u{0632}")) {
+                        return false;
+                    }
+                } else if (among_var == 34) {
+                    if (!env.slice_from("\u{0633}")) {
+                        return false;
+                    }
+                } else if (among_var == 35) {
+                    if (!env.slice_from("\u{0634}")) {
+                        return false;
+                    }
+                } else if (among_var == 36) {
+                    if (!env.slice_from("\u{0635}")) {
+                        return false;
+                    }
+                } else if (among_var == 37) {
+                    if (!env.slice_from("\u{0636}")) {
+                        return false;
+                    }
+                } else if (among_var == 38) {
+                    if (!env.slice_from("\u{0637}")) {
+                        return false;
+                    }
+                } else if (among_var == 39) {
+                    if (!env.slice_from("\u{0638}")) {
+                        return false;
+                    }
+                } else if (among_var == 40) {
+                    if (!env.slice_from("\u{0639}")) {
+                        return false;
+                    }
+                } else if (among_var == 41) {
+                    if (!env.slice_from("\u{063A}")) {
+                        return false;
+                    }
+                } else if (among_var == 42) {
+                    if (!env.slice_from("\u{0641}")) {
+                        return false;
+                    }
+                } else if (among_var == 43) {
+                    if (!env.slice_from("\u{0642}")) {
+                        return false;
+                    }
+                } else if (among_var == 44) {
+                    if (!env.slice_from("\u{0643}")) {
+                        return false;
+                    }
+                } else if (among_var == 45) {
+                    if (!env.slice_from("\u{0644}")) {
+                        return false;
+                    }
+                } else if (among_var == 46) {
+                    if (!env.slice_from("\u{0645}")) {
+                        return false;
+                    }
+                } else if (among_var == 47) {
+                    if (!env.slice_from("\u{0646}")) {
+                        return false;
+                    }
+                } else if (among_var == 48) {
+                    if (!env.slice_from("\u{0647}")) {
+                        return false;
+                    }
+                } else if (among_var == 49) {
+                    if (!env.slice_from("\u{0648}")) {
+                        return false;
+                    }
+                } else if (among_var == 50) {
+                    if (!env.slice_from("\u{0649}")) {
+                        return false;
+                    }
+                } else if (among_var == 51) {
+                    if (!env.slice_from("\u{064A}")) {
+                        return false;
+                    }
+                } else if (among_var == 52) {
+                    if (!env.slice_from("\u{0644}\u{0627}")) {
+                        return false;
+                    }
+                } else if (among_var == 53) {
+                    if (!env.slice_from("\u{0644}\u{0623}")) {
+                        return false;
+                    }
+                } else if (among_var == 54) {
+                    if (!env.slice_from("\u{0644}\u{0625}")) {
+                        return false;
+                    }
+                } else if (among_var == 55) {
+                    if (!env.slice_from("\u{0644}\u{0622}")) {
+                        return false;
+                    }
+                }
+                break lab0;
+            }
+            env.cursor = v_2;
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+            break lab0;
+        }
+    }
+    return true;
+}
+
+function r_Normalize_post(env, context) {
+    var among_var;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_1, context);
+        if (among_var == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            break lab0;
+        } else if (among_var == 1) {
+            if (!env.slice_from("\u{0621}")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("\u{0621}")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("\u{0621}")) {
+                return false;
+            }
+        }
+        env.cursor = env.limit_backward;
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        for (let _i2 = 0, _n2 = context.i_word_len; _i2 < _n2; _i2++) {
+            lab2: for (;;) {
+                var v_4 = env.cursor;
+                lab3: for (;;) {
+                    env.bra = env.cursor;
+                    among_var = env.find_among(A_2, context);
+                    if (among_var == 0) {
+                        break lab3;
+                    }
+                    env.ket = env.cursor;
+                    if (among_var == 0) {
+                        break lab3;
+                    } else if (among_var == 1) {
+                        if (!env.slice_from("\u{0627}")) {
+                            return false;
+                        }
+                    } else if (among_var == 2) {
+                        if (!env.slice_from("\u{0648}")) {
+                            return false;
+                        }
+                    } else if (among_var == 3) {
+                        if (!env.slice_from("\u{064A}")) {
+                            return false;
+                        }
+                    }
+                    break lab2;
+                }
+                env.cursor = v_4;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+                break lab2;
+            }
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    return true;
+}
+
+function r_Checks1(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.bra = env.cursor;
+    among_var = env.find_among(A_3, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        context.b_is_noun = true;
+        context.b_is_verb = false;
+        context.b_is_defined = true;
+    } else if (among_var == 2) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        context.b_is_noun = true;
+        context.b_is_verb = false;
+        context.b_is_defined = true;
+    }
+    return true;
+}
+
+function r_Prefix_Step1(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.bra = env.cursor;
+    among_var = env.find_among(A_4, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0623}")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0622}")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0623}")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0627}")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0625}")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Prefix_Step2(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!env.eq_s("\u{0641}\u{0627}")) {
+            break lab0;
+        }
+        return false;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!env.eq_s("\u{0648}\u{0627}")) {
+            break lab1;
+        }
+        return false;
+    }
+    env.cursor = v_2;
+    env.bra = env.cursor;
+    among_var = env.find_among(A_5, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Prefix_Step3a_Noun(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.bra = env.cursor;
+    among_var = env.find_among(A_6, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Prefix_Step3b_Noun(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!env.eq_s("\u{0628}\u{0627}")) {
+            break lab0;
+        }
+        return false;
+    }
+    env.cursor = v_1;
+    env.bra = env.cursor;
+    among_var = env.find_among(A_7, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0628}")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!(context.i_word_len > 3)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0643}")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Prefix_Step3_Verb(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.bra = env.cursor;
+    among_var = env.find_among(A_8, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        if (!env.slice_from("\u{064A}")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        if (!env.slice_from("\u{062A}")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0646}")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        if (!env.slice_from("\u{0623}")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Prefix_Step4_Verb(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.bra = env.cursor;
+    among_var = env.find_among(A_9, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        context.b_is_verb = true;
+        context.b_is_noun = false;
+        if (!env.slice_from("\u{0627}\u{0633}\u{062A}")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step1a(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_10, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len >= 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!(context.i_word_len >= 6)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step1b(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_11, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step2a(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_12, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len > 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step2b(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_13, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step2c1(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_14, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step2c2(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_15, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Noun_Step3(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_16, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 3)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Verb_Step1(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_17, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len >= 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!(context.i_word_len >= 6)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Verb_Step2a(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_18, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!(context.i_word_len >= 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!(context.i_word_len > 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!(context.i_word_len >= 6)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Verb_Step2b(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_19, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 5)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_Verb_Step2c(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_20, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!(context.i_word_len >= 4)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!(context.i_word_len >= 6)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Suffix_All_alef_maqsura(env, context) {
+    var among_var;
+    context.i_word_len = env.char_count();
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_21, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("\u{064A}")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        b_is_defined: false,
+        b_is_verb: false,
+        b_is_noun: false,
+        i_word_len: 0,
+    };
+    context.b_is_noun = true;
+    context.b_is_verb = true;
+    context.b_is_defined = false;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_Checks1(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_Normalize_pre(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        lab3: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            lab4: for (;;) {
+                if (!context.b_is_verb) {
+                    break lab4;
+                }
+                lab5: for (;;) {
+                    var v_5 = env.limit - env.cursor;
+                    lab6: for (;;) {
+                        var v_6 = 1;
+                        replab7: for (;;) {
+                            var v_7 = env.limit - env.cursor;
+                            lab8: for (let _i3 = 0, _n3 = 1; _i3 < _n3; _i3++) {
+                                if (!r_Suffix_Verb_Step1(env, context)) {
+                                    break lab8;
+                                }
+                                v_6 -= 1;
+                                continue replab7;
+                            }
+                            env.cursor = env.limit - v_7;
+                            break replab7;
+                        }
+                        if (v_6 > 0) {
+                            break lab6;
+                        }
+                        lab9: for (;;) {
+                            var v_8 = env.limit - env.cursor;
+                            lab10: for (;;) {
+                                if (!r_Suffix_Verb_Step2a(env, context)) {
+                                    break lab10;
+                                }
+                                break lab9;
+                            }
+                            env.cursor = env.limit - v_8;
+                            lab11: for (;;) {
+                                if (!r_Suffix_Verb_Step2c(env, context)) {
+                                    break lab11;
+                                }
+                                break lab9;
+                            }
+                            env.cursor = env.limit - v_8;
+                            if (env.cursor <= env.limit_backward) {
+                                break lab6;
+                            }
+                            env.previous_char();
+                            break lab9;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_5;
+                    lab12: for (;;) {
+                        if (!r_Suffix_Verb_Step2b(env, context)) {
+                            break lab12;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_5;
+                    if (!r_Suffix_Verb_Step2a(env, context)) {
+                        break lab4;
+                    }
+                    break lab5;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_4;
+            lab13: for (;;) {
+                if (!context.b_is_noun) {
+                    break lab13;
+                }
+                var v_9 = env.limit - env.cursor;
+                lab14: for (;;) {
+                    lab15: for (;;) {
+                        var v_10 = env.limit - env.cursor;
+                        lab16: for (;;) {
+                            if (!r_Suffix_Noun_Step2c2(env, context)) {
+                                break lab16;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_10;
+                        lab17: for (;;) {
+                            lab18: for (;;) {
+                                if (!context.b_is_defined) {
+                                    break lab18;
+                                }
+                                break lab17;
+                            }
+                            if (!r_Suffix_Noun_Step1a(env, context)) {
+                                break lab17;
+                            }
+                            lab19: for (;;) {
+                                var v_12 = env.limit - env.cursor;
+                                lab20: for (;;) {
+                                    if (!r_Suffix_Noun_Step2a(env, context)) {
+                                        break lab20;
+                                    }
+                                    break lab19;
+                                }
+                                env.cursor = env.limit - v_12;
+                                lab21: for (;;) {
+                                    if (!r_Suffix_Noun_Step2b(env, context)) {
+                                        break lab21;
+                                    }
+                                    break lab19;
+                                }
+                                env.cursor = env.limit - v_12;
+                                lab22: for (;;) {
+                                    if (!r_Suffix_Noun_Step2c1(env, context)) {
+                                        break lab22;
+                                    }
+                                    break lab19;
+                                }
+                                env.cursor = env.limit - v_12;
+                                if (env.cursor <= env.limit_backward) {
+                                    break lab17;
+                                }
+                                env.previous_char();
+                                break lab19;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_10;
+                        lab23: for (;;) {
+                            if (!r_Suffix_Noun_Step1b(env, context)) {
+                                break lab23;
+                            }
+                            lab24: for (;;) {
+                                var v_13 = env.limit - env.cursor;
+                                lab25: for (;;) {
+                                    if (!r_Suffix_Noun_Step2a(env, context)) {
+                                        break lab25;
+                                    }
+                                    break lab24;
+                                }
+                                env.cursor = env.limit - v_13;
+                                lab26: for (;;) {
+                                    if (!r_Suffix_Noun_Step2b(env, context)) {
+                                        break lab26;
+                                    }
+                                    break lab24;
+                                }
+                                env.cursor = env.limit - v_13;
+                                if (!r_Suffix_Noun_Step2c1(env, context)) {
+                                    break lab23;
+                                }
+                                break lab24;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_10;
+                        lab27: for (;;) {
+                            lab28: for (;;) {
+                                if (!context.b_is_defined) {
+                                    break lab28;
+                                }
+                                break lab27;
+                            }
+                            if (!r_Suffix_Noun_Step2a(env, context)) {
+                                break lab27;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_10;
+                        if (!r_Suffix_Noun_Step2b(env, context)) {
+                            env.cursor = env.limit - v_9;
+                            break lab14;
+                        }
+                        break lab15;
+                    }
+                    break lab14;
+                }
+                if (!r_Suffix_Noun_Step3(env, context)) {
+                    break lab13;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_4;
+            if (!r_Suffix_All_alef_maqsura(env, context)) {
+                break lab2;
+            }
+            break lab3;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    env.cursor = env.limit_backward;
+    var v_15 = env.cursor;
+    lab29: for (;;) {
+        var v_16 = env.cursor;
+        lab30: for (;;) {
+            if (!r_Prefix_Step1(env, context)) {
+                env.cursor = v_16;
+                break lab30;
+            }
+            break lab30;
+        }
+        var v_17 = env.cursor;
+        lab31: for (;;) {
+            if (!r_Prefix_Step2(env, context)) {
+                env.cursor = v_17;
+                break lab31;
+            }
+            break lab31;
+        }
+        lab32: for (;;) {
+            var v_18 = env.cursor;
+            lab33: for (;;) {
+                if (!r_Prefix_Step3a_Noun(env, context)) {
+                    break lab33;
+                }
+                break lab32;
+            }
+            env.cursor = v_18;
+            lab34: for (;;) {
+                if (!context.b_is_noun) {
+                    break lab34;
+                }
+                if (!r_Prefix_Step3b_Noun(env, context)) {
+                    break lab34;
+                }
+                break lab32;
+            }
+            env.cursor = v_18;
+            if (!context.b_is_verb) {
+                break lab29;
+            }
+            var v_19 = env.cursor;
+            lab35: for (;;) {
+                if (!r_Prefix_Step3_Verb(env, context)) {
+                    env.cursor = v_19;
+                    break lab35;
+                }
+                break lab35;
+            }
+            if (!r_Prefix_Step4_Verb(env, context)) {
+                break lab29;
+            }
+            break lab32;
+        }
+        break lab29;
+    }
+    env.cursor = v_15;
+    var v_20 = env.cursor;
+    lab36: for (;;) {
+        if (!r_Normalize_post(env, context)) {
+            break lab36;
+        }
+        break lab36;
+    }
+    env.cursor = v_20;
+    return true;
+}
@@ -0,0 +1,312 @@
+// Snowball stemmer for Danish, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/danish.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["hed", -1, 1, null],
+    ["ethed", 0, 1, null],
+    ["ered", -1, 1, null],
+    ["e", -1, 1, null],
+    ["erede", 3, 1, null],
+    ["ende", 3, 1, null],
+    ["erende", 5, 1, null],
+    ["ene", 3, 1, null],
+    ["erne", 3, 1, null],
+    ["ere", 3, 1, null],
+    ["en", -1, 1, null],
+    ["heden", 10, 1, null],
+    ["eren", 10, 1, null],
+    ["er", -1, 1, null],
+    ["heder", 13, 1, null],
+    ["erer", 13, 1, null],
+    ["s", -1, 2, null],
+    ["heds", 16, 1, null],
+    ["es", 16, 1, null],
+    ["endes", 18, 1, null],
+    ["erendes", 19, 1, null],
+    ["enes", 18, 1, null],
+    ["ernes", 18, 1, null],
+    ["eres", 18, 1, null],
+    ["ens", 16, 1, null],
+    ["hedens", 24, 1, null],
+    ["erens", 24, 1, null],
+    ["ers", 16, 1, null],
+    ["ets", 16, 1, null],
+    ["erets", 28, 1, null],
+    ["et", -1, 1, null],
+    ["eret", 30, 1, null],
+]);
+
+const A_1 = among([
+    ["gd", -1, -1, null],
+    ["dt", -1, -1, null],
+    ["gt", -1, -1, null],
+    ["kt", -1, -1, null],
+]);
+
+const A_2 = among([
+    ["ig", -1, 1, null],
+    ["lig", 0, 1, null],
+    ["elig", 1, 1, null],
+    ["els", -1, 1, null],
+    ["l\u{00F8}st", -1, 2, null],
+]);
+
+const G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 128];
+
+const G_s_ending = [239, 254, 42, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16];
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    var v_1 = env.cursor;
+    var c = env.byte_index_for_hop(3);
+    if (0 > c || c > env.limit) {
+        return false;
+    }
+    env.cursor = c;
+    context.i_x = env.cursor;
+    env.cursor = v_1;
+    golab0: for (;;) {
+        var v_2 = env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping(G_v, 97, 248)) {
+                break lab1;
+            }
+            env.cursor = v_2;
+            break golab0;
+        }
+        env.cursor = v_2;
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab2: for (;;) {
+        lab3: for (;;) {
+            if (!env.out_grouping(G_v, 97, 248)) {
+                break lab3;
+            }
+            break golab2;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p1 = env.cursor;
+    lab4: for (;;) {
+        if (!(context.i_p1 < context.i_x)) {
+            break lab4;
+        }
+        context.i_p1 = context.i_x;
+        break lab4;
+    }
+    return true;
+}
+
+function r_main_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_0, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.in_grouping_b(G_s_ending, 97, 229)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_consonant_pair(env, context) {
+    var v_1 = env.limit - env.cursor;
+    var v_2 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_3 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_2;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_1, context) == 0) {
+        env.limit_backward = v_3;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_3;
+    env.cursor = env.limit - v_1;
+    if (env.cursor <= env.limit_backward) {
+        return false;
+    }
+    env.previous_char();
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_other_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("st")) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.eq_s_b("ig")) {
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    var v_2 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_3 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_2;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_2, context);
+    if (among_var == 0) {
+        env.limit_backward = v_3;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_3;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_4 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!r_consonant_pair(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_4;
+    } else if (among_var == 2) {
+        if (!env.slice_from("l\u{00F8}s")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_undouble(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (!env.out_grouping_b(G_v, 97, 248)) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    context.S_ch = env.slice_to();
+    if (context.S_ch.length === 0) {
+        return false;
+    }
+    env.limit_backward = v_2;
+    if (!env.eq_s_b(context.S_ch)) {
+        return false;
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_x: 0,
+        i_p1: 0,
+        S_ch: [],
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_main_suffix(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_consonant_pair(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        if (!r_other_suffix(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    var v_5 = env.limit - env.cursor;
+    lab4: for (;;) {
+        if (!r_undouble(env, context)) {
+            break lab4;
+        }
+        break lab4;
+    }
+    env.cursor = env.limit - v_5;
+    env.cursor = env.limit_backward;
+    return true;
+}
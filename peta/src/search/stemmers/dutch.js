@@ -0,0 +1,599 @@
+// Snowball stemmer for Dutch, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/dutch.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 6, null],
+    ["\u{00E1}", 0, 1, null],
+    ["\u{00E4}", 0, 1, null],
+    ["\u{00E9}", 0, 2, null],
+    ["\u{00EB}", 0, 2, null],
+    ["\u{00ED}", 0, 3, null],
+    ["\u{00EF}", 0, 3, null],
+    ["\u{00F3}", 0, 4, null],
+    ["\u{00F6}", 0, 4, null],
+    ["\u{00FA}", 0, 5, null],
+    ["\u{00FC}", 0, 5, null],
+]);
+
+const A_1 = among([
+    ["", -1, 3, null],
+    ["I", 0, 2, null],
+    ["Y", 0, 1, null],
+]);
+
+const A_2 = among([
+    ["dd", -1, -1, null],
+    ["kk", -1, -1, null],
+    ["tt", -1, -1, null],
+]);
+
+const A_3 = among([
+    ["ene", -1, 2, null],
+    ["se", -1, 3, null],
+    ["en", -1, 2, null],
+    ["heden", 2, 1, null],
+    ["s", -1, 3, null],
+]);
+
+const A_4 = among([
+    ["end", -1, 1, null],
+    ["ig", -1, 2, null],
+    ["ing", -1, 1, null],
+    ["lijk", -1, 3, null],
+    ["baar", -1, 4, null],
+    ["bar", -1, 5, null],
+]);
+
+const A_5 = among([
+    ["aa", -1, -1, null],
+    ["ee", -1, -1, null],
+    ["oo", -1, -1, null],
+    ["uu", -1, -1, null],
+]);
+
+const G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+const G_v_I = [1, 0, 0, 17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+const G_v_j = [17, 67, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+function r_prelude(env, context) {
+    var among_var;
+    var v_1 = env.cursor;
+    replab0: for (;;) {
+        var v_2 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("a")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("e")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (!env.slice_from("o")) {
+                    return false;
+                }
+            } else if (among_var == 5) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 6) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_2;
+        break replab0;
+    }
+    env.cursor = v_1;
+    var v_3 = env.cursor;
+    lab2: for (;;) {
+        env.bra = env.cursor;
+        if (!env.eq_s("y")) {
+            env.cursor = v_3;
+            break lab2;
+        }
+        env.ket = env.cursor;
+        if (!env.slice_from("Y")) {
+            return false;
+        }
+        break lab2;
+    }
+    replab3: for (;;) {
+        var v_4 = env.cursor;
+        lab4: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            golab5: for (;;) {
+                var v_5 = env.cursor;
+                lab6: for (;;) {
+                    if (!env.in_grouping(G_v, 97, 232)) {
+                        break lab6;
+                    }
+                    env.bra = env.cursor;
+                    lab7: for (;;) {
+                        var v_6 = env.cursor;
+                        lab8: for (;;) {
+                            if (!env.eq_s("i")) {
+                                break lab8;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 232)) {
+                                break lab8;
+                            }
+                            if (!env.slice_from("I")) {
+                                return false;
+                            }
+                            break lab7;
+                        }
+                        env.cursor = v_6;
+                        if (!env.eq_s("y")) {
+                            break lab6;
+                        }
+                        env.ket = env.cursor;
+                        if (!env.slice_from("Y")) {
+                            return false;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = v_5;
+                    break golab5;
+                }
+                env.cursor = v_5;
+                if (env.cursor >= env.limit) {
+                    break lab4;
+                }
+                env.next_char();
+            }
+            continue replab3;
+        }
+        env.cursor = v_4;
+        break replab3;
+    }
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    golab0: for (;;) {
+        lab1: for (;;) {
+            if (!env.in_grouping(G_v, 97, 232)) {
+                break lab1;
+            }
+            break golab0;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab2: for (;;) {
+        lab3: for (;;) {
+            if (!env.out_grouping(G_v, 97, 232)) {
+                break lab3;
+            }
+            break golab2;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p1 = env.cursor;
+    lab4: for (;;) {
+        if (!(context.i_p1 < 3)) {
+            break lab4;
+        }
+        context.i_p1 = 3;
+        break lab4;
+    }
+    golab5: for (;;) {
+        lab6: for (;;) {
+            if (!env.in_grouping(G_v, 97, 232)) {
+                break lab6;
+            }
+            break golab5;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab7: for (;;) {
+        lab8: for (;;) {
+            if (!env.out_grouping(G_v, 97, 232)) {
+                break lab8;
+            }
+            break golab7;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p2 = env.cursor;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i3 = 0, _n3 = 1; _i3 < _n3; _i3++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_1, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("y")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_undouble(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.find_among_b(A_2, context) == 0) {
+        return false;
+    }
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (env.cursor <= env.limit_backward) {
+        return false;
+    }
+    env.previous_char();
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_e_ending(env, context) {
+    context.b_e_found = false;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("e")) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    var v_1 = env.limit - env.cursor;
+    if (!env.out_grouping_b(G_v, 97, 232)) {
+        return false;
+    }
+    env.cursor = env.limit - v_1;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_e_found = true;
+    if (!r_undouble(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_en_ending(env, context) {
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    var v_1 = env.limit - env.cursor;
+    if (!env.out_grouping_b(G_v, 97, 232)) {
+        return false;
+    }
+    env.cursor = env.limit - v_1;
+    var v_2 = env.limit - env.cursor;
+    lab0: for (;;) {
+        if (!env.eq_s_b("gem")) {
+            break lab0;
+        }
+        return false;
+    }
+    env.cursor = env.limit - v_2;
+    if (!env.slice_del()) {
+        return false;
+    }
+    if (!r_undouble(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_3, context);
+        if (among_var == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            break lab0;
+        } else if (among_var == 1) {
+            if (!r_R1(env, context)) {
+                break lab0;
+            }
+            if (!env.slice_from("heid")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_en_ending(env, context)) {
+                break lab0;
+            }
+        } else if (among_var == 3) {
+            if (!r_R1(env, context)) {
+                break lab0;
+            }
+            if (!env.out_grouping_b(G_v_j, 97, 232)) {
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_e_ending(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("heid")) {
+            break lab2;
+        }
+        env.bra = env.cursor;
+        if (!r_R2(env, context)) {
+            break lab2;
+        }
+        var v_4 = env.limit - env.cursor;
+        lab3: for (;;) {
+            if (!env.eq_s_b("c")) {
+                break lab3;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_4;
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.ket = env.cursor;
+        if (!env.eq_s_b("en")) {
+            break lab2;
+        }
+        env.bra = env.cursor;
+        if (!r_en_ending(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_5 = env.limit - env.cursor;
+    lab4: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_4, context);
+        if (among_var == 0) {
+            break lab4;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            break lab4;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                break lab4;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            lab5: for (;;) {
+                var v_6 = env.limit - env.cursor;
+                lab6: for (;;) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("ig")) {
+                        break lab6;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R2(env, context)) {
+                        break lab6;
+                    }
+                    var v_7 = env.limit - env.cursor;
+                    lab7: for (;;) {
+                        if (!env.eq_s_b("e")) {
+                            break lab7;
+                        }
+                        break lab6;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab5;
+                }
+                env.cursor = env.limit - v_6;
+                if (!r_undouble(env, context)) {
+                    break lab4;
+                }
+                break lab5;
+            }
+        } else if (among_var == 2) {
+            if (!r_R2(env, context)) {
+                break lab4;
+            }
+            var v_8 = env.limit - env.cursor;
+            lab8: for (;;) {
+                if (!env.eq_s_b("e")) {
+                    break lab8;
+                }
+                break lab4;
+            }
+            env.cursor = env.limit - v_8;
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!r_R2(env, context)) {
+                break lab4;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            if (!r_e_ending(env, context)) {
+                break lab4;
+            }
+        } else if (among_var == 4) {
+            if (!r_R2(env, context)) {
+                break lab4;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!r_R2(env, context)) {
+                break lab4;
+            }
+            if (!context.b_e_found) {
+                break lab4;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        break lab4;
+    }
+    env.cursor = env.limit - v_5;
+    var v_9 = env.limit - env.cursor;
+    lab9: for (;;) {
+        if (!env.out_grouping_b(G_v_I, 73, 232)) {
+            break lab9;
+        }
+        var v_10 = env.limit - env.cursor;
+        if (env.find_among_b(A_5, context) == 0) {
+            break lab9;
+        }
+        if (!env.out_grouping_b(G_v, 97, 232)) {
+            break lab9;
+        }
+        env.cursor = env.limit - v_10;
+        env.ket = env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            break lab9;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab9;
+    }
+    env.cursor = env.limit - v_9;
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p2: 0,
+        i_p1: 0,
+        b_e_found: false,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_prelude(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_standard_suffix(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    env.cursor = env.limit_backward;
+    var v_4 = env.cursor;
+    lab3: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = v_4;
+    return true;
+}
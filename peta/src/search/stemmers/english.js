@@ -0,0 +1,986 @@
+// Snowball stemmer for English, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/english.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["arsen", -1, -1, null],
+    ["commun", -1, -1, null],
+    ["gener", -1, -1, null],
+]);
+
+const A_1 = among([
+    ["'", -1, 1, null],
+    ["'s'", 0, 1, null],
+    ["'s", -1, 1, null],
+]);
+
+const A_2 = among([
+    ["ied", -1, 2, null],
+    ["s", -1, 3, null],
+    ["ies", 1, 2, null],
+    ["sses", 1, 1, null],
+    ["ss", 1, -1, null],
+    ["us", 1, -1, null],
+]);
+
+const A_3 = among([
+    ["", -1, 3, null],
+    ["bb", 0, 2, null],
+    ["dd", 0, 2, null],
+    ["ff", 0, 2, null],
+    ["gg", 0, 2, null],
+    ["bl", 0, 1, null],
+    ["mm", 0, 2, null],
+    ["nn", 0, 2, null],
+    ["pp", 0, 2, null],
+    ["rr", 0, 2, null],
+    ["at", 0, 1, null],
+    ["tt", 0, 2, null],
+    ["iz", 0, 1, null],
+]);
+
+const A_4 = among([
+    ["ed", -1, 2, null],
+    ["eed", 0, 1, null],
+    ["ing", -1, 2, null],
+    ["edly", -1, 2, null],
+    ["eedly", 3, 1, null],
+    ["ingly", -1, 2, null],
+]);
+
+const A_5 = among([
+    ["anci", -1, 3, null],
+    ["enci", -1, 2, null],
+    ["ogi", -1, 13, null],
+    ["li", -1, 16, null],
+    ["bli", 3, 12, null],
+    ["abli", 4, 4, null],
+    ["alli", 3, 8, null],
+    ["fulli", 3, 14, null],
+    ["lessli", 3, 15, null],
+    ["ousli", 3, 10, null],
+    ["entli", 3, 5, null],
+    ["aliti", -1, 8, null],
+    ["biliti", -1, 12, null],
+    ["iviti", -1, 11, null],
+    ["tional", -1, 1, null],
+    ["ational", 14, 7, null],
+    ["alism", -1, 8, null],
+    ["ation", -1, 7, null],
+    ["ization", 17, 6, null],
+    ["izer", -1, 6, null],
+    ["ator", -1, 7, null],
+    ["iveness", -1, 11, null],
+    ["fulness", -1, 9, null],
+    ["ousness", -1, 10, null],
+]);
+
+const A_6 = among([
+    ["icate", -1, 4, null],
+    ["ative", -1, 6, null],
+    ["alize", -1, 3, null],
+    ["iciti", -1, 4, null],
+    ["ical", -1, 4, null],
+    ["tional", -1, 1, null],
+    ["ational", 5, 2, null],
+    ["ful", -1, 5, null],
+    ["ness", -1, 5, null],
+]);
+
+const A_7 = among([
+    ["ic", -1, 1, null],
+    ["ance", -1, 1, null],
+    ["ence", -1, 1, null],
+    ["able", -1, 1, null],
+    ["ible", -1, 1, null],
+    ["ate", -1, 1, null],
+    ["ive", -1, 1, null],
+    ["ize", -1, 1, null],
+    ["iti", -1, 1, null],
+    ["al", -1, 1, null],
+    ["ism", -1, 1, null],
+    ["ion", -1, 2, null],
+    ["er", -1, 1, null],
+    ["ous", -1, 1, null],
+    ["ant", -1, 1, null],
+    ["ent", -1, 1, null],
+    ["ment", 15, 1, null],
+    ["ement", 16, 1, null],
+]);
+
+const A_8 = among([
+    ["e", -1, 1, null],
+    ["l", -1, 2, null],
+]);
+
+const A_9 = among([
+    ["succeed", -1, -1, null],
+    ["proceed", -1, -1, null],
+    ["exceed", -1, -1, null],
+    ["canning", -1, -1, null],
+    ["inning", -1, -1, null],
+    ["earring", -1, -1, null],
+    ["herring", -1, -1, null],
+    ["outing", -1, -1, null],
+]);
+
+const A_10 = among([
+    ["andes", -1, -1, null],
+    ["atlas", -1, -1, null],
+    ["bias", -1, -1, null],
+    ["cosmos", -1, -1, null],
+    ["dying", -1, 3, null],
+    ["early", -1, 9, null],
+    ["gently", -1, 7, null],
+    ["howe", -1, -1, null],
+    ["idly", -1, 6, null],
+    ["lying", -1, 4, null],
+    ["news", -1, -1, null],
+    ["only", -1, 10, null],
+    ["singly", -1, 11, null],
+    ["skies", -1, 2, null],
+    ["skis", -1, 1, null],
+    ["sky", -1, -1, null],
+    ["tying", -1, 5, null],
+    ["ugly", -1, 8, null],
+]);
+
+const G_v = [17, 65, 16, 1];
+
+const G_v_WXY = [1, 17, 65, 208, 1];
+
+const G_valid_LI = [55, 141, 2];
+
+function r_prelude(env, context) {
+    context.b_Y_found = false;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        env.bra = env.cursor;
+        if (!env.eq_s("'")) {
+            break lab0;
+        }
+        env.ket = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        env.bra = env.cursor;
+        if (!env.eq_s("y")) {
+            break lab1;
+        }
+        env.ket = env.cursor;
+        if (!env.slice_from("Y")) {
+            return false;
+        }
+        context.b_Y_found = true;
+        break lab1;
+    }
+    env.cursor = v_2;
+    var v_3 = env.cursor;
+    lab2: for (;;) {
+        replab3: for (;;) {
+            var v_4 = env.cursor;
+            lab4: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+                golab5: for (;;) {
+                    var v_5 = env.cursor;
+                    lab6: for (;;) {
+                        if (!env.in_grouping(G_v, 97, 121)) {
+                            break lab6;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.eq_s("y")) {
+                            break lab6;
+                        }
+                        env.ket = env.cursor;
+                        env.cursor = v_5;
+                        break golab5;
+                    }
+                    env.cursor = v_5;
+                    if (env.cursor >= env.limit) {
+                        break lab4;
+                    }
+                    env.next_char();
+                }
+                if (!env.slice_from("Y")) {
+                    return false;
+                }
+                context.b_Y_found = true;
+                continue replab3;
+            }
+            env.cursor = v_4;
+            break replab3;
+        }
+        break lab2;
+    }
+    env.cursor = v_3;
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            lab2: for (;;) {
+                if (env.find_among(A_0, context) == 0) {
+                    break lab2;
+                }
+                break lab1;
+            }
+            env.cursor = v_2;
+            golab3: for (;;) {
+                lab4: for (;;) {
+                    if (!env.in_grouping(G_v, 97, 121)) {
+                        break lab4;
+                    }
+                    break golab3;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            golab5: for (;;) {
+                lab6: for (;;) {
+                    if (!env.out_grouping(G_v, 97, 121)) {
+                        break lab6;
+                    }
+                    break golab5;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            break lab1;
+        }
+        context.i_p1 = env.cursor;
+        golab7: for (;;) {
+            lab8: for (;;) {
+                if (!env.in_grouping(G_v, 97, 121)) {
+                    break lab8;
+                }
+                break golab7;
+            }
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+        }
+        golab9: for (;;) {
+            lab10: for (;;) {
+                if (!env.out_grouping(G_v, 97, 121)) {
+                    break lab10;
+                }
+                break golab9;
+            }
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    return true;
+}
+
+function r_shortv(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.out_grouping_b(G_v_WXY, 89, 121)) {
+                break lab1;
+            }
+            if (!env.in_grouping_b(G_v, 97, 121)) {
+                break lab1;
+            }
+            if (!env.out_grouping_b(G_v, 97, 121)) {
+                break lab1;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        if (!env.out_grouping_b(G_v, 97, 121)) {
+            return false;
+        }
+        if (!env.in_grouping_b(G_v, 97, 121)) {
+            return false;
+        }
+        if (env.cursor > env.limit_backward) {
+            return false;
+        }
+        break lab0;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_Step_1a(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_1, context);
+        if (among_var == 0) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        break lab0;
+    }
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_2, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("ss")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        lab1: for (;;) {
+            var v_2 = env.limit - env.cursor;
+            lab2: for (;;) {
+                var c = env.byte_index_for_hop(-2);
+                if (env.limit_backward > c || c > env.limit) {
+                    break lab2;
+                }
+                env.cursor = c;
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+                break lab1;
+            }
+            env.cursor = env.limit - v_2;
+            if (!env.slice_from("ie")) {
+                return false;
+            }
+            break lab1;
+        }
+    } else if (among_var == 3) {
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        golab3: for (;;) {
+            lab4: for (;;) {
+                if (!env.in_grouping_b(G_v, 97, 121)) {
+                    break lab4;
+                }
+                break golab3;
+            }
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Step_1b(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_4, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ee")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        var v_1 = env.limit - env.cursor;
+        golab0: for (;;) {
+            lab1: for (;;) {
+                if (!env.in_grouping_b(G_v, 97, 121)) {
+                    break lab1;
+                }
+                break golab0;
+            }
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+        }
+        env.cursor = env.limit - v_1;
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_3 = env.limit - env.cursor;
+        among_var = env.find_among_b(A_3, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, "e");
+            env.cursor = c;
+        } else if (among_var == 2) {
+            env.ket = env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (env.cursor != context.i_p1) {
+                return false;
+            }
+            var v_4 = env.limit - env.cursor;
+            if (!r_shortv(env, context)) {
+                return false;
+            }
+            env.cursor = env.limit - v_4;
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, "e");
+            env.cursor = c;
+        }
+    }
+    return true;
+}
+
+function r_Step_1c(env, context) {
+    env.ket = env.cursor;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.eq_s_b("y")) {
+                break lab1;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        if (!env.eq_s_b("Y")) {
+            return false;
+        }
+        break lab0;
+    }
+    env.bra = env.cursor;
+    if (!env.out_grouping_b(G_v, 97, 121)) {
+        return false;
+    }
+    var v_2 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (env.cursor > env.limit_backward) {
+            break lab2;
+        }
+        return false;
+    }
+    env.cursor = env.limit - v_2;
+    if (!env.slice_from("i")) {
+        return false;
+    }
+    return true;
+}
+
+function r_Step_2(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_5, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("tion")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("ence")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("ance")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("able")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_from("ent")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("ize")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_from("ate")) {
+            return false;
+        }
+    } else if (among_var == 8) {
+        if (!env.slice_from("al")) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_from("ful")) {
+            return false;
+        }
+    } else if (among_var == 10) {
+        if (!env.slice_from("ous")) {
+            return false;
+        }
+    } else if (among_var == 11) {
+        if (!env.slice_from("ive")) {
+            return false;
+        }
+    } else if (among_var == 12) {
+        if (!env.slice_from("ble")) {
+            return false;
+        }
+    } else if (among_var == 13) {
+        if (!env.eq_s_b("l")) {
+            return false;
+        }
+        if (!env.slice_from("og")) {
+            return false;
+        }
+    } else if (among_var == 14) {
+        if (!env.slice_from("ful")) {
+            return false;
+        }
+    } else if (among_var == 15) {
+        if (!env.slice_from("less")) {
+            return false;
+        }
+    } else if (among_var == 16) {
+        if (!env.in_grouping_b(G_valid_LI, 99, 116)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Step_3(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("tion")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("ate")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("al")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("ic")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Step_4(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R2(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        lab0: for (;;) {
+            var v_1 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!env.eq_s_b("s")) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!env.eq_s_b("t")) {
+                return false;
+            }
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_Step_5(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_8, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        lab0: for (;;) {
+            var v_1 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!r_R2(env, context)) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab2: for (;;) {
+                if (!r_shortv(env, context)) {
+                    break lab2;
+                }
+                return false;
+            }
+            env.cursor = env.limit - v_2;
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.eq_s_b("l")) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_exception2(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_9, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    return true;
+}
+
+function r_exception1(env, context) {
+    var among_var;
+    env.bra = env.cursor;
+    among_var = env.find_among(A_10, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (env.cursor < env.limit) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("ski")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("sky")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("die")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("lie")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_from("tie")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("idl")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_from("gentl")) {
+            return false;
+        }
+    } else if (among_var == 8) {
+        if (!env.slice_from("ugli")) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_from("earli")) {
+            return false;
+        }
+    } else if (among_var == 10) {
+        if (!env.slice_from("onli")) {
+            return false;
+        }
+    } else if (among_var == 11) {
+        if (!env.slice_from("singl")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_postlude(env, context) {
+    if (!context.b_Y_found) {
+        return false;
+    }
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            golab2: for (;;) {
+                var v_2 = env.cursor;
+                lab3: for (;;) {
+                    env.bra = env.cursor;
+                    if (!env.eq_s("Y")) {
+                        break lab3;
+                    }
+                    env.ket = env.cursor;
+                    env.cursor = v_2;
+                    break golab2;
+                }
+                env.cursor = v_2;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            if (!env.slice_from("y")) {
+                return false;
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        b_Y_found: false,
+        i_p2: 0,
+        i_p1: 0,
+    };
+    lab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (;;) {
+            if (!r_exception1(env, context)) {
+                break lab1;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        lab2: for (;;) {
+            var v_2 = env.cursor;
+            lab3: for (;;) {
+                var c = env.byte_index_for_hop(3);
+                if (0 > c || c > env.limit) {
+                    break lab3;
+                }
+                env.cursor = c;
+                break lab2;
+            }
+            env.cursor = v_2;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_3 = env.cursor;
+        lab4: for (;;) {
+            if (!r_prelude(env, context)) {
+                break lab4;
+            }
+            break lab4;
+        }
+        env.cursor = v_3;
+        var v_4 = env.cursor;
+        lab5: for (;;) {
+            if (!r_mark_regions(env, context)) {
+                break lab5;
+            }
+            break lab5;
+        }
+        env.cursor = v_4;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_5 = env.limit - env.cursor;
+        lab6: for (;;) {
+            if (!r_Step_1a(env, context)) {
+                break lab6;
+            }
+            break lab6;
+        }
+        env.cursor = env.limit - v_5;
+        lab7: for (;;) {
+            var v_6 = env.limit - env.cursor;
+            lab8: for (;;) {
+                if (!r_exception2(env, context)) {
+                    break lab8;
+                }
+                break lab7;
+            }
+            env.cursor = env.limit - v_6;
+            var v_7 = env.limit - env.cursor;
+            lab9: for (;;) {
+                if (!r_Step_1b(env, context)) {
+                    break lab9;
+                }
+                break lab9;
+            }
+            env.cursor = env.limit - v_7;
+            var v_8 = env.limit - env.cursor;
+            lab10: for (;;) {
+                if (!r_Step_1c(env, context)) {
+                    break lab10;
+                }
+                break lab10;
+            }
+            env.cursor = env.limit - v_8;
+            var v_9 = env.limit - env.cursor;
+            lab11: for (;;) {
+                if (!r_Step_2(env, context)) {
+                    break lab11;
+                }
+                break lab11;
+            }
+            env.cursor = env.limit - v_9;
+            var v_10 = env.limit - env.cursor;
+            lab12: for (;;) {
+                if (!r_Step_3(env, context)) {
+                    break lab12;
+                }
+                break lab12;
+            }
+            env.cursor = env.limit - v_10;
+            var v_11 = env.limit - env.cursor;
+            lab13: for (;;) {
+                if (!r_Step_4(env, context)) {
+                    break lab13;
+                }
+                break lab13;
+            }
+            env.cursor = env.limit - v_11;
+            var v_12 = env.limit - env.cursor;
+            lab14: for (;;) {
+                if (!r_Step_5(env, context)) {
+                    break lab14;
+                }
+                break lab14;
+            }
+            env.cursor = env.limit - v_12;
+            break lab7;
+        }
+        env.cursor = env.limit_backward;
+        var v_13 = env.cursor;
+        lab15: for (;;) {
+            if (!r_postlude(env, context)) {
+                break lab15;
+            }
+            break lab15;
+        }
+        env.cursor = v_13;
+        break lab0;
+    }
+    return true;
+}
@@ -0,0 +1,749 @@
+// Snowball stemmer for Finnish, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/finnish.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["pa", -1, 1, null],
+    ["sti", -1, 2, null],
+    ["kaan", -1, 1, null],
+    ["han", -1, 1, null],
+    ["kin", -1, 1, null],
+    ["h\u{00E4}n", -1, 1, null],
+    ["k\u{00E4}\u{00E4}n", -1, 1, null],
+    ["ko", -1, 1, null],
+    ["p\u{00E4}", -1, 1, null],
+    ["k\u{00F6}", -1, 1, null],
+]);
+
+const A_1 = among([
+    ["lla", -1, -1, null],
+    ["na", -1, -1, null],
+    ["ssa", -1, -1, null],
+    ["ta", -1, -1, null],
+    ["lta", 3, -1, null],
+    ["sta", 3, -1, null],
+]);
+
+const A_2 = among([
+    ["ll\u{00E4}", -1, -1, null],
+    ["n\u{00E4}", -1, -1, null],
+    ["ss\u{00E4}", -1, -1, null],
+    ["t\u{00E4}", -1, -1, null],
+    ["lt\u{00E4}", 3, -1, null],
+    ["st\u{00E4}", 3, -1, null],
+]);
+
+const A_3 = among([
+    ["lle", -1, -1, null],
+    ["ine", -1, -1, null],
+]);
+
+const A_4 = among([
+    ["nsa", -1, 3, null],
+    ["mme", -1, 3, null],
+    ["nne", -1, 3, null],
+    ["ni", -1, 2, null],
+    ["si", -1, 1, null],
+    ["an", -1, 4, null],
+    ["en", -1, 6, null],
+    ["\u{00E4}n", -1, 5, null],
+    ["ns\u{00E4}", -1, 3, null],
+]);
+
+const A_5 = among([
+    ["aa", -1, -1, null],
+    ["ee", -1, -1, null],
+    ["ii", -1, -1, null],
+    ["oo", -1, -1, null],
+    ["uu", -1, -1, null],
+    ["\u{00E4}\u{00E4}", -1, -1, null],
+    ["\u{00F6}\u{00F6}", -1, -1, null],
+]);
+
+const A_6 = among([
+    ["a", -1, 8, null],
+    ["lla", 0, -1, null],
+    ["na", 0, -1, null],
+    ["ssa", 0, -1, null],
+    ["ta", 0, -1, null],
+    ["lta", 4, -1, null],
+    ["sta", 4, -1, null],
+    ["tta", 4, 9, null],
+    ["lle", -1, -1, null],
+    ["ine", -1, -1, null],
+    ["ksi", -1, -1, null],
+    ["n", -1, 7, null],
+    ["han", 11, 1, null],
+    ["den", 11, -1, r_VI],
+    ["seen", 11, -1, r_LONG],
+    ["hen", 11, 2, null],
+    ["tten", 11, -1, r_VI],
+    ["hin", 11, 3, null],
+    ["siin", 11, -1, r_VI],
+    ["hon", 11, 4, null],
+    ["h\u{00E4}n", 11, 5, null],
+    ["h\u{00F6}n", 11, 6, null],
+    ["\u{00E4}", -1, 8, null],
+    ["ll\u{00E4}", 22, -1, null],
+    ["n\u{00E4}", 22, -1, null],
+    ["ss\u{00E4}", 22, -1, null],
+    ["t\u{00E4}", 22, -1, null],
+    ["lt\u{00E4}", 26, -1, null],
+    ["st\u{00E4}", 26, -1, null],
+    ["tt\u{00E4}", 26, 9, null],
+]);
+
+const A_7 = among([
+    ["eja", -1, -1, null],
+    ["mma", -1, 1, null],
+    ["imma", 1, -1, null],
+    ["mpa", -1, 1, null],
+    ["impa", 3, -1, null],
+    ["mmi", -1, 1, null],
+    ["immi", 5, -1, null],
+    ["mpi", -1, 1, null],
+    ["impi", 7, -1, null],
+    ["ej\u{00E4}", -1, -1, null],
+    ["mm\u{00E4}", -1, 1, null],
+    ["imm\u{00E4}", 10, -1, null],
+    ["mp\u{00E4}", -1, 1, null],
+    ["imp\u{00E4}", 12, -1, null],
+]);
+
+const A_8 = among([
+    ["i", -1, -1, null],
+    ["j", -1, -1, null],
+]);
+
+const A_9 = among([
+    ["mma", -1, 1, null],
+    ["imma", 0, -1, null],
+]);
+
+const G_AEI = [17, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8];
+
+const G_V1 = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32];
+
+const G_V2 = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32];
+
+const G_particle_end = [17, 97, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32];
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    golab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping(G_V1, 97, 246)) {
+                break lab1;
+            }
+            env.cursor = v_1;
+            break golab0;
+        }
+        env.cursor = v_1;
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab2: for (;;) {
+        lab3: for (;;) {
+            if (!env.out_grouping(G_V1, 97, 246)) {
+                break lab3;
+            }
+            break golab2;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p1 = env.cursor;
+    golab4: for (;;) {
+        var v_3 = env.cursor;
+        lab5: for (;;) {
+            if (!env.in_grouping(G_V1, 97, 246)) {
+                break lab5;
+            }
+            env.cursor = v_3;
+            break golab4;
+        }
+        env.cursor = v_3;
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab6: for (;;) {
+        lab7: for (;;) {
+            if (!env.out_grouping(G_V1, 97, 246)) {
+                break lab7;
+            }
+            break golab6;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p2 = env.cursor;
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_particle_etc(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_0, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.in_grouping_b(G_particle_end, 97, 246)) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_possessive(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_4, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        var v_3 = env.limit - env.cursor;
+        lab0: for (;;) {
+            if (!env.eq_s_b("k")) {
+                break lab0;
+            }
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.ket = env.cursor;
+        if (!env.eq_s_b("kse")) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_from("ksi")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (env.find_among_b(A_1, context) == 0) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (env.find_among_b(A_2, context) == 0) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (env.find_among_b(A_3, context) == 0) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_LONG(env, context) {
+    if (env.find_among_b(A_5, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_VI(env, context) {
+    if (!env.eq_s_b("i")) {
+        return false;
+    }
+    if (!env.in_grouping_b(G_V2, 97, 246)) {
+        return false;
+    }
+    return true;
+}
+
+function r_case_ending(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.eq_s_b("a")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.eq_s_b("e")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.eq_s_b("i")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.eq_s_b("o")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.eq_s_b("\u{00E4}")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.eq_s_b("\u{00F6}")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        var v_3 = env.limit - env.cursor;
+        lab0: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            lab1: for (;;) {
+                var v_5 = env.limit - env.cursor;
+                lab2: for (;;) {
+                    if (!r_LONG(env, context)) {
+                        break lab2;
+                    }
+                    break lab1;
+                }
+                env.cursor = env.limit - v_5;
+                if (!env.eq_s_b("ie")) {
+                    env.cursor = env.limit - v_3;
+                    break lab0;
+                }
+                break lab1;
+            }
+            env.cursor = env.limit - v_4;
+            if (env.cursor <= env.limit_backward) {
+                env.cursor = env.limit - v_3;
+                break lab0;
+            }
+            env.previous_char();
+            env.bra = env.cursor;
+            break lab0;
+        }
+    } else if (among_var == 8) {
+        if (!env.in_grouping_b(G_V1, 97, 246)) {
+            return false;
+        }
+        if (!env.out_grouping_b(G_V1, 97, 246)) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.eq_s_b("e")) {
+            return false;
+        }
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_ending_removed = true;
+    return true;
+}
+
+function r_other_endings(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p2) {
+        return false;
+    }
+    env.cursor = context.i_p2;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        var v_3 = env.limit - env.cursor;
+        lab0: for (;;) {
+            if (!env.eq_s_b("po")) {
+                break lab0;
+            }
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_i_plural(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_8, context) == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_t_plural(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("t")) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    var v_3 = env.limit - env.cursor;
+    if (!env.in_grouping_b(G_V1, 97, 246)) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.cursor = env.limit - v_3;
+    if (!env.slice_del()) {
+        return false;
+    }
+    env.limit_backward = v_2;
+    var v_4 = env.limit - env.cursor;
+    if (env.cursor < context.i_p2) {
+        return false;
+    }
+    env.cursor = context.i_p2;
+    var v_5 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_4;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_9, context);
+    if (among_var == 0) {
+        env.limit_backward = v_5;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_5;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        var v_6 = env.limit - env.cursor;
+        lab0: for (;;) {
+            if (!env.eq_s_b("po")) {
+                break lab0;
+            }
+            return false;
+        }
+        env.cursor = env.limit - v_6;
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_tidy(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    var v_3 = env.limit - env.cursor;
+    lab0: for (;;) {
+        var v_4 = env.limit - env.cursor;
+        if (!r_LONG(env, context)) {
+            break lab0;
+        }
+        env.cursor = env.limit - v_4;
+        env.ket = env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            break lab0;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_3;
+    var v_5 = env.limit - env.cursor;
+    lab1: for (;;) {
+        env.ket = env.cursor;
+        if (!env.in_grouping_b(G_AEI, 97, 228)) {
+            break lab1;
+        }
+        env.bra = env.cursor;
+        if (!env.out_grouping_b(G_V1, 97, 246)) {
+            break lab1;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_5;
+    var v_6 = env.limit - env.cursor;
+    lab2: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("j")) {
+            break lab2;
+        }
+        env.bra = env.cursor;
+        lab3: for (;;) {
+            var v_7 = env.limit - env.cursor;
+            lab4: for (;;) {
+                if (!env.eq_s_b("o")) {
+                    break lab4;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_7;
+            if (!env.eq_s_b("u")) {
+                break lab2;
+            }
+            break lab3;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_6;
+    var v_8 = env.limit - env.cursor;
+    lab5: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("o")) {
+            break lab5;
+        }
+        env.bra = env.cursor;
+        if (!env.eq_s_b("j")) {
+            break lab5;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab5;
+    }
+    env.cursor = env.limit - v_8;
+    env.limit_backward = v_2;
+    golab6: for (;;) {
+        var v_9 = env.limit - env.cursor;
+        lab7: for (;;) {
+            if (!env.out_grouping_b(G_V1, 97, 246)) {
+                break lab7;
+            }
+            env.cursor = env.limit - v_9;
+            break golab6;
+        }
+        env.cursor = env.limit - v_9;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+    }
+    env.ket = env.cursor;
+    if (env.cursor <= env.limit_backward) {
+        return false;
+    }
+    env.previous_char();
+    env.bra = env.cursor;
+    context.S_x = env.slice_to();
+    if (context.S_x.length === 0) {
+        return false;
+    }
+    if (!env.eq_s_b(context.S_x)) {
+        return false;
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        b_ending_removed: false,
+        S_x: [],
+        i_p2: 0,
+        i_p1: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    context.b_ending_removed = false;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_particle_etc(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_possessive(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        if (!r_case_ending(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    var v_5 = env.limit - env.cursor;
+    lab4: for (;;) {
+        if (!r_other_endings(env, context)) {
+            break lab4;
+        }
+        break lab4;
+    }
+    env.cursor = env.limit - v_5;
+    lab5: for (;;) {
+        var v_6 = env.limit - env.cursor;
+        lab6: for (;;) {
+            if (!context.b_ending_removed) {
+                break lab6;
+            }
+            var v_7 = env.limit - env.cursor;
+            lab7: for (;;) {
+                if (!r_i_plural(env, context)) {
+                    break lab7;
+                }
+                break lab7;
+            }
+            env.cursor = env.limit - v_7;
+            break lab5;
+        }
+        env.cursor = env.limit - v_6;
+        var v_8 = env.limit - env.cursor;
+        lab8: for (;;) {
+            if (!r_t_plural(env, context)) {
+                break lab8;
+            }
+            break lab8;
+        }
+        env.cursor = env.limit - v_8;
+        break lab5;
+    }
+    var v_9 = env.limit - env.cursor;
+    lab9: for (;;) {
+        if (!r_tidy(env, context)) {
+            break lab9;
+        }
+        break lab9;
+    }
+    env.cursor = env.limit - v_9;
+    env.cursor = env.limit_backward;
+    return true;
+}
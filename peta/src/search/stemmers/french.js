@@ -0,0 +1,1139 @@
+// Snowball stemmer for French, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/french.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["col", -1, -1, null],
+    ["par", -1, -1, null],
+    ["tap", -1, -1, null],
+]);
+
+const A_1 = among([
+    ["", -1, 4, null],
+    ["I", 0, 1, null],
+    ["U", 0, 2, null],
+    ["Y", 0, 3, null],
+]);
+
+const A_2 = among([
+    ["iqU", -1, 3, null],
+    ["abl", -1, 3, null],
+    ["I\u{00E8}r", -1, 4, null],
+    ["i\u{00E8}r", -1, 4, null],
+    ["eus", -1, 2, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_3 = among([
+    ["ic", -1, 2, null],
+    ["abil", -1, 1, null],
+    ["iv", -1, 3, null],
+]);
+
+const A_4 = among([
+    ["iqUe", -1, 1, null],
+    ["atrice", -1, 2, null],
+    ["ance", -1, 1, null],
+    ["ence", -1, 5, null],
+    ["logie", -1, 3, null],
+    ["able", -1, 1, null],
+    ["isme", -1, 1, null],
+    ["euse", -1, 11, null],
+    ["iste", -1, 1, null],
+    ["ive", -1, 8, null],
+    ["if", -1, 8, null],
+    ["usion", -1, 4, null],
+    ["ation", -1, 2, null],
+    ["ution", -1, 4, null],
+    ["ateur", -1, 2, null],
+    ["iqUes", -1, 1, null],
+    ["atrices", -1, 2, null],
+    ["ances", -1, 1, null],
+    ["ences", -1, 5, null],
+    ["logies", -1, 3, null],
+    ["ables", -1, 1, null],
+    ["ismes", -1, 1, null],
+    ["euses", -1, 11, null],
+    ["istes", -1, 1, null],
+    ["ives", -1, 8, null],
+    ["ifs", -1, 8, null],
+    ["usions", -1, 4, null],
+    ["ations", -1, 2, null],
+    ["utions", -1, 4, null],
+    ["ateurs", -1, 2, null],
+    ["ments", -1, 15, null],
+    ["ements", 30, 6, null],
+    ["issements", 31, 12, null],
+    ["it\u{00E9}s", -1, 7, null],
+    ["ment", -1, 15, null],
+    ["ement", 34, 6, null],
+    ["issement", 35, 12, null],
+    ["amment", 34, 13, null],
+    ["emment", 34, 14, null],
+    ["aux", -1, 10, null],
+    ["eaux", 39, 9, null],
+    ["eux", -1, 1, null],
+    ["it\u{00E9}", -1, 7, null],
+]);
+
+const A_5 = among([
+    ["ira", -1, 1, null],
+    ["ie", -1, 1, null],
+    ["isse", -1, 1, null],
+    ["issante", -1, 1, null],
+    ["i", -1, 1, null],
+    ["irai", 4, 1, null],
+    ["ir", -1, 1, null],
+    ["iras", -1, 1, null],
+    ["ies", -1, 1, null],
+    ["\u{00EE}mes", -1, 1, null],
+    ["isses", -1, 1, null],
+    ["issantes", -1, 1, null],
+    ["\u{00EE}tes", -1, 1, null],
+    ["is", -1, 1, null],
+    ["irais", 13, 1, null],
+    ["issais", 13, 1, null],
+    ["irions", -1, 1, null],
+    ["issions", -1, 1, null],
+    ["irons", -1, 1, null],
+    ["issons", -1, 1, null],
+    ["issants", -1, 1, null],
+    ["it", -1, 1, null],
+    ["irait", 21, 1, null],
+    ["issait", 21, 1, null],
+    ["issant", -1, 1, null],
+    ["iraIent", -1, 1, null],
+    ["issaIent", -1, 1, null],
+    ["irent", -1, 1, null],
+    ["issent", -1, 1, null],
+    ["iront", -1, 1, null],
+    ["\u{00EE}t", -1, 1, null],
+    ["iriez", -1, 1, null],
+    ["issiez", -1, 1, null],
+    ["irez", -1, 1, null],
+    ["issez", -1, 1, null],
+]);
+
+const A_6 = among([
+    ["a", -1, 3, null],
+    ["era", 0, 2, null],
+    ["asse", -1, 3, null],
+    ["ante", -1, 3, null],
+    ["\u{00E9}e", -1, 2, null],
+    ["ai", -1, 3, null],
+    ["erai", 5, 2, null],
+    ["er", -1, 2, null],
+    ["as", -1, 3, null],
+    ["eras", 8, 2, null],
+    ["\u{00E2}mes", -1, 3, null],
+    ["asses", -1, 3, null],
+    ["antes", -1, 3, null],
+    ["\u{00E2}tes", -1, 3, null],
+    ["\u{00E9}es", -1, 2, null],
+    ["ais", -1, 3, null],
+    ["erais", 15, 2, null],
+    ["ions", -1, 1, null],
+    ["erions", 17, 2, null],
+    ["assions", 17, 3, null],
+    ["erons", -1, 2, null],
+    ["ants", -1, 3, null],
+    ["\u{00E9}s", -1, 2, null],
+    ["ait", -1, 3, null],
+    ["erait", 23, 2, null],
+    ["ant", -1, 3, null],
+    ["aIent", -1, 3, null],
+    ["eraIent", 26, 2, null],
+    ["\u{00E8}rent", -1, 2, null],
+    ["assent", -1, 3, null],
+    ["eront", -1, 2, null],
+    ["\u{00E2}t", -1, 3, null],
+    ["ez", -1, 2, null],
+    ["iez", 32, 2, null],
+    ["eriez", 33, 2, null],
+    ["assiez", 33, 3, null],
+    ["erez", 32, 2, null],
+    ["\u{00E9}", -1, 2, null],
+]);
+
+const A_7 = among([
+    ["e", -1, 3, null],
+    ["I\u{00E8}re", 0, 2, null],
+    ["i\u{00E8}re", 0, 2, null],
+    ["ion", -1, 1, null],
+    ["Ier", -1, 2, null],
+    ["ier", -1, 2, null],
+    ["\u{00EB}", -1, 4, null],
+]);
+
+const A_8 = among([
+    ["ell", -1, -1, null],
+    ["eill", -1, -1, null],
+    ["enn", -1, -1, null],
+    ["onn", -1, -1, null],
+    ["ett", -1, -1, null],
+]);
+
+const G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 130, 103, 8, 5];
+
+const G_keep_with_s = [1, 65, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+function r_prelude(env, context) {
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            golab2: for (;;) {
+                var v_2 = env.cursor;
+                lab3: for (;;) {
+                    lab4: for (;;) {
+                        var v_3 = env.cursor;
+                        lab5: for (;;) {
+                            if (!env.in_grouping(G_v, 97, 251)) {
+                                break lab5;
+                            }
+                            env.bra = env.cursor;
+                            lab6: for (;;) {
+                                var v_4 = env.cursor;
+                                lab7: for (;;) {
+                                    if (!env.eq_s("u")) {
+                                        break lab7;
+                                    }
+                                    env.ket = env.cursor;
+                                    if (!env.in_grouping(G_v, 97, 251)) {
+                                        break lab7;
+                                    }
+                                    if (!env.slice_from("U")) {
+                                        return false;
+                                    }
+                                    break lab6;
+                                }
+                                env.cursor = v_4;
+                                lab8: for (;;) {
+                                    if (!env.eq_s("i")) {
+                                        break lab8;
+                                    }
+                                    env.ket = env.cursor;
+                                    if (!env.in_grouping(G_v, 97, 251)) {
+                                        break lab8;
+                                    }
+                                    if (!env.slice_from("I")) {
+                                        return false;
+                                    }
+                                    break lab6;
+                                }
+                                env.cursor = v_4;
+                                if (!env.eq_s("y")) {
+                                    break lab5;
+                                }
+                                env.ket = env.cursor;
+                                if (!env.slice_from("Y")) {
+                                    return false;
+                                }
+                                break lab6;
+                            }
+                            break lab4;
+                        }
+                        env.cursor = v_3;
+                        lab9: for (;;) {
+                            env.bra = env.cursor;
+                            if (!env.eq_s("y")) {
+                                break lab9;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 251)) {
+                                break lab9;
+                            }
+                            if (!env.slice_from("Y")) {
+                                return false;
+                            }
+                            break lab4;
+                        }
+                        env.cursor = v_3;
+                        if (!env.eq_s("q")) {
+                            break lab3;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.eq_s("u")) {
+                            break lab3;
+                        }
+                        env.ket = env.cursor;
+                        if (!env.slice_from("U")) {
+                            return false;
+                        }
+                        break lab4;
+                    }
+                    env.cursor = v_2;
+                    break golab2;
+                }
+                env.cursor = v_2;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_pV = env.limit;
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            lab2: for (;;) {
+                if (!env.in_grouping(G_v, 97, 251)) {
+                    break lab2;
+                }
+                if (!env.in_grouping(G_v, 97, 251)) {
+                    break lab2;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab2;
+                }
+                env.next_char();
+                break lab1;
+            }
+            env.cursor = v_2;
+            lab3: for (;;) {
+                if (env.find_among(A_0, context) == 0) {
+                    break lab3;
+                }
+                break lab1;
+            }
+            env.cursor = v_2;
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+            golab4: for (;;) {
+                lab5: for (;;) {
+                    if (!env.in_grouping(G_v, 97, 251)) {
+                        break lab5;
+                    }
+                    break golab4;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            break lab1;
+        }
+        context.i_pV = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_4 = env.cursor;
+    lab6: for (;;) {
+        golab7: for (;;) {
+            lab8: for (;;) {
+                if (!env.in_grouping(G_v, 97, 251)) {
+                    break lab8;
+                }
+                break golab7;
+            }
+            if (env.cursor >= env.limit) {
+                break lab6;
+            }
+            env.next_char();
+        }
+        golab9: for (;;) {
+            lab10: for (;;) {
+                if (!env.out_grouping(G_v, 97, 251)) {
+                    break lab10;
+                }
+                break golab9;
+            }
+            if (env.cursor >= env.limit) {
+                break lab6;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        golab11: for (;;) {
+            lab12: for (;;) {
+                if (!env.in_grouping(G_v, 97, 251)) {
+                    break lab12;
+                }
+                break golab11;
+            }
+            if (env.cursor >= env.limit) {
+                break lab6;
+            }
+            env.next_char();
+        }
+        golab13: for (;;) {
+            lab14: for (;;) {
+                if (!env.out_grouping(G_v, 97, 251)) {
+                    break lab14;
+                }
+                break golab13;
+            }
+            if (env.cursor >= env.limit) {
+                break lab6;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab6;
+    }
+    env.cursor = v_4;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_1, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_from("y")) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_RV(env, context) {
+    if (!(context.i_pV <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_4, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        lab0: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("ic")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            lab1: for (;;) {
+                var v_2 = env.limit - env.cursor;
+                lab2: for (;;) {
+                    if (!r_R2(env, context)) {
+                        break lab2;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                if (!env.slice_from("iqU")) {
+                    return false;
+                }
+                break lab1;
+            }
+            break lab0;
+        }
+    } else if (among_var == 3) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("log")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("u")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ent")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_3 = env.limit - env.cursor;
+        lab3: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_2, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab3;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                lab4: for (;;) {
+                    var v_4 = env.limit - env.cursor;
+                    lab5: for (;;) {
+                        if (!r_R2(env, context)) {
+                            break lab5;
+                        }
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab4;
+                    }
+                    env.cursor = env.limit - v_4;
+                    if (!r_R1(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab3;
+                    }
+                    if (!env.slice_from("eux")) {
+                        return false;
+                    }
+                    break lab4;
+                }
+            } else if (among_var == 3) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (!r_RV(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                }
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            }
+            break lab3;
+        }
+    } else if (among_var == 7) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_5 = env.limit - env.cursor;
+        lab6: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_3, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_5;
+                break lab6;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_5;
+                break lab6;
+            } else if (among_var == 1) {
+                lab7: for (;;) {
+                    var v_6 = env.limit - env.cursor;
+                    lab8: for (;;) {
+                        if (!r_R2(env, context)) {
+                            break lab8;
+                        }
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!env.slice_from("abl")) {
+                        return false;
+                    }
+                    break lab7;
+                }
+            } else if (among_var == 2) {
+                lab9: for (;;) {
+                    var v_7 = env.limit - env.cursor;
+                    lab10: for (;;) {
+                        if (!r_R2(env, context)) {
+                            break lab10;
+                        }
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab9;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (!env.slice_from("iqU")) {
+                        return false;
+                    }
+                    break lab9;
+                }
+            } else if (among_var == 3) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_5;
+                    break lab6;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab6;
+        }
+    } else if (among_var == 8) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_8 = env.limit - env.cursor;
+        lab11: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("at")) {
+                env.cursor = env.limit - v_8;
+                break lab11;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_8;
+                break lab11;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            if (!env.eq_s_b("ic")) {
+                env.cursor = env.limit - v_8;
+                break lab11;
+            }
+            env.bra = env.cursor;
+            lab12: for (;;) {
+                var v_9 = env.limit - env.cursor;
+                lab13: for (;;) {
+                    if (!r_R2(env, context)) {
+                        break lab13;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab12;
+                }
+                env.cursor = env.limit - v_9;
+                if (!env.slice_from("iqU")) {
+                    return false;
+                }
+                break lab12;
+            }
+            break lab11;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_from("eau")) {
+            return false;
+        }
+    } else if (among_var == 10) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("al")) {
+            return false;
+        }
+    } else if (among_var == 11) {
+        lab14: for (;;) {
+            var v_10 = env.limit - env.cursor;
+            lab15: for (;;) {
+                if (!r_R2(env, context)) {
+                    break lab15;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab14;
+            }
+            env.cursor = env.limit - v_10;
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("eux")) {
+                return false;
+            }
+            break lab14;
+        }
+    } else if (among_var == 12) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (!env.out_grouping_b(G_v, 97, 251)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 13) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ant")) {
+            return false;
+        }
+        return false;
+    } else if (among_var == 14) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ent")) {
+            return false;
+        }
+        return false;
+    } else if (among_var == 15) {
+        var v_11 = env.limit - env.cursor;
+        if (!env.in_grouping_b(G_v, 97, 251)) {
+            return false;
+        }
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        env.cursor = env.limit - v_11;
+        if (!env.slice_del()) {
+            return false;
+        }
+        return false;
+    }
+    return true;
+}
+
+function r_i_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_5, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    } else if (among_var == 1) {
+        if (!env.out_grouping_b(G_v, 97, 251)) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function r_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R2(env, context)) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_3 = env.limit - env.cursor;
+        lab0: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("e")) {
+                env.cursor = env.limit - v_3;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function r_residual_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("s")) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        env.bra = env.cursor;
+        var v_2 = env.limit - env.cursor;
+        if (!env.out_grouping_b(G_keep_with_s, 97, 232)) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        env.cursor = env.limit - v_2;
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab0;
+    }
+    var v_3 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_4 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_3;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        env.limit_backward = v_4;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_4;
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R2(env, context)) {
+            env.limit_backward = v_4;
+            return false;
+        }
+        lab1: for (;;) {
+            var v_5 = env.limit - env.cursor;
+            lab2: for (;;) {
+                if (!env.eq_s_b("s")) {
+                    break lab2;
+                }
+                break lab1;
+            }
+            env.cursor = env.limit - v_5;
+            if (!env.eq_s_b("t")) {
+                env.limit_backward = v_4;
+                return false;
+            }
+            break lab1;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("i")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.eq_s_b("gu")) {
+            env.limit_backward = v_4;
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    env.limit_backward = v_4;
+    return true;
+}
+
+function r_un_double(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.find_among_b(A_8, context) == 0) {
+        return false;
+    }
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (env.cursor <= env.limit_backward) {
+        return false;
+    }
+    env.previous_char();
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_un_accent(env, context) {
+    var v_1 = 1;
+    replab0: for (;;) {
+        lab1: for (let _i3 = 0, _n3 = 1; _i3 < _n3; _i3++) {
+            if (!env.out_grouping_b(G_v, 97, 251)) {
+                break lab1;
+            }
+            v_1 -= 1;
+            continue replab0;
+        }
+        break replab0;
+    }
+    if (v_1 > 0) {
+        return false;
+    }
+    env.ket = env.cursor;
+    lab2: for (;;) {
+        var v_3 = env.limit - env.cursor;
+        lab3: for (;;) {
+            if (!env.eq_s_b("\u{00E9}")) {
+                break lab3;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        if (!env.eq_s_b("\u{00E8}")) {
+            return false;
+        }
+        break lab2;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_from("e")) {
+        return false;
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p2: 0,
+        i_p1: 0,
+        i_pV: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_prelude(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        lab3: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            lab4: for (;;) {
+                var v_5 = env.limit - env.cursor;
+                lab5: for (;;) {
+                    var v_6 = env.limit - env.cursor;
+                    lab6: for (;;) {
+                        if (!r_standard_suffix(env, context)) {
+                            break lab6;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_6;
+                    lab7: for (;;) {
+                        if (!r_i_verb_suffix(env, context)) {
+                            break lab7;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!r_verb_suffix(env, context)) {
+                        break lab4;
+                    }
+                    break lab5;
+                }
+                env.cursor = env.limit - v_5;
+                var v_7 = env.limit - env.cursor;
+                lab8: for (;;) {
+                    env.ket = env.cursor;
+                    lab9: for (;;) {
+                        var v_8 = env.limit - env.cursor;
+                        lab10: for (;;) {
+                            if (!env.eq_s_b("Y")) {
+                                break lab10;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_from("i")) {
+                                return false;
+                            }
+                            break lab9;
+                        }
+                        env.cursor = env.limit - v_8;
+                        if (!env.eq_s_b("\u{00E7}")) {
+                            env.cursor = env.limit - v_7;
+                            break lab8;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_from("c")) {
+                            return false;
+                        }
+                        break lab9;
+                    }
+                    break lab8;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_4;
+            if (!r_residual_suffix(env, context)) {
+                break lab2;
+            }
+            break lab3;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_9 = env.limit - env.cursor;
+    lab11: for (;;) {
+        if (!r_un_double(env, context)) {
+            break lab11;
+        }
+        break lab11;
+    }
+    env.cursor = env.limit - v_9;
+    var v_10 = env.limit - env.cursor;
+    lab12: for (;;) {
+        if (!r_un_accent(env, context)) {
+            break lab12;
+        }
+        break lab12;
+    }
+    env.cursor = env.limit - v_10;
+    env.cursor = env.limit_backward;
+    var v_11 = env.cursor;
+    lab13: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab13;
+        }
+        break lab13;
+    }
+    env.cursor = v_11;
+    return true;
+}
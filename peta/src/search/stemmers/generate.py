@@ -0,0 +1,148 @@
+#!/usr/bin/env python3
+"""Generate the JavaScript Snowball stemmers from rust-stemmers.
+
+The search client has to stem query words exactly like the indexer, so the
+algorithms are translated line by line from the Rust code rust-stemmers
+generated with the Snowball compiler, and run on `snowball.js`, a port of
+its `SnowballEnv`. Each file carries the Snowball and rust-stemmers copyright
+lines, and both licenses are written to LICENSE.
+
+    python3 generate.py ~/.cargo/registry/src/*/rust-stemmers-1.2.0
+"""
+
+import pathlib
+import re
+import sys
+
+VERSION = "1.2.0"
+
+HEADER = """\
+// Snowball stemmer for {title}, generated by generate.py from rust-stemmers
+// {version} (src/snowball/algorithms/{name}.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+{copyright}
+"""
+
+# Ported from the Snowball algorithms (BSD-3-Clause) as compiled to Rust by
+# rust-stemmers (MIT), see LICENSE
+COPYRIGHT = [
+    "Snowball: Copyright (c) 2001, Dr Martin Porter",
+    "Snowball: Copyright (c) 2004,2005, Richard Boulton",
+    "Licensed under the BSD 3-Clause License.",
+    "rust-stemmers: Copyright (c) 2017 Jakob Demler",
+    "Licensed under the MIT License. See LICENSE in this directory.",
+]
+
+LICENSE = """\
+The stemmers in this directory are ported from the Snowball algorithms, as
+compiled to Rust by rust-stemmers {version}, under the licenses below.
+
+Snowball (https://snowballstem.org), BSD 3-Clause License:
+
+{snowball}
+
+rust-stemmers (https://github.com/CurrySoftware/rust-stemmers), MIT License:
+
+{rust_stemmers}
+"""
+
+
+def translate(source):
+    out = []
+    loops = 0
+    in_struct = False
+    for raw in source.splitlines():
+        line = raw.rstrip()
+        body = line.strip()
+        indent = line[: len(line) - len(line.lstrip())]
+
+        if body.startswith("//") or body.startswith("#") or body.startswith("use "):
+            continue
+        if body.startswith("struct Context"):
+            in_struct = True
+            continue
+        if in_struct:
+            in_struct = body != "}"
+            continue
+
+        # Among tables and groupings
+        body = re.sub(r"^static (\w+): &'static \[Among<Context>; \d+\] = &\[$", r"const \1 = among([", body)
+        body = re.sub(r"^static (\w+): &'static \[u8; \d+\] = &\[(.*)\];$", r"const \1 = [\2];", body)
+        if body.startswith("Among("):
+            body = re.sub(r"^Among\((.*)\),$", r"[\1],", body)
+            body = re.sub(r"Some\(&(\w+)\)\],$", r"\1],", body)
+            body = re.sub(r"None\],$", "null],", body)
+        if body == "];" and out and out[-1].strip().endswith("],"):
+            body = "]);"
+
+        # Functions and the context of the entry point
+        body = re.sub(r"^(?:pub )?fn (\w+)\(env: &mut SnowballEnv(, context: &mut Context)?\) -> bool \{$",
+                      lambda m: "function {}(env{}) {{".format(m.group(1), ", context" if m.group(2) else ""),
+                      body)
+        body = body.replace("let mut context = &mut Context {", "const context = {")
+        body = re.sub(r"^(\w+): String::new\(\),$", r"\1: [],", body)
+
+        # Loops and labels
+        body = re.sub(r"^'(\w+): loop ?\{$", r"\1: for (;;) {", body)
+        m = re.match(r"^(?:'(\w+): )?for _ in (\S+)\.\.(.+) \{$", body)
+        if m:
+            loops += 1
+            label = m.group(1) + ": " if m.group(1) else ""
+            body = "{}for (let _i{n} = {}, _n{n} = {}; _i{n} < _n{n}; _i{n}++) {{".format(
+                label, m.group(2), m.group(3), n=loops)
+        body = re.sub(r"(break|continue) '(\w+);", r"\1 \2;", body)
+
+        # Expressions
+        body = body.replace("env.current.chars().count()", "env.char_count()")
+        body = re.sub(r" as (?:i32|usize|u32)\b", "", body)
+        body = re.sub(r"(context\.\w+)\.is_empty\(\)", r"(\1.length === 0)", body)
+        body = re.sub(r"\(&(\"|context\.)", r"(\1", body)
+        body = re.sub(r", &context\.", ", context.", body)
+        body = re.sub(r"^let \((\w+), (\w+)\) = \((.*)\);$", r"var [\1, \2] = [\3];", body)
+        body = re.sub(r"^let (?:mut )?", "var ", body)
+
+        # Conditions
+        m = re.match(r"^(\} else if|if) (.*?) ?\{$", body)
+        if m and not m.group(2).startswith("("):
+            body = "{} ({}) {{".format(m.group(1), m.group(2))
+        elif m and not balanced_parens(m.group(2)):
+            body = "{} ({}) {{".format(m.group(1), m.group(2))
+
+        out.append(indent + body if body else "")
+
+    text = "\n".join(out)
+    return re.sub(r"\n{3,}", "\n\n", text).strip() + "\n"
+
+
+def balanced_parens(expr):
+    """Whether `expr` is wrapped in one pair of parentheses"""
+    if not (expr.startswith("(") and expr.endswith(")")):
+        return False
+    depth = 0
+    for i, c in enumerate(expr):
+        depth += {"(": 1, ")": -1}.get(c, 0)
+        if depth == 0 and i < len(expr) - 1:
+            return False
+    return True
+
+
+def main():
+    crate = pathlib.Path(sys.argv[1])
+    algorithms = crate / "src" / "snowball" / "algorithms"
+    target = pathlib.Path(__file__).parent
+    copyright = "\n".join("// " + line for line in COPYRIGHT)
+    for path in sorted(algorithms.glob("*.rs")):
+        if path.stem == "mod":
+            continue
+        header = HEADER.format(title=path.stem.capitalize(), version=VERSION, name=path.stem, copyright=copyright)
+        (target / (path.stem + ".js")).write_text(header + "\n" + translate(path.read_text()))
+
+    (target / "LICENSE").write_text(LICENSE.format(
+        version=VERSION,
+        snowball=(crate / "algorithms" / "LICENSE").read_text().strip(),
+        rust_stemmers=(crate / "LICENSE").read_text().strip()))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,524 @@
+// Snowball stemmer for German, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/german.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 6, null],
+    ["U", 0, 2, null],
+    ["Y", 0, 1, null],
+    ["\u{00E4}", 0, 3, null],
+    ["\u{00F6}", 0, 4, null],
+    ["\u{00FC}", 0, 5, null],
+]);
+
+const A_1 = among([
+    ["e", -1, 2, null],
+    ["em", -1, 1, null],
+    ["en", -1, 2, null],
+    ["ern", -1, 1, null],
+    ["er", -1, 1, null],
+    ["s", -1, 3, null],
+    ["es", 5, 2, null],
+]);
+
+const A_2 = among([
+    ["en", -1, 1, null],
+    ["er", -1, 1, null],
+    ["st", -1, 2, null],
+    ["est", 2, 1, null],
+]);
+
+const A_3 = among([
+    ["ig", -1, 1, null],
+    ["lich", -1, 1, null],
+]);
+
+const A_4 = among([
+    ["end", -1, 1, null],
+    ["ig", -1, 2, null],
+    ["ung", -1, 1, null],
+    ["lich", -1, 3, null],
+    ["isch", -1, 2, null],
+    ["ik", -1, 2, null],
+    ["heit", -1, 3, null],
+    ["keit", -1, 4, null],
+]);
+
+const G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32, 8];
+
+const G_s_ending = [117, 30, 5];
+
+const G_st_ending = [117, 30, 4];
+
+function r_prelude(env, context) {
+    var v_1 = env.cursor;
+    replab0: for (;;) {
+        var v_2 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            lab2: for (;;) {
+                var v_3 = env.cursor;
+                lab3: for (;;) {
+                    env.bra = env.cursor;
+                    if (!env.eq_s("\u{00DF}")) {
+                        break lab3;
+                    }
+                    env.ket = env.cursor;
+                    if (!env.slice_from("ss")) {
+                        return false;
+                    }
+                    break lab2;
+                }
+                env.cursor = v_3;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+                break lab2;
+            }
+            continue replab0;
+        }
+        env.cursor = v_2;
+        break replab0;
+    }
+    env.cursor = v_1;
+    replab4: for (;;) {
+        var v_4 = env.cursor;
+        lab5: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            golab6: for (;;) {
+                var v_5 = env.cursor;
+                lab7: for (;;) {
+                    if (!env.in_grouping(G_v, 97, 252)) {
+                        break lab7;
+                    }
+                    env.bra = env.cursor;
+                    lab8: for (;;) {
+                        var v_6 = env.cursor;
+                        lab9: for (;;) {
+                            if (!env.eq_s("u")) {
+                                break lab9;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 252)) {
+                                break lab9;
+                            }
+                            if (!env.slice_from("U")) {
+                                return false;
+                            }
+                            break lab8;
+                        }
+                        env.cursor = v_6;
+                        if (!env.eq_s("y")) {
+                            break lab7;
+                        }
+                        env.ket = env.cursor;
+                        if (!env.in_grouping(G_v, 97, 252)) {
+                            break lab7;
+                        }
+                        if (!env.slice_from("Y")) {
+                            return false;
+                        }
+                        break lab8;
+                    }
+                    env.cursor = v_5;
+                    break golab6;
+                }
+                env.cursor = v_5;
+                if (env.cursor >= env.limit) {
+                    break lab5;
+                }
+                env.next_char();
+            }
+            continue replab4;
+        }
+        env.cursor = v_4;
+        break replab4;
+    }
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    var c = env.byte_index_for_hop(3);
+    if (0 > c || c > env.limit) {
+        return false;
+    }
+    env.cursor = c;
+    context.i_x = env.cursor;
+    env.cursor = v_1;
+    golab0: for (;;) {
+        lab1: for (;;) {
+            if (!env.in_grouping(G_v, 97, 252)) {
+                break lab1;
+            }
+            break golab0;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab2: for (;;) {
+        lab3: for (;;) {
+            if (!env.out_grouping(G_v, 97, 252)) {
+                break lab3;
+            }
+            break golab2;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p1 = env.cursor;
+    lab4: for (;;) {
+        if (!(context.i_p1 < context.i_x)) {
+            break lab4;
+        }
+        context.i_p1 = context.i_x;
+        break lab4;
+    }
+    golab5: for (;;) {
+        lab6: for (;;) {
+            if (!env.in_grouping(G_v, 97, 252)) {
+                break lab6;
+            }
+            break golab5;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab7: for (;;) {
+        lab8: for (;;) {
+            if (!env.out_grouping(G_v, 97, 252)) {
+                break lab8;
+            }
+            break golab7;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p2 = env.cursor;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i3 = 0, _n3 = 1; _i3 < _n3; _i3++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("y")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_from("a")) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (!env.slice_from("o")) {
+                    return false;
+                }
+            } else if (among_var == 5) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 6) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_1, context);
+        if (among_var == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!r_R1(env, context)) {
+            break lab0;
+        }
+        if (among_var == 0) {
+            break lab0;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab1: for (;;) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("s")) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!env.eq_s_b("nis")) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab1;
+            }
+        } else if (among_var == 3) {
+            if (!env.in_grouping_b(G_s_ending, 98, 116)) {
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_2, context);
+        if (among_var == 0) {
+            break lab2;
+        }
+        env.bra = env.cursor;
+        if (!r_R1(env, context)) {
+            break lab2;
+        }
+        if (among_var == 0) {
+            break lab2;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.in_grouping_b(G_st_ending, 98, 116)) {
+                break lab2;
+            }
+            var c = env.byte_index_for_hop(-3);
+            if (env.limit_backward > c || c > env.limit) {
+                break lab2;
+            }
+            env.cursor = c;
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_4, context);
+        if (among_var == 0) {
+            break lab3;
+        }
+        env.bra = env.cursor;
+        if (!r_R2(env, context)) {
+            break lab3;
+        }
+        if (among_var == 0) {
+            break lab3;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_5 = env.limit - env.cursor;
+            lab4: for (;;) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("ig")) {
+                    env.cursor = env.limit - v_5;
+                    break lab4;
+                }
+                env.bra = env.cursor;
+                var v_6 = env.limit - env.cursor;
+                lab5: for (;;) {
+                    if (!env.eq_s_b("e")) {
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_5;
+                    break lab4;
+                }
+                env.cursor = env.limit - v_6;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_5;
+                    break lab4;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab4;
+            }
+        } else if (among_var == 2) {
+            var v_7 = env.limit - env.cursor;
+            lab6: for (;;) {
+                if (!env.eq_s_b("e")) {
+                    break lab6;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_7;
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_8 = env.limit - env.cursor;
+            lab7: for (;;) {
+                env.ket = env.cursor;
+                lab8: for (;;) {
+                    var v_9 = env.limit - env.cursor;
+                    lab9: for (;;) {
+                        if (!env.eq_s_b("er")) {
+                            break lab9;
+                        }
+                        break lab8;
+                    }
+                    env.cursor = env.limit - v_9;
+                    if (!env.eq_s_b("en")) {
+                        env.cursor = env.limit - v_8;
+                        break lab7;
+                    }
+                    break lab8;
+                }
+                env.bra = env.cursor;
+                if (!r_R1(env, context)) {
+                    env.cursor = env.limit - v_8;
+                    break lab7;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab7;
+            }
+        } else if (among_var == 4) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_10 = env.limit - env.cursor;
+            lab10: for (;;) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_3, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_10;
+                    break lab10;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_10;
+                    break lab10;
+                }
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_10;
+                    break lab10;
+                } else if (among_var == 1) {
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab10;
+            }
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_x: 0,
+        i_p2: 0,
+        i_p1: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_prelude(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_standard_suffix(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    env.cursor = env.limit_backward;
+    var v_4 = env.cursor;
+    lab3: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = v_4;
+    return true;
+}
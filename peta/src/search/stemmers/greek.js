@@ -0,0 +1,3148 @@
+// Snowball stemmer for Greek, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/greek.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 25, null],
+    ["\u{03C2}", 0, 18, null],
+    ["\u{0386}", 0, 1, null],
+    ["\u{0388}", 0, 5, null],
+    ["\u{0389}", 0, 7, null],
+    ["\u{038A}", 0, 9, null],
+    ["\u{03CA}", 0, 7, null],
+    ["\u{03CB}", 0, 20, null],
+    ["\u{038C}", 0, 15, null],
+    ["\u{03CC}", 0, 15, null],
+    ["\u{03CD}", 0, 20, null],
+    ["\u{038E}", 0, 20, null],
+    ["\u{03CE}", 0, 24, null],
+    ["\u{038F}", 0, 24, null],
+    ["\u{0390}", 0, 7, null],
+    ["\u{0391}", 0, 1, null],
+    ["\u{0392}", 0, 2, null],
+    ["\u{0393}", 0, 3, null],
+    ["\u{0394}", 0, 4, null],
+    ["\u{0395}", 0, 5, null],
+    ["\u{0396}", 0, 6, null],
+    ["\u{0397}", 0, 7, null],
+    ["\u{0398}", 0, 8, null],
+    ["\u{0399}", 0, 9, null],
+    ["\u{039A}", 0, 10, null],
+    ["\u{039B}", 0, 11, null],
+    ["\u{039C}", 0, 12, null],
+    ["\u{039D}", 0, 13, null],
+    ["\u{039E}", 0, 14, null],
+    ["\u{039F}", 0, 15, null],
+    ["\u{03A0}", 0, 16, null],
+    ["\u{03A1}", 0, 17, null],
+    ["\u{03A3}", 0, 18, null],
+    ["\u{03A4}", 0, 19, null],
+    ["\u{03A5}", 0, 20, null],
+    ["\u{03A6}", 0, 21, null],
+    ["\u{03A7}", 0, 22, null],
+    ["\u{03A8}", 0, 23, null],
+    ["\u{03A9}", 0, 24, null],
+    ["\u{03AA}", 0, 9, null],
+    ["\u{03AB}", 0, 20, null],
+    ["\u{03AC}", 0, 1, null],
+    ["\u{03AD}", 0, 5, null],
+    ["\u{03AE}", 0, 7, null],
+    ["\u{03AF}", 0, 9, null],
+    ["\u{03B0}", 0, 20, null],
+]);
+
+const A_1 = among([
+    ["\u{03BA}\u{03B1}\u{03B8}\u{03B5}\u{03C3}\u{03C4}\u{03C9}\u{03C3}", -1, 10, null],
+    ["\u{03C6}\u{03C9}\u{03C3}", -1, 9, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B1}\u{03C3}", -1, 7, null],
+    ["\u{03C4}\u{03B5}\u{03C1}\u{03B1}\u{03C3}", -1, 8, null],
+    ["\u{03BA}\u{03C1}\u{03B5}\u{03B1}\u{03C3}", -1, 6, null],
+    ["\u{03BA}\u{03B1}\u{03B8}\u{03B5}\u{03C3}\u{03C4}\u{03C9}\u{03C4}\u{03BF}\u{03C3}", -1, 10, null],
+    ["\u{03C6}\u{03C9}\u{03C4}\u{03BF}\u{03C3}", -1, 9, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03BF}\u{03C3}", -1, 7, null],
+    ["\u{03C4}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03BF}\u{03C3}", -1, 8, null],
+    ["\u{03BA}\u{03C1}\u{03B5}\u{03B1}\u{03C4}\u{03BF}\u{03C3}", -1, 6, null],
+    ["\u{03B3}\u{03B5}\u{03B3}\u{03BF}\u{03BD}\u{03BF}\u{03C4}\u{03BF}\u{03C3}", -1, 11, null],
+    ["\u{03B3}\u{03B5}\u{03B3}\u{03BF}\u{03BD}\u{03BF}\u{03C3}", -1, 11, null],
+    ["\u{03C6}\u{03B1}\u{03B3}\u{03B9}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03C3}\u{03BA}\u{03B1}\u{03B3}\u{03B9}\u{03BF}\u{03C5}", -1, 2, null],
+    ["\u{03C3}\u{03BF}\u{03B3}\u{03B9}\u{03BF}\u{03C5}", -1, 4, null],
+    ["\u{03C4}\u{03B1}\u{03C4}\u{03BF}\u{03B3}\u{03B9}\u{03BF}\u{03C5}", -1, 5, null],
+    ["\u{03BF}\u{03BB}\u{03BF}\u{03B3}\u{03B9}\u{03BF}\u{03C5}", -1, 3, null],
+    ["\u{03BA}\u{03B1}\u{03B8}\u{03B5}\u{03C3}\u{03C4}\u{03C9}\u{03C4}\u{03B1}", -1, 10, null],
+    ["\u{03C6}\u{03C9}\u{03C4}\u{03B1}", -1, 9, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03B1}", -1, 7, null],
+    ["\u{03C4}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03B1}", -1, 8, null],
+    ["\u{03BA}\u{03C1}\u{03B5}\u{03B1}\u{03C4}\u{03B1}", -1, 6, null],
+    ["\u{03B3}\u{03B5}\u{03B3}\u{03BF}\u{03BD}\u{03BF}\u{03C4}\u{03B1}", -1, 11, null],
+    ["\u{03C6}\u{03B1}\u{03B3}\u{03B9}\u{03B1}", -1, 1, null],
+    ["\u{03C3}\u{03BA}\u{03B1}\u{03B3}\u{03B9}\u{03B1}", -1, 2, null],
+    ["\u{03C3}\u{03BF}\u{03B3}\u{03B9}\u{03B1}", -1, 4, null],
+    ["\u{03C4}\u{03B1}\u{03C4}\u{03BF}\u{03B3}\u{03B9}\u{03B1}", -1, 5, null],
+    ["\u{03BF}\u{03BB}\u{03BF}\u{03B3}\u{03B9}\u{03B1}", -1, 3, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03B7}", -1, 7, null],
+    ["\u{03BA}\u{03B1}\u{03B8}\u{03B5}\u{03C3}\u{03C4}\u{03C9}\u{03C4}\u{03C9}\u{03BD}", -1, 10, null],
+    ["\u{03C6}\u{03C9}\u{03C4}\u{03C9}\u{03BD}", -1, 9, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03C9}\u{03BD}", -1, 7, null],
+    ["\u{03C4}\u{03B5}\u{03C1}\u{03B1}\u{03C4}\u{03C9}\u{03BD}", -1, 8, null],
+    ["\u{03BA}\u{03C1}\u{03B5}\u{03B1}\u{03C4}\u{03C9}\u{03BD}", -1, 6, null],
+    ["\u{03B3}\u{03B5}\u{03B3}\u{03BF}\u{03BD}\u{03BF}\u{03C4}\u{03C9}\u{03BD}", -1, 11, null],
+    ["\u{03C6}\u{03B1}\u{03B3}\u{03B9}\u{03C9}\u{03BD}", -1, 1, null],
+    ["\u{03C3}\u{03BA}\u{03B1}\u{03B3}\u{03B9}\u{03C9}\u{03BD}", -1, 2, null],
+    ["\u{03C3}\u{03BF}\u{03B3}\u{03B9}\u{03C9}\u{03BD}", -1, 4, null],
+    ["\u{03C4}\u{03B1}\u{03C4}\u{03BF}\u{03B3}\u{03B9}\u{03C9}\u{03BD}", -1, 5, null],
+    ["\u{03BF}\u{03BB}\u{03BF}\u{03B3}\u{03B9}\u{03C9}\u{03BD}", -1, 3, null],
+]);
+
+const A_2 = among([
+    ["\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03BE}\u{03B1}\u{03BD}\u{03B1}\u{03C0}\u{03B1}", 0, 1, null],
+    ["\u{03B5}\u{03C0}\u{03B1}", 0, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B9}\u{03C0}\u{03B1}", 0, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B1}\u{03BC}\u{03C0}\u{03B1}", 0, 1, null],
+    ["\u{03B5}\u{03BC}\u{03C0}\u{03B1}", 0, 1, null],
+    ["\u{03B4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03B8}\u{03C1}\u{03BF}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B1}\u{03B8}\u{03C1}\u{03BF}", 7, 1, null],
+]);
+
+const A_3 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03B9}\u{03BC}\u{03C0}", 0, 1, null],
+    ["\u{03C1}", -1, 1, null],
+    ["\u{03C0}\u{03C1}", 2, 1, null],
+    ["\u{03BC}\u{03C0}\u{03C1}", 3, 1, null],
+    ["\u{03B1}\u{03C1}\u{03C1}", 2, 1, null],
+    ["\u{03B3}\u{03BB}\u{03C5}\u{03BA}\u{03C5}\u{03C1}", 2, 1, null],
+    ["\u{03C0}\u{03BF}\u{03BB}\u{03C5}\u{03C1}", 2, 1, null],
+    ["\u{03B1}\u{03BC}\u{03C0}\u{03B1}\u{03C1}", 2, 1, null],
+    ["\u{03BC}\u{03B1}\u{03C1}", 2, 1, null],
+    ["\u{03B3}\u{03BA}\u{03C1}", 2, 1, null],
+    ["\u{03C0}\u{03B9}\u{03C0}\u{03B5}\u{03C1}\u{03BF}\u{03C1}", 2, 1, null],
+    ["\u{03B2}\u{03BF}\u{03BB}\u{03B2}\u{03BF}\u{03C1}", 2, 1, null],
+    ["\u{03B3}\u{03BB}\u{03C5}\u{03BA}\u{03BF}\u{03C1}", 2, 1, null],
+    ["\u{03BB}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B2}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03B8}\u{03C5}\u{03C1}\u{03B9}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03C1}\u{03BA}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03C1}\u{03BA}", -1, 1, null],
+    ["\u{03BB}", -1, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03BA}\u{03BF}\u{03C1}\u{03BD}", -1, 1, null],
+]);
+
+const A_4 = among([
+    ["\u{03B9}\u{03B6}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B5}\u{03B9}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03C9}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B1}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B5}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03BF}\u{03C5}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03BF}\u{03C5}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B5}\u{03B9}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03BF}\u{03C5}\u{03BD}", -1, 1, null],
+    ["\u{03B9}\u{03B6}\u{03B1}\u{03BD}", -1, 1, null],
+]);
+
+const A_5 = among([
+    ["\u{03C3}", -1, 1, null],
+    ["\u{03C7}", -1, 1, null],
+    ["\u{03C5}\u{03C8}", -1, 1, null],
+    ["\u{03B6}\u{03C9}", -1, 1, null],
+    ["\u{03B2}\u{03B9}", -1, 1, null],
+    ["\u{03BB}\u{03B9}", -1, 1, null],
+    ["\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B5}\u{03BD}", -1, 1, null],
+]);
+
+const A_6 = among([
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B1}", -1, 1, null],
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B5}", -1, 1, null],
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03C9}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03BD}", -1, 1, null],
+]);
+
+const A_7 = among([
+    ["\u{03BE}\u{03B1}\u{03BD}\u{03B1}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B5}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B9}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B1}\u{03BC}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B5}\u{03BC}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03C7}\u{03B1}\u{03C1}\u{03C4}\u{03BF}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B5}\u{03BE}\u{03B1}\u{03C1}\u{03C7}\u{03B1}", -1, 1, null],
+    ["\u{03C0}\u{03B5}", -1, 1, null],
+    ["\u{03B5}\u{03C0}\u{03B5}", 7, 1, null],
+    ["\u{03BC}\u{03B5}\u{03C4}\u{03B5}\u{03C0}\u{03B5}", 8, 1, null],
+    ["\u{03B5}\u{03C3}\u{03B5}", -1, 1, null],
+    ["\u{03BA}\u{03BB}\u{03B5}", -1, 1, null],
+    ["\u{03B5}\u{03C3}\u{03C9}\u{03BA}\u{03BB}\u{03B5}", 11, 1, null],
+    ["\u{03B5}\u{03BA}\u{03BB}\u{03B5}", 11, 1, null],
+    ["\u{03B1}\u{03C0}\u{03B5}\u{03BA}\u{03BB}\u{03B5}", 13, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03BA}\u{03BB}\u{03B5}", 11, 1, null],
+    ["\u{03B4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03B8}\u{03C1}\u{03BF}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B1}\u{03B8}\u{03C1}\u{03BF}", 17, 1, null],
+]);
+
+const A_8 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03BB}\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03B4}\u{03B7}\u{03BC}\u{03BF}\u{03BA}\u{03C1}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03B1}\u{03C6}", -1, 1, null],
+    ["\u{03B3}\u{03B9}\u{03B3}\u{03B1}\u{03BD}\u{03C4}\u{03BF}\u{03B1}\u{03C6}", 3, 1, null],
+    ["\u{03B3}\u{03B5}", -1, 1, null],
+    ["\u{03B3}\u{03BA}\u{03B5}", -1, 1, null],
+    ["\u{03B3}\u{03BA}", -1, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03C5}\u{03BA}\u{03B1}\u{03BC}", 8, 1, null],
+    ["\u{03BA}\u{03BF}\u{03BC}", 8, 1, null],
+    ["\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03BF}\u{03BB}\u{03BF}", -1, 1, null],
+]);
+
+const A_9 = among([
+    ["\u{03B9}\u{03C3}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B1}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B1}\u{03BD}", -1, 1, null],
+]);
+
+const A_10 = among([
+    ["\u{03BE}\u{03B1}\u{03BD}\u{03B1}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B5}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B9}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B1}\u{03BC}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B5}\u{03BC}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03C7}\u{03B1}\u{03C1}\u{03C4}\u{03BF}\u{03C0}\u{03B1}", -1, 1, null],
+    ["\u{03B5}\u{03BE}\u{03B1}\u{03C1}\u{03C7}\u{03B1}", -1, 1, null],
+    ["\u{03C0}\u{03B5}", -1, 1, null],
+    ["\u{03B5}\u{03C0}\u{03B5}", 7, 1, null],
+    ["\u{03BC}\u{03B5}\u{03C4}\u{03B5}\u{03C0}\u{03B5}", 8, 1, null],
+    ["\u{03B5}\u{03C3}\u{03B5}", -1, 1, null],
+    ["\u{03BA}\u{03BB}\u{03B5}", -1, 1, null],
+    ["\u{03B5}\u{03C3}\u{03C9}\u{03BA}\u{03BB}\u{03B5}", 11, 1, null],
+    ["\u{03B5}\u{03BA}\u{03BB}\u{03B5}", 11, 1, null],
+    ["\u{03B1}\u{03C0}\u{03B5}\u{03BA}\u{03BB}\u{03B5}", 13, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03BA}\u{03BB}\u{03B5}", 11, 1, null],
+    ["\u{03B4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03B8}\u{03C1}\u{03BF}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B1}\u{03B8}\u{03C1}\u{03BF}", 17, 1, null],
+]);
+
+const A_11 = among([
+    ["\u{03B9}\u{03C3}\u{03B5}\u{03B9}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C9}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B5}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BF}\u{03C5}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BF}\u{03C5}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03B5}\u{03B9}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BF}\u{03C5}\u{03BD}", -1, 1, null],
+]);
+
+const A_12 = among([
+    ["\u{03C3}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03C3}\u{03B5}", 0, 1, null],
+    ["\u{03C0}\u{03BB}\u{03B5}", -1, 1, null],
+    ["\u{03BA}\u{03BB}\u{03B5}", -1, 1, null],
+    ["\u{03B5}\u{03C3}\u{03C9}\u{03BA}\u{03BB}\u{03B5}", 3, 1, null],
+    ["\u{03B4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B1}\u{03B8}\u{03C1}\u{03BF}", -1, 1, null],
+]);
+
+const A_13 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03B5}\u{03C5}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03C0}", 0, 1, null],
+    ["\u{03B5}\u{03BC}\u{03C0}", 0, 1, null],
+    ["\u{03B3}\u{03C5}\u{03C1}", -1, 1, null],
+    ["\u{03C7}\u{03C1}", -1, 1, null],
+    ["\u{03C7}\u{03C9}\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03BF}\u{03C1}", -1, 1, null],
+    ["\u{03C7}\u{03C4}", -1, 1, null],
+    ["\u{03B1}\u{03C7}\u{03C4}", 9, 1, null],
+    ["\u{03BA}\u{03C4}", -1, 1, null],
+    ["\u{03B1}\u{03BA}\u{03C4}", 11, 1, null],
+    ["\u{03C3}\u{03C7}", -1, 1, null],
+    ["\u{03B1}\u{03C3}\u{03C7}", 13, 1, null],
+    ["\u{03C4}\u{03B1}\u{03C7}", -1, 1, null],
+    ["\u{03C5}\u{03C8}", -1, 1, null],
+    ["\u{03B1}\u{03C4}\u{03B1}", -1, 1, null],
+    ["\u{03C6}\u{03B1}", -1, 1, null],
+    ["\u{03B7}\u{03C6}\u{03B1}", 18, 1, null],
+    ["\u{03BB}\u{03C5}\u{03B3}", -1, 1, null],
+    ["\u{03BC}\u{03B5}\u{03B3}", -1, 1, null],
+    ["\u{03B7}\u{03B4}", -1, 1, null],
+    ["\u{03B5}\u{03C7}\u{03B8}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03B8}", -1, 1, null],
+    ["\u{03C3}\u{03BA}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03BA}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03BA}", -1, 1, null],
+    ["\u{03BA}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03C6}\u{03B9}\u{03BB}", -1, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03B3}\u{03B5}\u{03BC}", 30, 1, null],
+    ["\u{03B1}\u{03C7}\u{03BD}", -1, 1, null],
+]);
+
+const A_14 = among([
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03BF}\u{03C5}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03B7}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03B7}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03BF}\u{03B9}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03C9}\u{03BD}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03C4}\u{03BF}", -1, 1, null],
+]);
+
+const A_15 = among([
+    ["\u{03C3}\u{03B5}", -1, 1, null],
+    ["\u{03BC}\u{03B5}\u{03C4}\u{03B1}\u{03C3}\u{03B5}", 0, 1, null],
+    ["\u{03BC}\u{03B9}\u{03BA}\u{03C1}\u{03BF}\u{03C3}\u{03B5}", 0, 1, null],
+    ["\u{03B5}\u{03B3}\u{03BA}\u{03BB}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03BA}\u{03BB}\u{03B5}", -1, 1, null],
+]);
+
+const A_16 = among([
+    ["\u{03B4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03C4}\u{03B9}\u{03B4}\u{03B1}\u{03BD}\u{03B5}", 0, 1, null],
+]);
+
+const A_17 = among([
+    ["\u{03C4}\u{03BF}\u{03C0}\u{03B9}\u{03BA}", -1, 7, null],
+    ["\u{03C3}\u{03BA}\u{03B5}\u{03C0}\u{03C4}\u{03B9}\u{03BA}", -1, 6, null],
+    ["\u{03B3}\u{03BD}\u{03C9}\u{03C3}\u{03C4}\u{03B9}\u{03BA}", -1, 3, null],
+    ["\u{03B1}\u{03B3}\u{03BD}\u{03C9}\u{03C3}\u{03C4}\u{03B9}\u{03BA}", 2, 1, null],
+    ["\u{03B5}\u{03BA}\u{03BB}\u{03B5}\u{03BA}\u{03C4}\u{03B9}\u{03BA}", -1, 5, null],
+    ["\u{03B1}\u{03C4}\u{03BF}\u{03BC}\u{03B9}\u{03BA}", -1, 2, null],
+    ["\u{03B5}\u{03B8}\u{03BD}\u{03B9}\u{03BA}", -1, 4, null],
+    ["\u{03B8}\u{03B5}\u{03B1}\u{03C4}\u{03C1}\u{03B9}\u{03BD}", -1, 10, null],
+    ["\u{03B1}\u{03BB}\u{03B5}\u{03BE}\u{03B1}\u{03BD}\u{03B4}\u{03C1}\u{03B9}\u{03BD}", -1, 8, null],
+    ["\u{03B2}\u{03C5}\u{03B6}\u{03B1}\u{03BD}\u{03C4}\u{03B9}\u{03BD}", -1, 9, null],
+]);
+
+const A_18 = among([
+    ["\u{03B9}\u{03C3}\u{03BC}\u{03BF}\u{03C5}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BC}\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BC}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BC}\u{03BF}\u{03B9}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BC}\u{03C9}\u{03BD}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BC}\u{03BF}", -1, 1, null],
+]);
+
+const A_19 = among([
+    ["\u{03C3}", -1, 1, null],
+    ["\u{03C7}", -1, 1, null],
+]);
+
+const A_20 = among([
+    ["\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B9}\u{03B1}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03B4}\u{03B1}\u{03BA}\u{03B9}\u{03B1}", -1, 1, null],
+    ["\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B9}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03B4}\u{03B1}\u{03BA}\u{03B9}", -1, 1, null],
+]);
+
+const A_21 = among([
+    ["\u{03BA}\u{03B1}\u{03C4}\u{03C1}\u{03B1}\u{03C0}", -1, 1, null],
+    ["\u{03C1}", -1, 1, null],
+    ["\u{03B2}\u{03C1}", 1, 1, null],
+    ["\u{03BB}\u{03B1}\u{03B2}\u{03C1}", 2, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B2}\u{03C1}", 2, 1, null],
+    ["\u{03BC}\u{03B5}\u{03C1}", 1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B8}\u{03C1}", 1, 1, null],
+    ["\u{03BA}\u{03BF}\u{03C1}", 1, 1, null],
+    ["\u{03C3}", -1, 1, null],
+    ["\u{03BD}\u{03B1}\u{03B3}\u{03BA}\u{03B1}\u{03C3}", 8, 1, null],
+    ["\u{03BC}\u{03BF}\u{03C5}\u{03C3}\u{03C4}", -1, 1, null],
+    ["\u{03C1}\u{03C5}", -1, 1, null],
+    ["\u{03C6}", -1, 1, null],
+    ["\u{03C3}\u{03C6}", 12, 1, null],
+    ["\u{03B1}\u{03BB}\u{03B9}\u{03C3}\u{03C6}", 13, 1, null],
+    ["\u{03C7}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03BC}\u{03B2}", -1, 1, null],
+    ["\u{03C3}\u{03BB}\u{03BF}\u{03B2}", -1, 1, null],
+    ["\u{03C4}\u{03C3}\u{03B5}\u{03C7}\u{03BF}\u{03C3}\u{03BB}\u{03BF}\u{03B2}", 17, 1, null],
+    ["\u{03C4}\u{03B6}", -1, 1, null],
+    ["\u{03BA}", -1, 1, null],
+    ["\u{03C3}\u{03BA}", 20, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C0}\u{03B1}\u{03BA}", 20, 1, null],
+    ["\u{03C3}\u{03BF}\u{03BA}", 20, 1, null],
+    ["\u{03C0}\u{03BB}", -1, 1, null],
+    ["\u{03C6}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03BB}\u{03BF}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03C6}\u{03B1}\u{03C1}\u{03BC}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03B9}\u{03BC}", -1, 1, null],
+    ["\u{03BA}\u{03BB}\u{03B9}\u{03BC}", -1, 1, null],
+    ["\u{03C3}\u{03C0}\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03BA}\u{03BF}\u{03BD}", -1, 1, null],
+]);
+
+const A_22 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C4}\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03C4}\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03BD}\u{03C5}\u{03C6}", -1, 1, null],
+    ["\u{03B2}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C1}\u{03B4}", -1, 1, null],
+    ["\u{03B6}", -1, 1, null],
+    ["\u{03C3}\u{03BA}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B3}\u{03BB}", -1, 1, null],
+    ["\u{03C4}\u{03C1}\u{03B9}\u{03C0}\u{03BF}\u{03BB}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03BA}\u{03C1}\u{03C5}\u{03BD}", -1, 1, null],
+    ["\u{03B3}\u{03B9}\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03B7}\u{03B3}\u{03BF}\u{03C5}\u{03BC}\u{03B5}\u{03BD}", -1, 1, null],
+    ["\u{03BA}\u{03BF}\u{03BD}", -1, 1, null],
+]);
+
+const A_23 = among([
+    ["\u{03B9}\u{03C4}\u{03C3}\u{03B1}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C4}\u{03C3}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C4}\u{03C3}\u{03B1}", -1, 1, null],
+    ["\u{03B1}\u{03BA}\u{03B9}\u{03B1}", -1, 1, null],
+    ["\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B9}\u{03B1}", 3, 1, null],
+    ["\u{03B1}\u{03BA}\u{03B9}", -1, 1, null],
+    ["\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B9}", 5, 1, null],
+    ["\u{03B9}\u{03C4}\u{03C3}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_24 = among([
+    ["\u{03B9}\u{03C1}", -1, 1, null],
+    ["\u{03C8}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B1}\u{03B9}\u{03C6}\u{03BD}", -1, 1, null],
+    ["\u{03BF}\u{03BB}\u{03BF}", -1, 1, null],
+]);
+
+const A_25 = among([
+    ["\u{03B5}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03B9}\u{03C7}\u{03BD}", -1, 1, null],
+]);
+
+const A_26 = among([
+    ["\u{03B9}\u{03B4}\u{03B9}\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03B4}\u{03B9}\u{03C9}\u{03BD}", -1, 1, null],
+    ["\u{03B9}\u{03B4}\u{03B9}\u{03BF}", -1, 1, null],
+]);
+
+const A_27 = among([
+    ["\u{03C1}", -1, 1, null],
+    ["\u{03B9}\u{03B2}", -1, 1, null],
+    ["\u{03B4}", -1, 1, null],
+    ["\u{03BB}\u{03C5}\u{03BA}", -1, 1, null],
+    ["\u{03C6}\u{03C1}\u{03B1}\u{03B3}\u{03BA}", -1, 1, null],
+    ["\u{03BF}\u{03B2}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03BC}\u{03B7}\u{03BD}", -1, 1, null],
+]);
+
+const A_28 = among([
+    ["\u{03B9}\u{03C3}\u{03BA}\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BA}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BA}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03C3}\u{03BA}\u{03BF}", -1, 1, null],
+]);
+
+const A_29 = among([
+    ["\u{03B1}\u{03B4}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B1}\u{03B4}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_30 = among([
+    ["\u{03BC}\u{03C0}\u{03B1}\u{03BC}\u{03C0}", -1, -1, null],
+    ["\u{03BA}\u{03C5}\u{03C1}", -1, -1, null],
+    ["\u{03C0}\u{03B1}\u{03C4}\u{03B5}\u{03C1}", -1, -1, null],
+    ["\u{03C0}\u{03B5}\u{03B8}\u{03B5}\u{03C1}", -1, -1, null],
+    ["\u{03BD}\u{03C4}\u{03B1}\u{03BD}\u{03C4}", -1, -1, null],
+    ["\u{03B3}\u{03B9}\u{03B1}\u{03B3}\u{03B9}", -1, -1, null],
+    ["\u{03B8}\u{03B5}\u{03B9}", -1, -1, null],
+    ["\u{03BF}\u{03BA}", -1, -1, null],
+    ["\u{03BC}\u{03B1}\u{03BC}", -1, -1, null],
+    ["\u{03BC}\u{03B1}\u{03BD}", -1, -1, null],
+]);
+
+const A_31 = among([
+    ["\u{03B5}\u{03B4}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B5}\u{03B4}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_32 = among([
+    ["\u{03BA}\u{03C1}\u{03B1}\u{03C3}\u{03C0}", -1, 1, null],
+    ["\u{03C5}\u{03C0}", -1, 1, null],
+    ["\u{03B4}\u{03B1}\u{03C0}", -1, 1, null],
+    ["\u{03B3}\u{03B7}\u{03C0}", -1, 1, null],
+    ["\u{03B9}\u{03C0}", -1, 1, null],
+    ["\u{03B5}\u{03BC}\u{03C0}", -1, 1, null],
+    ["\u{03BF}\u{03C0}", -1, 1, null],
+    ["\u{03BC}\u{03B9}\u{03BB}", -1, 1, null],
+]);
+
+const A_33 = among([
+    ["\u{03BF}\u{03C5}\u{03B4}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03B4}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_34 = among([
+    ["\u{03C3}\u{03C0}", -1, 1, null],
+    ["\u{03C6}\u{03C1}", -1, 1, null],
+    ["\u{03C3}", -1, 1, null],
+    ["\u{03BB}\u{03B9}\u{03C7}", -1, 1, null],
+    ["\u{03C4}\u{03C1}\u{03B1}\u{03B3}", -1, 1, null],
+    ["\u{03C6}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03C1}\u{03BA}", -1, 1, null],
+    ["\u{03C3}\u{03BA}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03BB}\u{03B9}\u{03B1}\u{03BA}", -1, 1, null],
+    ["\u{03BB}\u{03BF}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03C6}\u{03BB}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C4}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B2}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03C7}\u{03BD}", -1, 1, null],
+    ["\u{03C0}\u{03BB}\u{03B5}\u{03BE}", -1, 1, null],
+]);
+
+const A_35 = among([
+    ["\u{03B5}\u{03C9}\u{03C3}", -1, 1, null],
+    ["\u{03B5}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_36 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03B4}", -1, 1, null],
+    ["\u{03B9}\u{03B4}", 2, 1, null],
+    ["\u{03B8}", -1, 1, null],
+    ["\u{03B3}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03BD}", -1, 1, null],
+]);
+
+const A_37 = among([
+    ["\u{03B9}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B9}\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_38 = among([
+    ["\u{03B9}\u{03BA}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B9}\u{03BA}\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03BA}\u{03C9}\u{03BD}", -1, 1, null],
+    ["\u{03B9}\u{03BA}\u{03BF}", -1, 1, null],
+]);
+
+const A_39 = among([
+    ["\u{03BA}\u{03B1}\u{03BB}\u{03C0}", -1, 1, null],
+    ["\u{03B3}\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03C0}\u{03BB}\u{03B9}\u{03B1}\u{03C4}\u{03C3}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C4}\u{03C3}", -1, 1, null],
+    ["\u{03C0}\u{03B9}\u{03C4}\u{03C3}", -1, 1, null],
+    ["\u{03C6}\u{03C5}\u{03C3}", -1, 1, null],
+    ["\u{03C7}\u{03B1}\u{03C3}", -1, 1, null],
+    ["\u{03BC}\u{03C0}\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03C3}\u{03B5}\u{03C1}\u{03C4}", -1, 1, null],
+    ["\u{03BC}\u{03C0}\u{03B1}\u{03B3}\u{03B9}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03BD}\u{03B9}\u{03C4}", -1, 1, null],
+    ["\u{03C0}\u{03B9}\u{03BA}\u{03B1}\u{03BD}\u{03C4}", -1, 1, null],
+    ["\u{03B5}\u{03BE}\u{03C9}\u{03B4}", -1, 1, null],
+    ["\u{03B1}\u{03B4}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C4}\u{03B1}\u{03B4}", 13, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B1}\u{03B4}", 13, 1, null],
+    ["\u{03B1}\u{03BD}\u{03C4}\u{03B9}\u{03B4}", -1, 1, null],
+    ["\u{03B5}\u{03BD}\u{03B4}", -1, 1, null],
+    ["\u{03C5}\u{03C0}\u{03BF}\u{03B4}", -1, 1, null],
+    ["\u{03C0}\u{03C1}\u{03C9}\u{03C4}\u{03BF}\u{03B4}", -1, 1, null],
+    ["\u{03C6}\u{03C5}\u{03BB}\u{03BF}\u{03B4}", -1, 1, null],
+    ["\u{03B7}\u{03B8}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B7}\u{03B8}", 21, 1, null],
+    ["\u{03BE}\u{03B9}\u{03BA}", -1, 1, null],
+    ["\u{03BC}\u{03BF}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B1}\u{03BC}\u{03BC}\u{03BF}\u{03C7}\u{03B1}\u{03BB}", 25, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03BF}\u{03BC}\u{03B7}\u{03BB}", -1, 1, null],
+    ["\u{03BC}\u{03C0}\u{03BF}\u{03BB}", -1, 1, null],
+    ["\u{03B2}\u{03C1}\u{03C9}\u{03BC}", -1, 1, null],
+    ["\u{03C4}\u{03C3}\u{03B1}\u{03BC}", -1, 1, null],
+    ["\u{03BC}\u{03C0}\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03BB}\u{03BB}\u{03B9}\u{03BD}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03C3}\u{03C4}\u{03B5}\u{03BB}\u{03BD}", -1, 1, null],
+    ["\u{03C6}\u{03B9}\u{03BB}\u{03BF}\u{03BD}", -1, 1, null],
+]);
+
+const A_40 = among([
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B1}\u{03B3}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03BA}\u{03B1}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03BC}\u{03B5}", 3, 1, null],
+]);
+
+const A_41 = among([
+    ["\u{03B1}\u{03BD}\u{03B1}\u{03C0}", -1, 1, null],
+    ["\u{03C0}\u{03B9}\u{03BA}\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03C3}\u{03C4}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03C4}", -1, 1, null],
+    ["\u{03C7}", -1, 1, null],
+    ["\u{03C3}\u{03B9}\u{03C7}", 4, 1, null],
+    ["\u{03B2}\u{03BF}\u{03C5}\u{03B2}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03B8}", -1, 1, null],
+    ["\u{03BE}\u{03B5}\u{03B8}", -1, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03B8}", -1, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03BA}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BB}", -1, 1, null],
+]);
+
+const A_42 = among([
+    ["\u{03C4}\u{03C1}", -1, 1, null],
+    ["\u{03C4}\u{03C3}", -1, 1, null],
+]);
+
+const A_43 = among([
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BD}\u{03C4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C5}\u{03BD}\u{03C4}\u{03B1}\u{03BD}\u{03B5}", 2, 1, null],
+    ["\u{03BF}\u{03BD}\u{03C4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BD}\u{03C4}\u{03B1}\u{03BD}\u{03B5}", 4, 1, null],
+    ["\u{03BF}\u{03C4}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C4}\u{03B1}\u{03BD}\u{03B5}", 6, 1, null],
+    ["\u{03B1}\u{03B3}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03BA}\u{03B1}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03BD}\u{03B5}", 9, 1, null],
+]);
+
+const A_44 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03C3}\u{03C0}", 0, 1, null],
+    ["\u{03C0}\u{03BF}\u{03BB}\u{03C5}\u{03B4}\u{03B1}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03B4}\u{03B1}\u{03C0}", 0, 1, null],
+    ["\u{03C7}\u{03B1}\u{03BC}\u{03B7}\u{03BB}\u{03BF}\u{03B4}\u{03B1}\u{03C0}", 0, 1, null],
+    ["\u{03C4}\u{03C3}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03BA}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03C5}\u{03C0}\u{03BF}\u{03BA}\u{03BF}\u{03C0}", 6, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B9}\u{03C4}\u{03C1}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C1}", -1, 1, null],
+    ["\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03B2}\u{03B5}\u{03C4}\u{03B5}\u{03C1}", 10, 1, null],
+    ["\u{03B3}\u{03B5}\u{03C1}", 10, 1, null],
+    ["\u{03BB}\u{03BF}\u{03C5}\u{03B8}\u{03B7}\u{03C1}", -1, 1, null],
+    ["\u{03BA}\u{03BF}\u{03C1}\u{03BC}\u{03BF}\u{03C1}", -1, 1, null],
+    ["\u{03C3}", -1, 1, null],
+    ["\u{03C3}\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B1}\u{03C4}\u{03C3}", 15, 1, null],
+    ["\u{03B8}\u{03C5}\u{03C3}", 15, 1, null],
+    ["\u{03B2}\u{03B1}\u{03C3}", 15, 1, null],
+    ["\u{03C0}\u{03BF}\u{03BB}\u{03B9}\u{03C3}", 15, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C3}\u{03C4}", -1, 1, null],
+    ["\u{03B4}\u{03B9}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03C0}\u{03BB}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03C4}\u{03C3}\u{03B1}\u{03C1}\u{03BB}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03C4}\u{03B5}\u{03C4}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03C5}\u{03C1}\u{03B9}\u{03C4}", -1, 1, null],
+    ["\u{03C3}\u{03BF}\u{03C5}\u{03BB}\u{03C4}", -1, 1, null],
+    ["\u{03B6}\u{03C9}\u{03BD}\u{03C4}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03B9}\u{03BD}\u{03C4}", -1, 1, null],
+    ["\u{03C6}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03BD}\u{03C4}\u{03B1}\u{03C1}\u{03C6}", 29, 1, null],
+    ["\u{03BA}\u{03BF}\u{03B9}\u{03BB}\u{03B1}\u{03C1}\u{03C6}", 29, 1, null],
+    ["\u{03BF}\u{03C1}\u{03C6}", 29, 1, null],
+    ["\u{03B4}\u{03B9}\u{03B1}\u{03C6}", 29, 1, null],
+    ["\u{03C3}\u{03C4}\u{03B5}\u{03C6}", 29, 1, null],
+    ["\u{03C6}\u{03C9}\u{03C4}\u{03BF}\u{03C3}\u{03C4}\u{03B5}\u{03C6}", 34, 1, null],
+    ["\u{03C0}\u{03B5}\u{03C1}\u{03B7}\u{03C6}", 29, 1, null],
+    ["\u{03C5}\u{03C0}\u{03B5}\u{03C1}\u{03B7}\u{03C6}", 36, 1, null],
+    ["\u{03C7}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03BB}\u{03C5}\u{03BC}\u{03B7}\u{03C7}", 38, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B7}\u{03C7}", 38, 1, null],
+    ["\u{03B2}\u{03B9}\u{03BF}\u{03BC}\u{03B7}\u{03C7}", 38, 1, null],
+    ["\u{03BC}\u{03B9}\u{03BA}\u{03C1}\u{03BF}\u{03B2}\u{03B9}\u{03BF}\u{03BC}\u{03B7}\u{03C7}", 41, 1, null],
+    ["\u{03BC}\u{03B5}\u{03B3}\u{03BB}\u{03BF}\u{03B2}\u{03B9}\u{03BF}\u{03BC}\u{03B7}\u{03C7}", 41, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C0}\u{03BD}\u{03BF}\u{03B2}\u{03B9}\u{03BF}\u{03BC}\u{03B7}\u{03C7}", 41, 1, null],
+    ["\u{03BB}\u{03B9}\u{03C7}", 38, 1, null],
+    ["\u{03C4}\u{03B1}\u{03B2}", -1, 1, null],
+    ["\u{03BD}\u{03C4}\u{03B1}\u{03B2}", 46, 1, null],
+    ["\u{03C8}\u{03B7}\u{03BB}\u{03BF}\u{03C4}\u{03B1}\u{03B2}", 46, 1, null],
+    ["\u{03BB}\u{03B9}\u{03B2}", -1, 1, null],
+    ["\u{03BA}\u{03BB}\u{03B9}\u{03B2}", 49, 1, null],
+    ["\u{03BE}\u{03B7}\u{03C1}\u{03BF}\u{03BA}\u{03BB}\u{03B9}\u{03B2}", 50, 1, null],
+    ["\u{03B3}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03BF}\u{03C1}\u{03B3}", 52, 1, null],
+    ["\u{03B5}\u{03BD}\u{03BF}\u{03C1}\u{03B3}", 52, 1, null],
+    ["\u{03B1}\u{03B3}", 52, 1, null],
+    ["\u{03C4}\u{03C1}\u{03B1}\u{03B3}", 55, 1, null],
+    ["\u{03C4}\u{03C3}\u{03B1}\u{03B3}", 55, 1, null],
+    ["\u{03C4}\u{03C3}\u{03B9}\u{03B3}\u{03B3}", 52, 1, null],
+    ["\u{03B1}\u{03C4}\u{03C3}\u{03B9}\u{03B3}\u{03B3}", 58, 1, null],
+    ["\u{03B1}\u{03B8}\u{03B9}\u{03B3}\u{03B3}", 52, 1, null],
+    ["\u{03C3}\u{03C4}\u{03B5}\u{03B3}", 52, 1, null],
+    ["\u{03B1}\u{03C0}\u{03B7}\u{03B3}", 52, 1, null],
+    ["\u{03C3}\u{03B9}\u{03B3}", 52, 1, null],
+    ["\u{03BA}\u{03B1}\u{03BB}\u{03C0}\u{03BF}\u{03C5}\u{03B6}", -1, 1, null],
+    ["\u{03B8}", -1, 1, null],
+    ["\u{03BC}\u{03C9}\u{03B1}\u{03BC}\u{03B5}\u{03B8}", 65, 1, null],
+    ["\u{03C0}\u{03B9}\u{03B8}", 65, 1, null],
+    ["\u{03B1}\u{03C0}\u{03B9}\u{03B8}", 67, 1, null],
+    ["\u{03B2}\u{03B1}\u{03C3}\u{03BA}", -1, 1, null],
+    ["\u{03B2}\u{03C1}\u{03B1}\u{03C7}\u{03C5}\u{03BA}", -1, 1, null],
+    ["\u{03B4}\u{03B5}\u{03BA}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03BB}\u{03B5}\u{03BA}", -1, 1, null],
+    ["\u{03B9}\u{03BA}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B9}\u{03BA}", 73, 1, null],
+    ["\u{03B2}\u{03BF}\u{03C5}\u{03BB}\u{03BA}", -1, 1, null],
+    ["\u{03C0}\u{03BB}", -1, 1, null],
+    ["\u{03B4}\u{03B9}\u{03C0}\u{03BB}", 76, 1, null],
+    ["\u{03C8}\u{03C5}\u{03C7}\u{03BF}\u{03C0}\u{03BB}", 76, 1, null],
+    ["\u{03BB}\u{03B1}\u{03BF}\u{03C0}\u{03BB}", 76, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03B3}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03B8}\u{03C5}\u{03B3}\u{03B1}\u{03BB}", 81, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C4}\u{03B1}\u{03B3}\u{03B1}\u{03BB}", 81, 1, null],
+    ["\u{03BF}\u{03BB}\u{03BF}\u{03B3}\u{03B1}\u{03BB}", 81, 1, null],
+    ["\u{03BA}\u{03B1}\u{03C3}\u{03C4}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03BC}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03C1}\u{03C4}\u{03BF}\u{03BB}", -1, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03B4}\u{03C1}\u{03B1}\u{03B4}\u{03BF}\u{03C5}\u{03BC}", 88, 1, null],
+    ["\u{03B2}\u{03C1}\u{03B1}\u{03C7}\u{03BC}", 88, 1, null],
+    ["\u{03BF}\u{03BB}\u{03B9}\u{03B3}\u{03BF}\u{03B4}\u{03B1}\u{03BC}", 88, 1, null],
+    ["\u{03BC}\u{03BF}\u{03C5}\u{03C3}\u{03BF}\u{03C5}\u{03BB}\u{03BC}", 88, 1, null],
+    ["\u{03BD}", -1, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B5}\u{03C1}\u{03B9}\u{03BA}\u{03B1}\u{03BD}", 93, 1, null],
+]);
+
+const A_45 = among([
+    ["\u{03B7}\u{03C3}\u{03B5}\u{03C4}\u{03B5}", -1, 1, null],
+]);
+
+const A_46 = among([
+    ["\u{03C0}\u{03C5}\u{03C1}", -1, 1, null],
+    ["\u{03B5}\u{03C5}\u{03C1}", -1, 1, null],
+    ["\u{03C7}\u{03C9}\u{03C1}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03B2}\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03B9}\u{03C1}", -1, 1, null],
+    ["\u{03C6}\u{03BF}\u{03C1}", -1, 1, null],
+    ["\u{03BD}\u{03B5}\u{03C4}", -1, 1, null],
+    ["\u{03C3}\u{03C7}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B4}", -1, 1, null],
+    ["\u{03B5}\u{03BD}\u{03B4}", -1, 1, null],
+    ["\u{03BF}\u{03B4}", -1, 1, null],
+    ["\u{03C5}\u{03C0}\u{03B5}\u{03C1}\u{03B8}", -1, 1, null],
+    ["\u{03C3}\u{03B8}", -1, 1, null],
+    ["\u{03B5}\u{03C5}\u{03B8}", -1, 1, null],
+    ["\u{03C1}\u{03B1}\u{03B8}", -1, 1, null],
+    ["\u{03C4}\u{03B1}\u{03B8}", -1, 1, null],
+    ["\u{03B4}\u{03B9}\u{03B1}\u{03B8}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03B8}", -1, 1, null],
+    ["\u{03C4}\u{03B9}\u{03B8}", -1, 1, null],
+    ["\u{03B5}\u{03BA}\u{03B8}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B8}", -1, 1, null],
+    ["\u{03B5}\u{03BD}\u{03B8}", -1, 1, null],
+    ["\u{03C1}\u{03BF}\u{03B8}", -1, 1, null],
+    ["\u{03B1}\u{03C1}\u{03BA}", -1, 1, null],
+    ["\u{03C9}\u{03C6}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03B2}\u{03BF}\u{03BB}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}", -1, 1, null],
+    ["\u{03B1}\u{03B9}\u{03BD}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03BD}", -1, 1, null],
+    ["\u{03C1}\u{03BF}\u{03BD}", -1, 1, null],
+]);
+
+const A_47 = among([
+    ["\u{03C3}\u{03B5}\u{03C1}\u{03C0}", -1, 1, null],
+    ["\u{03BA}\u{03BF}\u{03C0}", -1, 1, null],
+    ["\u{03B8}\u{03B1}\u{03C1}\u{03C1}", -1, 1, null],
+    ["\u{03BD}\u{03C4}\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03B2}\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03B5}\u{03BD}\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03B2}\u{03C1}", -1, 1, null],
+    ["\u{03BC}\u{03C0}\u{03BF}\u{03C1}", -1, 1, null],
+    ["\u{03C5}", -1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03C1}\u{03C6}", -1, 1, null],
+    ["\u{03BD}\u{03B9}\u{03C6}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03B3}", -1, 1, null],
+    ["\u{03B4}", -1, 1, null],
+    ["\u{03B1}\u{03B4}", 12, 1, null],
+    ["\u{03B8}", -1, 1, null],
+    ["\u{03B1}\u{03B8}", 14, 1, null],
+    ["\u{03C3}\u{03BA}", -1, 1, null],
+    ["\u{03C4}\u{03BF}\u{03BA}", -1, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BB}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03C3}\u{03BA}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03B5}\u{03BC}", -1, 1, null],
+    ["\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03B2}\u{03B5}\u{03BD}", -1, 1, null],
+    ["\u{03B2}\u{03B1}\u{03C1}\u{03BF}\u{03BD}", -1, 1, null],
+]);
+
+const A_48 = among([
+    ["\u{03C9}\u{03BD}\u{03C4}\u{03B1}\u{03C3}", -1, 1, null],
+    ["\u{03BF}\u{03BD}\u{03C4}\u{03B1}\u{03C3}", -1, 1, null],
+]);
+
+const A_49 = among([
+    ["\u{03BF}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 0, 1, null],
+]);
+
+const A_50 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03B1}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03BA}\u{03B1}\u{03C4}\u{03B1}\u{03C0}", 1, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BC}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03C3}\u{03C5}\u{03BC}\u{03C0}", 3, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B5}\u{03C4}\u{03B1}\u{03BC}\u{03C6}", -1, 1, null],
+]);
+
+const A_51 = among([
+    ["\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03BD}\u{03B9}\u{03C3}", -1, 1, null],
+    ["\u{03B6}", -1, 1, null],
+    ["\u{03B1}\u{03BB}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B1}\u{03BB}", 3, 1, null],
+    ["\u{03B5}\u{03BA}\u{03C4}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03BE}", -1, 1, null],
+    ["\u{03C0}\u{03C1}\u{03BF}", -1, 1, null],
+]);
+
+const A_52 = among([
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B1}", -1, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B5}", -1, 1, null],
+]);
+
+const A_53 = among([
+    ["\u{03C3}\u{03C6}", -1, 1, null],
+    ["\u{03BD}\u{03B1}\u{03C1}\u{03B8}", -1, 1, null],
+    ["\u{03C0}\u{03B9}\u{03B8}", -1, 1, null],
+    ["\u{03BF}\u{03B8}", -1, 1, null],
+    ["\u{03C3}\u{03BA}\u{03BF}\u{03C5}\u{03BB}", -1, 1, null],
+    ["\u{03C3}\u{03BA}\u{03C9}\u{03BB}", -1, 1, null],
+]);
+
+const A_54 = among([
+    ["\u{03B8}", -1, 1, null],
+    ["\u{03C0}\u{03C1}\u{03BF}\u{03C3}\u{03B8}", 0, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C1}\u{03B1}\u{03BA}\u{03B1}\u{03C4}\u{03B1}\u{03B8}", 0, 1, null],
+    ["\u{03B4}\u{03B9}\u{03B1}\u{03B8}", 0, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03B8}", 0, 1, null],
+]);
+
+const A_55 = among([
+    ["\u{03B7}\u{03BA}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B7}\u{03BA}\u{03B1}", -1, 1, null],
+    ["\u{03B7}\u{03BA}\u{03B5}", -1, 1, null],
+]);
+
+const A_56 = among([
+    ["\u{03B2}\u{03BB}\u{03B5}\u{03C0}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03B4}\u{03B1}\u{03C1}", -1, 1, null],
+    ["\u{03C0}\u{03C1}\u{03C9}\u{03C4}", -1, 1, null],
+    ["\u{03BA}\u{03C5}\u{03BC}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03BD}\u{03C4}\u{03B1}\u{03C7}", -1, 1, null],
+    ["\u{03BB}\u{03B1}\u{03C7}", -1, 1, null],
+    ["\u{03C6}\u{03B1}\u{03B3}", -1, 1, null],
+    ["\u{03BB}\u{03B7}\u{03B3}", -1, 1, null],
+    ["\u{03C6}\u{03C1}\u{03C5}\u{03B4}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03BD}\u{03C4}\u{03B9}\u{03BB}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03BB}\u{03BB}", -1, 1, null],
+    ["\u{03BF}\u{03BC}", -1, 1, null],
+]);
+
+const A_57 = among([
+    ["\u{03B5}\u{03BA}\u{03BB}\u{03B9}\u{03C0}", -1, 1, null],
+    ["\u{03C1}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B1}\u{03C1}\u{03C1}", 1, 1, null],
+    ["\u{03B5}\u{03BD}\u{03B4}\u{03B9}\u{03B1}\u{03C6}\u{03B5}\u{03C1}", 1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03B8}\u{03B1}\u{03C1}\u{03B5}\u{03C5}", -1, 1, null],
+    ["\u{03B4}\u{03B5}\u{03C5}\u{03C4}\u{03B5}\u{03C1}\u{03B5}\u{03C5}", -1, 1, null],
+    ["\u{03BB}\u{03B5}\u{03C7}", -1, 1, null],
+    ["\u{03C4}\u{03C3}\u{03B1}", -1, 1, null],
+    ["\u{03C7}\u{03B1}\u{03B4}", -1, 1, null],
+    ["\u{03BC}\u{03B5}\u{03B4}", -1, 1, null],
+    ["\u{03BB}\u{03B1}\u{03BC}\u{03C0}\u{03B9}\u{03B4}", -1, 1, null],
+    ["\u{03B4}\u{03B5}", -1, 1, null],
+    ["\u{03C0}\u{03BB}\u{03B5}", -1, 1, null],
+    ["\u{03BC}\u{03B5}\u{03C3}\u{03B1}\u{03B6}", -1, 1, null],
+    ["\u{03B4}\u{03B5}\u{03C3}\u{03C0}\u{03BF}\u{03B6}", -1, 1, null],
+    ["\u{03B1}\u{03B9}\u{03B8}", -1, 1, null],
+    ["\u{03C6}\u{03B1}\u{03C1}\u{03BC}\u{03B1}\u{03BA}", -1, 1, null],
+    ["\u{03B1}\u{03B3}\u{03BA}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03B7}\u{03BA}", -1, 1, null],
+    ["\u{03BB}", -1, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03B1}\u{03BC}", 21, 1, null],
+    ["\u{03B2}\u{03C1}\u{03BF}\u{03BC}", 21, 1, null],
+    ["\u{03C5}\u{03C0}\u{03BF}\u{03C4}\u{03B5}\u{03B9}\u{03BD}", -1, 1, null],
+]);
+
+const A_58 = among([
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B1}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B5}", -1, 1, null],
+]);
+
+const A_59 = among([
+    ["\u{03C8}\u{03BF}\u{03C6}", -1, -1, null],
+    ["\u{03BD}\u{03B1}\u{03C5}\u{03BB}\u{03BF}\u{03C7}", -1, -1, null],
+]);
+
+const A_60 = among([
+    ["\u{03C1}\u{03C0}", -1, 1, null],
+    ["\u{03C0}\u{03C1}", -1, 1, null],
+    ["\u{03C6}\u{03C1}", -1, 1, null],
+    ["\u{03C7}\u{03BF}\u{03C1}\u{03C4}", -1, 1, null],
+    ["\u{03C3}\u{03C6}", -1, 1, null],
+    ["\u{03BF}\u{03C6}", -1, 1, null],
+    ["\u{03BB}\u{03BF}\u{03C7}", -1, 1, null],
+    ["\u{03C0}\u{03B5}\u{03BB}", -1, 1, null],
+    ["\u{03BB}\u{03BB}", -1, 1, null],
+    ["\u{03C3}\u{03BC}\u{03B7}\u{03BD}", -1, 1, null],
+]);
+
+const A_61 = among([
+    ["\u{03C0}", -1, 1, null],
+    ["\u{03B1}\u{03C3}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03BD}\u{03C5}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03C1}\u{03C4}\u{03B9}\u{03C0}", 0, 1, null],
+    ["\u{03B1}\u{03B5}\u{03B9}\u{03C0}", 0, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BC}\u{03C0}", 0, 1, null],
+    ["\u{03C0}\u{03C1}\u{03BF}\u{03C3}\u{03C9}\u{03C0}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03C3}\u{03B9}\u{03B4}\u{03B7}\u{03C1}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03B4}\u{03C1}\u{03BF}\u{03C3}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03BD}\u{03B5}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03BA}\u{03C1}\u{03BF}\u{03BA}\u{03B1}\u{03BB}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03BF}\u{03BB}\u{03BF}\u{03C0}", 0, 1, null],
+    ["\u{03C1}", -1, 1, null],
+    ["\u{03C4}\u{03C1}", 12, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C1}", 12, 1, null],
+    ["\u{03B1}\u{03C3}\u{03C0}\u{03B1}\u{03C1}", 12, 1, null],
+    ["\u{03C7}\u{03B1}\u{03C1}", 12, 1, null],
+    ["\u{03B1}\u{03C7}\u{03B1}\u{03C1}", 16, 1, null],
+    ["\u{03B1}\u{03C0}\u{03B5}\u{03C1}", 12, 1, null],
+    ["\u{03C4}", -1, 1, null],
+    ["\u{03B1}\u{03BD}\u{03C5}\u{03C3}\u{03C4}", 19, 1, null],
+    ["\u{03B1}\u{03B2}\u{03B1}\u{03C3}\u{03C4}", 19, 1, null],
+    ["\u{03C0}\u{03C1}\u{03BF}\u{03C3}\u{03C4}", 19, 1, null],
+    ["\u{03B1}\u{03B9}\u{03BC}\u{03BF}\u{03C3}\u{03C4}", 19, 1, null],
+    ["\u{03B4}\u{03B9}\u{03B1}\u{03C4}", 19, 1, null],
+    ["\u{03B5}\u{03C0}\u{03B9}\u{03C4}", 19, 1, null],
+    ["\u{03C3}\u{03C5}\u{03BD}\u{03C4}", 19, 1, null],
+    ["\u{03C5}\u{03C0}\u{03BF}\u{03C4}", 19, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BF}\u{03C4}", 19, 1, null],
+    ["\u{03BF}\u{03BC}\u{03BF}\u{03C4}", 19, 1, null],
+    ["\u{03BD}\u{03BF}\u{03BC}\u{03BF}\u{03C4}", 29, 1, null],
+    ["\u{03BD}\u{03B1}\u{03C5}", -1, 1, null],
+    ["\u{03C0}\u{03BF}\u{03BB}\u{03C5}\u{03C6}", -1, 1, null],
+    ["\u{03B1}\u{03C6}", -1, 1, null],
+    ["\u{03BE}\u{03B5}\u{03C6}", -1, 1, null],
+    ["\u{03B1}\u{03B4}\u{03B7}\u{03C6}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03BC}\u{03C6}", -1, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B1}\u{03BB}\u{03BB}\u{03B9}", -1, 1, null],
+    ["\u{03BB}", -1, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B1}\u{03BB}", 38, 1, null],
+    ["\u{03BC}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BB}\u{03B1}\u{03BC}", 40, 1, null],
+    ["\u{03B5}\u{03BD}", -1, 1, null],
+    ["\u{03B4}\u{03B5}\u{03C1}\u{03B2}\u{03B5}\u{03BD}", 42, 1, null],
+]);
+
+const A_62 = among([
+    ["\u{03B1}\u{03B3}\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B1}\u{03B3}\u{03B1}", -1, 1, null],
+    ["\u{03B1}\u{03B3}\u{03B5}", -1, 1, null],
+]);
+
+const A_63 = among([
+    ["\u{03B7}\u{03C3}\u{03BF}\u{03C5}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B1}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B5}", -1, 1, null],
+]);
+
+const A_64 = among([
+    ["\u{03BD}", -1, 1, null],
+    ["\u{03B5}\u{03C0}\u{03C4}\u{03B1}\u{03BD}", 0, 1, null],
+    ["\u{03B4}\u{03C9}\u{03B4}\u{03B5}\u{03BA}\u{03B1}\u{03BD}", 0, 1, null],
+    ["\u{03C7}\u{03B5}\u{03C1}\u{03C3}\u{03BF}\u{03BD}", 0, 1, null],
+    ["\u{03BC}\u{03B5}\u{03B3}\u{03B1}\u{03BB}\u{03BF}\u{03BD}", 0, 1, null],
+    ["\u{03B5}\u{03C1}\u{03B7}\u{03BC}\u{03BF}\u{03BD}", 0, 1, null],
+]);
+
+const A_65 = among([
+    ["\u{03B7}\u{03C3}\u{03C4}\u{03B5}", -1, 1, null],
+]);
+
+const A_66 = among([
+    ["\u{03C7}\u{03C1}", -1, 1, null],
+    ["\u{03B4}\u{03C5}\u{03C3}\u{03C7}\u{03C1}", 0, 1, null],
+    ["\u{03B5}\u{03C5}\u{03C7}\u{03C1}", 0, 1, null],
+    ["\u{03B1}\u{03C7}\u{03C1}", 0, 1, null],
+    ["\u{03BA}\u{03BF}\u{03B9}\u{03BD}\u{03BF}\u{03C7}\u{03C1}", 0, 1, null],
+    ["\u{03C0}\u{03B1}\u{03BB}\u{03B9}\u{03BC}\u{03C8}", -1, 1, null],
+    ["\u{03C3}\u{03B2}", -1, 1, null],
+    ["\u{03B1}\u{03C3}\u{03B2}", 6, 1, null],
+    ["\u{03B1}\u{03C0}\u{03BB}", -1, 1, null],
+    ["\u{03B1}\u{03B5}\u{03B9}\u{03BC}\u{03BD}", -1, 1, null],
+]);
+
+const A_67 = among([
+    ["\u{03BF}\u{03C5}\u{03BD}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03BF}\u{03C5}\u{03BD}\u{03B5}", 0, 1, null],
+    ["\u{03B7}\u{03B8}\u{03BF}\u{03C5}\u{03BD}\u{03B5}", 0, 1, null],
+]);
+
+const A_68 = among([
+    ["\u{03C1}", -1, 1, null],
+    ["\u{03C3}\u{03C4}\u{03C1}\u{03B1}\u{03B2}\u{03BF}\u{03BC}\u{03BF}\u{03C5}\u{03C4}\u{03C3}", -1, 1, null],
+    ["\u{03BA}\u{03B1}\u{03BA}\u{03BF}\u{03BC}\u{03BF}\u{03C5}\u{03C4}\u{03C3}", -1, 1, null],
+    ["\u{03C3}\u{03C0}\u{03B9}", -1, 1, null],
+    ["\u{03BD}", -1, 1, null],
+    ["\u{03B5}\u{03BE}\u{03C9}\u{03BD}", 4, 1, null],
+]);
+
+const A_69 = among([
+    ["\u{03BF}\u{03C5}\u{03BC}\u{03B5}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03BF}\u{03C5}\u{03BC}\u{03B5}", 0, 1, null],
+    ["\u{03B7}\u{03B8}\u{03BF}\u{03C5}\u{03BC}\u{03B5}", 0, 1, null],
+]);
+
+const A_70 = among([
+    ["\u{03B1}\u{03C3}\u{03BF}\u{03C5}\u{03C3}", -1, 1, null],
+    ["\u{03C0}\u{03B1}\u{03C1}\u{03B1}\u{03C3}\u{03BF}\u{03C5}\u{03C3}", 0, 1, null],
+    ["\u{03B1}\u{03BB}\u{03BB}\u{03BF}\u{03C3}\u{03BF}\u{03C5}\u{03C3}", -1, 1, null],
+    ["\u{03C6}", -1, 1, null],
+    ["\u{03C7}", -1, 1, null],
+    ["\u{03B1}\u{03B6}", -1, 1, null],
+    ["\u{03C9}\u{03C1}\u{03B9}\u{03BF}\u{03C0}\u{03BB}", -1, 1, null],
+]);
+
+const A_71 = among([
+    ["\u{03BC}\u{03B1}\u{03C4}\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03C4}\u{03B1}", -1, 1, null],
+    ["\u{03BC}\u{03B1}\u{03C4}\u{03C9}\u{03BD}", -1, 1, null],
+]);
+
+const A_72 = among([
+    ["\u{03C5}\u{03C3}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C3}", 0, 1, null],
+    ["\u{03B1}\u{03C3}", -1, 1, null],
+    ["\u{03B5}\u{03C3}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B5}\u{03C3}", 3, 1, null],
+    ["\u{03B7}\u{03B4}\u{03B5}\u{03C3}", 3, 1, null],
+    ["\u{03B7}\u{03C3}", -1, 1, null],
+    ["\u{03B5}\u{03B9}\u{03C3}", -1, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B5}\u{03B9}\u{03C3}", 7, 1, null],
+    ["\u{03BF}\u{03C3}", -1, 1, null],
+    ["\u{03C5}", -1, 1, null],
+    ["\u{03BF}\u{03C5}", 10, 1, null],
+    ["\u{03C9}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03C9}", 12, 1, null],
+    ["\u{03B1}\u{03C9}", 12, 1, null],
+    ["\u{03B7}\u{03B8}\u{03C9}", 12, 1, null],
+    ["\u{03B1}", -1, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C5}\u{03BC}\u{03B1}", 16, 1, null],
+    ["\u{03BF}\u{03C3}\u{03BF}\u{03C5}\u{03BD}\u{03B1}", 16, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C3}\u{03BF}\u{03C5}\u{03BD}\u{03B1}", 18, 1, null],
+    ["\u{03BF}\u{03BC}\u{03BF}\u{03C5}\u{03BD}\u{03B1}", 16, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BC}\u{03BF}\u{03C5}\u{03BD}\u{03B1}", 20, 1, null],
+    ["\u{03B5}", -1, 1, null],
+    ["\u{03B9}\u{03B5}\u{03C3}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03BF}\u{03C3}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C3}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 24, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C5}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 26, 1, null],
+    ["\u{03B9}\u{03B5}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B1}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B1}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B1}\u{03B3}\u{03B1}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B7}\u{03BA}\u{03B1}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03C4}\u{03B5}", 32, 1, null],
+    ["\u{03B5}\u{03B9}\u{03C4}\u{03B5}", 22, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B5}\u{03B9}\u{03C4}\u{03B5}", 34, 1, null],
+    ["\u{03B7}", -1, 1, null],
+    ["\u{03B9}", -1, 1, null],
+    ["\u{03B1}\u{03C3}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B5}\u{03C3}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B9}\u{03B5}\u{03C3}\u{03B1}\u{03B9}", 39, 1, null],
+    ["\u{03B1}\u{03C4}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B5}\u{03C4}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B9}\u{03B5}\u{03C4}\u{03B1}\u{03B9}", 42, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BD}\u{03C4}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C5}\u{03BD}\u{03C4}\u{03B1}\u{03B9}", 44, 1, null],
+    ["\u{03BF}\u{03BD}\u{03C4}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BC}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B1}\u{03BC}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B9}\u{03B5}\u{03BC}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03BF}\u{03BC}\u{03B1}\u{03B9}", 37, 1, null],
+    ["\u{03B5}\u{03B9}", 37, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B5}\u{03B9}", 51, 1, null],
+    ["\u{03B1}\u{03B5}\u{03B9}", 51, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B5}\u{03B9}", 51, 1, null],
+    ["\u{03BF}\u{03B9}", 37, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BD}", -1, 1, null],
+    ["\u{03B7}\u{03C3}\u{03BF}\u{03C5}\u{03BD}", 56, 1, null],
+    ["\u{03BF}\u{03C3}\u{03BF}\u{03C5}\u{03BD}", 56, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C3}\u{03BF}\u{03C5}\u{03BD}", 58, 1, null],
+    ["\u{03B7}\u{03B8}\u{03BF}\u{03C5}\u{03BD}", 56, 1, null],
+    ["\u{03BF}\u{03BC}\u{03BF}\u{03C5}\u{03BD}", 56, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BC}\u{03BF}\u{03C5}\u{03BD}", 61, 1, null],
+    ["\u{03C9}\u{03BD}", -1, 1, null],
+    ["\u{03B7}\u{03B4}\u{03C9}\u{03BD}", 63, 1, null],
+    ["\u{03B1}\u{03BD}", -1, 1, null],
+    ["\u{03BF}\u{03C5}\u{03C3}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03BF}\u{03BD}\u{03C4}\u{03BF}\u{03C5}\u{03C3}\u{03B1}\u{03BD}", 66, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BD}\u{03C4}\u{03BF}\u{03C5}\u{03C3}\u{03B1}\u{03BD}", 67, 1, null],
+    ["\u{03B7}\u{03C3}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03BF}\u{03C3}\u{03B1}\u{03C3}\u{03C4}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C3}\u{03B1}\u{03C3}\u{03C4}\u{03B1}\u{03BD}", 70, 1, null],
+    ["\u{03BF}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BC}\u{03B1}\u{03C3}\u{03C4}\u{03B1}\u{03BD}", 72, 1, null],
+    ["\u{03BF}\u{03C5}\u{03BD}\u{03C4}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C5}\u{03BD}\u{03C4}\u{03B1}\u{03BD}", 74, 1, null],
+    ["\u{03BF}\u{03BD}\u{03C4}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B9}\u{03BF}\u{03BD}\u{03C4}\u{03B1}\u{03BD}", 76, 1, null],
+    ["\u{03BF}\u{03C4}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B9}\u{03BF}\u{03C4}\u{03B1}\u{03BD}", 78, 1, null],
+    ["\u{03B1}\u{03B3}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B7}\u{03BA}\u{03B1}\u{03BD}", 65, 1, null],
+    ["\u{03B7}\u{03B8}\u{03B7}\u{03BA}\u{03B1}\u{03BD}", 81, 1, null],
+    ["\u{03BF}", -1, 1, null],
+]);
+
+const A_73 = among([
+    ["\u{03B5}\u{03C3}\u{03C4}\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03C5}\u{03C4}\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03C9}\u{03C4}\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03BF}\u{03C4}\u{03B5}\u{03C1}", -1, 1, null],
+    ["\u{03B5}\u{03C3}\u{03C4}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03C5}\u{03C4}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03C9}\u{03C4}\u{03B1}\u{03C4}", -1, 1, null],
+    ["\u{03BF}\u{03C4}\u{03B1}\u{03C4}", -1, 1, null],
+]);
+
+const G_v = [81, 65, 16, 1];
+
+const G_v2 = [81, 65, 0, 1];
+
+function r_has_min_length(env, context) {
+    if (!((env.char_count()) >= 3)) {
+        return false;
+    }
+    return true;
+}
+
+function r_tolower(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (among_var == 1) {
+                if (!env.slice_from("\u{03B1}")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("\u{03B2}")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_from("\u{03B3}")) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (!env.slice_from("\u{03B4}")) {
+                    return false;
+                }
+            } else if (among_var == 5) {
+                if (!env.slice_from("\u{03B5}")) {
+                    return false;
+                }
+            } else if (among_var == 6) {
+                if (!env.slice_from("\u{03B6}")) {
+                    return false;
+                }
+            } else if (among_var == 7) {
+                if (!env.slice_from("\u{03B7}")) {
+                    return false;
+                }
+            } else if (among_var == 8) {
+                if (!env.slice_from("\u{03B8}")) {
+                    return false;
+                }
+            } else if (among_var == 9) {
+                if (!env.slice_from("\u{03B9}")) {
+                    return false;
+                }
+            } else if (among_var == 10) {
+                if (!env.slice_from("\u{03BA}")) {
+                    return false;
+                }
+            } else if (among_var == 11) {
+                if (!env.slice_from("\u{03BB}")) {
+                    return false;
+                }
+            } else if (among_var == 12) {
+                if (!env.slice_from("\u{03BC}")) {
+                    return false;
+                }
+            } else if (among_var == 13) {
+                if (!env.slice_from("\u{03BD}")) {
+                    return false;
+                }
+            } else if (among_var == 14) {
+                if (!env.slice_from("\u{03BE}")) {
+                    return false;
+                }
+            } else if (among_var == 15) {
+                if (!env.slice_from("\u{03BF}")) {
+                    return false;
+                }
+            } else if (among_var == 16) {
+                if (!env.slice_from("\u{03C0}")) {
+                    return false;
+                }
+            } else if (among_var == 17) {
+                if (!env.slice_from("\u{03C1}")) {
+                    return false;
+                }
+            } else if (among_var == 18) {
+                if (!env.slice_from("\u{03C3}")) {
+                    return false;
+                }
+            } else if (among_var == 19) {
+                if (!env.slice_from("\u{03C4}")) {
+                    return false;
+                }
+            } else if (among_var == 20) {
+                if (!env.slice_from("\u{03C5}")) {
+                    return false;
+                }
+            } else if (among_var == 21) {
+                if (!env.slice_from("\u{03C6}")) {
+                    return false;
+                }
+            } else if (among_var == 22) {
+                if (!env.slice_from("\u{03C7}")) {
+                    return false;
+                }
+            } else if (among_var == 23) {
+                if (!env.slice_from("\u{03C8}")) {
+                    return false;
+                }
+            } else if (among_var == 24) {
+                if (!env.slice_from("\u{03C9}")) {
+                    return false;
+                }
+            } else if (among_var == 25) {
+                if (env.cursor <= env.limit_backward) {
+                    break lab1;
+                }
+                env.previous_char();
+            }
+            continue replab0;
+        }
+        env.cursor = env.limit - v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_step1(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_1, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 1) {
+        if (!env.slice_from("\u{03C6}\u{03B1}")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("\u{03C3}\u{03BA}\u{03B1}")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("\u{03BF}\u{03BB}\u{03BF}")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("\u{03C3}\u{03BF}")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_from("\u{03C4}\u{03B1}\u{03C4}\u{03BF}")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("\u{03BA}\u{03C1}\u{03B5}")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_from("\u{03C0}\u{03B5}\u{03C1}")) {
+            return false;
+        }
+    } else if (among_var == 8) {
+        if (!env.slice_from("\u{03C4}\u{03B5}\u{03C1}")) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_from("\u{03C6}\u{03C9}")) {
+            return false;
+        }
+    } else if (among_var == 10) {
+        if (!env.slice_from("\u{03BA}\u{03B1}\u{03B8}\u{03B5}\u{03C3}\u{03C4}")) {
+            return false;
+        }
+    } else if (among_var == 11) {
+        if (!env.slice_from("\u{03B3}\u{03B5}\u{03B3}\u{03BF}\u{03BD}")) {
+            return false;
+        }
+    }
+    context.b_test1 = false;
+    return true;
+}
+
+function r_steps1(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_4, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_2, context) == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_3, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            return false;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B9}\u{03B6}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_steps2(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_6, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_5, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03C9}\u{03BD}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_steps3(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_9, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.eq_s_b("\u{03B9}\u{03C3}\u{03B1}")) {
+                break lab1;
+            }
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            if (!env.slice_from("\u{03B9}\u{03C3}")) {
+                return false;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_7, context) == 0) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab2;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_8, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            return false;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B9}\u{03C3}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_steps4(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_11, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_10, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B9}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_steps5(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_14, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_12, context) == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_13, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            return false;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B9}\u{03C3}\u{03C4}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_steps6(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_18, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_15, context) == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}\u{03C3}\u{03BC}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_16, context) == 0) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab2;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_17, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 1) {
+            if (!env.slice_from("\u{03B1}\u{03B3}\u{03BD}\u{03C9}\u{03C3}\u{03C4}")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("\u{03B1}\u{03C4}\u{03BF}\u{03BC}")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("\u{03B3}\u{03BD}\u{03C9}\u{03C3}\u{03C4}")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!env.slice_from("\u{03B5}\u{03B8}\u{03BD}")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!env.slice_from("\u{03B5}\u{03BA}\u{03BB}\u{03B5}\u{03BA}\u{03C4}")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!env.slice_from("\u{03C3}\u{03BA}\u{03B5}\u{03C0}\u{03C4}")) {
+                return false;
+            }
+        } else if (among_var == 7) {
+            if (!env.slice_from("\u{03C4}\u{03BF}\u{03C0}")) {
+                return false;
+            }
+        } else if (among_var == 8) {
+            if (!env.slice_from("\u{03B1}\u{03BB}\u{03B5}\u{03BE}\u{03B1}\u{03BD}\u{03B4}\u{03C1}")) {
+                return false;
+            }
+        } else if (among_var == 9) {
+            if (!env.slice_from("\u{03B2}\u{03C5}\u{03B6}\u{03B1}\u{03BD}\u{03C4}")) {
+                return false;
+            }
+        } else if (among_var == 10) {
+            if (!env.slice_from("\u{03B8}\u{03B5}\u{03B1}\u{03C4}\u{03C1}")) {
+                return false;
+            }
+        }
+        break lab0;
+    }
+    return true;
+}
+
+function r_steps7(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_20, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_19, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B1}\u{03C1}\u{03B1}\u{03BA}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_steps8(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_23, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_21, context) == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B1}\u{03BA}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_22, context) == 0) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab2;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}\u{03C4}\u{03C3}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (!env.eq_s_b("\u{03BA}\u{03BF}\u{03C1}")) {
+            return false;
+        }
+        env.bra = env.cursor;
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B9}\u{03C4}\u{03C3}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_steps9(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_26, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_24, context) == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}\u{03B4}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_25, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B9}\u{03B4}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_steps10(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_28, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_27, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B9}\u{03C3}\u{03BA}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step2a(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_29, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (env.find_among_b(A_30, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        return false;
+    }
+    env.cursor = env.limit - v_1;
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, "\u{03B1}\u{03B4}");
+    env.cursor = c;
+    return true;
+}
+
+function r_step2b(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_31, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (env.find_among_b(A_32, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B5}\u{03B4}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step2c(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_33, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (env.find_among_b(A_34, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03BF}\u{03C5}\u{03B4}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step2d(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_35, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_36, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B5}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step3(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_37, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (!env.in_grouping_b(G_v, 945, 969)) {
+        return false;
+    }
+    env.bra = env.cursor;
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B9}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step4(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_38, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (!env.in_grouping_b(G_v, 945, 969)) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B9}\u{03BA}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        break lab0;
+    }
+    if (env.find_among_b(A_39, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B9}\u{03BA}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5a(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        if (!env.eq_s_b("\u{03B1}\u{03B3}\u{03B1}\u{03BC}\u{03B5}")) {
+            break lab0;
+        }
+        if (env.cursor > env.limit_backward) {
+            break lab0;
+        }
+        if (!env.slice_from("\u{03B1}\u{03B3}\u{03B1}\u{03BC}")) {
+            return false;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        env.ket = env.cursor;
+        if (env.find_among_b(A_40, context) == 0) {
+            break lab1;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_test1 = false;
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("\u{03B1}\u{03BC}\u{03B5}")) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_41, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B1}\u{03BC}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5b(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (env.find_among_b(A_43, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_test1 = false;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_42, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            break lab0;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B1}\u{03B3}\u{03B1}\u{03BD}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("\u{03B1}\u{03BD}\u{03B5}")) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab1: for (;;) {
+        var v_2 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (!env.in_grouping_b(G_v2, 945, 969)) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B1}\u{03BD}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        env.ket = env.cursor;
+        break lab1;
+    }
+    if (env.find_among_b(A_44, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B1}\u{03BD}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5c(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (env.find_among_b(A_45, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_test1 = false;
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("\u{03B5}\u{03C4}\u{03B5}")) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab1: for (;;) {
+        var v_2 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (!env.in_grouping_b(G_v2, 945, 969)) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B5}\u{03C4}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        lab3: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_46, context) == 0) {
+                break lab3;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B5}\u{03C4}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        env.ket = env.cursor;
+        break lab1;
+    }
+    if (env.find_among_b(A_47, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B5}\u{03C4}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5d(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_48, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("\u{03B1}\u{03C1}\u{03C7}")) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                break lab1;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03BF}\u{03BD}\u{03C4}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (!env.eq_s_b("\u{03BA}\u{03C1}\u{03B5}")) {
+            return false;
+        }
+        env.bra = env.cursor;
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03C9}\u{03BD}\u{03C4}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_step5e(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_49, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("\u{03BF}\u{03BD}")) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03BF}\u{03BC}\u{03B1}\u{03C3}\u{03C4}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5f(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("\u{03B9}\u{03B5}\u{03C3}\u{03C4}\u{03B5}")) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_test1 = false;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_50, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            break lab0;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B9}\u{03B5}\u{03C3}\u{03C4}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (!env.eq_s_b("\u{03B5}\u{03C3}\u{03C4}\u{03B5}")) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_51, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B9}\u{03B5}\u{03C3}\u{03C4}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5g(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (env.find_among_b(A_52, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_test1 = false;
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_55, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab1: for (;;) {
+        var v_2 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_53, context) == 0) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B7}\u{03BA}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_54, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            return false;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03B7}\u{03BA}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab1;
+    }
+    return true;
+}
+
+function r_step5h(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_58, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_56, context) == 0) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03BF}\u{03C5}\u{03C3}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_57, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (env.cursor > env.limit_backward) {
+            return false;
+        }
+        context.S_s = env.slice_to();
+        if (context.S_s.length === 0) {
+            return false;
+        }
+        if (!env.slice_from("\u{03BF}\u{03C5}\u{03C3}")) {
+            return false;
+        }
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, context.S_s);
+        env.cursor = c;
+        break lab0;
+    }
+    return true;
+}
+
+function r_step5i(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_62, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("\u{03BA}\u{03BF}\u{03BB}\u{03BB}")) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B1}\u{03B3}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_2 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            if (env.find_among_b(A_59, context) == 0) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            return false;
+        }
+        env.cursor = env.limit - v_2;
+        lab3: for (;;) {
+            var v_3 = env.limit - env.cursor;
+            lab4: for (;;) {
+                env.ket = env.cursor;
+                if (env.find_among_b(A_60, context) == 0) {
+                    break lab4;
+                }
+                env.bra = env.cursor;
+                context.S_s = env.slice_to();
+                if (context.S_s.length === 0) {
+                    return false;
+                }
+                if (!env.slice_from("\u{03B1}\u{03B3}")) {
+                    return false;
+                }
+                var c = env.cursor;
+                var [bra, ket] = [env.cursor, env.cursor];
+                env.insert(bra, ket, context.S_s);
+                env.cursor = c;
+                break lab3;
+            }
+            env.cursor = env.limit - v_3;
+            env.ket = env.cursor;
+            if (env.find_among_b(A_61, context) == 0) {
+                return false;
+            }
+            env.bra = env.cursor;
+            if (env.cursor > env.limit_backward) {
+                return false;
+            }
+            context.S_s = env.slice_to();
+            if (context.S_s.length === 0) {
+                return false;
+            }
+            if (!env.slice_from("\u{03B1}\u{03B3}")) {
+                return false;
+            }
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, context.S_s);
+            env.cursor = c;
+            break lab3;
+        }
+        break lab0;
+    }
+    return true;
+}
+
+function r_step5j(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_63, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_64, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B7}\u{03C3}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5k(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_65, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_66, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03B7}\u{03C3}\u{03C4}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5l(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_67, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_68, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03BF}\u{03C5}\u{03BD}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step5m(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_69, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    context.b_test1 = false;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_70, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (env.cursor > env.limit_backward) {
+        return false;
+    }
+    context.S_s = env.slice_to();
+    if (context.S_s.length === 0) {
+        return false;
+    }
+    if (!env.slice_from("\u{03BF}\u{03C5}\u{03BC}")) {
+        return false;
+    }
+    var c = env.cursor;
+    var [bra, ket] = [env.cursor, env.cursor];
+    env.insert(bra, ket, context.S_s);
+    env.cursor = c;
+    return true;
+}
+
+function r_step6(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (env.find_among_b(A_71, context) == 0) {
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_from("\u{03BC}\u{03B1}")) {
+            return false;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    if (!context.b_test1) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if (env.find_among_b(A_72, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_step7(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_73, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        S_s: [],
+        b_test1: false,
+    };
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        if (!r_tolower(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    if (!r_has_min_length(env, context)) {
+        return false;
+    }
+    context.b_test1 = true;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_step1(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_steps1(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        if (!r_steps2(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    var v_5 = env.limit - env.cursor;
+    lab4: for (;;) {
+        if (!r_steps3(env, context)) {
+            break lab4;
+        }
+        break lab4;
+    }
+    env.cursor = env.limit - v_5;
+    var v_6 = env.limit - env.cursor;
+    lab5: for (;;) {
+        if (!r_steps4(env, context)) {
+            break lab5;
+        }
+        break lab5;
+    }
+    env.cursor = env.limit - v_6;
+    var v_7 = env.limit - env.cursor;
+    lab6: for (;;) {
+        if (!r_steps5(env, context)) {
+            break lab6;
+        }
+        break lab6;
+    }
+    env.cursor = env.limit - v_7;
+    var v_8 = env.limit - env.cursor;
+    lab7: for (;;) {
+        if (!r_steps6(env, context)) {
+            break lab7;
+        }
+        break lab7;
+    }
+    env.cursor = env.limit - v_8;
+    var v_9 = env.limit - env.cursor;
+    lab8: for (;;) {
+        if (!r_steps7(env, context)) {
+            break lab8;
+        }
+        break lab8;
+    }
+    env.cursor = env.limit - v_9;
+    var v_10 = env.limit - env.cursor;
+    lab9: for (;;) {
+        if (!r_steps8(env, context)) {
+            break lab9;
+        }
+        break lab9;
+    }
+    env.cursor = env.limit - v_10;
+    var v_11 = env.limit - env.cursor;
+    lab10: for (;;) {
+        if (!r_steps9(env, context)) {
+            break lab10;
+        }
+        break lab10;
+    }
+    env.cursor = env.limit - v_11;
+    var v_12 = env.limit - env.cursor;
+    lab11: for (;;) {
+        if (!r_steps10(env, context)) {
+            break lab11;
+        }
+        break lab11;
+    }
+    env.cursor = env.limit - v_12;
+    var v_13 = env.limit - env.cursor;
+    lab12: for (;;) {
+        if (!r_step2a(env, context)) {
+            break lab12;
+        }
+        break lab12;
+    }
+    env.cursor = env.limit - v_13;
+    var v_14 = env.limit - env.cursor;
+    lab13: for (;;) {
+        if (!r_step2b(env, context)) {
+            break lab13;
+        }
+        break lab13;
+    }
+    env.cursor = env.limit - v_14;
+    var v_15 = env.limit - env.cursor;
+    lab14: for (;;) {
+        if (!r_step2c(env, context)) {
+            break lab14;
+        }
+        break lab14;
+    }
+    env.cursor = env.limit - v_15;
+    var v_16 = env.limit - env.cursor;
+    lab15: for (;;) {
+        if (!r_step2d(env, context)) {
+            break lab15;
+        }
+        break lab15;
+    }
+    env.cursor = env.limit - v_16;
+    var v_17 = env.limit - env.cursor;
+    lab16: for (;;) {
+        if (!r_step3(env, context)) {
+            break lab16;
+        }
+        break lab16;
+    }
+    env.cursor = env.limit - v_17;
+    var v_18 = env.limit - env.cursor;
+    lab17: for (;;) {
+        if (!r_step4(env, context)) {
+            break lab17;
+        }
+        break lab17;
+    }
+    env.cursor = env.limit - v_18;
+    var v_19 = env.limit - env.cursor;
+    lab18: for (;;) {
+        if (!r_step5a(env, context)) {
+            break lab18;
+        }
+        break lab18;
+    }
+    env.cursor = env.limit - v_19;
+    var v_20 = env.limit - env.cursor;
+    lab19: for (;;) {
+        if (!r_step5b(env, context)) {
+            break lab19;
+        }
+        break lab19;
+    }
+    env.cursor = env.limit - v_20;
+    var v_21 = env.limit - env.cursor;
+    lab20: for (;;) {
+        if (!r_step5c(env, context)) {
+            break lab20;
+        }
+        break lab20;
+    }
+    env.cursor = env.limit - v_21;
+    var v_22 = env.limit - env.cursor;
+    lab21: for (;;) {
+        if (!r_step5d(env, context)) {
+            break lab21;
+        }
+        break lab21;
+    }
+    env.cursor = env.limit - v_22;
+    var v_23 = env.limit - env.cursor;
+    lab22: for (;;) {
+        if (!r_step5e(env, context)) {
+            break lab22;
+        }
+        break lab22;
+    }
+    env.cursor = env.limit - v_23;
+    var v_24 = env.limit - env.cursor;
+    lab23: for (;;) {
+        if (!r_step5f(env, context)) {
+            break lab23;
+        }
+        break lab23;
+    }
+    env.cursor = env.limit - v_24;
+    var v_25 = env.limit - env.cursor;
+    lab24: for (;;) {
+        if (!r_step5g(env, context)) {
+            break lab24;
+        }
+        break lab24;
+    }
+    env.cursor = env.limit - v_25;
+    var v_26 = env.limit - env.cursor;
+    lab25: for (;;) {
+        if (!r_step5h(env, context)) {
+            break lab25;
+        }
+        break lab25;
+    }
+    env.cursor = env.limit - v_26;
+    var v_27 = env.limit - env.cursor;
+    lab26: for (;;) {
+        if (!r_step5j(env, context)) {
+            break lab26;
+        }
+        break lab26;
+    }
+    env.cursor = env.limit - v_27;
+    var v_28 = env.limit - env.cursor;
+    lab27: for (;;) {
+        if (!r_step5i(env, context)) {
+            break lab27;
+        }
+        break lab27;
+    }
+    env.cursor = env.limit - v_28;
+    var v_29 = env.limit - env.cursor;
+    lab28: for (;;) {
+        if (!r_step5k(env, context)) {
+            break lab28;
+        }
+        break lab28;
+    }
+    env.cursor = env.limit - v_29;
+    var v_30 = env.limit - env.cursor;
+    lab29: for (;;) {
+        if (!r_step5l(env, context)) {
+            break lab29;
+        }
+        break lab29;
+    }
+    env.cursor = env.limit - v_30;
+    var v_31 = env.limit - env.cursor;
+    lab30: for (;;) {
+        if (!r_step5m(env, context)) {
+            break lab30;
+        }
+        break lab30;
+    }
+    env.cursor = env.limit - v_31;
+    var v_32 = env.limit - env.cursor;
+    lab31: for (;;) {
+        if (!r_step6(env, context)) {
+            break lab31;
+        }
+        break lab31;
+    }
+    env.cursor = env.limit - v_32;
+    var v_33 = env.limit - env.cursor;
+    lab32: for (;;) {
+        if (!r_step7(env, context)) {
+            break lab32;
+        }
+        break lab32;
+    }
+    env.cursor = env.limit - v_33;
+    env.cursor = env.limit_backward;
+    return true;
+}
@@ -0,0 +1,911 @@
+// Snowball stemmer for Hungarian, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/hungarian.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["cs", -1, -1, null],
+    ["dzs", -1, -1, null],
+    ["gy", -1, -1, null],
+    ["ly", -1, -1, null],
+    ["ny", -1, -1, null],
+    ["sz", -1, -1, null],
+    ["ty", -1, -1, null],
+    ["zs", -1, -1, null],
+]);
+
+const A_1 = among([
+    ["\u{00E1}", -1, 1, null],
+    ["\u{00E9}", -1, 2, null],
+]);
+
+const A_2 = among([
+    ["bb", -1, -1, null],
+    ["cc", -1, -1, null],
+    ["dd", -1, -1, null],
+    ["ff", -1, -1, null],
+    ["gg", -1, -1, null],
+    ["jj", -1, -1, null],
+    ["kk", -1, -1, null],
+    ["ll", -1, -1, null],
+    ["mm", -1, -1, null],
+    ["nn", -1, -1, null],
+    ["pp", -1, -1, null],
+    ["rr", -1, -1, null],
+    ["ccs", -1, -1, null],
+    ["ss", -1, -1, null],
+    ["zzs", -1, -1, null],
+    ["tt", -1, -1, null],
+    ["vv", -1, -1, null],
+    ["ggy", -1, -1, null],
+    ["lly", -1, -1, null],
+    ["nny", -1, -1, null],
+    ["tty", -1, -1, null],
+    ["ssz", -1, -1, null],
+    ["zz", -1, -1, null],
+]);
+
+const A_3 = among([
+    ["al", -1, 1, null],
+    ["el", -1, 2, null],
+]);
+
+const A_4 = among([
+    ["ba", -1, -1, null],
+    ["ra", -1, -1, null],
+    ["be", -1, -1, null],
+    ["re", -1, -1, null],
+    ["ig", -1, -1, null],
+    ["nak", -1, -1, null],
+    ["nek", -1, -1, null],
+    ["val", -1, -1, null],
+    ["vel", -1, -1, null],
+    ["ul", -1, -1, null],
+    ["b\u{0151}l", -1, -1, null],
+    ["r\u{0151}l", -1, -1, null],
+    ["t\u{0151}l", -1, -1, null],
+    ["n\u{00E1}l", -1, -1, null],
+    ["n\u{00E9}l", -1, -1, null],
+    ["b\u{00F3}l", -1, -1, null],
+    ["r\u{00F3}l", -1, -1, null],
+    ["t\u{00F3}l", -1, -1, null],
+    ["\u{00FC}l", -1, -1, null],
+    ["n", -1, -1, null],
+    ["an", 19, -1, null],
+    ["ban", 20, -1, null],
+    ["en", 19, -1, null],
+    ["ben", 22, -1, null],
+    ["k\u{00E9}ppen", 22, -1, null],
+    ["on", 19, -1, null],
+    ["\u{00F6}n", 19, -1, null],
+    ["k\u{00E9}pp", -1, -1, null],
+    ["kor", -1, -1, null],
+    ["t", -1, -1, null],
+    ["at", 29, -1, null],
+    ["et", 29, -1, null],
+    ["k\u{00E9}nt", 29, -1, null],
+    ["ank\u{00E9}nt", 32, -1, null],
+    ["enk\u{00E9}nt", 32, -1, null],
+    ["onk\u{00E9}nt", 32, -1, null],
+    ["ot", 29, -1, null],
+    ["\u{00E9}rt", 29, -1, null],
+    ["\u{00F6}t", 29, -1, null],
+    ["hez", -1, -1, null],
+    ["hoz", -1, -1, null],
+    ["h\u{00F6}z", -1, -1, null],
+    ["v\u{00E1}", -1, -1, null],
+    ["v\u{00E9}", -1, -1, null],
+]);
+
+const A_5 = among([
+    ["\u{00E1}n", -1, 2, null],
+    ["\u{00E9}n", -1, 1, null],
+    ["\u{00E1}nk\u{00E9}nt", -1, 3, null],
+]);
+
+const A_6 = among([
+    ["stul", -1, 2, null],
+    ["astul", 0, 1, null],
+    ["\u{00E1}stul", 0, 3, null],
+    ["st\u{00FC}l", -1, 2, null],
+    ["est\u{00FC}l", 3, 1, null],
+    ["\u{00E9}st\u{00FC}l", 3, 4, null],
+]);
+
+const A_7 = among([
+    ["\u{00E1}", -1, 1, null],
+    ["\u{00E9}", -1, 2, null],
+]);
+
+const A_8 = among([
+    ["k", -1, 7, null],
+    ["ak", 0, 4, null],
+    ["ek", 0, 6, null],
+    ["ok", 0, 5, null],
+    ["\u{00E1}k", 0, 1, null],
+    ["\u{00E9}k", 0, 2, null],
+    ["\u{00F6}k", 0, 3, null],
+]);
+
+const A_9 = among([
+    ["\u{00E9}i", -1, 7, null],
+    ["\u{00E1}\u{00E9}i", 0, 6, null],
+    ["\u{00E9}\u{00E9}i", 0, 5, null],
+    ["\u{00E9}", -1, 9, null],
+    ["k\u{00E9}", 3, 4, null],
+    ["ak\u{00E9}", 4, 1, null],
+    ["ek\u{00E9}", 4, 1, null],
+    ["ok\u{00E9}", 4, 1, null],
+    ["\u{00E1}k\u{00E9}", 4, 3, null],
+    ["\u{00E9}k\u{00E9}", 4, 2, null],
+    ["\u{00F6}k\u{00E9}", 4, 1, null],
+    ["\u{00E9}\u{00E9}", 3, 8, null],
+]);
+
+const A_10 = among([
+    ["a", -1, 18, null],
+    ["ja", 0, 17, null],
+    ["d", -1, 16, null],
+    ["ad", 2, 13, null],
+    ["ed", 2, 13, null],
+    ["od", 2, 13, null],
+    ["\u{00E1}d", 2, 14, null],
+    ["\u{00E9}d", 2, 15, null],
+    ["\u{00F6}d", 2, 13, null],
+    ["e", -1, 18, null],
+    ["je", 9, 17, null],
+    ["nk", -1, 4, null],
+    ["unk", 11, 1, null],
+    ["\u{00E1}nk", 11, 2, null],
+    ["\u{00E9}nk", 11, 3, null],
+    ["\u{00FC}nk", 11, 1, null],
+    ["uk", -1, 8, null],
+    ["juk", 16, 7, null],
+    ["\u{00E1}juk", 17, 5, null],
+    ["\u{00FC}k", -1, 8, null],
+    ["j\u{00FC}k", 19, 7, null],
+    ["\u{00E9}j\u{00FC}k", 20, 6, null],
+    ["m", -1, 12, null],
+    ["am", 22, 9, null],
+    ["em", 22, 9, null],
+    ["om", 22, 9, null],
+    ["\u{00E1}m", 22, 10, null],
+    ["\u{00E9}m", 22, 11, null],
+    ["o", -1, 18, null],
+    ["\u{00E1}", -1, 19, null],
+    ["\u{00E9}", -1, 20, null],
+]);
+
+const A_11 = among([
+    ["id", -1, 10, null],
+    ["aid", 0, 9, null],
+    ["jaid", 1, 6, null],
+    ["eid", 0, 9, null],
+    ["jeid", 3, 6, null],
+    ["\u{00E1}id", 0, 7, null],
+    ["\u{00E9}id", 0, 8, null],
+    ["i", -1, 15, null],
+    ["ai", 7, 14, null],
+    ["jai", 8, 11, null],
+    ["ei", 7, 14, null],
+    ["jei", 10, 11, null],
+    ["\u{00E1}i", 7, 12, null],
+    ["\u{00E9}i", 7, 13, null],
+    ["itek", -1, 24, null],
+    ["eitek", 14, 21, null],
+    ["jeitek", 15, 20, null],
+    ["\u{00E9}itek", 14, 23, null],
+    ["ik", -1, 29, null],
+    ["aik", 18, 26, null],
+    ["jaik", 19, 25, null],
+    ["eik", 18, 26, null],
+    ["jeik", 21, 25, null],
+    ["\u{00E1}ik", 18, 27, null],
+    ["\u{00E9}ik", 18, 28, null],
+    ["ink", -1, 20, null],
+    ["aink", 25, 17, null],
+    ["jaink", 26, 16, null],
+    ["eink", 25, 17, null],
+    ["jeink", 28, 16, null],
+    ["\u{00E1}ink", 25, 18, null],
+    ["\u{00E9}ink", 25, 19, null],
+    ["aitok", -1, 21, null],
+    ["jaitok", 32, 20, null],
+    ["\u{00E1}itok", -1, 22, null],
+    ["im", -1, 5, null],
+    ["aim", 35, 4, null],
+    ["jaim", 36, 1, null],
+    ["eim", 35, 4, null],
+    ["jeim", 38, 1, null],
+    ["\u{00E1}im", 35, 2, null],
+    ["\u{00E9}im", 35, 3, null],
+]);
+
+const G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 17, 36, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1];
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    lab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping(G_v, 97, 369)) {
+                break lab1;
+            }
+            golab2: for (;;) {
+                var v_2 = env.cursor;
+                lab3: for (;;) {
+                    if (!env.out_grouping(G_v, 97, 369)) {
+                        break lab3;
+                    }
+                    env.cursor = v_2;
+                    break golab2;
+                }
+                env.cursor = v_2;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            lab4: for (;;) {
+                var v_3 = env.cursor;
+                lab5: for (;;) {
+                    if (env.find_among(A_0, context) == 0) {
+                        break lab5;
+                    }
+                    break lab4;
+                }
+                env.cursor = v_3;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+                break lab4;
+            }
+            context.i_p1 = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        if (!env.out_grouping(G_v, 97, 369)) {
+            return false;
+        }
+        golab6: for (;;) {
+            lab7: for (;;) {
+                if (!env.in_grouping(G_v, 97, 369)) {
+                    break lab7;
+                }
+                break golab6;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        break lab0;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_v_ending(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_1, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_double(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.find_among_b(A_2, context) == 0) {
+        return false;
+    }
+    env.cursor = env.limit - v_1;
+    return true;
+}
+
+function r_undouble(env, context) {
+    if (env.cursor <= env.limit_backward) {
+        return false;
+    }
+    env.previous_char();
+    env.ket = env.cursor;
+    var c = env.byte_index_for_hop(-1);
+    if (env.limit_backward > c || c > env.limit) {
+        return false;
+    }
+    env.cursor = c;
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_instrum(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_3, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_double(env, context)) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_double(env, context)) {
+            return false;
+        }
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    if (!r_undouble(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_case(env, context) {
+    env.ket = env.cursor;
+    if (env.find_among_b(A_4, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    if (!r_v_ending(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_case_special(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_5, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_case_other(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_factive(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_double(env, context)) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_double(env, context)) {
+            return false;
+        }
+    }
+    if (!env.slice_del()) {
+        return false;
+    }
+    if (!r_undouble(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_plural(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_8, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_owned(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_9, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 8) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_sing_owner(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_10, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 8) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 10) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 11) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 12) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 13) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 14) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 15) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 16) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 17) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 18) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 19) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 20) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_plur_owner(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_11, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 8) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 9) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 10) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 11) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 12) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 13) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 14) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 15) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 16) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 17) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 18) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 19) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 20) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 21) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 22) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 23) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 24) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 25) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 26) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 27) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 28) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 29) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p1: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_instrum(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_case(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        if (!r_case_special(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    var v_5 = env.limit - env.cursor;
+    lab4: for (;;) {
+        if (!r_case_other(env, context)) {
+            break lab4;
+        }
+        break lab4;
+    }
+    env.cursor = env.limit - v_5;
+    var v_6 = env.limit - env.cursor;
+    lab5: for (;;) {
+        if (!r_factive(env, context)) {
+            break lab5;
+        }
+        break lab5;
+    }
+    env.cursor = env.limit - v_6;
+    var v_7 = env.limit - env.cursor;
+    lab6: for (;;) {
+        if (!r_owned(env, context)) {
+            break lab6;
+        }
+        break lab6;
+    }
+    env.cursor = env.limit - v_7;
+    var v_8 = env.limit - env.cursor;
+    lab7: for (;;) {
+        if (!r_sing_owner(env, context)) {
+            break lab7;
+        }
+        break lab7;
+    }
+    env.cursor = env.limit - v_8;
+    var v_9 = env.limit - env.cursor;
+    lab8: for (;;) {
+        if (!r_plur_owner(env, context)) {
+            break lab8;
+        }
+        break lab8;
+    }
+    env.cursor = env.limit - v_9;
+    var v_10 = env.limit - env.cursor;
+    lab9: for (;;) {
+        if (!r_plural(env, context)) {
+            break lab9;
+        }
+        break lab9;
+    }
+    env.cursor = env.limit - v_10;
+    env.cursor = env.limit_backward;
+    return true;
+}
@@ -0,0 +1,913 @@
+// Snowball stemmer for Italian, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/italian.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 7, null],
+    ["qu", 0, 6, null],
+    ["\u{00E1}", 0, 1, null],
+    ["\u{00E9}", 0, 2, null],
+    ["\u{00ED}", 0, 3, null],
+    ["\u{00F3}", 0, 4, null],
+    ["\u{00FA}", 0, 5, null],
+]);
+
+const A_1 = among([
+    ["", -1, 3, null],
+    ["I", 0, 1, null],
+    ["U", 0, 2, null],
+]);
+
+const A_2 = among([
+    ["la", -1, -1, null],
+    ["cela", 0, -1, null],
+    ["gliela", 0, -1, null],
+    ["mela", 0, -1, null],
+    ["tela", 0, -1, null],
+    ["vela", 0, -1, null],
+    ["le", -1, -1, null],
+    ["cele", 6, -1, null],
+    ["gliele", 6, -1, null],
+    ["mele", 6, -1, null],
+    ["tele", 6, -1, null],
+    ["vele", 6, -1, null],
+    ["ne", -1, -1, null],
+    ["cene", 12, -1, null],
+    ["gliene", 12, -1, null],
+    ["mene", 12, -1, null],
+    ["sene", 12, -1, null],
+    ["tene", 12, -1, null],
+    ["vene", 12, -1, null],
+    ["ci", -1, -1, null],
+    ["li", -1, -1, null],
+    ["celi", 20, -1, null],
+    ["glieli", 20, -1, null],
+    ["meli", 20, -1, null],
+    ["teli", 20, -1, null],
+    ["veli", 20, -1, null],
+    ["gli", 20, -1, null],
+    ["mi", -1, -1, null],
+    ["si", -1, -1, null],
+    ["ti", -1, -1, null],
+    ["vi", -1, -1, null],
+    ["lo", -1, -1, null],
+    ["celo", 31, -1, null],
+    ["glielo", 31, -1, null],
+    ["melo", 31, -1, null],
+    ["telo", 31, -1, null],
+    ["velo", 31, -1, null],
+]);
+
+const A_3 = among([
+    ["ando", -1, 1, null],
+    ["endo", -1, 1, null],
+    ["ar", -1, 2, null],
+    ["er", -1, 2, null],
+    ["ir", -1, 2, null],
+]);
+
+const A_4 = among([
+    ["ic", -1, -1, null],
+    ["abil", -1, -1, null],
+    ["os", -1, -1, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_5 = among([
+    ["ic", -1, 1, null],
+    ["abil", -1, 1, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_6 = among([
+    ["ica", -1, 1, null],
+    ["logia", -1, 3, null],
+    ["osa", -1, 1, null],
+    ["ista", -1, 1, null],
+    ["iva", -1, 9, null],
+    ["anza", -1, 1, null],
+    ["enza", -1, 5, null],
+    ["ice", -1, 1, null],
+    ["atrice", 7, 1, null],
+    ["iche", -1, 1, null],
+    ["logie", -1, 3, null],
+    ["abile", -1, 1, null],
+    ["ibile", -1, 1, null],
+    ["usione", -1, 4, null],
+    ["azione", -1, 2, null],
+    ["uzione", -1, 4, null],
+    ["atore", -1, 2, null],
+    ["ose", -1, 1, null],
+    ["ante", -1, 1, null],
+    ["mente", -1, 1, null],
+    ["amente", 19, 7, null],
+    ["iste", -1, 1, null],
+    ["ive", -1, 9, null],
+    ["anze", -1, 1, null],
+    ["enze", -1, 5, null],
+    ["ici", -1, 1, null],
+    ["atrici", 25, 1, null],
+    ["ichi", -1, 1, null],
+    ["abili", -1, 1, null],
+    ["ibili", -1, 1, null],
+    ["ismi", -1, 1, null],
+    ["usioni", -1, 4, null],
+    ["azioni", -1, 2, null],
+    ["uzioni", -1, 4, null],
+    ["atori", -1, 2, null],
+    ["osi", -1, 1, null],
+    ["anti", -1, 1, null],
+    ["amenti", -1, 6, null],
+    ["imenti", -1, 6, null],
+    ["isti", -1, 1, null],
+    ["ivi", -1, 9, null],
+    ["ico", -1, 1, null],
+    ["ismo", -1, 1, null],
+    ["oso", -1, 1, null],
+    ["amento", -1, 6, null],
+    ["imento", -1, 6, null],
+    ["ivo", -1, 9, null],
+    ["it\u{00E0}", -1, 8, null],
+    ["ist\u{00E0}", -1, 1, null],
+    ["ist\u{00E8}", -1, 1, null],
+    ["ist\u{00EC}", -1, 1, null],
+]);
+
+const A_7 = among([
+    ["isca", -1, 1, null],
+    ["enda", -1, 1, null],
+    ["ata", -1, 1, null],
+    ["ita", -1, 1, null],
+    ["uta", -1, 1, null],
+    ["ava", -1, 1, null],
+    ["eva", -1, 1, null],
+    ["iva", -1, 1, null],
+    ["erebbe", -1, 1, null],
+    ["irebbe", -1, 1, null],
+    ["isce", -1, 1, null],
+    ["ende", -1, 1, null],
+    ["are", -1, 1, null],
+    ["ere", -1, 1, null],
+    ["ire", -1, 1, null],
+    ["asse", -1, 1, null],
+    ["ate", -1, 1, null],
+    ["avate", 16, 1, null],
+    ["evate", 16, 1, null],
+    ["ivate", 16, 1, null],
+    ["ete", -1, 1, null],
+    ["erete", 20, 1, null],
+    ["irete", 20, 1, null],
+    ["ite", -1, 1, null],
+    ["ereste", -1, 1, null],
+    ["ireste", -1, 1, null],
+    ["ute", -1, 1, null],
+    ["erai", -1, 1, null],
+    ["irai", -1, 1, null],
+    ["isci", -1, 1, null],
+    ["endi", -1, 1, null],
+    ["erei", -1, 1, null],
+    ["irei", -1, 1, null],
+    ["assi", -1, 1, null],
+    ["ati", -1, 1, null],
+    ["iti", -1, 1, null],
+    ["eresti", -1, 1, null],
+    ["iresti", -1, 1, null],
+    ["uti", -1, 1, null],
+    ["avi", -1, 1, null],
+    ["evi", -1, 1, null],
+    ["ivi", -1, 1, null],
+    ["isco", -1, 1, null],
+    ["ando", -1, 1, null],
+    ["endo", -1, 1, null],
+    ["Yamo", -1, 1, null],
+    ["iamo", -1, 1, null],
+    ["avamo", -1, 1, null],
+    ["evamo", -1, 1, null],
+    ["ivamo", -1, 1, null],
+    ["eremo", -1, 1, null],
+    ["iremo", -1, 1, null],
+    ["assimo", -1, 1, null],
+    ["ammo", -1, 1, null],
+    ["emmo", -1, 1, null],
+    ["eremmo", 54, 1, null],
+    ["iremmo", 54, 1, null],
+    ["immo", -1, 1, null],
+    ["ano", -1, 1, null],
+    ["iscano", 58, 1, null],
+    ["avano", 58, 1, null],
+    ["evano", 58, 1, null],
+    ["ivano", 58, 1, null],
+    ["eranno", -1, 1, null],
+    ["iranno", -1, 1, null],
+    ["ono", -1, 1, null],
+    ["iscono", 65, 1, null],
+    ["arono", 65, 1, null],
+    ["erono", 65, 1, null],
+    ["irono", 65, 1, null],
+    ["erebbero", -1, 1, null],
+    ["irebbero", -1, 1, null],
+    ["assero", -1, 1, null],
+    ["essero", -1, 1, null],
+    ["issero", -1, 1, null],
+    ["ato", -1, 1, null],
+    ["ito", -1, 1, null],
+    ["uto", -1, 1, null],
+    ["avo", -1, 1, null],
+    ["evo", -1, 1, null],
+    ["ivo", -1, 1, null],
+    ["ar", -1, 1, null],
+    ["ir", -1, 1, null],
+    ["er\u{00E0}", -1, 1, null],
+    ["ir\u{00E0}", -1, 1, null],
+    ["er\u{00F2}", -1, 1, null],
+    ["ir\u{00F2}", -1, 1, null],
+]);
+
+const G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 8, 2, 1];
+
+const G_AEIO = [17, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 8, 2];
+
+const G_CG = [17];
+
+function r_prelude(env, context) {
+    var among_var;
+    var v_1 = env.cursor;
+    replab0: for (;;) {
+        var v_2 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("\u{00E0}")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("\u{00E8}")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_from("\u{00EC}")) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (!env.slice_from("\u{00F2}")) {
+                    return false;
+                }
+            } else if (among_var == 5) {
+                if (!env.slice_from("\u{00F9}")) {
+                    return false;
+                }
+            } else if (among_var == 6) {
+                if (!env.slice_from("qU")) {
+                    return false;
+                }
+            } else if (among_var == 7) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_2;
+        break replab0;
+    }
+    env.cursor = v_1;
+    replab2: for (;;) {
+        var v_3 = env.cursor;
+        lab3: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            golab4: for (;;) {
+                var v_4 = env.cursor;
+                lab5: for (;;) {
+                    if (!env.in_grouping(G_v, 97, 249)) {
+                        break lab5;
+                    }
+                    env.bra = env.cursor;
+                    lab6: for (;;) {
+                        var v_5 = env.cursor;
+                        lab7: for (;;) {
+                            if (!env.eq_s("u")) {
+                                break lab7;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 249)) {
+                                break lab7;
+                            }
+                            if (!env.slice_from("U")) {
+                                return false;
+                            }
+                            break lab6;
+                        }
+                        env.cursor = v_5;
+                        if (!env.eq_s("i")) {
+                            break lab5;
+                        }
+                        env.ket = env.cursor;
+                        if (!env.in_grouping(G_v, 97, 249)) {
+                            break lab5;
+                        }
+                        if (!env.slice_from("I")) {
+                            return false;
+                        }
+                        break lab6;
+                    }
+                    env.cursor = v_4;
+                    break golab4;
+                }
+                env.cursor = v_4;
+                if (env.cursor >= env.limit) {
+                    break lab3;
+                }
+                env.next_char();
+            }
+            continue replab2;
+        }
+        env.cursor = v_3;
+        break replab2;
+    }
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_pV = env.limit;
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            lab2: for (;;) {
+                if (!env.in_grouping(G_v, 97, 249)) {
+                    break lab2;
+                }
+                lab3: for (;;) {
+                    var v_3 = env.cursor;
+                    lab4: for (;;) {
+                        if (!env.out_grouping(G_v, 97, 249)) {
+                            break lab4;
+                        }
+                        golab5: for (;;) {
+                            lab6: for (;;) {
+                                if (!env.in_grouping(G_v, 97, 249)) {
+                                    break lab6;
+                                }
+                                break golab5;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab4;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    env.cursor = v_3;
+                    if (!env.in_grouping(G_v, 97, 249)) {
+                        break lab2;
+                    }
+                    golab7: for (;;) {
+                        lab8: for (;;) {
+                            if (!env.out_grouping(G_v, 97, 249)) {
+                                break lab8;
+                            }
+                            break golab7;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab2;
+                        }
+                        env.next_char();
+                    }
+                    break lab3;
+                }
+                break lab1;
+            }
+            env.cursor = v_2;
+            if (!env.out_grouping(G_v, 97, 249)) {
+                break lab0;
+            }
+            lab9: for (;;) {
+                var v_6 = env.cursor;
+                lab10: for (;;) {
+                    if (!env.out_grouping(G_v, 97, 249)) {
+                        break lab10;
+                    }
+                    golab11: for (;;) {
+                        lab12: for (;;) {
+                            if (!env.in_grouping(G_v, 97, 249)) {
+                                break lab12;
+                            }
+                            break golab11;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab10;
+                        }
+                        env.next_char();
+                    }
+                    break lab9;
+                }
+                env.cursor = v_6;
+                if (!env.in_grouping(G_v, 97, 249)) {
+                    break lab0;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+                break lab9;
+            }
+            break lab1;
+        }
+        context.i_pV = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_8 = env.cursor;
+    lab13: for (;;) {
+        golab14: for (;;) {
+            lab15: for (;;) {
+                if (!env.in_grouping(G_v, 97, 249)) {
+                    break lab15;
+                }
+                break golab14;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab16: for (;;) {
+            lab17: for (;;) {
+                if (!env.out_grouping(G_v, 97, 249)) {
+                    break lab17;
+                }
+                break golab16;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        golab18: for (;;) {
+            lab19: for (;;) {
+                if (!env.in_grouping(G_v, 97, 249)) {
+                    break lab19;
+                }
+                break golab18;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab20: for (;;) {
+            lab21: for (;;) {
+                if (!env.out_grouping(G_v, 97, 249)) {
+                    break lab21;
+                }
+                break golab20;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab13;
+    }
+    env.cursor = v_8;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i3 = 0, _n3 = 1; _i3 < _n3; _i3++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_1, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_RV(env, context) {
+    if (!(context.i_pV <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_attached_pronoun(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_2, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    among_var = env.find_among_b(A_3, context);
+    if (among_var == 0) {
+        return false;
+    }
+    if (!r_RV(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        lab0: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("ic")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+    } else if (among_var == 3) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("log")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("u")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ente")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_2 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_4, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            if (among_var == 0) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            } else if (among_var == 1) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab1;
+        }
+    } else if (among_var == 8) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_5, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab2;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab2;
+        }
+    } else if (among_var == 9) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_4 = env.limit - env.cursor;
+        lab3: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("at")) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            if (!env.eq_s_b("ic")) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab3;
+        }
+    }
+    return true;
+}
+
+function r_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function r_vowel_suffix(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        if (!env.in_grouping_b(G_AEIO, 97, 242)) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!r_RV(env, context)) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.ket = env.cursor;
+        if (!env.eq_s_b("i")) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!r_RV(env, context)) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab0;
+    }
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("h")) {
+            env.cursor = env.limit - v_2;
+            break lab1;
+        }
+        env.bra = env.cursor;
+        if (!env.in_grouping_b(G_CG, 99, 103)) {
+            env.cursor = env.limit - v_2;
+            break lab1;
+        }
+        if (!r_RV(env, context)) {
+            env.cursor = env.limit - v_2;
+            break lab1;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab1;
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p2: 0,
+        i_p1: 0,
+        i_pV: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_prelude(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_attached_pronoun(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        lab4: for (;;) {
+            var v_5 = env.limit - env.cursor;
+            lab5: for (;;) {
+                if (!r_standard_suffix(env, context)) {
+                    break lab5;
+                }
+                break lab4;
+            }
+            env.cursor = env.limit - v_5;
+            if (!r_verb_suffix(env, context)) {
+                break lab3;
+            }
+            break lab4;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    var v_6 = env.limit - env.cursor;
+    lab6: for (;;) {
+        if (!r_vowel_suffix(env, context)) {
+            break lab6;
+        }
+        break lab6;
+    }
+    env.cursor = env.limit - v_6;
+    env.cursor = env.limit_backward;
+    var v_7 = env.cursor;
+    lab7: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab7;
+        }
+        break lab7;
+    }
+    env.cursor = v_7;
+    return true;
+}
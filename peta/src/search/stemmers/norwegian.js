@@ -0,0 +1,237 @@
+// Snowball stemmer for Norwegian, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/norwegian.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["a", -1, 1, null],
+    ["e", -1, 1, null],
+    ["ede", 1, 1, null],
+    ["ande", 1, 1, null],
+    ["ende", 1, 1, null],
+    ["ane", 1, 1, null],
+    ["ene", 1, 1, null],
+    ["hetene", 6, 1, null],
+    ["erte", 1, 3, null],
+    ["en", -1, 1, null],
+    ["heten", 9, 1, null],
+    ["ar", -1, 1, null],
+    ["er", -1, 1, null],
+    ["heter", 12, 1, null],
+    ["s", -1, 2, null],
+    ["as", 14, 1, null],
+    ["es", 14, 1, null],
+    ["edes", 16, 1, null],
+    ["endes", 16, 1, null],
+    ["enes", 16, 1, null],
+    ["hetenes", 19, 1, null],
+    ["ens", 14, 1, null],
+    ["hetens", 21, 1, null],
+    ["ers", 14, 1, null],
+    ["ets", 14, 1, null],
+    ["et", -1, 1, null],
+    ["het", 25, 1, null],
+    ["ert", -1, 3, null],
+    ["ast", -1, 1, null],
+]);
+
+const A_1 = among([
+    ["dt", -1, -1, null],
+    ["vt", -1, -1, null],
+]);
+
+const A_2 = among([
+    ["leg", -1, 1, null],
+    ["eleg", 0, 1, null],
+    ["ig", -1, 1, null],
+    ["eig", 2, 1, null],
+    ["lig", 2, 1, null],
+    ["elig", 4, 1, null],
+    ["els", -1, 1, null],
+    ["lov", -1, 1, null],
+    ["elov", 7, 1, null],
+    ["slov", 7, 1, null],
+    ["hetslov", 9, 1, null],
+]);
+
+const G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 128];
+
+const G_s_ending = [119, 125, 149, 1];
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    var v_1 = env.cursor;
+    var c = env.byte_index_for_hop(3);
+    if (0 > c || c > env.limit) {
+        return false;
+    }
+    env.cursor = c;
+    context.i_x = env.cursor;
+    env.cursor = v_1;
+    golab0: for (;;) {
+        var v_2 = env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping(G_v, 97, 248)) {
+                break lab1;
+            }
+            env.cursor = v_2;
+            break golab0;
+        }
+        env.cursor = v_2;
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab2: for (;;) {
+        lab3: for (;;) {
+            if (!env.out_grouping(G_v, 97, 248)) {
+                break lab3;
+            }
+            break golab2;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p1 = env.cursor;
+    lab4: for (;;) {
+        if (!(context.i_p1 < context.i_x)) {
+            break lab4;
+        }
+        context.i_p1 = context.i_x;
+        break lab4;
+    }
+    return true;
+}
+
+function r_main_suffix(env, context) {
+    var among_var;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    var v_2 = env.limit_backward;
+    env.limit_backward = context.i_p1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_0, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        lab0: for (;;) {
+            var v_3 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!env.in_grouping_b(G_s_ending, 98, 122)) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_3;
+            if (!env.eq_s_b("k")) {
+                return false;
+            }
+            if (!env.out_grouping_b(G_v, 97, 248)) {
+                return false;
+            }
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("er")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_consonant_pair(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    var v_3 = env.limit_backward;
+    env.limit_backward = context.i_p1;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_1, context) == 0) {
+        env.limit_backward = v_3;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_3;
+    env.cursor = env.limit - v_1;
+    if (env.cursor <= env.limit_backward) {
+        return false;
+    }
+    env.previous_char();
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_other_suffix(env, context) {
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+
+    var v_2 = env.limit_backward;
+    env.limit_backward = context.i_p1;
+    env.ket = env.cursor;
+
+    if (env.find_among_b(A_2, context) == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+
+    if (!env.slice_del()) {
+        return false;
+    }
+
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_x: 0,
+        i_p1: 0,
+    };
+
+    var v_1 = env.cursor;
+    r_mark_regions(env, context);
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+
+    var v_2 = env.limit - env.cursor;
+    r_main_suffix(env, context);
+    env.cursor = env.limit - v_2;
+
+    var v_3 = env.limit - env.cursor;
+    r_consonant_pair(env, context);
+    env.cursor = env.limit - v_3;
+
+    var v_4 = env.limit - env.cursor;
+    r_other_suffix(env, context);
+    env.cursor = env.limit - v_4;
+    env.cursor = env.limit_backward;
+
+    return true;
+}
@@ -0,0 +1,855 @@
+// Snowball stemmer for Portuguese, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/portuguese.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 3, null],
+    ["\u{00E3}", 0, 1, null],
+    ["\u{00F5}", 0, 2, null],
+]);
+
+const A_1 = among([
+    ["", -1, 3, null],
+    ["a~", 0, 1, null],
+    ["o~", 0, 2, null],
+]);
+
+const A_2 = among([
+    ["ic", -1, -1, null],
+    ["ad", -1, -1, null],
+    ["os", -1, -1, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_3 = among([
+    ["ante", -1, 1, null],
+    ["avel", -1, 1, null],
+    ["\u{00ED}vel", -1, 1, null],
+]);
+
+const A_4 = among([
+    ["ic", -1, 1, null],
+    ["abil", -1, 1, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_5 = among([
+    ["ica", -1, 1, null],
+    ["\u{00E2}ncia", -1, 1, null],
+    ["\u{00EA}ncia", -1, 4, null],
+    ["logia", -1, 2, null],
+    ["ira", -1, 9, null],
+    ["adora", -1, 1, null],
+    ["osa", -1, 1, null],
+    ["ista", -1, 1, null],
+    ["iva", -1, 8, null],
+    ["eza", -1, 1, null],
+    ["idade", -1, 7, null],
+    ["ante", -1, 1, null],
+    ["mente", -1, 6, null],
+    ["amente", 12, 5, null],
+    ["\u{00E1}vel", -1, 1, null],
+    ["\u{00ED}vel", -1, 1, null],
+    ["ico", -1, 1, null],
+    ["ismo", -1, 1, null],
+    ["oso", -1, 1, null],
+    ["amento", -1, 1, null],
+    ["imento", -1, 1, null],
+    ["ivo", -1, 8, null],
+    ["a\u{00E7}a~o", -1, 1, null],
+    ["u\u{00E7}a~o", -1, 3, null],
+    ["ador", -1, 1, null],
+    ["icas", -1, 1, null],
+    ["\u{00EA}ncias", -1, 4, null],
+    ["logias", -1, 2, null],
+    ["iras", -1, 9, null],
+    ["adoras", -1, 1, null],
+    ["osas", -1, 1, null],
+    ["istas", -1, 1, null],
+    ["ivas", -1, 8, null],
+    ["ezas", -1, 1, null],
+    ["idades", -1, 7, null],
+    ["adores", -1, 1, null],
+    ["antes", -1, 1, null],
+    ["a\u{00E7}o~es", -1, 1, null],
+    ["u\u{00E7}o~es", -1, 3, null],
+    ["icos", -1, 1, null],
+    ["ismos", -1, 1, null],
+    ["osos", -1, 1, null],
+    ["amentos", -1, 1, null],
+    ["imentos", -1, 1, null],
+    ["ivos", -1, 8, null],
+]);
+
+const A_6 = among([
+    ["ada", -1, 1, null],
+    ["ida", -1, 1, null],
+    ["ia", -1, 1, null],
+    ["aria", 2, 1, null],
+    ["eria", 2, 1, null],
+    ["iria", 2, 1, null],
+    ["ara", -1, 1, null],
+    ["era", -1, 1, null],
+    ["ira", -1, 1, null],
+    ["ava", -1, 1, null],
+    ["asse", -1, 1, null],
+    ["esse", -1, 1, null],
+    ["isse", -1, 1, null],
+    ["aste", -1, 1, null],
+    ["este", -1, 1, null],
+    ["iste", -1, 1, null],
+    ["ei", -1, 1, null],
+    ["arei", 16, 1, null],
+    ["erei", 16, 1, null],
+    ["irei", 16, 1, null],
+    ["am", -1, 1, null],
+    ["iam", 20, 1, null],
+    ["ariam", 21, 1, null],
+    ["eriam", 21, 1, null],
+    ["iriam", 21, 1, null],
+    ["aram", 20, 1, null],
+    ["eram", 20, 1, null],
+    ["iram", 20, 1, null],
+    ["avam", 20, 1, null],
+    ["em", -1, 1, null],
+    ["arem", 29, 1, null],
+    ["erem", 29, 1, null],
+    ["irem", 29, 1, null],
+    ["assem", 29, 1, null],
+    ["essem", 29, 1, null],
+    ["issem", 29, 1, null],
+    ["ado", -1, 1, null],
+    ["ido", -1, 1, null],
+    ["ando", -1, 1, null],
+    ["endo", -1, 1, null],
+    ["indo", -1, 1, null],
+    ["ara~o", -1, 1, null],
+    ["era~o", -1, 1, null],
+    ["ira~o", -1, 1, null],
+    ["ar", -1, 1, null],
+    ["er", -1, 1, null],
+    ["ir", -1, 1, null],
+    ["as", -1, 1, null],
+    ["adas", 47, 1, null],
+    ["idas", 47, 1, null],
+    ["ias", 47, 1, null],
+    ["arias", 50, 1, null],
+    ["erias", 50, 1, null],
+    ["irias", 50, 1, null],
+    ["aras", 47, 1, null],
+    ["eras", 47, 1, null],
+    ["iras", 47, 1, null],
+    ["avas", 47, 1, null],
+    ["es", -1, 1, null],
+    ["ardes", 58, 1, null],
+    ["erdes", 58, 1, null],
+    ["irdes", 58, 1, null],
+    ["ares", 58, 1, null],
+    ["eres", 58, 1, null],
+    ["ires", 58, 1, null],
+    ["asses", 58, 1, null],
+    ["esses", 58, 1, null],
+    ["isses", 58, 1, null],
+    ["astes", 58, 1, null],
+    ["estes", 58, 1, null],
+    ["istes", 58, 1, null],
+    ["is", -1, 1, null],
+    ["ais", 71, 1, null],
+    ["eis", 71, 1, null],
+    ["areis", 73, 1, null],
+    ["ereis", 73, 1, null],
+    ["ireis", 73, 1, null],
+    ["\u{00E1}reis", 73, 1, null],
+    ["\u{00E9}reis", 73, 1, null],
+    ["\u{00ED}reis", 73, 1, null],
+    ["\u{00E1}sseis", 73, 1, null],
+    ["\u{00E9}sseis", 73, 1, null],
+    ["\u{00ED}sseis", 73, 1, null],
+    ["\u{00E1}veis", 73, 1, null],
+    ["\u{00ED}eis", 73, 1, null],
+    ["ar\u{00ED}eis", 84, 1, null],
+    ["er\u{00ED}eis", 84, 1, null],
+    ["ir\u{00ED}eis", 84, 1, null],
+    ["ados", -1, 1, null],
+    ["idos", -1, 1, null],
+    ["amos", -1, 1, null],
+    ["\u{00E1}ramos", 90, 1, null],
+    ["\u{00E9}ramos", 90, 1, null],
+    ["\u{00ED}ramos", 90, 1, null],
+    ["\u{00E1}vamos", 90, 1, null],
+    ["\u{00ED}amos", 90, 1, null],
+    ["ar\u{00ED}amos", 95, 1, null],
+    ["er\u{00ED}amos", 95, 1, null],
+    ["ir\u{00ED}amos", 95, 1, null],
+    ["emos", -1, 1, null],
+    ["aremos", 99, 1, null],
+    ["eremos", 99, 1, null],
+    ["iremos", 99, 1, null],
+    ["\u{00E1}ssemos", 99, 1, null],
+    ["\u{00EA}ssemos", 99, 1, null],
+    ["\u{00ED}ssemos", 99, 1, null],
+    ["imos", -1, 1, null],
+    ["armos", -1, 1, null],
+    ["ermos", -1, 1, null],
+    ["irmos", -1, 1, null],
+    ["\u{00E1}mos", -1, 1, null],
+    ["ar\u{00E1}s", -1, 1, null],
+    ["er\u{00E1}s", -1, 1, null],
+    ["ir\u{00E1}s", -1, 1, null],
+    ["eu", -1, 1, null],
+    ["iu", -1, 1, null],
+    ["ou", -1, 1, null],
+    ["ar\u{00E1}", -1, 1, null],
+    ["er\u{00E1}", -1, 1, null],
+    ["ir\u{00E1}", -1, 1, null],
+]);
+
+const A_7 = among([
+    ["a", -1, 1, null],
+    ["i", -1, 1, null],
+    ["o", -1, 1, null],
+    ["os", -1, 1, null],
+    ["\u{00E1}", -1, 1, null],
+    ["\u{00ED}", -1, 1, null],
+    ["\u{00F3}", -1, 1, null],
+]);
+
+const A_8 = among([
+    ["e", -1, 1, null],
+    ["\u{00E7}", -1, 2, null],
+    ["\u{00E9}", -1, 1, null],
+    ["\u{00EA}", -1, 1, null],
+]);
+
+const G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 19, 12, 2];
+
+function r_prelude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("a~")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("o~")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_pV = env.limit;
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            lab2: for (;;) {
+                if (!env.in_grouping(G_v, 97, 250)) {
+                    break lab2;
+                }
+                lab3: for (;;) {
+                    var v_3 = env.cursor;
+                    lab4: for (;;) {
+                        if (!env.out_grouping(G_v, 97, 250)) {
+                            break lab4;
+                        }
+                        golab5: for (;;) {
+                            lab6: for (;;) {
+                                if (!env.in_grouping(G_v, 97, 250)) {
+                                    break lab6;
+                                }
+                                break golab5;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab4;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    env.cursor = v_3;
+                    if (!env.in_grouping(G_v, 97, 250)) {
+                        break lab2;
+                    }
+                    golab7: for (;;) {
+                        lab8: for (;;) {
+                            if (!env.out_grouping(G_v, 97, 250)) {
+                                break lab8;
+                            }
+                            break golab7;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab2;
+                        }
+                        env.next_char();
+                    }
+                    break lab3;
+                }
+                break lab1;
+            }
+            env.cursor = v_2;
+            if (!env.out_grouping(G_v, 97, 250)) {
+                break lab0;
+            }
+            lab9: for (;;) {
+                var v_6 = env.cursor;
+                lab10: for (;;) {
+                    if (!env.out_grouping(G_v, 97, 250)) {
+                        break lab10;
+                    }
+                    golab11: for (;;) {
+                        lab12: for (;;) {
+                            if (!env.in_grouping(G_v, 97, 250)) {
+                                break lab12;
+                            }
+                            break golab11;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab10;
+                        }
+                        env.next_char();
+                    }
+                    break lab9;
+                }
+                env.cursor = v_6;
+                if (!env.in_grouping(G_v, 97, 250)) {
+                    break lab0;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+                break lab9;
+            }
+            break lab1;
+        }
+        context.i_pV = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_8 = env.cursor;
+    lab13: for (;;) {
+        golab14: for (;;) {
+            lab15: for (;;) {
+                if (!env.in_grouping(G_v, 97, 250)) {
+                    break lab15;
+                }
+                break golab14;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab16: for (;;) {
+            lab17: for (;;) {
+                if (!env.out_grouping(G_v, 97, 250)) {
+                    break lab17;
+                }
+                break golab16;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        golab18: for (;;) {
+            lab19: for (;;) {
+                if (!env.in_grouping(G_v, 97, 250)) {
+                    break lab19;
+                }
+                break golab18;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab20: for (;;) {
+            lab21: for (;;) {
+                if (!env.out_grouping(G_v, 97, 250)) {
+                    break lab21;
+                }
+                break golab20;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab13;
+    }
+    env.cursor = v_8;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_1, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("\u{00E3}")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("\u{00F5}")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_RV(env, context) {
+    if (!(context.i_pV <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_5, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("log")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("u")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ente")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        lab0: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_2, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            if (among_var == 0) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            } else if (among_var == 1) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab0;
+        }
+    } else if (among_var == 6) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_2 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_3, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab1;
+        }
+    } else if (among_var == 7) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_4, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab2;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab2;
+        }
+    } else if (among_var == 8) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_4 = env.limit - env.cursor;
+        lab3: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("at")) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab3;
+        }
+    } else if (among_var == 9) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.eq_s_b("e")) {
+            return false;
+        }
+        if (!env.slice_from("ir")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function r_residual_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_residual_form(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_8, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.ket = env.cursor;
+        lab0: for (;;) {
+            var v_1 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!env.eq_s_b("u")) {
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                var v_2 = env.limit - env.cursor;
+                if (!env.eq_s_b("g")) {
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!env.eq_s_b("i")) {
+                return false;
+            }
+            env.bra = env.cursor;
+            var v_3 = env.limit - env.cursor;
+            if (!env.eq_s_b("c")) {
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+            break lab0;
+        }
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("c")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p2: 0,
+        i_p1: 0,
+        i_pV: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_prelude(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        lab3: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            lab4: for (;;) {
+                var v_5 = env.limit - env.cursor;
+                lab5: for (;;) {
+                    var v_6 = env.limit - env.cursor;
+                    lab6: for (;;) {
+                        if (!r_standard_suffix(env, context)) {
+                            break lab6;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!r_verb_suffix(env, context)) {
+                        break lab4;
+                    }
+                    break lab5;
+                }
+                env.cursor = env.limit - v_5;
+                var v_7 = env.limit - env.cursor;
+                lab7: for (;;) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("i")) {
+                        break lab7;
+                    }
+                    env.bra = env.cursor;
+                    var v_8 = env.limit - env.cursor;
+                    if (!env.eq_s_b("c")) {
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_8;
+                    if (!r_RV(env, context)) {
+                        break lab7;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_7;
+                break lab3;
+            }
+            env.cursor = env.limit - v_4;
+            if (!r_residual_suffix(env, context)) {
+                break lab2;
+            }
+            break lab3;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_9 = env.limit - env.cursor;
+    lab8: for (;;) {
+        if (!r_residual_form(env, context)) {
+            break lab8;
+        }
+        break lab8;
+    }
+    env.cursor = env.limit - v_9;
+    env.cursor = env.limit_backward;
+    var v_10 = env.cursor;
+    lab9: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab9;
+        }
+        break lab9;
+    }
+    env.cursor = v_10;
+    return true;
+}
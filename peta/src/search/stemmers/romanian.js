@@ -0,0 +1,809 @@
+// Snowball stemmer for Romanian, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/romanian.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 3, null],
+    ["I", 0, 1, null],
+    ["U", 0, 2, null],
+]);
+
+const A_1 = among([
+    ["ea", -1, 3, null],
+    ["a\u{0163}ia", -1, 7, null],
+    ["aua", -1, 2, null],
+    ["iua", -1, 4, null],
+    ["a\u{0163}ie", -1, 7, null],
+    ["ele", -1, 3, null],
+    ["ile", -1, 5, null],
+    ["iile", 6, 4, null],
+    ["iei", -1, 4, null],
+    ["atei", -1, 6, null],
+    ["ii", -1, 4, null],
+    ["ului", -1, 1, null],
+    ["ul", -1, 1, null],
+    ["elor", -1, 3, null],
+    ["ilor", -1, 4, null],
+    ["iilor", 14, 4, null],
+]);
+
+const A_2 = among([
+    ["icala", -1, 4, null],
+    ["iciva", -1, 4, null],
+    ["ativa", -1, 5, null],
+    ["itiva", -1, 6, null],
+    ["icale", -1, 4, null],
+    ["a\u{0163}iune", -1, 5, null],
+    ["i\u{0163}iune", -1, 6, null],
+    ["atoare", -1, 5, null],
+    ["itoare", -1, 6, null],
+    ["\u{0103}toare", -1, 5, null],
+    ["icitate", -1, 4, null],
+    ["abilitate", -1, 1, null],
+    ["ibilitate", -1, 2, null],
+    ["ivitate", -1, 3, null],
+    ["icive", -1, 4, null],
+    ["ative", -1, 5, null],
+    ["itive", -1, 6, null],
+    ["icali", -1, 4, null],
+    ["atori", -1, 5, null],
+    ["icatori", 18, 4, null],
+    ["itori", -1, 6, null],
+    ["\u{0103}tori", -1, 5, null],
+    ["icitati", -1, 4, null],
+    ["abilitati", -1, 1, null],
+    ["ivitati", -1, 3, null],
+    ["icivi", -1, 4, null],
+    ["ativi", -1, 5, null],
+    ["itivi", -1, 6, null],
+    ["icit\u{0103}i", -1, 4, null],
+    ["abilit\u{0103}i", -1, 1, null],
+    ["ivit\u{0103}i", -1, 3, null],
+    ["icit\u{0103}\u{0163}i", -1, 4, null],
+    ["abilit\u{0103}\u{0163}i", -1, 1, null],
+    ["ivit\u{0103}\u{0163}i", -1, 3, null],
+    ["ical", -1, 4, null],
+    ["ator", -1, 5, null],
+    ["icator", 35, 4, null],
+    ["itor", -1, 6, null],
+    ["\u{0103}tor", -1, 5, null],
+    ["iciv", -1, 4, null],
+    ["ativ", -1, 5, null],
+    ["itiv", -1, 6, null],
+    ["ical\u{0103}", -1, 4, null],
+    ["iciv\u{0103}", -1, 4, null],
+    ["ativ\u{0103}", -1, 5, null],
+    ["itiv\u{0103}", -1, 6, null],
+]);
+
+const A_3 = among([
+    ["ica", -1, 1, null],
+    ["abila", -1, 1, null],
+    ["ibila", -1, 1, null],
+    ["oasa", -1, 1, null],
+    ["ata", -1, 1, null],
+    ["ita", -1, 1, null],
+    ["anta", -1, 1, null],
+    ["ista", -1, 3, null],
+    ["uta", -1, 1, null],
+    ["iva", -1, 1, null],
+    ["ic", -1, 1, null],
+    ["ice", -1, 1, null],
+    ["abile", -1, 1, null],
+    ["ibile", -1, 1, null],
+    ["isme", -1, 3, null],
+    ["iune", -1, 2, null],
+    ["oase", -1, 1, null],
+    ["ate", -1, 1, null],
+    ["itate", 17, 1, null],
+    ["ite", -1, 1, null],
+    ["ante", -1, 1, null],
+    ["iste", -1, 3, null],
+    ["ute", -1, 1, null],
+    ["ive", -1, 1, null],
+    ["ici", -1, 1, null],
+    ["abili", -1, 1, null],
+    ["ibili", -1, 1, null],
+    ["iuni", -1, 2, null],
+    ["atori", -1, 1, null],
+    ["osi", -1, 1, null],
+    ["ati", -1, 1, null],
+    ["itati", 30, 1, null],
+    ["iti", -1, 1, null],
+    ["anti", -1, 1, null],
+    ["isti", -1, 3, null],
+    ["uti", -1, 1, null],
+    ["i\u{015F}ti", -1, 3, null],
+    ["ivi", -1, 1, null],
+    ["it\u{0103}i", -1, 1, null],
+    ["o\u{015F}i", -1, 1, null],
+    ["it\u{0103}\u{0163}i", -1, 1, null],
+    ["abil", -1, 1, null],
+    ["ibil", -1, 1, null],
+    ["ism", -1, 3, null],
+    ["ator", -1, 1, null],
+    ["os", -1, 1, null],
+    ["at", -1, 1, null],
+    ["it", -1, 1, null],
+    ["ant", -1, 1, null],
+    ["ist", -1, 3, null],
+    ["ut", -1, 1, null],
+    ["iv", -1, 1, null],
+    ["ic\u{0103}", -1, 1, null],
+    ["abil\u{0103}", -1, 1, null],
+    ["ibil\u{0103}", -1, 1, null],
+    ["oas\u{0103}", -1, 1, null],
+    ["at\u{0103}", -1, 1, null],
+    ["it\u{0103}", -1, 1, null],
+    ["ant\u{0103}", -1, 1, null],
+    ["ist\u{0103}", -1, 3, null],
+    ["ut\u{0103}", -1, 1, null],
+    ["iv\u{0103}", -1, 1, null],
+]);
+
+const A_4 = among([
+    ["ea", -1, 1, null],
+    ["ia", -1, 1, null],
+    ["esc", -1, 1, null],
+    ["\u{0103}sc", -1, 1, null],
+    ["ind", -1, 1, null],
+    ["\u{00E2}nd", -1, 1, null],
+    ["are", -1, 1, null],
+    ["ere", -1, 1, null],
+    ["ire", -1, 1, null],
+    ["\u{00E2}re", -1, 1, null],
+    ["se", -1, 2, null],
+    ["ase", 10, 1, null],
+    ["sese", 10, 2, null],
+    ["ise", 10, 1, null],
+    ["use", 10, 1, null],
+    ["\u{00E2}se", 10, 1, null],
+    ["e\u{015F}te", -1, 1, null],
+    ["\u{0103}\u{015F}te", -1, 1, null],
+    ["eze", -1, 1, null],
+    ["ai", -1, 1, null],
+    ["eai", 19, 1, null],
+    ["iai", 19, 1, null],
+    ["sei", -1, 2, null],
+    ["e\u{015F}ti", -1, 1, null],
+    ["\u{0103}\u{015F}ti", -1, 1, null],
+    ["ui", -1, 1, null],
+    ["ezi", -1, 1, null],
+    ["a\u{015F}i", -1, 1, null],
+    ["se\u{015F}i", -1, 2, null],
+    ["ase\u{015F}i", 28, 1, null],
+    ["sese\u{015F}i", 28, 2, null],
+    ["ise\u{015F}i", 28, 1, null],
+    ["use\u{015F}i", 28, 1, null],
+    ["\u{00E2}se\u{015F}i", 28, 1, null],
+    ["i\u{015F}i", -1, 1, null],
+    ["u\u{015F}i", -1, 1, null],
+    ["\u{00E2}\u{015F}i", -1, 1, null],
+    ["\u{00E2}i", -1, 1, null],
+    ["a\u{0163}i", -1, 2, null],
+    ["ea\u{0163}i", 38, 1, null],
+    ["ia\u{0163}i", 38, 1, null],
+    ["e\u{0163}i", -1, 2, null],
+    ["i\u{0163}i", -1, 2, null],
+    ["ar\u{0103}\u{0163}i", -1, 1, null],
+    ["ser\u{0103}\u{0163}i", -1, 2, null],
+    ["aser\u{0103}\u{0163}i", 44, 1, null],
+    ["seser\u{0103}\u{0163}i", 44, 2, null],
+    ["iser\u{0103}\u{0163}i", 44, 1, null],
+    ["user\u{0103}\u{0163}i", 44, 1, null],
+    ["\u{00E2}ser\u{0103}\u{0163}i", 44, 1, null],
+    ["ir\u{0103}\u{0163}i", -1, 1, null],
+    ["ur\u{0103}\u{0163}i", -1, 1, null],
+    ["\u{00E2}r\u{0103}\u{0163}i", -1, 1, null],
+    ["\u{00E2}\u{0163}i", -1, 2, null],
+    ["am", -1, 1, null],
+    ["eam", 54, 1, null],
+    ["iam", 54, 1, null],
+    ["em", -1, 2, null],
+    ["asem", 57, 1, null],
+    ["sesem", 57, 2, null],
+    ["isem", 57, 1, null],
+    ["usem", 57, 1, null],
+    ["\u{00E2}sem", 57, 1, null],
+    ["im", -1, 2, null],
+    ["\u{0103}m", -1, 2, null],
+    ["ar\u{0103}m", 64, 1, null],
+    ["ser\u{0103}m", 64, 2, null],
+    ["aser\u{0103}m", 66, 1, null],
+    ["seser\u{0103}m", 66, 2, null],
+    ["iser\u{0103}m", 66, 1, null],
+    ["user\u{0103}m", 66, 1, null],
+    ["\u{00E2}ser\u{0103}m", 66, 1, null],
+    ["ir\u{0103}m", 64, 1, null],
+    ["ur\u{0103}m", 64, 1, null],
+    ["\u{00E2}r\u{0103}m", 64, 1, null],
+    ["\u{00E2}m", -1, 2, null],
+    ["au", -1, 1, null],
+    ["eau", 76, 1, null],
+    ["iau", 76, 1, null],
+    ["indu", -1, 1, null],
+    ["\u{00E2}ndu", -1, 1, null],
+    ["ez", -1, 1, null],
+    ["easc\u{0103}", -1, 1, null],
+    ["ar\u{0103}", -1, 1, null],
+    ["ser\u{0103}", -1, 2, null],
+    ["aser\u{0103}", 84, 1, null],
+    ["seser\u{0103}", 84, 2, null],
+    ["iser\u{0103}", 84, 1, null],
+    ["user\u{0103}", 84, 1, null],
+    ["\u{00E2}ser\u{0103}", 84, 1, null],
+    ["ir\u{0103}", -1, 1, null],
+    ["ur\u{0103}", -1, 1, null],
+    ["\u{00E2}r\u{0103}", -1, 1, null],
+    ["eaz\u{0103}", -1, 1, null],
+]);
+
+const A_5 = among([
+    ["a", -1, 1, null],
+    ["e", -1, 1, null],
+    ["ie", 1, 1, null],
+    ["i", -1, 1, null],
+    ["\u{0103}", -1, 1, null],
+]);
+
+const G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 32, 0, 0, 4];
+
+function r_prelude(env, context) {
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            golab2: for (;;) {
+                var v_2 = env.cursor;
+                lab3: for (;;) {
+                    if (!env.in_grouping(G_v, 97, 259)) {
+                        break lab3;
+                    }
+                    env.bra = env.cursor;
+                    lab4: for (;;) {
+                        var v_3 = env.cursor;
+                        lab5: for (;;) {
+                            if (!env.eq_s("u")) {
+                                break lab5;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 259)) {
+                                break lab5;
+                            }
+                            if (!env.slice_from("U")) {
+                                return false;
+                            }
+                            break lab4;
+                        }
+                        env.cursor = v_3;
+                        if (!env.eq_s("i")) {
+                            break lab3;
+                        }
+                        env.ket = env.cursor;
+                        if (!env.in_grouping(G_v, 97, 259)) {
+                            break lab3;
+                        }
+                        if (!env.slice_from("I")) {
+                            return false;
+                        }
+                        break lab4;
+                    }
+                    env.cursor = v_2;
+                    break golab2;
+                }
+                env.cursor = v_2;
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_mark_regions(env, context) {
+    context.i_pV = env.limit;
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            lab2: for (;;) {
+                if (!env.in_grouping(G_v, 97, 259)) {
+                    break lab2;
+                }
+                lab3: for (;;) {
+                    var v_3 = env.cursor;
+                    lab4: for (;;) {
+                        if (!env.out_grouping(G_v, 97, 259)) {
+                            break lab4;
+                        }
+                        golab5: for (;;) {
+                            lab6: for (;;) {
+                                if (!env.in_grouping(G_v, 97, 259)) {
+                                    break lab6;
+                                }
+                                break golab5;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab4;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    env.cursor = v_3;
+                    if (!env.in_grouping(G_v, 97, 259)) {
+                        break lab2;
+                    }
+                    golab7: for (;;) {
+                        lab8: for (;;) {
+                            if (!env.out_grouping(G_v, 97, 259)) {
+                                break lab8;
+                            }
+                            break golab7;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab2;
+                        }
+                        env.next_char();
+                    }
+                    break lab3;
+                }
+                break lab1;
+            }
+            env.cursor = v_2;
+            if (!env.out_grouping(G_v, 97, 259)) {
+                break lab0;
+            }
+            lab9: for (;;) {
+                var v_6 = env.cursor;
+                lab10: for (;;) {
+                    if (!env.out_grouping(G_v, 97, 259)) {
+                        break lab10;
+                    }
+                    golab11: for (;;) {
+                        lab12: for (;;) {
+                            if (!env.in_grouping(G_v, 97, 259)) {
+                                break lab12;
+                            }
+                            break golab11;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab10;
+                        }
+                        env.next_char();
+                    }
+                    break lab9;
+                }
+                env.cursor = v_6;
+                if (!env.in_grouping(G_v, 97, 259)) {
+                    break lab0;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+                break lab9;
+            }
+            break lab1;
+        }
+        context.i_pV = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_8 = env.cursor;
+    lab13: for (;;) {
+        golab14: for (;;) {
+            lab15: for (;;) {
+                if (!env.in_grouping(G_v, 97, 259)) {
+                    break lab15;
+                }
+                break golab14;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab16: for (;;) {
+            lab17: for (;;) {
+                if (!env.out_grouping(G_v, 97, 259)) {
+                    break lab17;
+                }
+                break golab16;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        golab18: for (;;) {
+            lab19: for (;;) {
+                if (!env.in_grouping(G_v, 97, 259)) {
+                    break lab19;
+                }
+                break golab18;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab20: for (;;) {
+            lab21: for (;;) {
+                if (!env.out_grouping(G_v, 97, 259)) {
+                    break lab21;
+                }
+                break golab20;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab13;
+    }
+    env.cursor = v_8;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i2 = 0, _n2 = 1; _i2 < _n2; _i2++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_RV(env, context) {
+    if (!(context.i_pV <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_step_0(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_1, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("a")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("e")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("i")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        var v_1 = env.limit - env.cursor;
+        lab0: for (;;) {
+            if (!env.eq_s_b("ab")) {
+                break lab0;
+            }
+            return false;
+        }
+        env.cursor = env.limit - v_1;
+        if (!env.slice_from("i")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("at")) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.slice_from("a\u{0163}i")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_combo_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_2, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R1(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("abil")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("ibil")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("iv")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("ic")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!env.slice_from("at")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_from("it")) {
+            return false;
+        }
+    }
+    context.b_standard_suffix_removed = true;
+    env.cursor = env.limit - v_1;
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    context.b_standard_suffix_removed = false;
+    replab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (let _i3 = 0, _n3 = 1; _i3 < _n3; _i3++) {
+            if (!r_combo_suffix(env, context)) {
+                break lab1;
+            }
+            continue replab0;
+        }
+        env.cursor = env.limit - v_1;
+        break replab0;
+    }
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_3, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R2(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.eq_s_b("\u{0163}")) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_from("t")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("ist")) {
+            return false;
+        }
+    }
+    context.b_standard_suffix_removed = true;
+    return true;
+}
+
+function r_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_4, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    } else if (among_var == 1) {
+        lab0: for (;;) {
+            var v_3 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!env.out_grouping_b(G_v, 97, 259)) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_3;
+            if (!env.eq_s_b("u")) {
+                env.limit_backward = v_2;
+                return false;
+            }
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function r_vowel_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_5, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_RV(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        b_standard_suffix_removed: false,
+        i_p2: 0,
+        i_p1: 0,
+        i_pV: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_prelude(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_2 = env.cursor;
+    lab1: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = v_2;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_step_0(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        if (!r_standard_suffix(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    var v_5 = env.limit - env.cursor;
+    lab4: for (;;) {
+        lab5: for (;;) {
+            var v_6 = env.limit - env.cursor;
+            lab6: for (;;) {
+                if (!context.b_standard_suffix_removed) {
+                    break lab6;
+                }
+                break lab5;
+            }
+            env.cursor = env.limit - v_6;
+            if (!r_verb_suffix(env, context)) {
+                break lab4;
+            }
+            break lab5;
+        }
+        break lab4;
+    }
+    env.cursor = env.limit - v_5;
+    var v_7 = env.limit - env.cursor;
+    lab7: for (;;) {
+        if (!r_vowel_suffix(env, context)) {
+            break lab7;
+        }
+        break lab7;
+    }
+    env.cursor = env.limit - v_7;
+    env.cursor = env.limit_backward;
+    var v_8 = env.cursor;
+    lab8: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab8;
+        }
+        break lab8;
+    }
+    env.cursor = v_8;
+    return true;
+}
@@ -0,0 +1,571 @@
+// Snowball stemmer for Russian, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/russian.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["\u{0432}\u{0448}\u{0438}\u{0441}\u{044C}", -1, 1, null],
+    ["\u{044B}\u{0432}\u{0448}\u{0438}\u{0441}\u{044C}", 0, 2, null],
+    ["\u{0438}\u{0432}\u{0448}\u{0438}\u{0441}\u{044C}", 0, 2, null],
+    ["\u{0432}", -1, 1, null],
+    ["\u{044B}\u{0432}", 3, 2, null],
+    ["\u{0438}\u{0432}", 3, 2, null],
+    ["\u{0432}\u{0448}\u{0438}", -1, 1, null],
+    ["\u{044B}\u{0432}\u{0448}\u{0438}", 6, 2, null],
+    ["\u{0438}\u{0432}\u{0448}\u{0438}", 6, 2, null],
+]);
+
+const A_1 = among([
+    ["\u{0435}\u{043C}\u{0443}", -1, 1, null],
+    ["\u{043E}\u{043C}\u{0443}", -1, 1, null],
+    ["\u{044B}\u{0445}", -1, 1, null],
+    ["\u{0438}\u{0445}", -1, 1, null],
+    ["\u{0443}\u{044E}", -1, 1, null],
+    ["\u{044E}\u{044E}", -1, 1, null],
+    ["\u{0435}\u{044E}", -1, 1, null],
+    ["\u{043E}\u{044E}", -1, 1, null],
+    ["\u{044F}\u{044F}", -1, 1, null],
+    ["\u{0430}\u{044F}", -1, 1, null],
+    ["\u{044B}\u{0435}", -1, 1, null],
+    ["\u{0435}\u{0435}", -1, 1, null],
+    ["\u{0438}\u{0435}", -1, 1, null],
+    ["\u{043E}\u{0435}", -1, 1, null],
+    ["\u{044B}\u{043C}\u{0438}", -1, 1, null],
+    ["\u{0438}\u{043C}\u{0438}", -1, 1, null],
+    ["\u{044B}\u{0439}", -1, 1, null],
+    ["\u{0435}\u{0439}", -1, 1, null],
+    ["\u{0438}\u{0439}", -1, 1, null],
+    ["\u{043E}\u{0439}", -1, 1, null],
+    ["\u{044B}\u{043C}", -1, 1, null],
+    ["\u{0435}\u{043C}", -1, 1, null],
+    ["\u{0438}\u{043C}", -1, 1, null],
+    ["\u{043E}\u{043C}", -1, 1, null],
+    ["\u{0435}\u{0433}\u{043E}", -1, 1, null],
+    ["\u{043E}\u{0433}\u{043E}", -1, 1, null],
+]);
+
+const A_2 = among([
+    ["\u{0432}\u{0448}", -1, 1, null],
+    ["\u{044B}\u{0432}\u{0448}", 0, 2, null],
+    ["\u{0438}\u{0432}\u{0448}", 0, 2, null],
+    ["\u{0449}", -1, 1, null],
+    ["\u{044E}\u{0449}", 3, 1, null],
+    ["\u{0443}\u{044E}\u{0449}", 4, 2, null],
+    ["\u{0435}\u{043C}", -1, 1, null],
+    ["\u{043D}\u{043D}", -1, 1, null],
+]);
+
+const A_3 = among([
+    ["\u{0441}\u{044C}", -1, 1, null],
+    ["\u{0441}\u{044F}", -1, 1, null],
+]);
+
+const A_4 = among([
+    ["\u{044B}\u{0442}", -1, 2, null],
+    ["\u{044E}\u{0442}", -1, 1, null],
+    ["\u{0443}\u{044E}\u{0442}", 1, 2, null],
+    ["\u{044F}\u{0442}", -1, 2, null],
+    ["\u{0435}\u{0442}", -1, 1, null],
+    ["\u{0443}\u{0435}\u{0442}", 4, 2, null],
+    ["\u{0438}\u{0442}", -1, 2, null],
+    ["\u{043D}\u{044B}", -1, 1, null],
+    ["\u{0435}\u{043D}\u{044B}", 7, 2, null],
+    ["\u{0442}\u{044C}", -1, 1, null],
+    ["\u{044B}\u{0442}\u{044C}", 9, 2, null],
+    ["\u{0438}\u{0442}\u{044C}", 9, 2, null],
+    ["\u{0435}\u{0448}\u{044C}", -1, 1, null],
+    ["\u{0438}\u{0448}\u{044C}", -1, 2, null],
+    ["\u{044E}", -1, 2, null],
+    ["\u{0443}\u{044E}", 14, 2, null],
+    ["\u{043B}\u{0430}", -1, 1, null],
+    ["\u{044B}\u{043B}\u{0430}", 16, 2, null],
+    ["\u{0438}\u{043B}\u{0430}", 16, 2, null],
+    ["\u{043D}\u{0430}", -1, 1, null],
+    ["\u{0435}\u{043D}\u{0430}", 19, 2, null],
+    ["\u{0435}\u{0442}\u{0435}", -1, 1, null],
+    ["\u{0438}\u{0442}\u{0435}", -1, 2, null],
+    ["\u{0439}\u{0442}\u{0435}", -1, 1, null],
+    ["\u{0443}\u{0439}\u{0442}\u{0435}", 23, 2, null],
+    ["\u{0435}\u{0439}\u{0442}\u{0435}", 23, 2, null],
+    ["\u{043B}\u{0438}", -1, 1, null],
+    ["\u{044B}\u{043B}\u{0438}", 26, 2, null],
+    ["\u{0438}\u{043B}\u{0438}", 26, 2, null],
+    ["\u{0439}", -1, 1, null],
+    ["\u{0443}\u{0439}", 29, 2, null],
+    ["\u{0435}\u{0439}", 29, 2, null],
+    ["\u{043B}", -1, 1, null],
+    ["\u{044B}\u{043B}", 32, 2, null],
+    ["\u{0438}\u{043B}", 32, 2, null],
+    ["\u{044B}\u{043C}", -1, 2, null],
+    ["\u{0435}\u{043C}", -1, 1, null],
+    ["\u{0438}\u{043C}", -1, 2, null],
+    ["\u{043D}", -1, 1, null],
+    ["\u{0435}\u{043D}", 38, 2, null],
+    ["\u{043B}\u{043E}", -1, 1, null],
+    ["\u{044B}\u{043B}\u{043E}", 40, 2, null],
+    ["\u{0438}\u{043B}\u{043E}", 40, 2, null],
+    ["\u{043D}\u{043E}", -1, 1, null],
+    ["\u{0435}\u{043D}\u{043E}", 43, 2, null],
+    ["\u{043D}\u{043D}\u{043E}", 43, 1, null],
+]);
+
+const A_5 = among([
+    ["\u{0443}", -1, 1, null],
+    ["\u{044F}\u{0445}", -1, 1, null],
+    ["\u{0438}\u{044F}\u{0445}", 1, 1, null],
+    ["\u{0430}\u{0445}", -1, 1, null],
+    ["\u{044B}", -1, 1, null],
+    ["\u{044C}", -1, 1, null],
+    ["\u{044E}", -1, 1, null],
+    ["\u{044C}\u{044E}", 6, 1, null],
+    ["\u{0438}\u{044E}", 6, 1, null],
+    ["\u{044F}", -1, 1, null],
+    ["\u{044C}\u{044F}", 9, 1, null],
+    ["\u{0438}\u{044F}", 9, 1, null],
+    ["\u{0430}", -1, 1, null],
+    ["\u{0435}\u{0432}", -1, 1, null],
+    ["\u{043E}\u{0432}", -1, 1, null],
+    ["\u{0435}", -1, 1, null],
+    ["\u{044C}\u{0435}", 15, 1, null],
+    ["\u{0438}\u{0435}", 15, 1, null],
+    ["\u{0438}", -1, 1, null],
+    ["\u{0435}\u{0438}", 18, 1, null],
+    ["\u{0438}\u{0438}", 18, 1, null],
+    ["\u{044F}\u{043C}\u{0438}", 18, 1, null],
+    ["\u{0438}\u{044F}\u{043C}\u{0438}", 21, 1, null],
+    ["\u{0430}\u{043C}\u{0438}", 18, 1, null],
+    ["\u{0439}", -1, 1, null],
+    ["\u{0435}\u{0439}", 24, 1, null],
+    ["\u{0438}\u{0435}\u{0439}", 25, 1, null],
+    ["\u{0438}\u{0439}", 24, 1, null],
+    ["\u{043E}\u{0439}", 24, 1, null],
+    ["\u{044F}\u{043C}", -1, 1, null],
+    ["\u{0438}\u{044F}\u{043C}", 29, 1, null],
+    ["\u{0430}\u{043C}", -1, 1, null],
+    ["\u{0435}\u{043C}", -1, 1, null],
+    ["\u{0438}\u{0435}\u{043C}", 32, 1, null],
+    ["\u{043E}\u{043C}", -1, 1, null],
+    ["\u{043E}", -1, 1, null],
+]);
+
+const A_6 = among([
+    ["\u{043E}\u{0441}\u{0442}", -1, 1, null],
+    ["\u{043E}\u{0441}\u{0442}\u{044C}", -1, 1, null],
+]);
+
+const A_7 = among([
+    ["\u{0435}\u{0439}\u{0448}", -1, 1, null],
+    ["\u{044C}", -1, 3, null],
+    ["\u{0435}\u{0439}\u{0448}\u{0435}", -1, 1, null],
+    ["\u{043D}", -1, 2, null],
+]);
+
+const G_v = [33, 65, 8, 232];
+
+function r_mark_regions(env, context) {
+    context.i_pV = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        golab1: for (;;) {
+            lab2: for (;;) {
+                if (!env.in_grouping(G_v, 1072, 1103)) {
+                    break lab2;
+                }
+                break golab1;
+            }
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+        }
+        context.i_pV = env.cursor;
+        golab3: for (;;) {
+            lab4: for (;;) {
+                if (!env.out_grouping(G_v, 1072, 1103)) {
+                    break lab4;
+                }
+                break golab3;
+            }
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+        }
+        golab5: for (;;) {
+            lab6: for (;;) {
+                if (!env.in_grouping(G_v, 1072, 1103)) {
+                    break lab6;
+                }
+                break golab5;
+            }
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+        }
+        golab7: for (;;) {
+            lab8: for (;;) {
+                if (!env.out_grouping(G_v, 1072, 1103)) {
+                    break lab8;
+                }
+                break golab7;
+            }
+            if (env.cursor >= env.limit) {
+                break lab0;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_perfective_gerund(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_0, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        lab0: for (;;) {
+            var v_1 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!env.eq_s_b("\u{0430}")) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!env.eq_s_b("\u{044F}")) {
+                return false;
+            }
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_adjective(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_1, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_adjectival(env, context) {
+    var among_var;
+    if (!r_adjective(env, context)) {
+        return false;
+    }
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_2, context);
+        if (among_var == 0) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.cursor = env.limit - v_1;
+            break lab0;
+        } else if (among_var == 1) {
+            lab1: for (;;) {
+                var v_2 = env.limit - env.cursor;
+                lab2: for (;;) {
+                    if (!env.eq_s_b("\u{0430}")) {
+                        break lab2;
+                    }
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                if (!env.eq_s_b("\u{044F}")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                break lab1;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        break lab0;
+    }
+    return true;
+}
+
+function r_reflexive(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_3, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_verb(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_4, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        lab0: for (;;) {
+            var v_1 = env.limit - env.cursor;
+            lab1: for (;;) {
+                if (!env.eq_s_b("\u{0430}")) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!env.eq_s_b("\u{044F}")) {
+                return false;
+            }
+            break lab0;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_noun(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_5, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_derivational(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (!r_R2(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_tidy_up(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.ket = env.cursor;
+        if (!env.eq_s_b("\u{043D}")) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!env.eq_s_b("\u{043D}")) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.eq_s_b("\u{043D}")) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p2: 0,
+        i_pV: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_3 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_2;
+    var v_4 = env.limit - env.cursor;
+    lab1: for (;;) {
+        lab2: for (;;) {
+            var v_5 = env.limit - env.cursor;
+            lab3: for (;;) {
+                if (!r_perfective_gerund(env, context)) {
+                    break lab3;
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_5;
+            var v_6 = env.limit - env.cursor;
+            lab4: for (;;) {
+                if (!r_reflexive(env, context)) {
+                    env.cursor = env.limit - v_6;
+                    break lab4;
+                }
+                break lab4;
+            }
+            lab5: for (;;) {
+                var v_7 = env.limit - env.cursor;
+                lab6: for (;;) {
+                    if (!r_adjectival(env, context)) {
+                        break lab6;
+                    }
+                    break lab5;
+                }
+                env.cursor = env.limit - v_7;
+                lab7: for (;;) {
+                    if (!r_verb(env, context)) {
+                        break lab7;
+                    }
+                    break lab5;
+                }
+                env.cursor = env.limit - v_7;
+                if (!r_noun(env, context)) {
+                    break lab1;
+                }
+                break lab5;
+            }
+            break lab2;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_4;
+    var v_8 = env.limit - env.cursor;
+    lab8: for (;;) {
+        env.ket = env.cursor;
+        if (!env.eq_s_b("\u{0438}")) {
+            env.cursor = env.limit - v_8;
+            break lab8;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        break lab8;
+    }
+    var v_9 = env.limit - env.cursor;
+    lab9: for (;;) {
+        if (!r_derivational(env, context)) {
+            break lab9;
+        }
+        break lab9;
+    }
+    env.cursor = env.limit - v_9;
+    var v_10 = env.limit - env.cursor;
+    lab10: for (;;) {
+        if (!r_tidy_up(env, context)) {
+            break lab10;
+        }
+        break lab10;
+    }
+    env.cursor = env.limit - v_10;
+    env.limit_backward = v_3;
+    env.cursor = env.limit_backward;
+    return true;
+}
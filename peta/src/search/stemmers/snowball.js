@@ -0,0 +1,309 @@
+// Snowball runtime shared by the generated stemmers in this directory, a
+// port of `SnowballEnv` from rust-stemmers 1.2.0. Like the original it works
+// on UTF-8 bytes, so the cursor arithmetic of the generated code produces
+// exactly the stems the indexer does.
+//
+// `write_client_index` prepends this file to each stemmer it writes; the
+// search client evaluates the result and gets back a `word => stem` function.
+//
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const utf8Encoder = new TextEncoder();
+const utf8Decoder = new TextDecoder();
+const literals = new Map();
+
+function utf8(s) {
+    if (typeof s !== 'string') return s;
+    if (!literals.has(s)) literals.set(s, Array.from(utf8Encoder.encode(s)));
+    return literals.get(s);
+}
+
+// Among tables as [bytes, substring index, result, method]
+function among(rows) {
+    return rows.map(([s, i, result, method]) => [utf8(s), i, result, method]);
+}
+
+class SnowballEnv {
+    constructor(word) {
+        this.current = Array.from(utf8Encoder.encode(word));
+        this.cursor = 0;
+        this.limit = this.current.length;
+        this.limit_backward = 0;
+        this.bra = 0;
+        this.ket = this.current.length;
+    }
+
+    get_current() {
+        return utf8Decoder.decode(Uint8Array.from(this.current));
+    }
+
+    is_char_boundary(i) {
+        if (i === 0 || i === this.current.length) return true;
+        return i < this.current.length && (this.current[i] & 0xC0) !== 0x80;
+    }
+
+    starts_with(at, s) {
+        if (at + s.length > this.current.length) return false;
+        for (let i = 0; i < s.length; i++) {
+            if (this.current[at + i] !== s[i]) return false;
+        }
+        return true;
+    }
+
+    // Code point starting at byte `at`
+    char_at(at) {
+        const b = this.current[at];
+        if (b < 0x80) return b;
+        const extra = b >= 0xF0 ? 3 : b >= 0xE0 ? 2 : 1;
+        let ch = b & (0x3F >> extra);
+        for (let i = 1; i <= extra; i++) ch = (ch << 6) | (this.current[at + i] & 0x3F);
+        return ch;
+    }
+
+    char_count() {
+        return this.current.filter(b => (b & 0xC0) !== 0x80).length;
+    }
+
+    replace_s(bra, ket, s) {
+        s = utf8(s);
+        const adjustment = s.length - (ket - bra);
+        this.current = [...this.current.slice(0, bra), ...s, ...this.current.slice(ket)];
+        this.limit += adjustment;
+        if (this.cursor >= ket) {
+            this.cursor += adjustment;
+        } else if (this.cursor > bra) {
+            this.cursor = bra;
+        }
+        return adjustment;
+    }
+
+    eq_s(s) {
+        s = utf8(s);
+        if (this.cursor >= this.limit) return false;
+        if (!this.starts_with(this.cursor, s)) return false;
+        this.cursor += s.length;
+        while (!this.is_char_boundary(this.cursor)) this.cursor += 1;
+        return true;
+    }
+
+    eq_s_b(s) {
+        s = utf8(s);
+        if (this.cursor - this.limit_backward < s.length) return false;
+        if (!this.is_char_boundary(this.cursor - s.length) || !this.starts_with(this.cursor - s.length, s)) {
+            return false;
+        }
+        this.cursor -= s.length;
+        return true;
+    }
+
+    slice_from(s) {
+        this.replace_s(this.bra, this.ket, s);
+        return true;
+    }
+
+    slice_del() {
+        return this.slice_from([]);
+    }
+
+    slice_to() {
+        return this.current.slice(this.bra, this.ket);
+    }
+
+    insert(bra, ket, s) {
+        const adjustment = this.replace_s(bra, ket, s);
+        if (bra <= this.bra) this.bra += adjustment;
+        if (bra <= this.ket) this.ket += adjustment;
+    }
+
+    next_char() {
+        this.cursor += 1;
+        while (!this.is_char_boundary(this.cursor)) this.cursor += 1;
+    }
+
+    previous_char() {
+        this.cursor -= 1;
+        while (!this.is_char_boundary(this.cursor)) this.cursor -= 1;
+    }
+
+    byte_index_for_hop(delta) {
+        let res = this.cursor;
+        if (delta > 0) {
+            while (delta > 0) {
+                res += 1;
+                delta -= 1;
+                while (res <= this.current.length && !this.is_char_boundary(res)) res += 1;
+            }
+        } else if (delta < 0) {
+            while (delta < 0) {
+                res -= 1;
+                delta += 1;
+                while (res >= 0 && !this.is_char_boundary(res)) res -= 1;
+            }
+        }
+        return res;
+    }
+
+    in_grouping(chars, min, max) {
+        if (this.cursor >= this.limit) return false;
+        let ch = this.char_at(this.cursor);
+        if (ch > max || ch < min) return false;
+        ch -= min;
+        if ((chars[ch >> 3] & (1 << (ch & 0x7))) === 0) return false;
+        this.next_char();
+        return true;
+    }
+
+    in_grouping_b(chars, min, max) {
+        if (this.cursor <= this.limit_backward) return false;
+        this.previous_char();
+        let ch = this.char_at(this.cursor);
+        this.next_char();
+        if (ch > max || ch < min) return false;
+        ch -= min;
+        if ((chars[ch >> 3] & (1 << (ch & 0x7))) === 0) return false;
+        this.previous_char();
+        return true;
+    }
+
+    out_grouping(chars, min, max) {
+        if (this.cursor >= this.limit) return false;
+        let ch = this.char_at(this.cursor);
+        if (ch > max || ch < min) {
+            this.next_char();
+            return true;
+        }
+        ch -= min;
+        if ((chars[ch >> 3] & (1 << (ch & 0x7))) === 0) {
+            this.next_char();
+            return true;
+        }
+        return false;
+    }
+
+    out_grouping_b(chars, min, max) {
+        if (this.cursor <= this.limit_backward) return false;
+        this.previous_char();
+        let ch = this.char_at(this.cursor);
+        this.next_char();
+        if (ch > max || ch < min) {
+            this.previous_char();
+            return true;
+        }
+        ch -= min;
+        if ((chars[ch >> 3] & (1 << (ch & 0x7))) === 0) {
+            this.previous_char();
+            return true;
+        }
+        return false;
+    }
+
+    find_among(amongs, context) {
+        let i = 0;
+        let j = amongs.length;
+        const c = this.cursor;
+        const l = this.limit;
+        let commonI = 0;
+        let commonJ = 0;
+        let firstKeyInspected = false;
+
+        for (;;) {
+            const k = i + ((j - i) >> 1);
+            let diff = 0;
+            let common = Math.min(commonI, commonJ);
+            const w = amongs[k];
+            for (let lvar = common; lvar < w[0].length; lvar++) {
+                if (c + common === l) {
+                    diff = -1;
+                    break;
+                }
+                diff = this.current[c + common] - w[0][lvar];
+                if (diff !== 0) break;
+                common += 1;
+            }
+            if (diff < 0) {
+                j = k;
+                commonJ = common;
+            } else {
+                i = k;
+                commonI = common;
+            }
+            if (j - i <= 1) {
+                if (i > 0 || j === i || firstKeyInspected) break;
+                firstKeyInspected = true;
+            }
+        }
+
+        for (;;) {
+            const w = amongs[i];
+            if (commonI >= w[0].length) {
+                this.cursor = c + w[0].length;
+                if (!w[3]) return w[2];
+                const res = w[3](this, context);
+                this.cursor = c + w[0].length;
+                if (res) return w[2];
+            }
+            i = w[1];
+            if (i < 0) return 0;
+        }
+    }
+
+    find_among_b(amongs, context) {
+        let i = 0;
+        let j = amongs.length;
+        const c = this.cursor;
+        const lb = this.limit_backward;
+        let commonI = 0;
+        let commonJ = 0;
+        let firstKeyInspected = false;
+
+        for (;;) {
+            const k = i + ((j - i) >> 1);
+            let diff = 0;
+            let common = Math.min(commonI, commonJ);
+            const w = amongs[k];
+            for (let lvar = w[0].length - common - 1; lvar >= 0; lvar--) {
+                if (c - common === lb) {
+                    diff = -1;
+                    break;
+                }
+                diff = this.current[c - common - 1] - w[0][lvar];
+                if (diff !== 0) break;
+                common += 1;
+            }
+            if (diff < 0) {
+                j = k;
+                commonJ = common;
+            } else {
+                i = k;
+                commonI = common;
+            }
+            if (j - i <= 1) {
+                if (i > 0 || j === i || firstKeyInspected) break;
+                firstKeyInspected = true;
+            }
+        }
+
+        for (;;) {
+            const w = amongs[i];
+            if (commonI >= w[0].length) {
+                this.cursor = c - w[0].length;
+                if (!w[3]) return w[2];
+                const res = w[3](this, context);
+                this.cursor = c - w[0].length;
+                if (res) return w[2];
+            }
+            i = w[1];
+            if (i < 0) return 0;
+        }
+    }
+}
+
+// `stem` is the entry point of the generated algorithm appended after this file
+function snowballStemmer(stem) {
+    return word => {
+        const env = new SnowballEnv(word);
+        stem(env);
+        return env.get_current();
+    };
+}
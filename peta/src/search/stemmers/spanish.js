@@ -0,0 +1,909 @@
+// Snowball stemmer for Spanish, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/spanish.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["", -1, 6, null],
+    ["\u{00E1}", 0, 1, null],
+    ["\u{00E9}", 0, 2, null],
+    ["\u{00ED}", 0, 3, null],
+    ["\u{00F3}", 0, 4, null],
+    ["\u{00FA}", 0, 5, null],
+]);
+
+const A_1 = among([
+    ["la", -1, -1, null],
+    ["sela", 0, -1, null],
+    ["le", -1, -1, null],
+    ["me", -1, -1, null],
+    ["se", -1, -1, null],
+    ["lo", -1, -1, null],
+    ["selo", 5, -1, null],
+    ["las", -1, -1, null],
+    ["selas", 7, -1, null],
+    ["les", -1, -1, null],
+    ["los", -1, -1, null],
+    ["selos", 10, -1, null],
+    ["nos", -1, -1, null],
+]);
+
+const A_2 = among([
+    ["ando", -1, 6, null],
+    ["iendo", -1, 6, null],
+    ["yendo", -1, 7, null],
+    ["\u{00E1}ndo", -1, 2, null],
+    ["i\u{00E9}ndo", -1, 1, null],
+    ["ar", -1, 6, null],
+    ["er", -1, 6, null],
+    ["ir", -1, 6, null],
+    ["\u{00E1}r", -1, 3, null],
+    ["\u{00E9}r", -1, 4, null],
+    ["\u{00ED}r", -1, 5, null],
+]);
+
+const A_3 = among([
+    ["ic", -1, -1, null],
+    ["ad", -1, -1, null],
+    ["os", -1, -1, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_4 = among([
+    ["able", -1, 1, null],
+    ["ible", -1, 1, null],
+    ["ante", -1, 1, null],
+]);
+
+const A_5 = among([
+    ["ic", -1, 1, null],
+    ["abil", -1, 1, null],
+    ["iv", -1, 1, null],
+]);
+
+const A_6 = among([
+    ["ica", -1, 1, null],
+    ["ancia", -1, 2, null],
+    ["encia", -1, 5, null],
+    ["adora", -1, 2, null],
+    ["osa", -1, 1, null],
+    ["ista", -1, 1, null],
+    ["iva", -1, 9, null],
+    ["anza", -1, 1, null],
+    ["log\u{00ED}a", -1, 3, null],
+    ["idad", -1, 8, null],
+    ["able", -1, 1, null],
+    ["ible", -1, 1, null],
+    ["ante", -1, 2, null],
+    ["mente", -1, 7, null],
+    ["amente", 13, 6, null],
+    ["aci\u{00F3}n", -1, 2, null],
+    ["uci\u{00F3}n", -1, 4, null],
+    ["ico", -1, 1, null],
+    ["ismo", -1, 1, null],
+    ["oso", -1, 1, null],
+    ["amiento", -1, 1, null],
+    ["imiento", -1, 1, null],
+    ["ivo", -1, 9, null],
+    ["ador", -1, 2, null],
+    ["icas", -1, 1, null],
+    ["ancias", -1, 2, null],
+    ["encias", -1, 5, null],
+    ["adoras", -1, 2, null],
+    ["osas", -1, 1, null],
+    ["istas", -1, 1, null],
+    ["ivas", -1, 9, null],
+    ["anzas", -1, 1, null],
+    ["log\u{00ED}as", -1, 3, null],
+    ["idades", -1, 8, null],
+    ["ables", -1, 1, null],
+    ["ibles", -1, 1, null],
+    ["aciones", -1, 2, null],
+    ["uciones", -1, 4, null],
+    ["adores", -1, 2, null],
+    ["antes", -1, 2, null],
+    ["icos", -1, 1, null],
+    ["ismos", -1, 1, null],
+    ["osos", -1, 1, null],
+    ["amientos", -1, 1, null],
+    ["imientos", -1, 1, null],
+    ["ivos", -1, 9, null],
+]);
+
+const A_7 = among([
+    ["ya", -1, 1, null],
+    ["ye", -1, 1, null],
+    ["yan", -1, 1, null],
+    ["yen", -1, 1, null],
+    ["yeron", -1, 1, null],
+    ["yendo", -1, 1, null],
+    ["yo", -1, 1, null],
+    ["yas", -1, 1, null],
+    ["yes", -1, 1, null],
+    ["yais", -1, 1, null],
+    ["yamos", -1, 1, null],
+    ["y\u{00F3}", -1, 1, null],
+]);
+
+const A_8 = among([
+    ["aba", -1, 2, null],
+    ["ada", -1, 2, null],
+    ["ida", -1, 2, null],
+    ["ara", -1, 2, null],
+    ["iera", -1, 2, null],
+    ["\u{00ED}a", -1, 2, null],
+    ["ar\u{00ED}a", 5, 2, null],
+    ["er\u{00ED}a", 5, 2, null],
+    ["ir\u{00ED}a", 5, 2, null],
+    ["ad", -1, 2, null],
+    ["ed", -1, 2, null],
+    ["id", -1, 2, null],
+    ["ase", -1, 2, null],
+    ["iese", -1, 2, null],
+    ["aste", -1, 2, null],
+    ["iste", -1, 2, null],
+    ["an", -1, 2, null],
+    ["aban", 16, 2, null],
+    ["aran", 16, 2, null],
+    ["ieran", 16, 2, null],
+    ["\u{00ED}an", 16, 2, null],
+    ["ar\u{00ED}an", 20, 2, null],
+    ["er\u{00ED}an", 20, 2, null],
+    ["ir\u{00ED}an", 20, 2, null],
+    ["en", -1, 1, null],
+    ["asen", 24, 2, null],
+    ["iesen", 24, 2, null],
+    ["aron", -1, 2, null],
+    ["ieron", -1, 2, null],
+    ["ar\u{00E1}n", -1, 2, null],
+    ["er\u{00E1}n", -1, 2, null],
+    ["ir\u{00E1}n", -1, 2, null],
+    ["ado", -1, 2, null],
+    ["ido", -1, 2, null],
+    ["ando", -1, 2, null],
+    ["iendo", -1, 2, null],
+    ["ar", -1, 2, null],
+    ["er", -1, 2, null],
+    ["ir", -1, 2, null],
+    ["as", -1, 2, null],
+    ["abas", 39, 2, null],
+    ["adas", 39, 2, null],
+    ["idas", 39, 2, null],
+    ["aras", 39, 2, null],
+    ["ieras", 39, 2, null],
+    ["\u{00ED}as", 39, 2, null],
+    ["ar\u{00ED}as", 45, 2, null],
+    ["er\u{00ED}as", 45, 2, null],
+    ["ir\u{00ED}as", 45, 2, null],
+    ["es", -1, 1, null],
+    ["ases", 49, 2, null],
+    ["ieses", 49, 2, null],
+    ["abais", -1, 2, null],
+    ["arais", -1, 2, null],
+    ["ierais", -1, 2, null],
+    ["\u{00ED}ais", -1, 2, null],
+    ["ar\u{00ED}ais", 55, 2, null],
+    ["er\u{00ED}ais", 55, 2, null],
+    ["ir\u{00ED}ais", 55, 2, null],
+    ["aseis", -1, 2, null],
+    ["ieseis", -1, 2, null],
+    ["asteis", -1, 2, null],
+    ["isteis", -1, 2, null],
+    ["\u{00E1}is", -1, 2, null],
+    ["\u{00E9}is", -1, 1, null],
+    ["ar\u{00E9}is", 64, 2, null],
+    ["er\u{00E9}is", 64, 2, null],
+    ["ir\u{00E9}is", 64, 2, null],
+    ["ados", -1, 2, null],
+    ["idos", -1, 2, null],
+    ["amos", -1, 2, null],
+    ["\u{00E1}bamos", 70, 2, null],
+    ["\u{00E1}ramos", 70, 2, null],
+    ["i\u{00E9}ramos", 70, 2, null],
+    ["\u{00ED}amos", 70, 2, null],
+    ["ar\u{00ED}amos", 74, 2, null],
+    ["er\u{00ED}amos", 74, 2, null],
+    ["ir\u{00ED}amos", 74, 2, null],
+    ["emos", -1, 1, null],
+    ["aremos", 78, 2, null],
+    ["eremos", 78, 2, null],
+    ["iremos", 78, 2, null],
+    ["\u{00E1}semos", 78, 2, null],
+    ["i\u{00E9}semos", 78, 2, null],
+    ["imos", -1, 2, null],
+    ["ar\u{00E1}s", -1, 2, null],
+    ["er\u{00E1}s", -1, 2, null],
+    ["ir\u{00E1}s", -1, 2, null],
+    ["\u{00ED}s", -1, 2, null],
+    ["ar\u{00E1}", -1, 2, null],
+    ["er\u{00E1}", -1, 2, null],
+    ["ir\u{00E1}", -1, 2, null],
+    ["ar\u{00E9}", -1, 2, null],
+    ["er\u{00E9}", -1, 2, null],
+    ["ir\u{00E9}", -1, 2, null],
+    ["i\u{00F3}", -1, 2, null],
+]);
+
+const A_9 = among([
+    ["a", -1, 1, null],
+    ["e", -1, 2, null],
+    ["o", -1, 1, null],
+    ["os", -1, 1, null],
+    ["\u{00E1}", -1, 1, null],
+    ["\u{00E9}", -1, 2, null],
+    ["\u{00ED}", -1, 1, null],
+    ["\u{00F3}", -1, 1, null],
+]);
+
+const G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 17, 4, 10];
+
+function r_mark_regions(env, context) {
+    context.i_pV = env.limit;
+    context.i_p1 = env.limit;
+    context.i_p2 = env.limit;
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            lab2: for (;;) {
+                if (!env.in_grouping(G_v, 97, 252)) {
+                    break lab2;
+                }
+                lab3: for (;;) {
+                    var v_3 = env.cursor;
+                    lab4: for (;;) {
+                        if (!env.out_grouping(G_v, 97, 252)) {
+                            break lab4;
+                        }
+                        golab5: for (;;) {
+                            lab6: for (;;) {
+                                if (!env.in_grouping(G_v, 97, 252)) {
+                                    break lab6;
+                                }
+                                break golab5;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab4;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    env.cursor = v_3;
+                    if (!env.in_grouping(G_v, 97, 252)) {
+                        break lab2;
+                    }
+                    golab7: for (;;) {
+                        lab8: for (;;) {
+                            if (!env.out_grouping(G_v, 97, 252)) {
+                                break lab8;
+                            }
+                            break golab7;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab2;
+                        }
+                        env.next_char();
+                    }
+                    break lab3;
+                }
+                break lab1;
+            }
+            env.cursor = v_2;
+            if (!env.out_grouping(G_v, 97, 252)) {
+                break lab0;
+            }
+            lab9: for (;;) {
+                var v_6 = env.cursor;
+                lab10: for (;;) {
+                    if (!env.out_grouping(G_v, 97, 252)) {
+                        break lab10;
+                    }
+                    golab11: for (;;) {
+                        lab12: for (;;) {
+                            if (!env.in_grouping(G_v, 97, 252)) {
+                                break lab12;
+                            }
+                            break golab11;
+                        }
+                        if (env.cursor >= env.limit) {
+                            break lab10;
+                        }
+                        env.next_char();
+                    }
+                    break lab9;
+                }
+                env.cursor = v_6;
+                if (!env.in_grouping(G_v, 97, 252)) {
+                    break lab0;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+                break lab9;
+            }
+            break lab1;
+        }
+        context.i_pV = env.cursor;
+        break lab0;
+    }
+    env.cursor = v_1;
+    var v_8 = env.cursor;
+    lab13: for (;;) {
+        golab14: for (;;) {
+            lab15: for (;;) {
+                if (!env.in_grouping(G_v, 97, 252)) {
+                    break lab15;
+                }
+                break golab14;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab16: for (;;) {
+            lab17: for (;;) {
+                if (!env.out_grouping(G_v, 97, 252)) {
+                    break lab17;
+                }
+                break golab16;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        golab18: for (;;) {
+            lab19: for (;;) {
+                if (!env.in_grouping(G_v, 97, 252)) {
+                    break lab19;
+                }
+                break golab18;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        golab20: for (;;) {
+            lab21: for (;;) {
+                if (!env.out_grouping(G_v, 97, 252)) {
+                    break lab21;
+                }
+                break golab20;
+            }
+            if (env.cursor >= env.limit) {
+                break lab13;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        break lab13;
+    }
+    env.cursor = v_8;
+    return true;
+}
+
+function r_postlude(env, context) {
+    var among_var;
+    replab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            env.bra = env.cursor;
+            among_var = env.find_among(A_0, context);
+            if (among_var == 0) {
+                break lab1;
+            }
+            env.ket = env.cursor;
+            if (among_var == 0) {
+                break lab1;
+            } else if (among_var == 1) {
+                if (!env.slice_from("a")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_from("e")) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_from("i")) {
+                    return false;
+                }
+            } else if (among_var == 4) {
+                if (!env.slice_from("o")) {
+                    return false;
+                }
+            } else if (among_var == 5) {
+                if (!env.slice_from("u")) {
+                    return false;
+                }
+            } else if (among_var == 6) {
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            continue replab0;
+        }
+        env.cursor = v_1;
+        break replab0;
+    }
+    return true;
+}
+
+function r_RV(env, context) {
+    if (!(context.i_pV <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R1(env, context) {
+    if (!(context.i_p1 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_R2(env, context) {
+    if (!(context.i_p2 <= env.cursor)) {
+        return false;
+    }
+    return true;
+}
+
+function r_attached_pronoun(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    if (env.find_among_b(A_1, context) == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    among_var = env.find_among_b(A_2, context);
+    if (among_var == 0) {
+        return false;
+    }
+    if (!r_RV(env, context)) {
+        return false;
+    }
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        env.bra = env.cursor;
+        if (!env.slice_from("iendo")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        env.bra = env.cursor;
+        if (!env.slice_from("ando")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        env.bra = env.cursor;
+        if (!env.slice_from("ar")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        env.bra = env.cursor;
+        if (!env.slice_from("er")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        env.bra = env.cursor;
+        if (!env.slice_from("ir")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 7) {
+        if (!env.eq_s_b("u")) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_standard_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_6, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        lab0: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("ic")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+    } else if (among_var == 3) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("log")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("u")) {
+            return false;
+        }
+    } else if (among_var == 5) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_from("ente")) {
+            return false;
+        }
+    } else if (among_var == 6) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_2 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_3, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            if (among_var == 0) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            } else if (among_var == 1) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab1;
+        }
+    } else if (among_var == 7) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_4, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_3;
+                break lab2;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab2;
+        }
+    } else if (among_var == 8) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_4 = env.limit - env.cursor;
+        lab3: for (;;) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_5, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_4;
+                break lab3;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab3;
+        }
+    } else if (among_var == 9) {
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_5 = env.limit - env.cursor;
+        lab4: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("at")) {
+                env.cursor = env.limit - v_5;
+                break lab4;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                env.cursor = env.limit - v_5;
+                break lab4;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab4;
+        }
+    }
+    return true;
+}
+
+function r_y_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_7, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.eq_s_b("u")) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_verb_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_pV) {
+        return false;
+    }
+    env.cursor = context.i_pV;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_8, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        var v_3 = env.limit - env.cursor;
+        lab0: for (;;) {
+            if (!env.eq_s_b("u")) {
+                env.cursor = env.limit - v_3;
+                break lab0;
+            }
+            var v_4 = env.limit - env.cursor;
+            if (!env.eq_s_b("g")) {
+                env.cursor = env.limit - v_3;
+                break lab0;
+            }
+            env.cursor = env.limit - v_4;
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_residual_suffix(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_9, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        lab0: for (;;) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("u")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            var v_2 = env.limit - env.cursor;
+            if (!env.eq_s_b("g")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.cursor = env.limit - v_2;
+            if (!r_RV(env, context)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+    }
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_p2: 0,
+        i_p1: 0,
+        i_pV: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_attached_pronoun(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        lab3: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            lab4: for (;;) {
+                if (!r_standard_suffix(env, context)) {
+                    break lab4;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_4;
+            lab5: for (;;) {
+                if (!r_y_verb_suffix(env, context)) {
+                    break lab5;
+                }
+                break lab3;
+            }
+            env.cursor = env.limit - v_4;
+            if (!r_verb_suffix(env, context)) {
+                break lab2;
+            }
+            break lab3;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_5 = env.limit - env.cursor;
+    lab6: for (;;) {
+        if (!r_residual_suffix(env, context)) {
+            break lab6;
+        }
+        break lab6;
+    }
+    env.cursor = env.limit - v_5;
+    env.cursor = env.limit_backward;
+    var v_6 = env.cursor;
+    lab7: for (;;) {
+        if (!r_postlude(env, context)) {
+            break lab7;
+        }
+        break lab7;
+    }
+    env.cursor = v_6;
+    return true;
+}
@@ -0,0 +1,263 @@
+// Snowball stemmer for Swedish, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/swedish.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["a", -1, 1, null],
+    ["arna", 0, 1, null],
+    ["erna", 0, 1, null],
+    ["heterna", 2, 1, null],
+    ["orna", 0, 1, null],
+    ["ad", -1, 1, null],
+    ["e", -1, 1, null],
+    ["ade", 6, 1, null],
+    ["ande", 6, 1, null],
+    ["arne", 6, 1, null],
+    ["are", 6, 1, null],
+    ["aste", 6, 1, null],
+    ["en", -1, 1, null],
+    ["anden", 12, 1, null],
+    ["aren", 12, 1, null],
+    ["heten", 12, 1, null],
+    ["ern", -1, 1, null],
+    ["ar", -1, 1, null],
+    ["er", -1, 1, null],
+    ["heter", 18, 1, null],
+    ["or", -1, 1, null],
+    ["s", -1, 2, null],
+    ["as", 21, 1, null],
+    ["arnas", 22, 1, null],
+    ["ernas", 22, 1, null],
+    ["ornas", 22, 1, null],
+    ["es", 21, 1, null],
+    ["ades", 26, 1, null],
+    ["andes", 26, 1, null],
+    ["ens", 21, 1, null],
+    ["arens", 29, 1, null],
+    ["hetens", 29, 1, null],
+    ["erns", 21, 1, null],
+    ["at", -1, 1, null],
+    ["andet", -1, 1, null],
+    ["het", -1, 1, null],
+    ["ast", -1, 1, null],
+]);
+
+const A_1 = among([
+    ["dd", -1, -1, null],
+    ["gd", -1, -1, null],
+    ["nn", -1, -1, null],
+    ["dt", -1, -1, null],
+    ["gt", -1, -1, null],
+    ["kt", -1, -1, null],
+    ["tt", -1, -1, null],
+]);
+
+const A_2 = among([
+    ["ig", -1, 1, null],
+    ["lig", 0, 1, null],
+    ["els", -1, 1, null],
+    ["fullt", -1, 3, null],
+    ["l\u{00F6}st", -1, 2, null],
+]);
+
+const G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 32];
+
+const G_s_ending = [119, 127, 149];
+
+function r_mark_regions(env, context) {
+    context.i_p1 = env.limit;
+    var v_1 = env.cursor;
+    var c = env.byte_index_for_hop(3);
+    if (0 > c || c > env.limit) {
+        return false;
+    }
+    env.cursor = c;
+    context.i_x = env.cursor;
+    env.cursor = v_1;
+    golab0: for (;;) {
+        var v_2 = env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping(G_v, 97, 246)) {
+                break lab1;
+            }
+            env.cursor = v_2;
+            break golab0;
+        }
+        env.cursor = v_2;
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    golab2: for (;;) {
+        lab3: for (;;) {
+            if (!env.out_grouping(G_v, 97, 246)) {
+                break lab3;
+            }
+            break golab2;
+        }
+        if (env.cursor >= env.limit) {
+            return false;
+        }
+        env.next_char();
+    }
+    context.i_p1 = env.cursor;
+    lab4: for (;;) {
+        if (!(context.i_p1 < context.i_x)) {
+            break lab4;
+        }
+        context.i_p1 = context.i_x;
+        break lab4;
+    }
+    return true;
+}
+
+function r_main_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_0, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    env.limit_backward = v_2;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.in_grouping_b(G_s_ending, 98, 121)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_consonant_pair(env, context) {
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    var v_3 = env.limit - env.cursor;
+    if (env.find_among_b(A_1, context) == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.cursor = env.limit - v_3;
+    env.ket = env.cursor;
+    if (env.cursor <= env.limit_backward) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.previous_char();
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function r_other_suffix(env, context) {
+    var among_var;
+    var v_1 = env.limit - env.cursor;
+    if (env.cursor < context.i_p1) {
+        return false;
+    }
+    env.cursor = context.i_p1;
+    var v_2 = env.limit_backward;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit - v_1;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_2, context);
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        env.limit_backward = v_2;
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_del()) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("l\u{00F6}s")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("full")) {
+            return false;
+        }
+    }
+    env.limit_backward = v_2;
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        i_x: 0,
+        i_p1: 0,
+    };
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_mark_regions(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_main_suffix(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_consonant_pair(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    var v_4 = env.limit - env.cursor;
+    lab3: for (;;) {
+        if (!r_other_suffix(env, context)) {
+            break lab3;
+        }
+        break lab3;
+    }
+    env.cursor = env.limit - v_4;
+    env.cursor = env.limit_backward;
+    return true;
+}
@@ -0,0 +1,2193 @@
+// Snowball stemmer for Turkish, generated by generate.py from rust-stemmers
+// 1.2.0 (src/snowball/algorithms/turkish.rs). Do not edit.
+// Runs on the `SnowballEnv` of snowball.js.
+//
+// Snowball: Copyright (c) 2001, Dr Martin Porter
+// Snowball: Copyright (c) 2004,2005, Richard Boulton
+// Licensed under the BSD 3-Clause License.
+// rust-stemmers: Copyright (c) 2017 Jakob Demler
+// Licensed under the MIT License. See LICENSE in this directory.
+
+const A_0 = among([
+    ["m", -1, -1, null],
+    ["n", -1, -1, null],
+    ["miz", -1, -1, null],
+    ["niz", -1, -1, null],
+    ["muz", -1, -1, null],
+    ["nuz", -1, -1, null],
+    ["m\u{0131}z", -1, -1, null],
+    ["n\u{0131}z", -1, -1, null],
+    ["m\u{00FC}z", -1, -1, null],
+    ["n\u{00FC}z", -1, -1, null],
+]);
+
+const A_1 = among([
+    ["leri", -1, -1, null],
+    ["lar\u{0131}", -1, -1, null],
+]);
+
+const A_2 = among([
+    ["ni", -1, -1, null],
+    ["nu", -1, -1, null],
+    ["n\u{0131}", -1, -1, null],
+    ["n\u{00FC}", -1, -1, null],
+]);
+
+const A_3 = among([
+    ["in", -1, -1, null],
+    ["un", -1, -1, null],
+    ["\u{0131}n", -1, -1, null],
+    ["\u{00FC}n", -1, -1, null],
+]);
+
+const A_4 = among([
+    ["a", -1, -1, null],
+    ["e", -1, -1, null],
+]);
+
+const A_5 = among([
+    ["na", -1, -1, null],
+    ["ne", -1, -1, null],
+]);
+
+const A_6 = among([
+    ["da", -1, -1, null],
+    ["ta", -1, -1, null],
+    ["de", -1, -1, null],
+    ["te", -1, -1, null],
+]);
+
+const A_7 = among([
+    ["nda", -1, -1, null],
+    ["nde", -1, -1, null],
+]);
+
+const A_8 = among([
+    ["dan", -1, -1, null],
+    ["tan", -1, -1, null],
+    ["den", -1, -1, null],
+    ["ten", -1, -1, null],
+]);
+
+const A_9 = among([
+    ["ndan", -1, -1, null],
+    ["nden", -1, -1, null],
+]);
+
+const A_10 = among([
+    ["la", -1, -1, null],
+    ["le", -1, -1, null],
+]);
+
+const A_11 = among([
+    ["ca", -1, -1, null],
+    ["ce", -1, -1, null],
+]);
+
+const A_12 = among([
+    ["im", -1, -1, null],
+    ["um", -1, -1, null],
+    ["\u{0131}m", -1, -1, null],
+    ["\u{00FC}m", -1, -1, null],
+]);
+
+const A_13 = among([
+    ["sin", -1, -1, null],
+    ["sun", -1, -1, null],
+    ["s\u{0131}n", -1, -1, null],
+    ["s\u{00FC}n", -1, -1, null],
+]);
+
+const A_14 = among([
+    ["iz", -1, -1, null],
+    ["uz", -1, -1, null],
+    ["\u{0131}z", -1, -1, null],
+    ["\u{00FC}z", -1, -1, null],
+]);
+
+const A_15 = among([
+    ["siniz", -1, -1, null],
+    ["sunuz", -1, -1, null],
+    ["s\u{0131}n\u{0131}z", -1, -1, null],
+    ["s\u{00FC}n\u{00FC}z", -1, -1, null],
+]);
+
+const A_16 = among([
+    ["lar", -1, -1, null],
+    ["ler", -1, -1, null],
+]);
+
+const A_17 = among([
+    ["niz", -1, -1, null],
+    ["nuz", -1, -1, null],
+    ["n\u{0131}z", -1, -1, null],
+    ["n\u{00FC}z", -1, -1, null],
+]);
+
+const A_18 = among([
+    ["dir", -1, -1, null],
+    ["tir", -1, -1, null],
+    ["dur", -1, -1, null],
+    ["tur", -1, -1, null],
+    ["d\u{0131}r", -1, -1, null],
+    ["t\u{0131}r", -1, -1, null],
+    ["d\u{00FC}r", -1, -1, null],
+    ["t\u{00FC}r", -1, -1, null],
+]);
+
+const A_19 = among([
+    ["cas\u{0131}na", -1, -1, null],
+    ["cesine", -1, -1, null],
+]);
+
+const A_20 = among([
+    ["di", -1, -1, null],
+    ["ti", -1, -1, null],
+    ["dik", -1, -1, null],
+    ["tik", -1, -1, null],
+    ["duk", -1, -1, null],
+    ["tuk", -1, -1, null],
+    ["d\u{0131}k", -1, -1, null],
+    ["t\u{0131}k", -1, -1, null],
+    ["d\u{00FC}k", -1, -1, null],
+    ["t\u{00FC}k", -1, -1, null],
+    ["dim", -1, -1, null],
+    ["tim", -1, -1, null],
+    ["dum", -1, -1, null],
+    ["tum", -1, -1, null],
+    ["d\u{0131}m", -1, -1, null],
+    ["t\u{0131}m", -1, -1, null],
+    ["d\u{00FC}m", -1, -1, null],
+    ["t\u{00FC}m", -1, -1, null],
+    ["din", -1, -1, null],
+    ["tin", -1, -1, null],
+    ["dun", -1, -1, null],
+    ["tun", -1, -1, null],
+    ["d\u{0131}n", -1, -1, null],
+    ["t\u{0131}n", -1, -1, null],
+    ["d\u{00FC}n", -1, -1, null],
+    ["t\u{00FC}n", -1, -1, null],
+    ["du", -1, -1, null],
+    ["tu", -1, -1, null],
+    ["d\u{0131}", -1, -1, null],
+    ["t\u{0131}", -1, -1, null],
+    ["d\u{00FC}", -1, -1, null],
+    ["t\u{00FC}", -1, -1, null],
+]);
+
+const A_21 = among([
+    ["sa", -1, -1, null],
+    ["se", -1, -1, null],
+    ["sak", -1, -1, null],
+    ["sek", -1, -1, null],
+    ["sam", -1, -1, null],
+    ["sem", -1, -1, null],
+    ["san", -1, -1, null],
+    ["sen", -1, -1, null],
+]);
+
+const A_22 = among([
+    ["mi\u{015F}", -1, -1, null],
+    ["mu\u{015F}", -1, -1, null],
+    ["m\u{0131}\u{015F}", -1, -1, null],
+    ["m\u{00FC}\u{015F}", -1, -1, null],
+]);
+
+const A_23 = among([
+    ["b", -1, 1, null],
+    ["c", -1, 2, null],
+    ["d", -1, 3, null],
+    ["\u{011F}", -1, 4, null],
+]);
+
+const G_vowel = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 8, 0, 0, 0, 0, 0, 0, 1];
+
+const G_U = [1, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1];
+
+const G_vowel1 = [1, 64, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+const G_vowel2 = [17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130];
+
+const G_vowel3 = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+const G_vowel4 = [17];
+
+const G_vowel5 = [65];
+
+const G_vowel6 = [65];
+
+function r_check_vowel_harmony(env, context) {
+    var v_1 = env.limit - env.cursor;
+    golab0: for (;;) {
+        var v_2 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                break lab1;
+            }
+            env.cursor = env.limit - v_2;
+            break golab0;
+        }
+        env.cursor = env.limit - v_2;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+    }
+    lab2: for (;;) {
+        var v_3 = env.limit - env.cursor;
+        lab3: for (;;) {
+            if (!env.eq_s_b("a")) {
+                break lab3;
+            }
+            golab4: for (;;) {
+                var v_4 = env.limit - env.cursor;
+                lab5: for (;;) {
+                    if (!env.in_grouping_b(G_vowel1, 97, 305)) {
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_4;
+                    break golab4;
+                }
+                env.cursor = env.limit - v_4;
+                if (env.cursor <= env.limit_backward) {
+                    break lab3;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab6: for (;;) {
+            if (!env.eq_s_b("e")) {
+                break lab6;
+            }
+            golab7: for (;;) {
+                var v_5 = env.limit - env.cursor;
+                lab8: for (;;) {
+                    if (!env.in_grouping_b(G_vowel2, 101, 252)) {
+                        break lab8;
+                    }
+                    env.cursor = env.limit - v_5;
+                    break golab7;
+                }
+                env.cursor = env.limit - v_5;
+                if (env.cursor <= env.limit_backward) {
+                    break lab6;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab9: for (;;) {
+            if (!env.eq_s_b("\u{0131}")) {
+                break lab9;
+            }
+            golab10: for (;;) {
+                var v_6 = env.limit - env.cursor;
+                lab11: for (;;) {
+                    if (!env.in_grouping_b(G_vowel3, 97, 305)) {
+                        break lab11;
+                    }
+                    env.cursor = env.limit - v_6;
+                    break golab10;
+                }
+                env.cursor = env.limit - v_6;
+                if (env.cursor <= env.limit_backward) {
+                    break lab9;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab12: for (;;) {
+            if (!env.eq_s_b("i")) {
+                break lab12;
+            }
+            golab13: for (;;) {
+                var v_7 = env.limit - env.cursor;
+                lab14: for (;;) {
+                    if (!env.in_grouping_b(G_vowel4, 101, 105)) {
+                        break lab14;
+                    }
+                    env.cursor = env.limit - v_7;
+                    break golab13;
+                }
+                env.cursor = env.limit - v_7;
+                if (env.cursor <= env.limit_backward) {
+                    break lab12;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab15: for (;;) {
+            if (!env.eq_s_b("o")) {
+                break lab15;
+            }
+            golab16: for (;;) {
+                var v_8 = env.limit - env.cursor;
+                lab17: for (;;) {
+                    if (!env.in_grouping_b(G_vowel5, 111, 117)) {
+                        break lab17;
+                    }
+                    env.cursor = env.limit - v_8;
+                    break golab16;
+                }
+                env.cursor = env.limit - v_8;
+                if (env.cursor <= env.limit_backward) {
+                    break lab15;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab18: for (;;) {
+            if (!env.eq_s_b("\u{00F6}")) {
+                break lab18;
+            }
+            golab19: for (;;) {
+                var v_9 = env.limit - env.cursor;
+                lab20: for (;;) {
+                    if (!env.in_grouping_b(G_vowel6, 246, 252)) {
+                        break lab20;
+                    }
+                    env.cursor = env.limit - v_9;
+                    break golab19;
+                }
+                env.cursor = env.limit - v_9;
+                if (env.cursor <= env.limit_backward) {
+                    break lab18;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab21: for (;;) {
+            if (!env.eq_s_b("u")) {
+                break lab21;
+            }
+            golab22: for (;;) {
+                var v_10 = env.limit - env.cursor;
+                lab23: for (;;) {
+                    if (!env.in_grouping_b(G_vowel5, 111, 117)) {
+                        break lab23;
+                    }
+                    env.cursor = env.limit - v_10;
+                    break golab22;
+                }
+                env.cursor = env.limit - v_10;
+                if (env.cursor <= env.limit_backward) {
+                    break lab21;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        if (!env.eq_s_b("\u{00FC}")) {
+            return false;
+        }
+        golab24: for (;;) {
+            var v_11 = env.limit - env.cursor;
+            lab25: for (;;) {
+                if (!env.in_grouping_b(G_vowel6, 246, 252)) {
+                    break lab25;
+                }
+                env.cursor = env.limit - v_11;
+                break golab24;
+            }
+            env.cursor = env.limit - v_11;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_1;
+    return true;
+}
+
+function r_mark_suffix_with_optional_n_consonant(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.eq_s_b("n")) {
+                break lab1;
+            }
+            var v_2 = env.limit - env.cursor;
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                break lab1;
+            }
+            env.cursor = env.limit - v_2;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            if (!env.eq_s_b("n")) {
+                break lab2;
+            }
+            env.cursor = env.limit - v_4;
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        if (!env.in_grouping_b(G_vowel, 97, 305)) {
+            return false;
+        }
+        env.cursor = env.limit - v_5;
+        break lab0;
+    }
+    return true;
+}
+
+function r_mark_suffix_with_optional_s_consonant(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.eq_s_b("s")) {
+                break lab1;
+            }
+            var v_2 = env.limit - env.cursor;
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                break lab1;
+            }
+            env.cursor = env.limit - v_2;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            if (!env.eq_s_b("s")) {
+                break lab2;
+            }
+            env.cursor = env.limit - v_4;
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        if (!env.in_grouping_b(G_vowel, 97, 305)) {
+            return false;
+        }
+        env.cursor = env.limit - v_5;
+        break lab0;
+    }
+    return true;
+}
+
+function r_mark_suffix_with_optional_y_consonant(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.eq_s_b("y")) {
+                break lab1;
+            }
+            var v_2 = env.limit - env.cursor;
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                break lab1;
+            }
+            env.cursor = env.limit - v_2;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            if (!env.eq_s_b("y")) {
+                break lab2;
+            }
+            env.cursor = env.limit - v_4;
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        if (!env.in_grouping_b(G_vowel, 97, 305)) {
+            return false;
+        }
+        env.cursor = env.limit - v_5;
+        break lab0;
+    }
+    return true;
+}
+
+function r_mark_suffix_with_optional_U_vowel(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.in_grouping_b(G_U, 105, 305)) {
+                break lab1;
+            }
+            var v_2 = env.limit - env.cursor;
+            if (!env.out_grouping_b(G_vowel, 97, 305)) {
+                break lab1;
+            }
+            env.cursor = env.limit - v_2;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        lab2: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            if (!env.in_grouping_b(G_U, 105, 305)) {
+                break lab2;
+            }
+            env.cursor = env.limit - v_4;
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        if (!env.out_grouping_b(G_vowel, 97, 305)) {
+            return false;
+        }
+        env.cursor = env.limit - v_5;
+        break lab0;
+    }
+    return true;
+}
+
+function r_mark_possessives(env, context) {
+    if (env.find_among_b(A_0, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_U_vowel(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_sU(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (!env.in_grouping_b(G_U, 105, 305)) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_s_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_lArI(env, context) {
+    if (env.find_among_b(A_1, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_yU(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (!env.in_grouping_b(G_U, 105, 305)) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_nU(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_2, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_nUn(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_3, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_n_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_yA(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_4, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_nA(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_5, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_DA(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_6, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ndA(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_7, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_DAn(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_8, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ndAn(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_9, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ylA(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_10, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ki(env, context) {
+    if (!env.eq_s_b("ki")) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ncA(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_11, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_n_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_yUm(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_12, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_sUn(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_13, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_yUz(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_14, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_sUnUz(env, context) {
+    if (env.find_among_b(A_15, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_lAr(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_16, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_nUz(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_17, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_DUr(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_18, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_cAsInA(env, context) {
+    if (env.find_among_b(A_19, context) == 0) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_yDU(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_20, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ysA(env, context) {
+    if (env.find_among_b(A_21, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_ymUs_(env, context) {
+    if (!r_check_vowel_harmony(env, context)) {
+        return false;
+    }
+    if (env.find_among_b(A_22, context) == 0) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_mark_yken(env, context) {
+    if (!env.eq_s_b("ken")) {
+        return false;
+    }
+    if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+        return false;
+    }
+    return true;
+}
+
+function r_stem_nominal_verb_suffixes(env, context) {
+    env.ket = env.cursor;
+    context.b_continue_stemming_noun_suffixes = true;
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            lab2: for (;;) {
+                var v_2 = env.limit - env.cursor;
+                lab3: for (;;) {
+                    if (!r_mark_ymUs_(env, context)) {
+                        break lab3;
+                    }
+                    break lab2;
+                }
+                env.cursor = env.limit - v_2;
+                lab4: for (;;) {
+                    if (!r_mark_yDU(env, context)) {
+                        break lab4;
+                    }
+                    break lab2;
+                }
+                env.cursor = env.limit - v_2;
+                lab5: for (;;) {
+                    if (!r_mark_ysA(env, context)) {
+                        break lab5;
+                    }
+                    break lab2;
+                }
+                env.cursor = env.limit - v_2;
+                if (!r_mark_yken(env, context)) {
+                    break lab1;
+                }
+                break lab2;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab6: for (;;) {
+            if (!r_mark_cAsInA(env, context)) {
+                break lab6;
+            }
+            lab7: for (;;) {
+                var v_3 = env.limit - env.cursor;
+                lab8: for (;;) {
+                    if (!r_mark_sUnUz(env, context)) {
+                        break lab8;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_3;
+                lab9: for (;;) {
+                    if (!r_mark_lAr(env, context)) {
+                        break lab9;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_3;
+                lab10: for (;;) {
+                    if (!r_mark_yUm(env, context)) {
+                        break lab10;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_3;
+                lab11: for (;;) {
+                    if (!r_mark_sUn(env, context)) {
+                        break lab11;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_3;
+                lab12: for (;;) {
+                    if (!r_mark_yUz(env, context)) {
+                        break lab12;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_3;
+                break lab7;
+            }
+            if (!r_mark_ymUs_(env, context)) {
+                break lab6;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab13: for (;;) {
+            if (!r_mark_lAr(env, context)) {
+                break lab13;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_4 = env.limit - env.cursor;
+            lab14: for (;;) {
+                env.ket = env.cursor;
+                lab15: for (;;) {
+                    var v_5 = env.limit - env.cursor;
+                    lab16: for (;;) {
+                        if (!r_mark_DUr(env, context)) {
+                            break lab16;
+                        }
+                        break lab15;
+                    }
+                    env.cursor = env.limit - v_5;
+                    lab17: for (;;) {
+                        if (!r_mark_yDU(env, context)) {
+                            break lab17;
+                        }
+                        break lab15;
+                    }
+                    env.cursor = env.limit - v_5;
+                    lab18: for (;;) {
+                        if (!r_mark_ysA(env, context)) {
+                            break lab18;
+                        }
+                        break lab15;
+                    }
+                    env.cursor = env.limit - v_5;
+                    if (!r_mark_ymUs_(env, context)) {
+                        env.cursor = env.limit - v_4;
+                        break lab14;
+                    }
+                    break lab15;
+                }
+                break lab14;
+            }
+            context.b_continue_stemming_noun_suffixes = false;
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab19: for (;;) {
+            if (!r_mark_nUz(env, context)) {
+                break lab19;
+            }
+            lab20: for (;;) {
+                var v_6 = env.limit - env.cursor;
+                lab21: for (;;) {
+                    if (!r_mark_yDU(env, context)) {
+                        break lab21;
+                    }
+                    break lab20;
+                }
+                env.cursor = env.limit - v_6;
+                if (!r_mark_ysA(env, context)) {
+                    break lab19;
+                }
+                break lab20;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab22: for (;;) {
+            lab23: for (;;) {
+                var v_7 = env.limit - env.cursor;
+                lab24: for (;;) {
+                    if (!r_mark_sUnUz(env, context)) {
+                        break lab24;
+                    }
+                    break lab23;
+                }
+                env.cursor = env.limit - v_7;
+                lab25: for (;;) {
+                    if (!r_mark_yUz(env, context)) {
+                        break lab25;
+                    }
+                    break lab23;
+                }
+                env.cursor = env.limit - v_7;
+                lab26: for (;;) {
+                    if (!r_mark_sUn(env, context)) {
+                        break lab26;
+                    }
+                    break lab23;
+                }
+                env.cursor = env.limit - v_7;
+                if (!r_mark_yUm(env, context)) {
+                    break lab22;
+                }
+                break lab23;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_8 = env.limit - env.cursor;
+            lab27: for (;;) {
+                env.ket = env.cursor;
+                if (!r_mark_ymUs_(env, context)) {
+                    env.cursor = env.limit - v_8;
+                    break lab27;
+                }
+                break lab27;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        if (!r_mark_DUr(env, context)) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_9 = env.limit - env.cursor;
+        lab28: for (;;) {
+            env.ket = env.cursor;
+            lab29: for (;;) {
+                var v_10 = env.limit - env.cursor;
+                lab30: for (;;) {
+                    if (!r_mark_sUnUz(env, context)) {
+                        break lab30;
+                    }
+                    break lab29;
+                }
+                env.cursor = env.limit - v_10;
+                lab31: for (;;) {
+                    if (!r_mark_lAr(env, context)) {
+                        break lab31;
+                    }
+                    break lab29;
+                }
+                env.cursor = env.limit - v_10;
+                lab32: for (;;) {
+                    if (!r_mark_yUm(env, context)) {
+                        break lab32;
+                    }
+                    break lab29;
+                }
+                env.cursor = env.limit - v_10;
+                lab33: for (;;) {
+                    if (!r_mark_sUn(env, context)) {
+                        break lab33;
+                    }
+                    break lab29;
+                }
+                env.cursor = env.limit - v_10;
+                lab34: for (;;) {
+                    if (!r_mark_yUz(env, context)) {
+                        break lab34;
+                    }
+                    break lab29;
+                }
+                env.cursor = env.limit - v_10;
+                break lab29;
+            }
+            if (!r_mark_ymUs_(env, context)) {
+                env.cursor = env.limit - v_9;
+                break lab28;
+            }
+            break lab28;
+        }
+        break lab0;
+    }
+    env.bra = env.cursor;
+    if (!env.slice_del()) {
+        return false;
+    }
+    return true;
+}
+
+function r_stem_suffix_chain_before_ki(env, context) {
+    env.ket = env.cursor;
+    if (!r_mark_ki(env, context)) {
+        return false;
+    }
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!r_mark_DA(env, context)) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab2: for (;;) {
+                env.ket = env.cursor;
+                lab3: for (;;) {
+                    var v_3 = env.limit - env.cursor;
+                    lab4: for (;;) {
+                        if (!r_mark_lAr(env, context)) {
+                            break lab4;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_4 = env.limit - env.cursor;
+                        lab5: for (;;) {
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_4;
+                                break lab5;
+                            }
+                            break lab5;
+                        }
+                        break lab3;
+                    }
+                    env.cursor = env.limit - v_3;
+                    if (!r_mark_possessives(env, context)) {
+                        env.cursor = env.limit - v_2;
+                        break lab2;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    var v_5 = env.limit - env.cursor;
+                    lab6: for (;;) {
+                        env.ket = env.cursor;
+                        if (!r_mark_lAr(env, context)) {
+                            env.cursor = env.limit - v_5;
+                            break lab6;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_5;
+                            break lab6;
+                        }
+                        break lab6;
+                    }
+                    break lab3;
+                }
+                break lab2;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab7: for (;;) {
+            if (!r_mark_nUn(env, context)) {
+                break lab7;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_6 = env.limit - env.cursor;
+            lab8: for (;;) {
+                env.ket = env.cursor;
+                lab9: for (;;) {
+                    var v_7 = env.limit - env.cursor;
+                    lab10: for (;;) {
+                        if (!r_mark_lArI(env, context)) {
+                            break lab10;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab9;
+                    }
+                    env.cursor = env.limit - v_7;
+                    lab11: for (;;) {
+                        env.ket = env.cursor;
+                        lab12: for (;;) {
+                            var v_8 = env.limit - env.cursor;
+                            lab13: for (;;) {
+                                if (!r_mark_possessives(env, context)) {
+                                    break lab13;
+                                }
+                                break lab12;
+                            }
+                            env.cursor = env.limit - v_8;
+                            if (!r_mark_sU(env, context)) {
+                                break lab11;
+                            }
+                            break lab12;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_9 = env.limit - env.cursor;
+                        lab14: for (;;) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_9;
+                                break lab14;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_9;
+                                break lab14;
+                            }
+                            break lab14;
+                        }
+                        break lab9;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_6;
+                        break lab8;
+                    }
+                    break lab9;
+                }
+                break lab8;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        if (!r_mark_ndA(env, context)) {
+            return false;
+        }
+        lab15: for (;;) {
+            var v_10 = env.limit - env.cursor;
+            lab16: for (;;) {
+                if (!r_mark_lArI(env, context)) {
+                    break lab16;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab15;
+            }
+            env.cursor = env.limit - v_10;
+            lab17: for (;;) {
+                if (!r_mark_sU(env, context)) {
+                    break lab17;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_11 = env.limit - env.cursor;
+                lab18: for (;;) {
+                    env.ket = env.cursor;
+                    if (!r_mark_lAr(env, context)) {
+                        env.cursor = env.limit - v_11;
+                        break lab18;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_11;
+                        break lab18;
+                    }
+                    break lab18;
+                }
+                break lab15;
+            }
+            env.cursor = env.limit - v_10;
+            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                return false;
+            }
+            break lab15;
+        }
+        break lab0;
+    }
+    return true;
+}
+
+function r_stem_noun_suffixes(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.limit - env.cursor;
+        lab1: for (;;) {
+            env.ket = env.cursor;
+            if (!r_mark_lAr(env, context)) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab2: for (;;) {
+                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                    env.cursor = env.limit - v_2;
+                    break lab2;
+                }
+                break lab2;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab3: for (;;) {
+            env.ket = env.cursor;
+            if (!r_mark_ncA(env, context)) {
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_3 = env.limit - env.cursor;
+            lab4: for (;;) {
+                lab5: for (;;) {
+                    var v_4 = env.limit - env.cursor;
+                    lab6: for (;;) {
+                        env.ket = env.cursor;
+                        if (!r_mark_lArI(env, context)) {
+                            break lab6;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_4;
+                    lab7: for (;;) {
+                        env.ket = env.cursor;
+                        lab8: for (;;) {
+                            var v_5 = env.limit - env.cursor;
+                            lab9: for (;;) {
+                                if (!r_mark_possessives(env, context)) {
+                                    break lab9;
+                                }
+                                break lab8;
+                            }
+                            env.cursor = env.limit - v_5;
+                            if (!r_mark_sU(env, context)) {
+                                break lab7;
+                            }
+                            break lab8;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_6 = env.limit - env.cursor;
+                        lab10: for (;;) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_6;
+                                break lab10;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_6;
+                                break lab10;
+                            }
+                            break lab10;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_4;
+                    env.ket = env.cursor;
+                    if (!r_mark_lAr(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab4;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab4;
+                    }
+                    break lab5;
+                }
+                break lab4;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab11: for (;;) {
+            env.ket = env.cursor;
+            lab12: for (;;) {
+                var v_7 = env.limit - env.cursor;
+                lab13: for (;;) {
+                    if (!r_mark_ndA(env, context)) {
+                        break lab13;
+                    }
+                    break lab12;
+                }
+                env.cursor = env.limit - v_7;
+                if (!r_mark_nA(env, context)) {
+                    break lab11;
+                }
+                break lab12;
+            }
+            lab14: for (;;) {
+                var v_8 = env.limit - env.cursor;
+                lab15: for (;;) {
+                    if (!r_mark_lArI(env, context)) {
+                        break lab15;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab14;
+                }
+                env.cursor = env.limit - v_8;
+                lab16: for (;;) {
+                    if (!r_mark_sU(env, context)) {
+                        break lab16;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    var v_9 = env.limit - env.cursor;
+                    lab17: for (;;) {
+                        env.ket = env.cursor;
+                        if (!r_mark_lAr(env, context)) {
+                            env.cursor = env.limit - v_9;
+                            break lab17;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_9;
+                            break lab17;
+                        }
+                        break lab17;
+                    }
+                    break lab14;
+                }
+                env.cursor = env.limit - v_8;
+                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                    break lab11;
+                }
+                break lab14;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab18: for (;;) {
+            env.ket = env.cursor;
+            lab19: for (;;) {
+                var v_10 = env.limit - env.cursor;
+                lab20: for (;;) {
+                    if (!r_mark_ndAn(env, context)) {
+                        break lab20;
+                    }
+                    break lab19;
+                }
+                env.cursor = env.limit - v_10;
+                if (!r_mark_nU(env, context)) {
+                    break lab18;
+                }
+                break lab19;
+            }
+            lab21: for (;;) {
+                var v_11 = env.limit - env.cursor;
+                lab22: for (;;) {
+                    if (!r_mark_sU(env, context)) {
+                        break lab22;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    var v_12 = env.limit - env.cursor;
+                    lab23: for (;;) {
+                        env.ket = env.cursor;
+                        if (!r_mark_lAr(env, context)) {
+                            env.cursor = env.limit - v_12;
+                            break lab23;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_12;
+                            break lab23;
+                        }
+                        break lab23;
+                    }
+                    break lab21;
+                }
+                env.cursor = env.limit - v_11;
+                if (!r_mark_lArI(env, context)) {
+                    break lab18;
+                }
+                break lab21;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab24: for (;;) {
+            env.ket = env.cursor;
+            if (!r_mark_DAn(env, context)) {
+                break lab24;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_13 = env.limit - env.cursor;
+            lab25: for (;;) {
+                env.ket = env.cursor;
+                lab26: for (;;) {
+                    var v_14 = env.limit - env.cursor;
+                    lab27: for (;;) {
+                        if (!r_mark_possessives(env, context)) {
+                            break lab27;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_15 = env.limit - env.cursor;
+                        lab28: for (;;) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_15;
+                                break lab28;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_15;
+                                break lab28;
+                            }
+                            break lab28;
+                        }
+                        break lab26;
+                    }
+                    env.cursor = env.limit - v_14;
+                    lab29: for (;;) {
+                        if (!r_mark_lAr(env, context)) {
+                            break lab29;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_16 = env.limit - env.cursor;
+                        lab30: for (;;) {
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_16;
+                                break lab30;
+                            }
+                            break lab30;
+                        }
+                        break lab26;
+                    }
+                    env.cursor = env.limit - v_14;
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_13;
+                        break lab25;
+                    }
+                    break lab26;
+                }
+                break lab25;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab31: for (;;) {
+            env.ket = env.cursor;
+            lab32: for (;;) {
+                var v_17 = env.limit - env.cursor;
+                lab33: for (;;) {
+                    if (!r_mark_nUn(env, context)) {
+                        break lab33;
+                    }
+                    break lab32;
+                }
+                env.cursor = env.limit - v_17;
+                if (!r_mark_ylA(env, context)) {
+                    break lab31;
+                }
+                break lab32;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_18 = env.limit - env.cursor;
+            lab34: for (;;) {
+                lab35: for (;;) {
+                    var v_19 = env.limit - env.cursor;
+                    lab36: for (;;) {
+                        env.ket = env.cursor;
+                        if (!r_mark_lAr(env, context)) {
+                            break lab36;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            break lab36;
+                        }
+                        break lab35;
+                    }
+                    env.cursor = env.limit - v_19;
+                    lab37: for (;;) {
+                        env.ket = env.cursor;
+                        lab38: for (;;) {
+                            var v_20 = env.limit - env.cursor;
+                            lab39: for (;;) {
+                                if (!r_mark_possessives(env, context)) {
+                                    break lab39;
+                                }
+                                break lab38;
+                            }
+                            env.cursor = env.limit - v_20;
+                            if (!r_mark_sU(env, context)) {
+                                break lab37;
+                            }
+                            break lab38;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_21 = env.limit - env.cursor;
+                        lab40: for (;;) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_21;
+                                break lab40;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_21;
+                                break lab40;
+                            }
+                            break lab40;
+                        }
+                        break lab35;
+                    }
+                    env.cursor = env.limit - v_19;
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_18;
+                        break lab34;
+                    }
+                    break lab35;
+                }
+                break lab34;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab41: for (;;) {
+            env.ket = env.cursor;
+            if (!r_mark_lArI(env, context)) {
+                break lab41;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab42: for (;;) {
+            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                break lab42;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab43: for (;;) {
+            env.ket = env.cursor;
+            lab44: for (;;) {
+                var v_22 = env.limit - env.cursor;
+                lab45: for (;;) {
+                    if (!r_mark_DA(env, context)) {
+                        break lab45;
+                    }
+                    break lab44;
+                }
+                env.cursor = env.limit - v_22;
+                lab46: for (;;) {
+                    if (!r_mark_yU(env, context)) {
+                        break lab46;
+                    }
+                    break lab44;
+                }
+                env.cursor = env.limit - v_22;
+                if (!r_mark_yA(env, context)) {
+                    break lab43;
+                }
+                break lab44;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_23 = env.limit - env.cursor;
+            lab47: for (;;) {
+                env.ket = env.cursor;
+                lab48: for (;;) {
+                    var v_24 = env.limit - env.cursor;
+                    lab49: for (;;) {
+                        if (!r_mark_possessives(env, context)) {
+                            break lab49;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_25 = env.limit - env.cursor;
+                        lab50: for (;;) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_25;
+                                break lab50;
+                            }
+                            break lab50;
+                        }
+                        break lab48;
+                    }
+                    env.cursor = env.limit - v_24;
+                    if (!r_mark_lAr(env, context)) {
+                        env.cursor = env.limit - v_23;
+                        break lab47;
+                    }
+                    break lab48;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                env.ket = env.cursor;
+                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                    env.cursor = env.limit - v_23;
+                    break lab47;
+                }
+                break lab47;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        lab51: for (;;) {
+            var v_26 = env.limit - env.cursor;
+            lab52: for (;;) {
+                if (!r_mark_possessives(env, context)) {
+                    break lab52;
+                }
+                break lab51;
+            }
+            env.cursor = env.limit - v_26;
+            if (!r_mark_sU(env, context)) {
+                return false;
+            }
+            break lab51;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        var v_27 = env.limit - env.cursor;
+        lab53: for (;;) {
+            env.ket = env.cursor;
+            if (!r_mark_lAr(env, context)) {
+                env.cursor = env.limit - v_27;
+                break lab53;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                env.cursor = env.limit - v_27;
+                break lab53;
+            }
+            break lab53;
+        }
+        break lab0;
+    }
+    return true;
+}
+
+function r_post_process_last_consonants(env, context) {
+    var among_var;
+    env.ket = env.cursor;
+    among_var = env.find_among_b(A_23, context);
+    if (among_var == 0) {
+        return false;
+    }
+    env.bra = env.cursor;
+    if (among_var == 0) {
+        return false;
+    } else if (among_var == 1) {
+        if (!env.slice_from("p")) {
+            return false;
+        }
+    } else if (among_var == 2) {
+        if (!env.slice_from("\u{00E7}")) {
+            return false;
+        }
+    } else if (among_var == 3) {
+        if (!env.slice_from("t")) {
+            return false;
+        }
+    } else if (among_var == 4) {
+        if (!env.slice_from("k")) {
+            return false;
+        }
+    }
+    return true;
+}
+
+function r_append_U_to_stems_ending_with_d_or_g(env, context) {
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        var v_2 = env.limit - env.cursor;
+        lab1: for (;;) {
+            if (!env.eq_s_b("d")) {
+                break lab1;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_2;
+        if (!env.eq_s_b("g")) {
+            return false;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    lab2: for (;;) {
+        var v_3 = env.limit - env.cursor;
+        lab3: for (;;) {
+            var v_4 = env.limit - env.cursor;
+            golab4: for (;;) {
+                var v_5 = env.limit - env.cursor;
+                lab5: for (;;) {
+                    if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_5;
+                    break golab4;
+                }
+                env.cursor = env.limit - v_5;
+                if (env.cursor <= env.limit_backward) {
+                    break lab3;
+                }
+                env.previous_char();
+            }
+            lab6: for (;;) {
+                var v_6 = env.limit - env.cursor;
+                lab7: for (;;) {
+                    if (!env.eq_s_b("a")) {
+                        break lab7;
+                    }
+                    break lab6;
+                }
+                env.cursor = env.limit - v_6;
+                if (!env.eq_s_b("\u{0131}")) {
+                    break lab3;
+                }
+                break lab6;
+            }
+            env.cursor = env.limit - v_4;
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, "\u{0131}");
+            env.cursor = c;
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab8: for (;;) {
+            var v_7 = env.limit - env.cursor;
+            golab9: for (;;) {
+                var v_8 = env.limit - env.cursor;
+                lab10: for (;;) {
+                    if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                        break lab10;
+                    }
+                    env.cursor = env.limit - v_8;
+                    break golab9;
+                }
+                env.cursor = env.limit - v_8;
+                if (env.cursor <= env.limit_backward) {
+                    break lab8;
+                }
+                env.previous_char();
+            }
+            lab11: for (;;) {
+                var v_9 = env.limit - env.cursor;
+                lab12: for (;;) {
+                    if (!env.eq_s_b("e")) {
+                        break lab12;
+                    }
+                    break lab11;
+                }
+                env.cursor = env.limit - v_9;
+                if (!env.eq_s_b("i")) {
+                    break lab8;
+                }
+                break lab11;
+            }
+            env.cursor = env.limit - v_7;
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, "i");
+            env.cursor = c;
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        lab13: for (;;) {
+            var v_10 = env.limit - env.cursor;
+            golab14: for (;;) {
+                var v_11 = env.limit - env.cursor;
+                lab15: for (;;) {
+                    if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                        break lab15;
+                    }
+                    env.cursor = env.limit - v_11;
+                    break golab14;
+                }
+                env.cursor = env.limit - v_11;
+                if (env.cursor <= env.limit_backward) {
+                    break lab13;
+                }
+                env.previous_char();
+            }
+            lab16: for (;;) {
+                var v_12 = env.limit - env.cursor;
+                lab17: for (;;) {
+                    if (!env.eq_s_b("o")) {
+                        break lab17;
+                    }
+                    break lab16;
+                }
+                env.cursor = env.limit - v_12;
+                if (!env.eq_s_b("u")) {
+                    break lab13;
+                }
+                break lab16;
+            }
+            env.cursor = env.limit - v_10;
+            var c = env.cursor;
+            var [bra, ket] = [env.cursor, env.cursor];
+            env.insert(bra, ket, "u");
+            env.cursor = c;
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_13 = env.limit - env.cursor;
+        golab18: for (;;) {
+            var v_14 = env.limit - env.cursor;
+            lab19: for (;;) {
+                if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                    break lab19;
+                }
+                env.cursor = env.limit - v_14;
+                break golab18;
+            }
+            env.cursor = env.limit - v_14;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+        }
+        lab20: for (;;) {
+            var v_15 = env.limit - env.cursor;
+            lab21: for (;;) {
+                if (!env.eq_s_b("\u{00F6}")) {
+                    break lab21;
+                }
+                break lab20;
+            }
+            env.cursor = env.limit - v_15;
+            if (!env.eq_s_b("\u{00FC}")) {
+                return false;
+            }
+            break lab20;
+        }
+        env.cursor = env.limit - v_13;
+        var c = env.cursor;
+        var [bra, ket] = [env.cursor, env.cursor];
+        env.insert(bra, ket, "\u{00FC}");
+        env.cursor = c;
+        break lab2;
+    }
+    return true;
+}
+
+function r_more_than_one_syllable_word(env, context) {
+    var v_1 = env.cursor;
+    var v_2 = 2;
+    replab0: for (;;) {
+        var v_3 = env.cursor;
+        lab1: for (let _i1 = 0, _n1 = 1; _i1 < _n1; _i1++) {
+            golab2: for (;;) {
+                lab3: for (;;) {
+                    if (!env.in_grouping(G_vowel, 97, 305)) {
+                        break lab3;
+                    }
+                    break golab2;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            v_2 -= 1;
+            continue replab0;
+        }
+        env.cursor = v_3;
+        break replab0;
+    }
+    if (v_2 > 0) {
+        return false;
+    }
+    env.cursor = v_1;
+    return true;
+}
+
+function r_is_reserved_word(env, context) {
+    lab0: for (;;) {
+        var v_1 = env.cursor;
+        lab1: for (;;) {
+            var v_2 = env.cursor;
+            golab2: for (;;) {
+                lab3: for (;;) {
+                    if (!env.eq_s("ad")) {
+                        break lab3;
+                    }
+                    break golab2;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab1;
+                }
+                env.next_char();
+            }
+            context.i_strlen = 2;
+            if (!(context.i_strlen == env.limit)) {
+                break lab1;
+            }
+            env.cursor = v_2;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_4 = env.cursor;
+        golab4: for (;;) {
+            lab5: for (;;) {
+                if (!env.eq_s("soyad")) {
+                    break lab5;
+                }
+                break golab4;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_strlen = 5;
+        if (!(context.i_strlen == env.limit)) {
+            return false;
+        }
+        env.cursor = v_4;
+        break lab0;
+    }
+    return true;
+}
+
+function r_postlude(env, context) {
+    var v_1 = env.cursor;
+    lab0: for (;;) {
+        if (!r_is_reserved_word(env, context)) {
+            break lab0;
+        }
+        return false;
+    }
+    env.cursor = v_1;
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_append_U_to_stems_ending_with_d_or_g(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    var v_3 = env.limit - env.cursor;
+    lab2: for (;;) {
+        if (!r_post_process_last_consonants(env, context)) {
+            break lab2;
+        }
+        break lab2;
+    }
+    env.cursor = env.limit - v_3;
+    env.cursor = env.limit_backward;
+    return true;
+}
+
+function stem(env) {
+    const context = {
+        b_continue_stemming_noun_suffixes: false,
+        i_strlen: 0,
+    };
+    if (!r_more_than_one_syllable_word(env, context)) {
+        return false;
+    }
+    env.limit_backward = env.cursor;
+    env.cursor = env.limit;
+    var v_1 = env.limit - env.cursor;
+    lab0: for (;;) {
+        if (!r_stem_nominal_verb_suffixes(env, context)) {
+            break lab0;
+        }
+        break lab0;
+    }
+    env.cursor = env.limit - v_1;
+    if (!context.b_continue_stemming_noun_suffixes) {
+        return false;
+    }
+    var v_2 = env.limit - env.cursor;
+    lab1: for (;;) {
+        if (!r_stem_noun_suffixes(env, context)) {
+            break lab1;
+        }
+        break lab1;
+    }
+    env.cursor = env.limit - v_2;
+    env.cursor = env.limit_backward;
+    if (!r_postlude(env, context)) {
+        return false;
+    }
+    return true;
+}
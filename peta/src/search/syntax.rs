@@ -0,0 +1,478 @@
+//! Search query syntax
+//!
+//! A query is a whitespace-separated list of clauses:
+//!
+//! - `word`: scored with BM25F, see `ranking`
+//! - `"exact phrase"`: scored like its words, and required to appear in order
+//! - `tag:rust`, `type:snippet`, `author:jane`, `language:python`: filters on
+//!   the document's metadata; quote values with spaces, `author:"Jane Doe"`
+//! - `after:2024-01`, `before:2024-06-15`: publication date on or after, or
+//!   before, the start of a year, month or day
+//! - `-clause`: excludes documents matching any of the above
+//!
+//! Every `tag:` and date filter must hold; of several `type:`, `author:` or
+//! `language:` filters, one must. `parseQuery` and `QueryFilter` in
+//! `search_bar.js` implement the same rules; keep them in sync.
+
+use crate::search::{analyzer, fuzzy};
+use crate::search::indexer::{SearchDocument, SearchIndex};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Metadata a filter clause tests
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterKey {
+    Tag,
+    Type,
+    Author,
+    Language,
+    After,
+    Before,
+}
+
+impl FilterKey {
+    /// Parse the part of a clause before the colon
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "tag" => Some(Self::Tag),
+            "type" => Some(Self::Type),
+            "author" => Some(Self::Author),
+            "language" => Some(Self::Language),
+            "after" => Some(Self::After),
+            "before" => Some(Self::Before),
+            _ => None,
+        }
+    }
+
+    /// Name used in queries
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Tag => "tag",
+            Self::Type => "type",
+            Self::Author => "author",
+            Self::Language => "language",
+            Self::After => "after",
+            Self::Before => "before",
+        }
+    }
+
+    /// Whether several positive filters of this key must all hold
+    fn requires_all(&self) -> bool {
+        matches!(self, Self::Tag | Self::After | Self::Before)
+    }
+}
+
+/// One clause of a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Clause {
+    Word(String),
+    Phrase(String),
+    Filter(FilterKey, String),
+}
+
+/// A clause and whether it was negated with `-`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryClause {
+    pub clause: Clause,
+    pub negated: bool,
+}
+
+/// A parsed query
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedQuery {
+    pub clauses: Vec<QueryClause>,
+    /// Whether the query ended in whitespace, which ends prefix completion
+    pub trailing_space: bool,
+}
+
+/// Parse `query` into clauses; malformed operators are read as words
+pub fn parse(query: &str) -> ParsedQuery {
+    let chars: Vec<char> = query.chars().collect();
+    let mut clauses = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let negated = chars[i] == '-' && chars.get(i + 1).is_some_and(|c| !c.is_whitespace());
+        if negated {
+            i += 1;
+        }
+
+        let clause = if chars[i] == '"' {
+            let (phrase, next) = read_quoted(&chars, i);
+            i = next;
+            Clause::Phrase(phrase)
+        } else {
+            let start = i;
+            while i < chars.len() && !chars[i].is_whitespace() && chars[i] != '"' {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            match word.split_once(':').and_then(|(key, value)| Some((FilterKey::from_key(&key.to_lowercase())?, value))) {
+                Some((key, "")) if chars.get(i) == Some(&'"') => {
+                    let (value, next) = read_quoted(&chars, i);
+                    i = next;
+                    filter_clause(key, &value).unwrap_or(Clause::Word(word))
+                }
+                Some((key, value)) => filter_clause(key, value).unwrap_or(Clause::Word(word)),
+                None => Clause::Word(word),
+            }
+        };
+
+        let empty = match &clause {
+            Clause::Word(text) | Clause::Phrase(text) => text.trim().is_empty(),
+            Clause::Filter(..) => false,
+        };
+        if !empty {
+            clauses.push(QueryClause { clause, negated });
+        }
+    }
+
+    ParsedQuery {
+        clauses,
+        trailing_space: query.chars().last().is_some_and(char::is_whitespace),
+    }
+}
+
+/// Text between the quote at `start` and the next one, and the index after it
+fn read_quoted(chars: &[char], start: usize) -> (String, usize) {
+    let mut end = start + 1;
+    while end < chars.len() && chars[end] != '"' {
+        end += 1;
+    }
+    (chars[start + 1..end].iter().collect(), (end + 1).min(chars.len()))
+}
+
+/// A filter clause, or `None` when the value is not valid for the key
+fn filter_clause(key: FilterKey, value: &str) -> Option<Clause> {
+    let value = value.trim().to_lowercase();
+    if value.is_empty() {
+        return None;
+    }
+    if matches!(key, FilterKey::After | FilterKey::Before) && !is_date_prefix(&value) {
+        return None;
+    }
+    Some(Clause::Filter(key, value))
+}
+
+/// `YYYY`, `YYYY-MM` or `YYYY-MM-DD`
+fn is_date_prefix(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    let widths = [4, 2, 2];
+    parts.len() <= widths.len()
+        && parts
+            .iter()
+            .zip(widths)
+            .all(|(part, width)| part.len() == width && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+impl ParsedQuery {
+    /// Text scored with BM25F: the words of positive words and phrases.
+    /// Ends in a space unless the query ends in a word still being typed.
+    pub fn text(&self) -> String {
+        let mut words: Vec<&str> = Vec::new();
+        for clause in self.clauses.iter().filter(|c| !c.negated) {
+            match &clause.clause {
+                Clause::Word(text) | Clause::Phrase(text) => words.push(text),
+                Clause::Filter(..) => {}
+            }
+        }
+
+        let mut text = words.join(" ");
+        let completes_last = !self.trailing_space
+            && matches!(self.clauses.last(), Some(QueryClause { clause: Clause::Word(_), negated: false }));
+        if !completes_last && !text.is_empty() {
+            text.push(' ');
+        }
+        text
+    }
+
+    /// Whether the query restricts results beyond its scored text
+    pub fn has_constraints(&self) -> bool {
+        self.clauses
+            .iter()
+            .any(|c| c.negated || !matches!(c.clause, Clause::Word(_)))
+    }
+
+    /// The query with its positive words replaced by `words`, in order
+    pub fn with_words(&self, words: &[String]) -> Self {
+        let mut words = words.iter();
+        let clauses = self
+            .clauses
+            .iter()
+            .map(|c| match (&c.clause, c.negated) {
+                (Clause::Word(_), false) => QueryClause {
+                    clause: Clause::Word(words.next().cloned().unwrap_or_default()),
+                    negated: false,
+                },
+                _ => c.clone(),
+            })
+            .collect();
+        Self { clauses, trailing_space: false }
+    }
+}
+
+impl fmt::Display for ParsedQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, clause) in self.clauses.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            if clause.negated {
+                f.write_str("-")?;
+            }
+            match &clause.clause {
+                Clause::Word(text) => f.write_str(text)?,
+                Clause::Phrase(text) => write!(f, "\"{}\"", text)?,
+                Clause::Filter(key, value) if value.contains(char::is_whitespace) => write!(f, "{}:\"{}\"", key.key(), value)?,
+                Clause::Filter(key, value) => write!(f, "{}:{}", key.key(), value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decides which documents a parsed query accepts
+pub struct QueryFilter<'a> {
+    index: Option<&'a SearchIndex>,
+    /// Documents each phrase or word clause occurs in, by clause position
+    occurrences: HashMap<usize, HashSet<usize>>,
+    clauses: &'a [QueryClause],
+}
+
+impl<'a> QueryFilter<'a> {
+    /// Resolve the phrases and exclusions of `query` against `index`
+    pub fn new(index: &'a SearchIndex, query: &'a ParsedQuery) -> Self {
+        let mut filter = Self::with_phrase_docs(query, |text| phrase_docs(index, text));
+        filter.index = Some(index);
+        filter
+    }
+
+    /// Resolve the phrases and exclusions of `query` with `phrase_docs`, for
+    /// documents that are not held in a `SearchIndex`
+    pub fn with_phrase_docs(query: &'a ParsedQuery, mut phrase_docs: impl FnMut(&str) -> HashSet<usize>) -> Self {
+        let mut occurrences = HashMap::new();
+        for (i, clause) in query.clauses.iter().enumerate() {
+            match (&clause.clause, clause.negated) {
+                (Clause::Phrase(text), _) | (Clause::Word(text), true) => {
+                    occurrences.insert(i, phrase_docs(text));
+                }
+                _ => {}
+            }
+        }
+        Self { index: None, occurrences, clauses: &query.clauses }
+    }
+
+    /// Whether document `doc` of the index satisfies every clause
+    pub fn accepts(&self, doc: usize) -> bool {
+        self.accepts_with(doc, |key, value| self.filter_matches(doc, key, value))
+    }
+
+    /// Whether document `doc`, with metadata `document`, satisfies every clause
+    pub fn accepts_document(&self, doc: usize, document: &SearchDocument) -> bool {
+        self.accepts_with(doc, |key, value| document_matches(document, key, value))
+    }
+
+    fn accepts_with(&self, doc: usize, filter_matches: impl Fn(FilterKey, &str) -> bool) -> bool {
+        let mut groups: HashMap<FilterKey, bool> = HashMap::new();
+
+        for (i, clause) in self.clauses.iter().enumerate() {
+            let matches = match &clause.clause {
+                Clause::Word(_) if !clause.negated => continue,
+                Clause::Word(_) | Clause::Phrase(_) => self.occurrences.get(&i).is_some_and(|docs| docs.contains(&doc)),
+                Clause::Filter(key, value) => {
+                    let matches = filter_matches(*key, value);
+                    if !clause.negated && !key.requires_all() {
+                        *groups.entry(*key).or_insert(false) |= matches;
+                        continue;
+                    }
+                    matches
+                }
+            };
+            if matches == clause.negated {
+                return false;
+            }
+        }
+
+        groups.values().all(|matched| *matched)
+    }
+
+    fn filter_matches(&self, doc: usize, key: FilterKey, value: &str) -> bool {
+        let index = match self.index {
+            Some(index) => index,
+            None => return false,
+        };
+        let listed = |map: &HashMap<String, Vec<usize>>| map.get(value).is_some_and(|docs| docs.contains(&doc));
+        match key {
+            FilterKey::Tag => listed(&index.tags),
+            FilterKey::Type => listed(&index.content_types),
+            _ => index.documents.get(doc).is_some_and(|document| document_matches(document, key, value)),
+        }
+    }
+}
+
+/// Whether the metadata of `document` passes the filter `key:value`
+fn document_matches(document: &SearchDocument, key: FilterKey, value: &str) -> bool {
+    match key {
+        FilterKey::Tag => document.tags.iter().any(|tag| tag.to_lowercase() == value),
+        FilterKey::Type => document.content_type == value,
+        FilterKey::Author => document.author.as_ref().is_some_and(|a| a.to_lowercase().contains(value)),
+        FilterKey::Language => document.language.as_ref().is_some_and(|l| l.to_lowercase() == value),
+        FilterKey::After => !document.date.is_empty() && document.date.as_str() >= value,
+        FilterKey::Before => !document.date.is_empty() && document.date.as_str() < value,
+    }
+}
+
+/// Documents containing the words of `text` at consecutive positions.
+///
+/// Stop words match any position. A word that is neither indexed nor a stop
+/// word matches nothing, so neither does the phrase.
+pub fn phrase_docs(index: &SearchIndex, text: &str) -> HashSet<usize> {
+    // Positions of each phrase word by document, `None` for stop words
+    let mut words: Vec<Option<HashMap<usize, HashSet<u32>>>> = Vec::new();
+    for token in analyzer::query_tokens(text, &index.config) {
+        let mut positions: HashMap<usize, HashSet<u32>> = HashMap::new();
+        for term in fuzzy::resolve(index, &token) {
+            for posting in index.terms.get(term.as_ref()).into_iter().flatten() {
+                positions.entry(posting.doc).or_default().extend(&posting.positions);
+            }
+        }
+        if positions.is_empty() {
+            if index.stop_words.binary_search(&token).is_err() {
+                return HashSet::new();
+            }
+            words.push(None);
+        } else {
+            words.push(Some(positions));
+        }
+    }
+
+    let (anchor, anchor_positions) = match words.iter().enumerate().find_map(|(i, w)| Some((i, w.as_ref()?))) {
+        Some(found) => found,
+        None => return HashSet::new(),
+    };
+
+    let mut docs = HashSet::new();
+    for (doc, positions) in anchor_positions {
+        let found = positions.iter().any(|&position| {
+            let start = match (position as usize).checked_sub(anchor) {
+                Some(start) => start,
+                None => return false,
+            };
+            words.iter().enumerate().all(|(offset, word)| match word {
+                Some(word) => word.get(doc).is_some_and(|p| p.contains(&((start + offset) as u32))),
+                None => true,
+            })
+        });
+        if found {
+            docs.insert(*doc);
+        }
+    }
+    docs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::indexer::Posting;
+
+    fn clauses(query: &str) -> Vec<(Clause, bool)> {
+        parse(query).clauses.into_iter().map(|c| (c.clause, c.negated)).collect()
+    }
+
+    #[test]
+    fn test_parse_clauses() {
+        assert_eq!(
+            clauses(r#"borrow "exact phrase" -unsafe tag:Rust author:"Jane Doe" after:2024-01"#),
+            vec![
+                (Clause::Word("borrow".to_string()), false),
+                (Clause::Phrase("exact phrase".to_string()), false),
+                (Clause::Word("unsafe".to_string()), true),
+                (Clause::Filter(FilterKey::Tag, "rust".to_string()), false),
+                (Clause::Filter(FilterKey::Author, "jane doe".to_string()), false),
+                (Clause::Filter(FilterKey::After, "2024-01".to_string()), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_operators_are_words() {
+        assert_eq!(
+            clauses("after:january url:x - c-3po"),
+            vec![
+                (Clause::Word("after:january".to_string()), false),
+                (Clause::Word("url:x".to_string()), false),
+                (Clause::Word("-".to_string()), false),
+                (Clause::Word("c-3po".to_string()), false),
+            ]
+        );
+        assert_eq!(clauses(r#""unterminated phrase"#), vec![(Clause::Phrase("unterminated phrase".to_string()), false)]);
+    }
+
+    fn index() -> SearchIndex {
+        let mut index = SearchIndex::new();
+        for (doc, (words, tags, date)) in [
+            ("state of the art", "rust", "2024-03-02"),
+            ("the art of state", "python", "2023-11-20"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            for (position, word) in words.split(' ').enumerate() {
+                if word == "the" || word == "of" {
+                    continue;
+                }
+                index.terms.entry(word.to_string()).or_default().push(Posting {
+                    doc,
+                    tf: [0, 0, 0, 1],
+                    positions: vec![position as u32],
+                });
+            }
+            index.tags.entry(tags.to_string()).or_default().push(doc);
+            index.documents.push(SearchDocument { date: date.to_string(), ..Default::default() });
+        }
+        index.stop_words = vec!["of".to_string(), "the".to_string()];
+        index
+    }
+
+    fn accepted(index: &SearchIndex, query: &str) -> Vec<usize> {
+        let query = parse(query);
+        let filter = QueryFilter::new(index, &query);
+        (0..index.documents.len()).filter(|&doc| filter.accepts(doc)).collect()
+    }
+
+    #[test]
+    fn test_phrases_respect_order_and_skip_stop_words() {
+        let index = index();
+        assert_eq!(phrase_docs(&index, "state of the art"), HashSet::from([0]));
+        assert_eq!(phrase_docs(&index, "art of state"), HashSet::from([1]));
+        assert_eq!(phrase_docs(&index, "state art"), HashSet::new());
+        assert_eq!(phrase_docs(&index, "state of unknown"), HashSet::new());
+    }
+
+    #[test]
+    fn test_filters_and_exclusions() {
+        let index = index();
+        assert_eq!(accepted(&index, "tag:rust"), vec![0]);
+        assert_eq!(accepted(&index, "tag:rust tag:python"), Vec::<usize>::new());
+        assert_eq!(accepted(&index, "-tag:rust"), vec![1]);
+        assert_eq!(accepted(&index, "after:2024"), vec![0]);
+        assert_eq!(accepted(&index, "before:2023-12"), vec![1]);
+        assert_eq!(accepted(&index, "-\"art of state\" state"), vec![0]);
+    }
+
+    #[test]
+    fn test_scored_text_and_display() {
+        let query = parse(r#"type:snippet "hello world" pyth"#);
+        assert_eq!(query.text(), "hello world pyth");
+        assert_eq!(parse("pyth tag:rust").text(), "pyth ");
+        assert_eq!(parse("pyth ").text(), "pyth ");
+
+        let corrected = query.with_words(&["python".to_string()]);
+        assert_eq!(corrected.to_string(), r#"type:snippet "hello world" python"#);
+    }
+}
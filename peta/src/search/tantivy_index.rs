@@ -0,0 +1,614 @@
+//! Server-side search backed by tantivy
+//!
+//! With `backend = "server"` the build writes the search index as a tantivy
+//! index plus a lexicon of surface forms and document frequencies
+//! (`lexicon.json`), and `peta search-server` answers queries from it.
+//!
+//! Documents are indexed pre-tokenized with the terms and positions of the
+//! `SearchIndex` they were built from, so stemming, stop word gaps and
+//! phrases behave exactly as in the static search. Query expansion,
+//! suggestions, filters, section collapsing and highlights reuse `fuzzy`,
+//! `syntax` and `highlight`. Scores are tantivy's BM25 per field, weighted by
+//! the field boosts and expansion weights, so they differ slightly from the
+//! static search's BM25F; the matching documents do not.
+
+use crate::core::{Error, Result};
+use crate::search::analyzer;
+use crate::search::fuzzy::{self, Lexicon, QueryTerm};
+use crate::search::highlight::Highlighter;
+use crate::search::indexer::{IndexerConfig, SearchDocument, SearchIndex};
+use crate::search::query::SearchResult;
+use crate::search::ranking::FIELD_COUNT;
+use crate::search::syntax::{self, Clause, QueryFilter};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use tantivy::collector::{DocSetCollector, TopDocs};
+use tantivy::query::{AllQuery, BooleanQuery, BoostQuery, Occur, PhraseQuery, Query, TermQuery};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value, FAST, INDEXED, STORED,
+};
+use tantivy::tokenizer::{PreTokenizedString, Token};
+use tantivy::{DocAddress, Index, IndexReader, Searcher, TantivyDocument, Term};
+
+/// Lexicon file written next to the tantivy index
+pub const LEXICON_FILE: &str = "lexicon.json";
+
+/// Names of the text fields, indexed by `ranking::Field`
+const TEXT_FIELDS: [&str; FIELD_COUNT] = ["title", "tags", "headings", "body"];
+
+/// Indexing memory budget of the tantivy writer
+const WRITER_MEMORY: usize = 50_000_000;
+
+/// What queries are resolved and expanded against, without the postings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchLexicon {
+    pub config: IndexerConfig,
+    pub forms: HashMap<String, Vec<String>>,
+    pub vocabulary: Vec<String>,
+    pub stop_words: Vec<String>,
+    #[serde(default)]
+    pub stemmers: Vec<String>,
+    /// Number of documents containing each indexed term
+    pub doc_freqs: HashMap<String, usize>,
+}
+
+impl SearchLexicon {
+    /// Lexicon of a built search index
+    pub fn from_index(index: &SearchIndex) -> Self {
+        Self {
+            config: index.config.clone(),
+            forms: index.forms.clone(),
+            vocabulary: index.vocabulary.clone(),
+            stop_words: index.stop_words.clone(),
+            stemmers: index.stemmers.clone(),
+            doc_freqs: index.terms.iter().map(|(term, postings)| (term.clone(), postings.len())).collect(),
+        }
+    }
+}
+
+impl Lexicon for SearchLexicon {
+    fn config(&self) -> &IndexerConfig {
+        &self.config
+    }
+
+    fn forms(&self) -> &HashMap<String, Vec<String>> {
+        &self.forms
+    }
+
+    fn vocabulary(&self) -> &[String] {
+        &self.vocabulary
+    }
+
+    fn stemmers(&self) -> &[String] {
+        &self.stemmers
+    }
+
+    fn terms(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        Box::new(self.doc_freqs.keys().map(String::as_str))
+    }
+
+    fn doc_freq(&self, term: &str) -> usize {
+        self.doc_freqs.get(term).copied().unwrap_or(0)
+    }
+}
+
+/// Answer to a search request
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchResponse {
+    /// Number of matching pages before the limit was applied
+    pub total: usize,
+    pub results: Vec<SearchHit>,
+    /// Corrected query when no result matched every word as typed
+    pub suggestion: Option<String>,
+}
+
+/// One result of a search request
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    #[serde(flatten)]
+    pub result: SearchResult,
+    /// Whether the document matched a query word without expansion
+    pub exact: bool,
+}
+
+/// A ranked document, before its stored fields are loaded
+struct Ranked {
+    doc: usize,
+    address: DocAddress,
+    score: f64,
+    matched: usize,
+}
+
+/// Schema fields of the tantivy index
+#[derive(Debug, Clone, Copy)]
+struct Fields {
+    text: [Field; FIELD_COUNT],
+    /// Document index in the `SearchIndex`
+    doc: Field,
+    /// Index of the page document, the document itself for a page
+    page: Field,
+    /// The `SearchDocument` as JSON
+    document: Field,
+}
+
+impl Fields {
+    fn schema() -> (Schema, Self) {
+        let mut builder = Schema::builder();
+        let indexing = TextFieldIndexing::default()
+            .set_tokenizer("raw")
+            .set_index_option(IndexRecordOption::WithFreqsAndPositions);
+        let options = TextOptions::default().set_indexing_options(indexing);
+        let text = TEXT_FIELDS.map(|name| builder.add_text_field(name, options.clone()));
+        let fields = Self {
+            text,
+            doc: builder.add_u64_field("doc", INDEXED | STORED | FAST),
+            page: builder.add_u64_field("page", FAST),
+            document: builder.add_text_field("document", STORED),
+        };
+        (builder.build(), fields)
+    }
+
+    fn from_schema(schema: &Schema) -> Result<Self> {
+        let field = |name: &str| {
+            schema
+                .get_field(name)
+                .map_err(|_| Error::search(format!("Search index has no `{}` field", name)))
+        };
+        Ok(Self {
+            text: [field(TEXT_FIELDS[0])?, field(TEXT_FIELDS[1])?, field(TEXT_FIELDS[2])?, field(TEXT_FIELDS[3])?],
+            doc: field("doc")?,
+            page: field("page")?,
+            document: field("document")?,
+        })
+    }
+}
+
+/// A tantivy index of the site's search documents
+pub struct TantivyIndex {
+    reader: IndexReader,
+    fields: Fields,
+    lexicon: SearchLexicon,
+}
+
+impl TantivyIndex {
+    /// Write `index` as a tantivy index to `dir`, replacing its contents
+    pub fn create(index: &SearchIndex, dir: &Path) -> Result<Self> {
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        fs::create_dir_all(dir)?;
+
+        let (schema, fields) = Fields::schema();
+        let tantivy_index = Index::create_in_dir(dir, schema).map_err(error("create the search index"))?;
+        let mut writer = tantivy_index
+            .writer_with_num_threads::<TantivyDocument>(1, WRITER_MEMORY)
+            .map_err(error("open the search index writer"))?;
+
+        // Indexed terms of each document in position order; the fields were
+        // indexed one after the other, so their lengths split the stream
+        let mut tokens: Vec<Vec<(u32, &str)>> = vec![Vec::new(); index.documents.len()];
+        for (term, postings) in &index.terms {
+            for posting in postings {
+                if let Some(doc_tokens) = tokens.get_mut(posting.doc) {
+                    doc_tokens.extend(posting.positions.iter().map(|&position| (position, term.as_str())));
+                }
+            }
+        }
+
+        for (doc, document) in index.documents.iter().enumerate() {
+            let mut doc_tokens = std::mem::take(&mut tokens[doc]);
+            doc_tokens.sort_unstable();
+            let lengths = index.field_lengths.get(doc).copied().unwrap_or_default();
+
+            let mut tantivy_doc = TantivyDocument::default();
+            let mut rest = doc_tokens.as_slice();
+            for (field, length) in fields.text.iter().zip(lengths) {
+                let (field_tokens, remaining) = rest.split_at((length as usize).min(rest.len()));
+                rest = remaining;
+                if field_tokens.is_empty() {
+                    continue;
+                }
+                tantivy_doc.add_pre_tokenized_text(
+                    *field,
+                    PreTokenizedString {
+                        text: String::new(),
+                        tokens: field_tokens
+                            .iter()
+                            .map(|(position, term)| Token {
+                                position: *position as usize,
+                                text: term.to_string(),
+                                ..Token::default()
+                            })
+                            .collect(),
+                    },
+                );
+            }
+            tantivy_doc.add_u64(fields.doc, doc as u64);
+            tantivy_doc.add_u64(fields.page, document.parent.unwrap_or(doc) as u64);
+            tantivy_doc.add_text(fields.document, serde_json::to_string(document)?);
+            writer.add_document(tantivy_doc).map_err(error("index a document"))?;
+        }
+        writer.commit().map_err(error("commit the search index"))?;
+        writer.wait_merging_threads().map_err(error("merge the search index"))?;
+
+        let lexicon = SearchLexicon::from_index(index);
+        fs::write(dir.join(LEXICON_FILE), serde_json::to_string(&lexicon)?)?;
+
+        Self::with_lexicon(&tantivy_index, fields, lexicon)
+    }
+
+    /// Open the index written to `dir` by `create`
+    pub fn open(dir: &Path) -> Result<Self> {
+        let tantivy_index = Index::open_in_dir(dir).map_err(error("open the search index"))?;
+        let fields = Fields::from_schema(&tantivy_index.schema())?;
+        let lexicon = fs::read_to_string(dir.join(LEXICON_FILE))
+            .map_err(|e| Error::search(format!("Failed to read {}: {}", LEXICON_FILE, e)))?;
+        let lexicon = serde_json::from_str(&lexicon)
+            .map_err(|e| Error::search(format!("Failed to parse {}: {}", LEXICON_FILE, e)))?;
+        Self::with_lexicon(&tantivy_index, fields, lexicon)
+    }
+
+    fn with_lexicon(index: &Index, fields: Fields, lexicon: SearchLexicon) -> Result<Self> {
+        let reader = index.reader().map_err(error("read the search index"))?;
+        Ok(Self { reader, fields, lexicon })
+    }
+
+    /// Lexicon queries are expanded against
+    pub fn lexicon(&self) -> &SearchLexicon {
+        &self.lexicon
+    }
+
+    /// Number of indexed documents, sections included
+    pub fn num_docs(&self) -> u64 {
+        self.reader.searcher().num_docs()
+    }
+
+    /// Rank documents for `query`, as `(document index, score)`, with the
+    /// query syntax and section collapsing of `SearchIndex::rank`
+    pub fn rank(&self, query: &str) -> Result<Vec<(usize, f64)>> {
+        let searcher = self.reader.searcher();
+        Ok(self.ranked(&searcher, query)?.into_iter().map(|r| (r.doc, r.score)).collect())
+    }
+
+    /// Search for `query`, returning at most `limit` highlighted results
+    pub fn search(&self, query: &str, limit: usize) -> Result<SearchResponse> {
+        let searcher = self.reader.searcher();
+        let ranked = self.ranked(&searcher, query)?;
+        let highlighter = Highlighter::for_query(&self.lexicon, query);
+
+        let mut results = Vec::new();
+        for ranked in ranked.iter().take(limit) {
+            let document = self.stored(&searcher, ranked.address)?;
+            results.push(SearchHit {
+                exact: ranked.matched > 0,
+                result: SearchResult {
+                    highlights: highlighter.highlight_document(&document),
+                    document,
+                    score: ranked.score,
+                },
+            });
+        }
+
+        let suggestion = if ranked.iter().all(|r| r.matched == 0) {
+            fuzzy::suggest(&self.lexicon, query)
+        } else {
+            None
+        };
+        Ok(SearchResponse { total: ranked.len(), results, suggestion })
+    }
+
+    fn ranked(&self, searcher: &Searcher, query: &str) -> Result<Vec<Ranked>> {
+        let query = syntax::parse(query);
+        let terms = fuzzy::expand_query(&self.lexicon, &query.text());
+
+        let ranked = if terms.is_empty() {
+            if !query.has_constraints() {
+                return Ok(Vec::new());
+            }
+            self.newest_first(searcher)?
+        } else {
+            self.scored(searcher, &terms)?
+        };
+
+        // Phrases and exclusions, resolved before filtering
+        let mut phrases = HashMap::new();
+        for clause in &query.clauses {
+            match (&clause.clause, clause.negated) {
+                (Clause::Phrase(text), _) | (Clause::Word(text), true) => {
+                    phrases.insert(text.clone(), self.phrase_docs(searcher, text)?);
+                }
+                _ => {}
+            }
+        }
+        let filter = QueryFilter::with_phrase_docs(&query, |text| phrases.remove(text).unwrap_or_default());
+
+        // Only filter clauses read the document's metadata
+        let has_filters = query.clauses.iter().any(|c| matches!(c.clause, Clause::Filter(..)));
+        let mut pages = HashSet::new();
+        let mut accepted = Vec::with_capacity(ranked.len());
+        for ranked in ranked {
+            let document = if has_filters { self.stored(searcher, ranked.address)? } else { SearchDocument::default() };
+            if filter.accepts_document(ranked.doc, &document) && pages.insert(self.page(searcher, ranked.address)?) {
+                accepted.push(ranked);
+            }
+        }
+        Ok(accepted)
+    }
+
+    /// Documents matching any expanded term, ranked like `RankingAlgorithm`:
+    /// more exactly matched query tokens first, then by score
+    fn scored(&self, searcher: &Searcher, terms: &[QueryTerm]) -> Result<Vec<Ranked>> {
+        let boosts = &self.lexicon.config.ranking.boosts;
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+        for query_term in terms {
+            for (field, boost) in self.fields.text.iter().zip(boosts) {
+                if *boost > 0.0 {
+                    let query = TermQuery::new(Term::from_field_text(*field, &query_term.term), IndexRecordOption::WithFreqs);
+                    clauses.push((Occur::Should, Box::new(BoostQuery::new(Box::new(query), (boost * query_term.weight) as f32))));
+                }
+            }
+        }
+        let limit = (searcher.num_docs() as usize).max(1);
+        let scored = searcher
+            .search(&BooleanQuery::new(clauses), &TopDocs::with_limit(limit))
+            .map_err(error("search the index"))?;
+
+        // Query tokens each document contains without expansion
+        let mut matched: HashMap<DocAddress, usize> = HashMap::new();
+        let tokens: HashSet<usize> = terms.iter().map(|q| q.token).collect();
+        for token in tokens {
+            let exact: Vec<&str> = terms.iter().filter(|q| q.exact && q.token == token).map(|q| q.term.as_str()).collect();
+            for address in self.docs_with_any(searcher, &exact)? {
+                *matched.entry(address).or_default() += 1;
+            }
+        }
+
+        let mut ranked = Vec::with_capacity(scored.len());
+        for (score, address) in scored {
+            ranked.push(Ranked {
+                doc: self.doc_index(searcher, address)?,
+                address,
+                score: score as f64,
+                matched: matched.get(&address).copied().unwrap_or(0),
+            });
+        }
+        ranked.sort_by(|a, b| {
+            b.matched
+                .cmp(&a.matched)
+                .then(b.score.total_cmp(&a.score))
+                .then(a.doc.cmp(&b.doc))
+        });
+        Ok(ranked)
+    }
+
+    /// Every document, newest first, for queries of filters alone
+    fn newest_first(&self, searcher: &Searcher) -> Result<Vec<Ranked>> {
+        let addresses = searcher.search(&AllQuery, &DocSetCollector).map_err(error("search the index"))?;
+        let mut dated = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let date = self.stored(searcher, address)?.date;
+            dated.push((date, Ranked { doc: self.doc_index(searcher, address)?, address, score: 0.0, matched: 0 }));
+        }
+        dated.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.doc.cmp(&b.1.doc)));
+        Ok(dated.into_iter().map(|(_, ranked)| ranked).collect())
+    }
+
+    /// Documents containing the words of `text` at consecutive positions,
+    /// with the stop word and unknown word rules of `syntax::phrase_docs`
+    fn phrase_docs(&self, searcher: &Searcher, text: &str) -> Result<HashSet<usize>> {
+        // Terms of each phrase word, `None` for stop words
+        let mut words: Vec<Option<Vec<String>>> = Vec::new();
+        for token in analyzer::query_tokens(text, &self.lexicon.config) {
+            let terms: Vec<String> = fuzzy::resolve(&self.lexicon, &token)
+                .into_iter()
+                .filter(|term| self.lexicon.doc_freq(term) > 0)
+                .map(Cow::into_owned)
+                .collect();
+            if terms.is_empty() {
+                if self.lexicon.stop_words.binary_search(&token).is_err() {
+                    return Ok(HashSet::new());
+                }
+                words.push(None);
+            } else {
+                words.push(Some(terms));
+            }
+        }
+
+        // Every combination of the words' terms, at their offsets
+        let mut sequences: Vec<Vec<(usize, &str)>> = vec![Vec::new()];
+        for (offset, terms) in words.iter().enumerate() {
+            if let Some(terms) = terms {
+                sequences = sequences
+                    .into_iter()
+                    .flat_map(|sequence| {
+                        terms.iter().map(move |term| {
+                            let mut sequence = sequence.clone();
+                            sequence.push((offset, term.as_str()));
+                            sequence
+                        })
+                    })
+                    .collect();
+            }
+        }
+
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+        for sequence in sequences.iter().filter(|s| !s.is_empty()) {
+            let anchor = sequence[0].0;
+            for field in &self.fields.text {
+                let query: Box<dyn Query> = if sequence.len() == 1 {
+                    Box::new(TermQuery::new(Term::from_field_text(*field, sequence[0].1), IndexRecordOption::Basic))
+                } else {
+                    Box::new(PhraseQuery::new_with_offset(
+                        sequence
+                            .iter()
+                            .map(|(offset, term)| (offset - anchor, Term::from_field_text(*field, term)))
+                            .collect(),
+                    ))
+                };
+                clauses.push((Occur::Should, query));
+            }
+        }
+        if clauses.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let addresses = searcher
+            .search(&BooleanQuery::new(clauses), &DocSetCollector)
+            .map_err(error("search the index"))?;
+        addresses.into_iter().map(|address| self.doc_index(searcher, address)).collect()
+    }
+
+    /// Documents containing any of `terms` in any field
+    fn docs_with_any(&self, searcher: &Searcher, terms: &[&str]) -> Result<HashSet<DocAddress>> {
+        let clauses: Vec<(Occur, Box<dyn Query>)> = terms
+            .iter()
+            .flat_map(|term| {
+                self.fields.text.iter().map(move |field| {
+                    let query = TermQuery::new(Term::from_field_text(*field, term), IndexRecordOption::Basic);
+                    (Occur::Should, Box::new(query) as Box<dyn Query>)
+                })
+            })
+            .collect();
+        if clauses.is_empty() {
+            return Ok(HashSet::new());
+        }
+        searcher
+            .search(&BooleanQuery::new(clauses), &DocSetCollector)
+            .map_err(error("search the index"))
+    }
+
+    fn doc_index(&self, searcher: &Searcher, address: DocAddress) -> Result<usize> {
+        self.fast_u64(searcher, address, "doc")
+    }
+
+    fn page(&self, searcher: &Searcher, address: DocAddress) -> Result<usize> {
+        self.fast_u64(searcher, address, "page")
+    }
+
+    fn fast_u64(&self, searcher: &Searcher, address: DocAddress, name: &str) -> Result<usize> {
+        let column = searcher
+            .segment_reader(address.segment_ord)
+            .fast_fields()
+            .u64(name)
+            .map_err(error("read the search index"))?;
+        column
+            .first(address.doc_id)
+            .map(|value| value as usize)
+            .ok_or_else(|| Error::search(format!("Search index document has no `{}` value", name)))
+    }
+
+    /// The stored `SearchDocument` at `address`
+    fn stored(&self, searcher: &Searcher, address: DocAddress) -> Result<SearchDocument> {
+        let stored: TantivyDocument = searcher.doc(address).map_err(error("read a search document"))?;
+        let json = stored
+            .get_first(self.fields.document)
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| Error::search("Search index document has no stored fields"))?;
+        serde_json::from_str(json).map_err(|e| Error::search(format!("Failed to parse a search document: {}", e)))
+    }
+}
+
+/// Map a tantivy error to a search error, saying what failed
+fn error(action: &'static str) -> impl Fn(tantivy::TantivyError) -> Error {
+    move |e| Error::search(format!("Failed to {}: {}", action, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{ContentMetadata, ContentType, RstContent};
+
+    fn content(id: &str, title: &str, content_type: ContentType, tags: &[&str], date: &str, html: &str) -> RstContent {
+        RstContent::new(
+            ContentMetadata {
+                id: id.to_string(),
+                title: title.to_string(),
+                content_type,
+                date: date.to_string(),
+                date_time: None,
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                author: None,
+                excerpt: None,
+                url: format!("/{}.html", id),
+                extra: HashMap::new(),
+            },
+            html.to_string(),
+            Vec::new(),
+            HashMap::new(),
+        )
+    }
+
+    fn index() -> SearchIndex {
+        let mut index = SearchIndex::new();
+        index
+            .build(&[
+                content("ownership", "Ownership in Rust", ContentType::Article, &["rust"], "2024-02-01",
+                    "<p>The borrow checker enforces ownership of the state of the art.</p>"),
+                content("testing", "Testing Rust and Python", ContentType::Article, &["rust", "python"], "2023-05-10",
+                    "<h2>Fixtures</h2><p>Running unit tests with rust and python fixtures.</p>"),
+                content("pytest", "Pytest fixtures", ContentType::Snippet, &["python"], "2022-01-15",
+                    "<p>Parametrized tests in python, running quickly.</p>"),
+            ])
+            .unwrap();
+        index
+    }
+
+    fn docs(ranked: &[(usize, f64)]) -> Vec<usize> {
+        ranked.iter().map(|r| r.0).collect()
+    }
+
+    #[test]
+    fn test_matches_the_static_search() {
+        let index = index();
+        let dir = tempfile::tempdir().unwrap();
+        let tantivy_index = TantivyIndex::create(&index, dir.path()).unwrap();
+        let reopened = TantivyIndex::open(dir.path()).unwrap();
+        assert_eq!(reopened.num_docs(), 3);
+
+        for query in [
+            "rust",
+            "running",
+            "python tests",
+            "pyth",
+            "fixturs",
+            "\"state of the art\"",
+            "\"art of the state\"",
+            "\"unit tests\"",
+            "tests -python",
+            "tag:python",
+            "type:snippet tests",
+            "after:2023 rust",
+            "-\"borrow checker\" rust",
+        ] {
+            let mut expected = docs(&index.rank(query));
+            let mut actual = docs(&reopened.rank(query).unwrap());
+            assert_eq!(actual, docs(&tantivy_index.rank(query).unwrap()));
+            // Scores differ from BM25F, so only filter-only queries share an order
+            if !fuzzy::expand_query(&index, &syntax::parse(query).text()).is_empty() {
+                expected.sort_unstable();
+                actual.sort_unstable();
+            }
+            assert_eq!(actual, expected, "results differ for {:?}", query);
+        }
+    }
+
+    #[test]
+    fn test_search_highlights_and_suggests() {
+        let index = index();
+        let dir = tempfile::tempdir().unwrap();
+        let tantivy_index = TantivyIndex::create(&index, dir.path()).unwrap();
+
+        let response = tantivy_index.search("ownership", 10).unwrap();
+        assert_eq!(response.total, 1);
+        assert!(response.results[0].exact);
+        assert_eq!(response.results[0].result.highlights[0].snippet.to_html(), "<mark>Ownership</mark> in Rust");
+        assert_eq!(response.suggestion, None);
+
+        let response = tantivy_index.search("ownrship", 10).unwrap();
+        assert!(!response.results[0].exact);
+        assert_eq!(response.suggestion.as_deref(), Some("ownership"));
+    }
+}
@@ -39,7 +39,11 @@ impl DevServer {
         
         // Create and start file watcher for themes directory
         let theme_watcher = FileWatcher::new(&self.site.config.theme_dir())?;
-        theme_watcher.start(event_sender).await?;
+        theme_watcher.start(event_sender.clone()).await?;
+        
+        // Watch files pages were rendered from outside the content directory
+        let mut watched_files = Vec::new();
+        watch_dependencies(&self.site, &mut watched_files, &event_sender).await?;
         
         // Start live reload
         self.livereload.lock().await.start(self.port).await?;
@@ -52,7 +56,7 @@ impl DevServer {
             let mut last_rebuild_time = std::time::Instant::now();
             let rebuild_delay = std::time::Duration::from_millis(300); // Debounce rebuilds
             
-            while let Some(_changed_path) = event_receiver.recv().await {
+            while let Some(changed_path) = event_receiver.recv().await {
                 let now = std::time::Instant::now();
                 
                 // Debounce rebuilds to avoid rebuilding on every file change
@@ -60,12 +64,25 @@ impl DevServer {
                     tokio::time::sleep(rebuild_delay).await;
                 }
                 
+                // Collect everything that changed while waiting
+                let mut changed_files = vec![changed_path];
+                while let Ok(path) = event_receiver.try_recv() {
+                    if !changed_files.contains(&path) {
+                        changed_files.push(path);
+                    }
+                }
+                
                 // Rebuild site
                 println!("🔄 Rebuilding site...");
-                let mut builder = crate::core::builder::SiteBuilder::new(config.clone());
+                let mut builder = crate::core::builder::SiteBuilder::new(config.clone())
+                    .with_mode(crate::core::BuildMode::Serve)
+                    .with_changed_files(changed_files);
                 match builder.build().await {
-                    Ok(_) => {
+                    Ok(site) => {
                         println!("✓ Site rebuilt successfully");
+                        if let Err(e) = watch_dependencies(&site, &mut watched_files, &event_sender).await {
+                            eprintln!("Failed to watch included files: {}", e);
+                        }
                         // Trigger live reload
                         if let Err(e) = livereload.lock().await.trigger().await {
                             eprintln!("Failed to trigger live reload: {}", e);
@@ -136,13 +153,42 @@ impl DevServer {
     }
 }
 
+/// Start watching dependencies of `site` that no watcher covers yet
+async fn watch_dependencies(
+    site: &Site,
+    watched_files: &mut Vec<std::path::PathBuf>,
+    event_sender: &tokio_mpsc::Sender<std::path::PathBuf>,
+) -> Result<()> {
+    let watched_dirs: Vec<std::path::PathBuf> = [std::path::PathBuf::from(&site.config.build.content_dir), site.config.theme_dir()]
+        .iter()
+        .filter_map(|dir| dir.canonicalize().ok())
+        .collect();
+    
+    let new_files: Vec<_> = site
+        .dependencies()
+        .into_iter()
+        .filter(|file| !watched_dirs.iter().any(|dir| file.starts_with(dir)))
+        .filter(|file| !watched_files.contains(file))
+        .collect();
+    if new_files.is_empty() {
+        return Ok(());
+    }
+    
+    FileWatcher::for_files(&new_files)?.start(event_sender.clone()).await?;
+    watched_files.extend(new_files);
+    Ok(())
+}
+
 /// Serve static files
-async fn serve_file(path: axum::extract::Path<String>) -> axum::response::Response {
-    let path = path.0;
-    let output_dir = std::path::Path::new("_out/dist");
+pub(crate) async fn serve_file(path: axum::extract::Path<String>) -> axum::response::Response {
+    serve_output_file(std::path::Path::new("_out/dist"), &path.0)
+}
+
+/// Serve a file of the built site in `output_dir`, falling back to its 404 page
+pub(crate) fn serve_output_file(output_dir: &std::path::Path, path: &str) -> axum::response::Response {
     
     // Try the path as-is first
-    let file_path = output_dir.join(&path);
+    let file_path = output_dir.join(path);
     
     // If not found, try adding .html extension
     let final_path = if !file_path.exists() && !path.ends_with(".html") {
@@ -166,7 +212,7 @@ async fn serve_file(path: axum::extract::Path<String>) -> axum::response::Respon
         }
         Err(_) => {
             // Return 404 page instead of NOT_FOUND status
-            match std::fs::read_to_string("_out/dist/404.html") {
+            match std::fs::read_to_string(output_dir.join("404.html")) {
                 Ok(content) => {
                     axum::response::Response::builder()
                         .status(axum::http::StatusCode::NOT_FOUND)
@@ -8,10 +8,11 @@ use tokio::sync::mpsc as tokio_mpsc;
 
 /// File watcher
 pub struct FileWatcher {
-    #[allow(dead_code)]
     watcher: RecommendedWatcher,
     receiver: Receiver<std::result::Result<Event, notify::Error>>,
     watch_path: std::path::PathBuf,
+    /// Individual files to report, instead of everything under `watch_path`
+    files: Vec<std::path::PathBuf>,
 }
 
 impl FileWatcher {
@@ -29,21 +30,51 @@ impl FileWatcher {
         watcher.watch(&watch_path, RecursiveMode::Recursive)
             .map_err(|e| crate::core::Error::server(e.to_string()))?;
         
-        Ok(Self { watcher, receiver: rx, watch_path })
+        Ok(Self { watcher, receiver: rx, watch_path, files: Vec::new() })
+    }
+    
+    /// Create a watcher for individual files, such as `literalinclude`
+    /// sources outside the content directory
+    ///
+    /// Their directories are watched rather than the files themselves, so
+    /// editors that save by replacing the file are still noticed.
+    pub fn for_files(files: &[std::path::PathBuf]) -> Result<Self> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        
+        let mut watcher = notify::recommended_watcher(move |res| {
+            let _ = tx.send(res);
+        }).map_err(|e| crate::core::Error::server(e.to_string()))?;
+        
+        let mut dirs: Vec<&Path> = files.iter().filter_map(|file| file.parent()).collect();
+        dirs.sort();
+        dirs.dedup();
+        for dir in dirs {
+            watcher.watch(dir, RecursiveMode::NonRecursive)
+                .map_err(|e| crate::core::Error::server(e.to_string()))?;
+        }
+        
+        Ok(Self { watcher, receiver: rx, watch_path: std::path::PathBuf::new(), files: files.to_vec() })
     }
     
     /// Start watching and send events to a channel
     pub async fn start(self, event_sender: tokio_mpsc::Sender<std::path::PathBuf>) -> Result<()> {
-        let watch_path: std::path::PathBuf = self.watch_path;
+        let FileWatcher { watcher, receiver, watch_path, files } = self;
         
         // Spawn a task to process file change events
         tokio::spawn(async move {
-            while let Ok(event) = self.receiver.recv() {
+            // Keep watching for as long as events are processed
+            let _watcher = watcher;
+            while let Ok(event) = receiver.recv() {
                 if let Ok(event) = event {
                     // Filter for relevant file changes
                     for path in event.paths {
-                        // Only process files in watched directory
-                        if path.starts_with(&watch_path) {
+                        // Only process the watched files, or files in the watched directory
+                        let watched = if files.is_empty() {
+                            path.starts_with(&watch_path)
+                        } else {
+                            files.contains(&path)
+                        };
+                        if watched {
                             // Filter out temporary files and hidden files
                             let file_name = path.file_name()
                                 .and_then(|n: &std::ffi::OsStr| n.to_str())
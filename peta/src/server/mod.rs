@@ -1,8 +1,10 @@
-//! Development server module
+//! Development and search server module
 
 pub mod dev_server;
 pub mod file_watcher;
 pub mod livereload;
+pub mod search_server;
 pub mod websocket;
 
-pub use dev_server::DevServer;
\ No newline at end of file
+pub use dev_server::DevServer;
+pub use search_server::SearchServer;
\ No newline at end of file
@@ -0,0 +1,126 @@
+//! Search server for the `server` search backend
+
+use crate::core::{Error, Result};
+use crate::search::tantivy_index::TantivyIndex;
+use crate::server::dev_server::serve_output_file;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{Html, IntoResponse, Response};
+use axum::{routing::get, Json, Router};
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tower_http::cors::CorsLayer;
+
+/// Results returned when a request sets no limit
+const DEFAULT_LIMIT: usize = 20;
+
+/// Most results a request may ask for
+const MAX_LIMIT: usize = 100;
+
+/// Answers search queries from a tantivy index and serves the built site
+pub struct SearchServer {
+    addr: SocketAddr,
+    url: String,
+    index: Arc<TantivyIndex>,
+    output_dir: Arc<PathBuf>,
+}
+
+/// Query string of a search request, `?q=...&limit=...`
+#[derive(Debug, Deserialize)]
+struct SearchParams {
+    #[serde(default)]
+    q: String,
+    limit: Option<usize>,
+}
+
+impl SearchServer {
+    /// Create a server answering queries at `url` on `host:port` and
+    /// serving the site built in `output_dir`
+    pub fn new(host: &str, port: u16, url: &str, index: TantivyIndex, output_dir: &str) -> Result<Self> {
+        let addr = format!("{}:{}", host, port)
+            .parse()
+            .map_err(|e| Error::config(format!("Invalid search server address {}:{}: {}", host, port, e)))?;
+        Ok(Self {
+            addr,
+            url: url.to_string(),
+            index: Arc::new(index),
+            output_dir: Arc::new(PathBuf::from(output_dir)),
+        })
+    }
+
+    /// Start the search server
+    pub async fn start(&self) -> Result<()> {
+        let listener = tokio::net::TcpListener::bind(self.addr).await?;
+        println!("🔎 Search server running at http://{}{}", self.addr, self.url);
+        axum::serve(listener, self.create_router().into_make_service()).await?;
+        Ok(())
+    }
+
+    /// Create router for the search endpoint and the built site; the
+    /// endpoint allows cross-origin requests so the site can be hosted apart
+    pub fn create_router(&self) -> Router {
+        let search = Router::new()
+            .route(&self.url, get(search))
+            .layer(CorsLayer::permissive())
+            .with_state(self.index.clone());
+
+        let site = Router::new()
+            .route("/", get(|State(output_dir): State<Arc<PathBuf>>| async move {
+                match std::fs::read_to_string(output_dir.join("index.html")) {
+                    Ok(content) => Html(content),
+                    Err(_) => Html("<h1>Error: Site not built</h1>".to_string()),
+                }
+            }))
+            .route("/*path", get(|State(output_dir): State<Arc<PathBuf>>, Path(path): Path<String>| async move {
+                serve_output_file(&output_dir, &path)
+            }))
+            .with_state(self.output_dir.clone());
+
+        site.merge(search)
+    }
+}
+
+/// Answer a search request with a JSON `SearchResponse`
+async fn search(State(index): State<Arc<TantivyIndex>>, Query(params): Query<SearchParams>) -> Response {
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    match index.search(&params.q, limit) {
+        Ok(response) => Json(response).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::SearchIndex;
+
+    #[tokio::test]
+    async fn test_serves_the_configured_output_dir() {
+        let index_dir = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        std::fs::write(output.path().join("index.html"), "home").unwrap();
+        std::fs::write(output.path().join("about.html"), "about").unwrap();
+
+        let mut search_index = SearchIndex::new();
+        search_index.build(&[]).unwrap();
+        let index = TantivyIndex::create(&search_index, index_dir.path()).unwrap();
+        let server = SearchServer::new("127.0.0.1", 0, "/api/search", index, &output.path().to_string_lossy()).unwrap();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let router = server.create_router();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        for (path, body) in [("/", "home"), ("/about", "about")] {
+            let response = reqwest::get(format!("http://{}{}", addr, path)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.text().await.unwrap(), body);
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod file;
 pub mod http;
 pub mod cache;
 pub mod progress;
+pub mod process;
 pub mod url;
 
 pub use file::FileUtils;
@@ -0,0 +1,48 @@
+//! Running user-configured commands through the platform shell
+
+use std::io;
+use std::process::{Child, Command};
+
+/// Command that runs `command` through the platform shell
+pub fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+/// [`shell`] for async callers
+pub fn async_shell(command: &str) -> tokio::process::Command {
+    tokio::process::Command::from(shell(command))
+}
+
+/// Put the command in a process group of its own, so that
+/// [`kill_process_group`] also reaches the processes it starts
+pub fn in_own_process_group(cmd: &mut Command) -> &mut Command {
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(cmd, 0);
+    cmd
+}
+
+/// Kill a child spawned with [`in_own_process_group`] and everything it started
+#[cfg(unix)]
+pub fn kill_process_group(child: &mut Child) -> io::Result<()> {
+    // The group id is the child's pid, and a negative pid signals the group
+    let pgid = child.id() as libc::pid_t;
+    if unsafe { libc::kill(-pgid, libc::SIGKILL) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Kill a child spawned with [`in_own_process_group`] and everything it started
+#[cfg(not(unix))]
+pub fn kill_process_group(child: &mut Child) -> io::Result<()> {
+    child.kill()
+}
@@ -0,0 +1,198 @@
+//! Netlify and Vercel deploy flows against a local mock API
+
+use axum::extract::{OriginalUri, Path as AxumPath, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{post, put};
+use axum::{Json, Router};
+use peta::core::{Site, SiteConfig};
+use peta::deploy::netlify::{NetlifyConfig, NetlifyDeployer};
+use peta::deploy::Deployer;
+use peta::deploy::vercel::{VercelConfig, VercelDeployer};
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+/// Requests seen by the mock server
+#[derive(Default)]
+struct Recorded {
+    manifest: Option<serde_json::Value>,
+    uploads: Vec<String>,
+    creates: usize,
+}
+
+type Shared = Arc<Mutex<Recorded>>;
+
+fn output_dir() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("index.html"), "<h1>home</h1>").unwrap();
+    std::fs::create_dir_all(dir.path().join("articles")).unwrap();
+    std::fs::write(dir.path().join("articles/new.html"), "<h1>new</h1>").unwrap();
+    dir
+}
+
+fn sha1_of(manifest: &serde_json::Value, path: &str) -> String {
+    manifest[path].as_str().unwrap().to_string()
+}
+
+async fn serve(router: Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_netlify_uploads_only_required_files() {
+    let state: Shared = Arc::default();
+
+    let router = Router::new()
+        .route(
+            "/api/v1/sites/:site_id/deploys",
+            post(|State(state): State<Shared>, AxumPath(site_id): AxumPath<String>, Json(body): Json<serde_json::Value>| async move {
+                assert_eq!(site_id, "my-site");
+                let files = body["files"].clone();
+                let required = sha1_of(&files, "/articles/new.html");
+                state.lock().unwrap().manifest = Some(files);
+                Json(serde_json::json!({
+                    "id": "deploy-1",
+                    "required": [required],
+                    "deploy_ssl_url": "https://deploy-1--my-site.netlify.app"
+                }))
+            }),
+        )
+        .route(
+            "/api/v1/deploys/:deploy_id/files/*path",
+            put(|State(state): State<Shared>, headers: HeaderMap, AxumPath((deploy_id, path)): AxumPath<(String, String)>| async move {
+                assert_eq!(deploy_id, "deploy-1");
+                assert_eq!(headers["authorization"], "Bearer secret");
+                state.lock().unwrap().uploads.push(path);
+                Json(serde_json::json!({}))
+            }),
+        )
+        .with_state(state.clone());
+
+    let api_url = serve(router).await;
+    let output = output_dir();
+
+    let deployer = NetlifyDeployer::with_config(NetlifyConfig {
+        site_id: "my-site".to_string(),
+        token: "secret".to_string(),
+        output_dir: output.path().to_string_lossy().to_string(),
+        api_url,
+        redirects: Vec::new(),
+        headers: Vec::new(),
+    });
+
+    let report = deployer.deploy(&Site::new(SiteConfig::default())).await.unwrap();
+
+    let recorded = state.lock().unwrap();
+    assert!(recorded.manifest.as_ref().unwrap().get("/index.html").is_some());
+    assert_eq!(recorded.uploads, vec!["articles/new.html".to_string()]);
+    assert_eq!(report.uploaded, 1);
+    assert_eq!(report.unchanged, 1);
+    assert_eq!(report.url.as_deref(), Some("https://deploy-1--my-site.netlify.app"));
+}
+
+#[tokio::test]
+async fn test_netlify_percent_encodes_upload_paths() {
+    let state: Shared = Arc::default();
+
+    let router = Router::new()
+        .route(
+            "/api/v1/sites/:site_id/deploys",
+            post(|Json(body): Json<serde_json::Value>| async move {
+                let required = sha1_of(&body["files"], "/docs/my page #1?é.html");
+                Json(serde_json::json!({ "id": "deploy-1", "required": [required] }))
+            }),
+        )
+        .route(
+            "/api/v1/deploys/:deploy_id/files/*path",
+            put(|State(state): State<Shared>, OriginalUri(uri): OriginalUri| async move {
+                state.lock().unwrap().uploads.push(uri.path().to_string());
+                Json(serde_json::json!({}))
+            }),
+        )
+        .with_state(state.clone());
+
+    let api_url = serve(router).await;
+    let output = TempDir::new().unwrap();
+    std::fs::create_dir_all(output.path().join("docs")).unwrap();
+    std::fs::write(output.path().join("docs/my page #1?é.html"), "<h1>page</h1>").unwrap();
+
+    let deployer = NetlifyDeployer::with_config(NetlifyConfig {
+        site_id: "my-site".to_string(),
+        token: "secret".to_string(),
+        output_dir: output.path().to_string_lossy().to_string(),
+        api_url,
+        redirects: Vec::new(),
+        headers: Vec::new(),
+    });
+
+    let report = deployer.deploy(&Site::new(SiteConfig::default())).await.unwrap();
+
+    assert_eq!(report.uploaded, 1);
+    assert_eq!(
+        state.lock().unwrap().uploads,
+        vec!["/api/v1/deploys/deploy-1/files/docs/my%20page%20%231%3F%C3%A9.html".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_vercel_uploads_missing_files_then_deploys() {
+    let state: Shared = Arc::default();
+
+    let router = Router::new()
+        .route(
+            "/v13/deployments",
+            post(|State(state): State<Shared>, Json(body): Json<serde_json::Value>| async move {
+                let mut recorded = state.lock().unwrap();
+                recorded.creates += 1;
+                let files = body["files"].as_array().unwrap().clone();
+                let new_sha = files
+                    .iter()
+                    .find(|f| f["file"] == "articles/new.html")
+                    .map(|f| f["sha"].as_str().unwrap().to_string())
+                    .unwrap();
+
+                if recorded.uploads.contains(&new_sha) {
+                    (StatusCode::OK, Json(serde_json::json!({ "id": "dpl_1", "url": "my-site-abc.vercel.app" })))
+                } else {
+                    (StatusCode::BAD_REQUEST, Json(serde_json::json!({
+                        "error": { "code": "missing_files", "message": "Missing files", "missing": [new_sha] }
+                    })))
+                }
+            }),
+        )
+        .route(
+            "/v2/files",
+            post(|State(state): State<Shared>, headers: HeaderMap| async move {
+                let digest = headers["x-vercel-digest"].to_str().unwrap().to_string();
+                state.lock().unwrap().uploads.push(digest);
+                Json(serde_json::json!({}))
+            }),
+        )
+        .with_state(state.clone());
+
+    let api_url = serve(router).await;
+    let output = output_dir();
+
+    let deployer = VercelDeployer::with_config(VercelConfig {
+        project_id: "my-site".to_string(),
+        token: "secret".to_string(),
+        output_dir: output.path().to_string_lossy().to_string(),
+        team_id: None,
+        production: true,
+        api_url,
+        redirects: Vec::new(),
+        headers: Vec::new(),
+    });
+
+    let report = deployer.deploy(&Site::new(SiteConfig::default())).await.unwrap();
+
+    let recorded = state.lock().unwrap();
+    assert_eq!(recorded.creates, 2);
+    assert_eq!(recorded.uploads.len(), 1);
+    assert_eq!(report.uploaded, 1);
+    assert_eq!(report.url.as_deref(), Some("https://my-site-abc.vercel.app"));
+}
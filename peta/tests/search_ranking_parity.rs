@@ -0,0 +1,374 @@
+//! Checks that the client-side search script ranks results exactly like the
+//! Rust index. The client checks are skipped when `node` is not installed.
+
+use peta::search::client::{read_client_index, write_client_index, ClientIndexOptions, ClientIndexStats};
+use peta::search::highlight::Highlight;
+use peta::search::QueryProcessor;
+use peta::content::TocEntry;
+use peta::{ContentMetadata, ContentType, RstContent, SearchIndex};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+fn content(id: &str, title: &str, tags: &[&str], html: &str) -> RstContent {
+    localized(id, title, tags, html, None)
+}
+
+fn localized(id: &str, title: &str, tags: &[&str], html: &str, lang: Option<&str>) -> RstContent {
+    let mut frontmatter = HashMap::new();
+    if let Some(lang) = lang {
+        frontmatter.insert("lang".to_string(), serde_json::json!(lang));
+    }
+    RstContent::new(
+        ContentMetadata {
+            id: id.to_string(),
+            title: title.to_string(),
+            content_type: ContentType::Article,
+            date: "2024-01-01".to_string(),
+            date_time: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            author: None,
+            excerpt: None,
+            url: format!("/articles/{}.html", id),
+            extra: HashMap::new(),
+        },
+        html.to_string(),
+        Vec::new(),
+        frontmatter,
+    )
+}
+
+fn snippet(id: &str, title: &str, tags: &[&str], html: &str, language: &str, author: &str, date: &str) -> RstContent {
+    let mut item = content(id, title, tags, html);
+    item.metadata.content_type = ContentType::Snippet;
+    item.metadata.date = date.to_string();
+    item.metadata.extra.insert("language".to_string(), language.to_string());
+    item.frontmatter.insert("author".to_string(), serde_json::json!(author));
+    item
+}
+
+fn heading(title: &str, anchor: &str, children: Vec<TocEntry>) -> TocEntry {
+    TocEntry { level: 2, title: title.to_string(), anchor: anchor.to_string(), children }
+}
+
+fn node_available() -> bool {
+    Command::new("node").arg("--version").output().map(|o| o.status.success()).unwrap_or(false)
+}
+
+/// Index shared by the tests, written with small shards and chunks so
+/// queries span several files
+fn fixture() -> (SearchIndex, tempfile::TempDir, ClientIndexStats) {
+    let mut index = SearchIndex::new();
+    index
+        .build(&[
+            content("rust-intro", "Rust for Beginners", &["rust"], "<h2>Ownership</h2><p>Rust ownership explained. Running programs.</p>"),
+            content("testing", "Testing Strategies", &["testing", "rust"], "<p>Unit tests in Rust and Python, testing everything.</p>"),
+            content("python", "Python Packaging", &["python"], "<h2>Testing packages</h2><p>Python wheels and sdists.</p>"),
+            content("long", "Notes", &[], &format!("<p>{} rust</p>", "filler words here ".repeat(50))),
+            content("über", "Über Unicode", &["unicode"], "<p>Straße naïve café tests, cargo runs</p>"),
+            localized("haus", "Die Häuser am See", &[], "<p>Ein Haus mit Garten</p>", Some("de")),
+            localized("kensaku", "検索エンジンの仕組み", &[], "<p>全文検索とRust</p>", Some("ja")),
+            snippet("pytest", "Pytest Fixtures", &["python", "testing"], "<p>Execute the tests with pytest. Unit tests are fast.</p>", "python", "Jane Doe", "2023-06-10"),
+            {
+                let mut chapter = content(
+                    "memory",
+                    "Chapter One: Memory",
+                    &["rust"],
+                    "<p>Intro to memory.</p><h2 id=\"stack\">The Stack</h2><p>Stack frames are fast.</p>\
+                     <h2 id=\"heap\">The Heap</h2><p>Heap allocation with Box.</p>\
+                     <h3 id=\"arenas\">Arenas</h3><p>Arena allocation in bulk.</p>",
+                );
+                chapter.toc = vec![
+                    heading("The Stack", "stack", Vec::new()),
+                    heading("The Heap", "heap", vec![heading("Arenas", "arenas", Vec::new())]),
+                ];
+                chapter
+            },
+            content("deploy", "Deploy Targets", &[], "<p>Sites are deployed to Netlify.</p>"),
+        ])
+        .unwrap();
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let options = ClientIndexOptions { shard_prefix_length: 1, chunk_size: 2 };
+    let stats = write_client_index(&index, dir.path(), &options).unwrap();
+    (index, dir, stats)
+}
+
+/// Every query the client is checked with
+const QUERIES: &[&str] = &[
+    "rust",
+    "testing rust",
+    "Python tests",
+    "ownership",
+    "café naïve",
+    "the rust rust",
+    "running",
+    "häuser",
+    "エンジン",
+    "検索",
+    "owner",
+    "pyth",
+    "pythn",
+    "testing pythn",
+    "ownrship rust",
+    "straß",
+    "unit test",
+    "\"unit tests\"",
+    "\"rust and python\"",
+    "\"ownership explained\"",
+    "tests -python",
+    "testing -\"unit tests\"",
+    "type:snippet",
+    "tag:rust testing",
+    "author:jane",
+    "author:\"jane doe\" fixtures",
+    "language:python tests",
+    "after:2024-01 tests",
+    "before:2024 tests",
+    "tag:python type:article",
+    "-rust",
+    "pythn type:snippet",
+    "allocation",
+    "arena",
+    "stack frames",
+    "memory",
+    "\"heap allocation\"",
+    "type:article",
+    "deploying",
+    "häusern",
+];
+
+fn docs(index: &SearchIndex, query: &str) -> Vec<usize> {
+    index.rank(query).into_iter().map(|r| r.0).collect()
+}
+
+/// What the client-side search returned for each query
+#[derive(serde::Deserialize)]
+struct ClientOutput {
+    ranked: Vec<Vec<(usize, f64)>>,
+    suggestions: Vec<Option<String>>,
+    highlights: Vec<Vec<Vec<Highlight>>>,
+    fetched: Vec<String>,
+}
+
+/// Run the search script under node against the written index, or `None`
+/// when node is not installed
+fn run_client(index_dir: &Path, queries: &[&str]) -> Option<ClientOutput> {
+    if !node_available() {
+        eprintln!("node not found, skipping client search parity checks");
+        return None;
+    }
+
+    let script = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../themes/default/components/atomic/search_bar/search_bar.js");
+    let harness = r#"
+        const fs = require('fs');
+        const vm = require('vm');
+        const path = require('path');
+        const [script, indexDir, ...queries] = process.argv.slice(1);
+        const fetched = new Set();
+        const fetch = async url => {
+            const file = url.replace(/^\/search\//, '').replace(/\?.*$/, '');
+            fetched.add(file);
+            const body = fs.readFileSync(path.join(indexDir, file), 'utf8');
+            return { ok: true, json: async () => JSON.parse(body), text: async () => body };
+        };
+        const quiet = { log() {}, warn: console.error, error: console.error };
+        const context = vm.createContext({ console: quiet, fetch, TextEncoder, TextDecoder, window: {}, document: { addEventListener() {} } });
+        vm.runInContext(fs.readFileSync(script, 'utf8'), context);
+        vm.runInContext(`
+            (async () => {
+                const search = new PetaSearch();
+                await search.init();
+                const ranked = [];
+                const suggestions = [];
+                const highlights = [];
+                for (const q of ${JSON.stringify(queries)}) {
+                    const results = await search.search(q, { limit: 1000 });
+                    ranked.push(results.map(r => [r.docIdx, r.score]));
+                    highlights.push(results.map(r => r.highlights));
+                    suggestions.push(await search.suggest(q));
+                }
+                return { ranked, suggestions, highlights };
+            })()
+        `, context).then(({ ranked, suggestions, highlights }) => {
+            console.log(JSON.stringify({ ranked, suggestions, highlights, fetched: [...fetched].sort() }));
+        });
+    "#;
+
+    let output = Command::new("node")
+        .arg("-e")
+        .arg(harness)
+        .arg(&script)
+        .arg(index_dir)
+        .args(queries)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    Some(serde_json::from_slice(&output.stdout).unwrap())
+}
+
+/// Check that the client, and the index read back from disk, rank every
+/// query exactly like the Rust index
+fn assert_rankings_match(index: &SearchIndex, index_dir: &Path, queries: &[&str]) {
+    let reloaded = read_client_index(index_dir).unwrap();
+    for query in queries {
+        assert_eq!(index.rank(query), reloaded.rank(query), "reloaded ranking differs for {:?}", query);
+    }
+
+    let Some(client) = run_client(index_dir, queries) else { return };
+    for (query, client_ranking) in queries.iter().zip(client.ranked) {
+        let rust_ranking = index.rank(query);
+        assert!(!rust_ranking.is_empty(), "no results for {:?}", query);
+        assert_eq!(
+            rust_ranking.iter().map(|r| r.0).collect::<Vec<_>>(),
+            client_ranking.iter().map(|r| r.0).collect::<Vec<_>>(),
+            "result order differs for {:?}",
+            query
+        );
+        for (rust, client) in rust_ranking.iter().zip(&client_ranking) {
+            assert!((rust.1 - client.1).abs() < 1e-9, "score differs for {:?}: {} vs {}", query, rust.1, client.1);
+        }
+    }
+}
+
+#[test]
+fn test_bm25f_field_weights() {
+    let (index, dir, _) = fixture();
+
+    // Title and tag matches outrank body matches, repeated words add nothing
+    assert_eq!(docs(&index, "rust")[0], 0);
+    assert_eq!(index.rank("the rust rust"), index.rank("rust"));
+
+    assert_rankings_match(&index, dir.path(), &["rust", "testing rust", "Python tests", "ownership", "the rust rust"]);
+}
+
+#[test]
+fn test_shards() {
+    let (index, dir, stats) = fixture();
+    assert_eq!(stats.chunks, 7);
+
+    // The written index loads back with the same documents
+    let reloaded = read_client_index(dir.path()).unwrap();
+    assert_eq!(reloaded.documents[4].content, index.documents[4].content);
+
+    // Only the shards for the queried terms' first letters are downloaded
+    let Some(client) = run_client(dir.path(), &["rust", "ownership"]) else { return };
+    let shards: Vec<&String> = client.fetched.iter().filter(|f| f.starts_with("terms-")).collect();
+    assert!(!shards.is_empty());
+    assert!(shards.len() < stats.shards, "fetched every shard: {:?}", shards);
+}
+
+#[test]
+fn test_stemming() {
+    let (index, dir, _) = fixture();
+
+    // Stemmed forms resolve across inflections, CJK text is bigram-indexed
+    assert_eq!(docs(&index, "running"), vec![0, 4]);
+    assert_eq!(docs(&index, "häuser"), vec![5]);
+    assert_eq!(docs(&index, "エンジン"), vec![6]);
+
+    // Inflections no document contains are stemmed with each indexed
+    // language's stemmer, on both sides
+    assert_eq!(index.stemmers, vec!["english", "german"]);
+    assert!(index.vocabulary.binary_search(&"deploying".to_string()).is_err());
+    assert_eq!(docs(&index, "deploying"), vec![12]);
+    assert_eq!(docs(&index, "häusern"), vec![5]);
+    assert_eq!(index.suggest("deploying"), None);
+
+    assert_rankings_match(
+        &index,
+        dir.path(),
+        &["running", "häuser", "エンジン", "検索", "café naïve", "straß", "unit test", "deploying", "häusern", "\"deploying targets\""],
+    );
+
+    // The client downloads only the stemmers of the indexed languages
+    assert!(dir.path().join("stemmers/german.js").is_file());
+    assert!(!dir.path().join("stemmers/french.js").exists());
+    let Some(client) = run_client(dir.path(), &["deploying"]) else { return };
+    assert!(client.fetched.contains(&"stemmers/english.js".to_string()));
+}
+
+#[test]
+fn test_fuzzy_and_prefix_matching() {
+    let (index, dir, _) = fixture();
+
+    // The last word completes as a prefix, unmatched words tolerate typos
+    assert_eq!(docs(&index, "pyth"), vec![2, 1, 7]);
+    assert_eq!(docs(&index, "pythn"), vec![2, 1, 7]);
+    assert!(docs(&index, "pyth ").is_empty());
+    assert_eq!(index.suggest("pythn wheels").as_deref(), Some("python wheels"));
+
+    assert_rankings_match(&index, dir.path(), &["owner", "pyth", "pythn", "testing pythn", "ownrship rust", "pythn type:snippet"]);
+
+    // Did-you-mean suggestions match for every query
+    let Some(client) = run_client(dir.path(), QUERIES) else { return };
+    for (query, suggestion) in QUERIES.iter().zip(&client.suggestions) {
+        assert_eq!(&index.suggest(query), suggestion, "suggestion differs for {:?}", query);
+    }
+}
+
+#[test]
+fn test_query_syntax() {
+    let (index, dir, _) = fixture();
+
+    // Phrases need their words in order, stop words match any word
+    assert_eq!(docs(&index, "\"rust and python\""), vec![1]);
+    assert!(docs(&index, "\"python and rust\"").is_empty());
+    assert_eq!(docs(&index, "type:snippet"), vec![7]);
+    assert!(docs(&index, "tests -python").iter().all(|doc| ![1, 2, 7].contains(doc)));
+    assert_eq!(index.suggest("pythn type:snippet").as_deref(), Some("python type:snippet"));
+
+    assert_rankings_match(
+        &index,
+        dir.path(),
+        &[
+            "\"unit tests\"",
+            "\"rust and python\"",
+            "\"ownership explained\"",
+            "tests -python",
+            "testing -\"unit tests\"",
+            "type:snippet",
+            "tag:rust testing",
+            "author:jane",
+            "author:\"jane doe\" fixtures",
+            "language:python tests",
+            "after:2024-01 tests",
+            "before:2024 tests",
+            "tag:python type:article",
+            "-rust",
+            "type:article",
+        ],
+    );
+}
+
+#[test]
+fn test_section_anchors() {
+    let (index, dir, _) = fixture();
+
+    // Pages split into sections at their headings, one result per page
+    assert_eq!(index.documents[9].url, "/articles/memory.html#stack");
+    assert_eq!(index.documents[11].section.as_deref(), Some("Arenas"));
+    assert_eq!(index.documents[11].parent, Some(8));
+    assert_eq!(index.documents[8].content, "Intro to memory.");
+    assert_eq!(docs(&index, "arena"), vec![11]);
+    assert_eq!(docs(&index, "memory"), vec![8]);
+    assert_eq!(docs(&index, "allocation").len(), 1);
+
+    assert_rankings_match(&index, dir.path(), &["allocation", "arena", "stack frames", "memory", "\"heap allocation\""]);
+}
+
+#[test]
+fn test_highlights() {
+    let (index, dir, _) = fixture();
+
+    // Highlight spans match the server-side ones, graphemes and all
+    let Some(client) = run_client(dir.path(), QUERIES) else { return };
+    let processor = QueryProcessor::new();
+    assert!(client.highlights.iter().flatten().flatten().any(|h| h.snippet.has_match()));
+    for (query, client_highlights) in QUERIES.iter().zip(&client.highlights) {
+        let results = processor.process(query, &index).unwrap();
+        let rust_highlights: Vec<&Vec<Highlight>> = results.iter().map(|r| &r.highlights).collect();
+        assert_eq!(rust_highlights, client_highlights.iter().collect::<Vec<_>>(), "highlights differ for {:?}", query);
+    }
+}
@@ -2,35 +2,75 @@
 
 class PetaSearch {
     constructor() {
-        this.searchData = null;
+        this.manifest = null;
+        this.shards = new Map();
+        this.positions = new Map();
+        this.chunks = new Map();
+        this.vocabulary = null;
+        this.stemmers = null;
         this.debounceTimer = null;
         this.debounceDelay = 300;
         this.minQueryLength = 2;
         this.maxResults = 20;
         this.initialized = false;
         this.baseUrl = window.PETA_BASE_URL || '';
+
+        // The search page picks the backend from [search] in peta.toml
+        const backend = window.PETA_SEARCH || {};
+        this.backend = backend.backend || 'static';
+        this.serverUrl = backend.url || '/api/search';
+        this.serverSuggestions = new Map();
     }
 
     async init() {
         if (this.initialized) return;
 
+        // `peta search-server` holds the index, nothing to load up front
+        if (this.backend === 'server') {
+            this.initialized = true;
+            return;
+        }
+
         try {
-            const searchJsonUrl = this.baseUrl ? `${this.baseUrl}/search.json` : '/search.json';
-            const response = await fetch(searchJsonUrl);
-            if (!response.ok) {
-                throw new Error(`Failed to load search index: ${response.status}`);
-            }
-            this.searchData = await response.json();
+            // Only the manifest is loaded up front; term shards and document
+            // bodies are fetched on demand (see peta/src/search/client.rs)
+            this.manifest = await this.fetchJson(this.indexUrl('manifest.json'));
             this.initialized = true;
-            console.log('Search index loaded:', this.searchData.metadata);
+            console.log('Search index loaded:', this.manifest.metadata);
         } catch (error) {
             console.error('Failed to initialize search:', error);
             this.showError('Failed to load search functionality. Please refresh the page.');
         }
     }
 
-    search(query, options = {}) {
-        if (!this.searchData) {
+    indexUrl(file) {
+        const url = this.baseUrl ? `${this.baseUrl}/search/${file}` : `/search/${file}`;
+        // Tie shards to the manifest's build so stale caches never mix builds
+        return this.manifest ? `${url}?v=${encodeURIComponent(this.manifest.metadata.build_timestamp)}` : url;
+    }
+
+    async fetchJson(url) {
+        const response = await fetch(url);
+        if (!response.ok) {
+            throw new Error(`Failed to load ${url}: ${response.status}`);
+        }
+        return response.json();
+    }
+
+    async fetchText(url) {
+        const response = await fetch(url);
+        if (!response.ok) {
+            throw new Error(`Failed to load ${url}: ${response.status}`);
+        }
+        return response.text();
+    }
+
+    async search(query, options = {}) {
+        if (this.backend === 'server') {
+            return query.length < this.minQueryLength ? [] : this.serverSearch(query, options);
+        }
+
+        if (!this.manifest) {
             console.warn('Search data not loaded');
             return [];
         }
@@ -46,15 +86,33 @@ class PetaSearch {
             limit = this.maxResults
         } = options;
 
-        // Tokenize query
-        const terms = this.tokenize(query);
+        // Phrases, exclusions and filters, see peta/src/search/syntax.rs
+        const parsed = this.parseQuery(query);
+        const text = this.queryText(parsed);
+        const accepts = await this.queryFilter(parsed);
+        const queryTerms = await this.expandQuery(text);
+        const postings = await this.loadPostings(queryTerms.map(q => q.term));
+
+        // Score documents with the same BM25F ranking as the Rust indexer,
+        // mirroring SearchIndex::rank
+        let ranked = this.rank(queryTerms, postings);
+        if (queryTerms.length === 0) {
+            if (!this.hasConstraints(parsed)) return [];
+            const documents = this.manifest.documents;
+            ranked = documents
+                .map((_, docIdx) => [docIdx, 0, 0])
+                .sort((x, y) => this.compareStrings(documents[y[0]].date, documents[x[0]].date) || (x[0] - y[0]));
+        }
 
-        // Score documents
         const scoredDocs = [];
-        for (let i = 0; i < this.searchData.documents.length; i++) {
-            const doc = this.searchData.documents[i];
+        const pages = new Set();
+        for (const [docIdx, score, matched] of ranked) {
+            const doc = this.manifest.documents[docIdx];
 
             // Apply filters
+            if (!accepts(docIdx)) {
+                continue;
+            }
             if (contentTypes.length > 0 && !contentTypes.includes(doc.content_type)) {
                 continue;
             }
@@ -62,115 +120,804 @@ class PetaSearch {
                 continue;
             }
 
-            const score = this.calculateScore(doc, terms);
-            if (score > 0) {
-                scoredDocs.push({
-                    document: doc,
-                    score: score,
-                    highlights: this.generateHighlights(doc, terms)
-                });
+            // Each page once, as its best matching section or as itself
+            const page = doc.parent ?? docIdx;
+            if (pages.has(page)) {
+                continue;
             }
+            pages.add(page);
+
+            scoredDocs.push({ docIdx, document: doc, score: score, matched, exact: matched > 0 });
         }
 
         // Sort results
         this.sortResults(scoredDocs, sortBy);
 
-        // Return limited results
-        return scoredDocs.slice(0, limit);
+        // Fetch bodies only for the results that are shown
+        const results = scoredDocs.slice(0, limit);
+        const bodies = await this.loadBodies(results.map(r => r.docIdx));
+        const highlighter = await this.highlighter(text, queryTerms);
+        for (const result of results) {
+            result.highlights = this.generateHighlights(result.document, bodies.get(result.docIdx), highlighter);
+        }
+
+        return results;
     }
 
-    tokenize(query) {
-        return query
-            .toLowerCase()
-            .split(/\s+/)
-            .map(term => term.replace(/[.,\/#!$%\^&\*;:{}=\-_`~()]/g, ''))
-            .filter(term => term.length > 0);
+    // Results from `peta search-server`, see peta/src/search/tantivy_index.rs;
+    // the server parses, ranks and highlights like the static search
+    async serverSearch(query, options = {}) {
+        const { contentTypes = [], tags = [], sortBy = 'relevance', limit = this.maxResults } = options;
+        const url = `${this.serverUrl}?q=${encodeURIComponent(query)}&limit=${limit}`;
+        let response;
+        try {
+            response = await this.fetchJson(url);
+        } catch (error) {
+            console.error('Search server request failed:', error);
+            this.showError('The search server is not responding. Please try again later.');
+            return [];
+        }
+        this.serverSuggestions.set(query.trim(), response.suggestion);
+
+        const results = response.results
+            .filter(r => contentTypes.length === 0 || contentTypes.includes(r.document.content_type))
+            .filter(r => tags.length === 0 || tags.some(tag => r.document.tags.includes(tag)))
+            .map(r => ({ ...r, docIdx: null, matched: r.exact ? 1 : 0 }));
+        if (sortBy !== 'relevance') this.sortResults(results, sortBy);
+        return results;
     }
 
-    calculateScore(doc, terms) {
-        let score = 0;
-        const titleLower = doc.title.toLowerCase();
-        const excerptLower = doc.excerpt.toLowerCase();
-        const contentLower = doc.content.toLowerCase();
-        const tagsLower = doc.tags.map(t => t.toLowerCase());
+    // Mirrors syntax::parse in peta/src/search/syntax.rs
+    parseQuery(query) {
+        const filterKeys = ['tag', 'type', 'author', 'language', 'after', 'before'];
+        const chars = [...query];
+        const isSpace = c => /\s/u.test(c);
+        const readQuoted = start => {
+            let end = start + 1;
+            while (end < chars.length && chars[end] !== '"') end++;
+            return [chars.slice(start + 1, end).join(''), Math.min(end + 1, chars.length)];
+        };
+        const filterClause = (key, value) => {
+            value = value.trim().toLowerCase();
+            if (value.length === 0) return null;
+            if ((key === 'after' || key === 'before') && !/^\d{4}(-\d{2}(-\d{2})?)?$/.test(value)) return null;
+            return { kind: 'filter', key, value };
+        };
+
+        const clauses = [];
+        let i = 0;
+        while (i < chars.length) {
+            if (isSpace(chars[i])) {
+                i++;
+                continue;
+            }
+
+            const negated = chars[i] === '-' && i + 1 < chars.length && !isSpace(chars[i + 1]);
+            if (negated) i++;
+
+            let clause;
+            if (chars[i] === '"') {
+                const [phrase, next] = readQuoted(i);
+                i = next;
+                clause = { kind: 'phrase', text: phrase };
+            } else {
+                const start = i;
+                while (i < chars.length && !isSpace(chars[i]) && chars[i] !== '"') i++;
+                const word = chars.slice(start, i).join('');
+                const colon = word.indexOf(':');
+                const key = colon === -1 ? null : word.slice(0, colon).toLowerCase();
+                clause = { kind: 'word', text: word };
+                if (key !== null && filterKeys.includes(key)) {
+                    let value = word.slice(colon + 1);
+                    if (value === '' && chars[i] === '"') {
+                        const [quoted, next] = readQuoted(i);
+                        i = next;
+                        value = quoted;
+                    }
+                    clause = filterClause(key, value) || clause;
+                }
+            }
+
+            if (clause.kind === 'filter' || clause.text.trim().length > 0) {
+                clauses.push({ ...clause, negated });
+            }
+        }
+
+        return { clauses, trailingSpace: query.length > 0 && isSpace(chars[chars.length - 1]) };
+    }
+
+    // Mirrors ParsedQuery::text: the words of positive words and phrases
+    queryText(parsed) {
+        const words = parsed.clauses.filter(c => !c.negated && c.kind !== 'filter').map(c => c.text);
+        const last = parsed.clauses[parsed.clauses.length - 1];
+        const completesLast = !parsed.trailingSpace && last && last.kind === 'word' && !last.negated;
+        let text = words.join(' ');
+        if (!completesLast && text.length > 0) text += ' ';
+        return text;
+    }
+
+    hasConstraints(parsed) {
+        return parsed.clauses.some(c => c.negated || c.kind !== 'word');
+    }
+
+    // Mirrors the Display impl of ParsedQuery
+    formatQuery(parsed) {
+        return parsed.clauses.map(c => {
+            const prefix = c.negated ? '-' : '';
+            if (c.kind === 'word') return prefix + c.text;
+            if (c.kind === 'phrase') return `${prefix}"${c.text}"`;
+            return /\s/u.test(c.value) ? `${prefix}${c.key}:"${c.value}"` : `${prefix}${c.key}:${c.value}`;
+        }).join(' ');
+    }
+
+    // Mirrors syntax::QueryFilter, returning docIdx => whether it is accepted
+    async queryFilter(parsed) {
+        const occurrences = new Map();
+        for (const [i, clause] of parsed.clauses.entries()) {
+            if (clause.kind === 'phrase' || (clause.kind === 'word' && clause.negated)) {
+                occurrences.set(i, await this.phraseDocs(clause.text));
+            }
+        }
 
-        for (const term of terms) {
-            // Title matches (highest weight)
-            if (titleLower.includes(term)) {
-                score += 10;
+        const listed = (map, value, docIdx) => (map[value] || []).includes(docIdx);
+        const filterMatches = (docIdx, key, value) => {
+            const doc = this.manifest.documents[docIdx];
+            switch (key) {
+                case 'tag': return listed(this.manifest.tags, value, docIdx);
+                case 'type': return listed(this.manifest.content_types, value, docIdx);
+                case 'author': return !!doc.author && doc.author.toLowerCase().includes(value);
+                case 'language': return !!doc.language && doc.language.toLowerCase() === value;
+                case 'after': return doc.date !== '' && doc.date >= value;
+                case 'before': return doc.date !== '' && doc.date < value;
             }
+            return false;
+        };
+        const requiresAll = key => key === 'tag' || key === 'after' || key === 'before';
 
-            // Tag matches (high weight)
-            if (tagsLower.some(tag => tag.includes(term))) {
-                score += 5;
+        return docIdx => {
+            const groups = new Map();
+            for (const [i, clause] of parsed.clauses.entries()) {
+                let matches;
+                if (clause.kind === 'word' && !clause.negated) continue;
+                if (clause.kind === 'filter') {
+                    matches = filterMatches(docIdx, clause.key, clause.value);
+                    if (!clause.negated && !requiresAll(clause.key)) {
+                        groups.set(clause.key, groups.get(clause.key) || matches);
+                        continue;
+                    }
+                } else {
+                    matches = occurrences.get(i).has(docIdx);
+                }
+                if (matches === clause.negated) return false;
             }
+            return [...groups.values()].every(Boolean);
+        };
+    }
+
+    async loadPositions(key) {
+        if (!this.positions.has(key)) {
+            this.positions.set(key, this.fetchJson(this.indexUrl(`positions-${key}.json`)));
+        }
+        return this.positions.get(key);
+    }
+
+    // Positions of `term` as docIdx -> Set, mirrors client::decode_positions
+    async termPositions(term) {
+        const shard = await this.shardFor(term);
+        const encoded = shard && shard.terms[term];
+        const byDoc = new Map();
+        if (!encoded) return byDoc;
 
-            // Excerpt matches (medium weight)
-            if (excerptLower.includes(term)) {
-                score += 3;
+        const positions = (await this.loadPositions(this.shardKey(term)))[term] || [];
+        let offset = 0;
+        for (const [docIdx] of this.decodePostings(encoded)) {
+            const count = positions[offset++];
+            const set = new Set();
+            let position = 0;
+            for (let n = 0; n < count; n++) {
+                position += positions[offset++];
+                set.add(position);
             }
+            byDoc.set(docIdx, set);
+        }
+        return byDoc;
+    }
 
-            // Content matches (lower weight)
-            if (contentLower.includes(term)) {
-                score += 1;
+    // Mirrors syntax::phrase_docs: documents with the words of `text` in order
+    async phraseDocs(text) {
+        const stopWords = new Set(this.manifest.stop_words || []);
+        const words = [];
+        for (const token of this.queryTokens(text)) {
+            const positions = new Map();
+            for (const term of await this.resolve(token)) {
+                for (const [docIdx, set] of await this.termPositions(term)) {
+                    if (!positions.has(docIdx)) positions.set(docIdx, new Set());
+                    for (const position of set) positions.get(docIdx).add(position);
+                }
+            }
+            if (positions.size === 0) {
+                if (!stopWords.has(token)) return new Set();
+                words.push(null);
+            } else {
+                words.push(positions);
             }
+        }
 
-            // Exact phrase match (bonus)
-            if (titleLower === term || excerptLower.includes(term)) {
-                score += 2;
+        const anchor = words.findIndex(w => w !== null);
+        const docs = new Set();
+        if (anchor === -1) return docs;
+        for (const [docIdx, positions] of words[anchor]) {
+            const found = [...positions].some(position => {
+                const start = position - anchor;
+                if (start < 0) return false;
+                return words.every((word, offset) =>
+                    word === null || (word.has(docIdx) && word.get(docIdx).has(start + offset)));
+            });
+            if (found) docs.add(docIdx);
+        }
+        return docs;
+    }
+
+    // Mirrors client::shard_key in peta/src/search/client.rs
+    shardKey(term) {
+        const prefix = [...term].slice(0, this.manifest.shard_prefix_length).join('');
+        return Array.from(new TextEncoder().encode(prefix), b => b.toString(16).padStart(2, '0')).join('');
+    }
+
+    async loadShard(key) {
+        if (!this.shards.has(key)) {
+            this.shards.set(key, this.fetchJson(this.indexUrl(`terms-${key}.json`)));
+        }
+        return this.shards.get(key);
+    }
+
+    // Fetch the shards holding `terms`, returning term -> [[doc, tf], ...]
+    async loadPostings(terms) {
+        const available = new Set(this.manifest.shards);
+        const postings = new Map();
+        await Promise.all(terms.map(async term => {
+            const key = this.shardKey(term);
+            if (!available.has(key)) return;
+            const shard = await this.loadShard(key);
+            if (shard.terms[term]) {
+                postings.set(term, this.decodePostings(shard.terms[term]));
             }
+        }));
+        return postings;
+    }
+
+    // Mirrors client::decode_postings
+    decodePostings(encoded) {
+        const fields = this.manifest.config.ranking.boosts.length;
+        const postings = [];
+        let doc = 0;
+        for (let i = 0; i < encoded.length; i += fields + 1) {
+            doc += encoded[i];
+            postings.push([doc, encoded.slice(i + 1, i + 1 + fields)]);
         }
+        return postings;
+    }
+
+    // Fetch the body chunks for `docIdxs`, returning docIdx -> body
+    async loadBodies(docIdxs) {
+        const size = this.manifest.chunk_size;
+        const needed = [...new Set(docIdxs.map(i => Math.floor(i / size)))];
+        await Promise.all(needed.map(n => {
+            if (!this.chunks.has(n)) {
+                this.chunks.set(n, this.fetchJson(this.indexUrl(`docs-${n}.json`)).catch(() => []));
+            }
+            return this.chunks.get(n);
+        }));
 
-        // Recency bonus
-        const date = new Date(doc.date);
-        const daysOld = (Date.now() - date) / (1000 * 60 * 60 * 24);
-        if (daysOld < 30) {
-            score += 2;
-        } else if (daysOld < 365) {
-            score += 1;
+        const bodies = new Map();
+        for (const docIdx of docIdxs) {
+            const chunk = await this.chunks.get(Math.floor(docIdx / size));
+            bodies.set(docIdx, chunk[docIdx % size] || '');
         }
+        return bodies;
+    }
 
-        return score;
+    // Mirrors analyzer::is_cjk in peta/src/search/analyzer.rs
+    isCjk(c) {
+        const cp = c.codePointAt(0);
+        return (cp >= 0x1100 && cp <= 0x11FF)
+            || (cp >= 0x3040 && cp <= 0x30FF)
+            || (cp >= 0x3130 && cp <= 0x318F)
+            || (cp >= 0x31F0 && cp <= 0x31FF)
+            || (cp >= 0x3400 && cp <= 0x4DBF)
+            || (cp >= 0x4E00 && cp <= 0x9FFF)
+            || (cp >= 0xAC00 && cp <= 0xD7AF)
+            || (cp >= 0xF900 && cp <= 0xFAFF)
+            || (cp >= 0xFF66 && cp <= 0xFF9F)
+            || (cp >= 0x20000 && cp <= 0x2FA1F);
     }
 
-    generateHighlights(doc, terms) {
-        const highlights = [];
-        const excerptLower = doc.excerpt.toLowerCase();
+    // Mirrors analyzer::query_tokens: words, CJK runs as overlapping bigrams
+    queryTokens(query) {
+        const { min_term_length, max_term_length } = this.manifest.config;
+        const tokens = [];
+        for (const word of query.toLowerCase().split(/[^\p{Alphabetic}\p{N}]+/u)) {
+            const chars = [...word];
+            let start = 0;
+            while (start < chars.length) {
+                const cjk = this.isCjk(chars[start]);
+                let end = start + 1;
+                while (end < chars.length && this.isCjk(chars[end]) === cjk) end++;
 
-        for (const term of terms) {
-            if (doc.title.toLowerCase().includes(term)) {
-                highlights.push({
-                    type: 'title',
-                    text: doc.title
-                });
-                break;
+                const run = chars.slice(start, end);
+                if (!cjk) {
+                    if (run.length >= min_term_length && run.length <= max_term_length) {
+                        tokens.push(run.join(''));
+                    }
+                } else if (run.length === 1) {
+                    tokens.push(run[0]);
+                } else {
+                    for (let i = 0; i + 1 < run.length; i++) tokens.push(run[i] + run[i + 1]);
+                }
+                start = end;
             }
         }
+        return tokens;
+    }
 
-        for (const term of terms) {
-            const pos = excerptLower.indexOf(term);
-            if (pos !== -1) {
-                const start = Math.max(0, pos - 50);
-                const end = Math.min(doc.excerpt.length, pos + term.length + 50);
-                let snippet = doc.excerpt.substring(start, end);
-                if (start > 0) snippet = '...' + snippet;
-                if (end < doc.excerpt.length) snippet = snippet + '...';
+    // Shard holding `word`, or null when no shard exists for it
+    async shardFor(word) {
+        const key = this.shardKey(word);
+        return this.manifest.shards.includes(key) ? this.loadShard(key) : null;
+    }
 
-                highlights.push({
-                    type: 'excerpt',
-                    text: snippet
-                });
-                break;
+    // Terms a token or surface form resolves to, mirrors fuzzy::resolve
+    async resolve(word) {
+        const shard = await this.shardFor(word);
+        if (shard && shard.forms[word]) return shard.forms[word];
+
+        // Words no document contains are stemmed like the indexer did
+        const terms = [word];
+        if ([...word].some(c => this.isCjk(c))) return terms;
+        for (const stem of await this.loadStemmers()) {
+            const term = stem(word);
+            if (!terms.includes(term) && await this.docFreq(term) > 0) terms.push(term);
+        }
+        return terms;
+    }
+
+    // Snowball stemmers the index was built with, generated from the Rust
+    // ones (see peta/src/search/stemmers); each script returns `word => stem`
+    async loadStemmers() {
+        if (!this.stemmers) {
+            this.stemmers = Promise.all((this.manifest.stemmers || []).map(async name => {
+                const source = await this.fetchText(this.indexUrl(`stemmers/${name}.js`));
+                return new Function(source)();
+            })).catch(() => []);
+        }
+        return this.stemmers;
+    }
+
+    async docFreq(term) {
+        const shard = await this.shardFor(term);
+        const encoded = shard && shard.terms[term];
+        return encoded ? encoded.length / (this.manifest.config.ranking.boosts.length + 1) : 0;
+    }
+
+    // Fetched only when a token needs typo tolerance or a suggestion
+    async loadVocabulary() {
+        if (!this.vocabulary) {
+            this.vocabulary = this.fetchJson(this.indexUrl('vocab.json')).catch(() => []);
+        }
+        return this.vocabulary;
+    }
+
+    // Mirrors fuzzy::max_edits in peta/src/search/fuzzy.rs
+    maxEdits(length) {
+        return length <= 2 ? 0 : length <= 5 ? 1 : 2;
+    }
+
+    // Mirrors fuzzy::levenshtein: distance in code points, or null above `max`
+    levenshtein(a, b, max) {
+        a = [...a];
+        b = [...b];
+        if (Math.abs(a.length - b.length) > max) return null;
+
+        let previous = Array.from({ length: b.length + 1 }, (_, j) => j);
+        let current = new Array(b.length + 1).fill(0);
+        for (let i = 0; i < a.length; i++) {
+            current[0] = i + 1;
+            let rowMin = current[0];
+            for (let j = 0; j < b.length; j++) {
+                const substitution = previous[j] + (a[i] === b[j] ? 0 : 1);
+                current[j + 1] = Math.min(substitution, previous[j + 1] + 1, current[j] + 1);
+                rowMin = Math.min(rowMin, current[j + 1]);
             }
+            if (rowMin > max) return null;
+            [previous, current] = [current, previous];
         }
+        return previous[b.length] <= max ? previous[b.length] : null;
+    }
 
+    // Code point order, which matches Rust's byte-wise string ordering
+    compareStrings(a, b) {
+        const x = [...a];
+        const y = [...b];
+        for (let i = 0; i < Math.min(x.length, y.length); i++) {
+            const diff = x[i].codePointAt(0) - y[i].codePointAt(0);
+            if (diff !== 0) return diff;
+        }
+        return x.length - y.length;
+    }
+
+    // Mirrors fuzzy::expand_query: exact terms, prefix completions of the last
+    // token and typo-tolerant matches, as [{ term, token, weight, exact }]
+    async expandQuery(query) {
+        const { fuzzy_search: fuzzy, max_expansions: maxExpansions, ranking } = this.manifest.config;
+        const tokens = this.queryTokens(query);
+        const completesLast = fuzzy && !/\s$/u.test(query);
+        const expanded = [];
+
+        for (let position = 0; position < tokens.length; position++) {
+            const token = tokens[position];
+            const exact = await this.resolve(token);
+            for (const term of exact) expanded.push({ term, token: position, weight: 1, exact: true });
+            if (!fuzzy) continue;
+
+            // Search-as-you-type completions of the last token
+            if (completesLast && position + 1 === tokens.length) {
+                const prefix = this.shardKey(token);
+                const candidates = new Set();
+                for (const key of this.manifest.shards.filter(k => k.startsWith(prefix))) {
+                    const shard = await this.loadShard(key);
+                    for (const word of [...Object.keys(shard.terms), ...Object.keys(shard.forms)]) {
+                        if (word.startsWith(token) && word !== token) {
+                            for (const term of shard.forms[word] || [word]) candidates.add(term);
+                        }
+                    }
+                }
+                const scored = [];
+                for (const term of candidates) {
+                    if (exact.includes(term)) continue;
+                    const df = await this.docFreq(term);
+                    if (df > 0) scored.push([term, df]);
+                }
+                scored.sort((a, b) => (b[1] - a[1]) || this.compareStrings(a[0], b[0]));
+                for (const [term] of scored.slice(0, maxExpansions)) {
+                    expanded.push({ term, token: position, weight: ranking.prefix_weight, exact: false });
+                }
+            }
+
+            // Typo tolerance for tokens that match nothing
+            let matches = false;
+            for (const term of exact) matches = matches || await this.docFreq(term) > 0;
+            if (!matches) {
+                const scored = await this.fuzzyTerms(token);
+                scored.sort((a, b) => (a[1] - b[1]) || (b[2] - a[2]) || this.compareStrings(a[0], b[0]));
+                for (const [term, distance] of scored.slice(0, maxExpansions)) {
+                    expanded.push({ term, token: position, weight: Math.pow(ranking.fuzzy_weight, distance), exact: false });
+                }
+            }
+        }
+
+        // A term keeps its first, strongest expansion
+        const seen = new Set();
+        return expanded.filter(q => !seen.has(q.term) && seen.add(q.term));
+    }
+
+    // Mirrors fuzzy::fuzzy_terms, as [term, distance, df]
+    async fuzzyTerms(token) {
+        const max = this.maxEdits([...token].length);
+        if (max === 0) return [];
+
+        const best = new Map();
+        for (const word of await this.loadVocabulary()) {
+            const distance = this.levenshtein(token, word, max);
+            if (distance === null || distance === 0) continue;
+            for (const term of await this.resolve(word)) {
+                best.set(term, Math.min(best.has(term) ? best.get(term) : distance, distance));
+            }
+        }
+
+        const scored = [];
+        for (const [term, distance] of best) {
+            const df = await this.docFreq(term);
+            if (df > 0) scored.push([term, distance, df]);
+        }
+        return scored;
+    }
+
+    // Mirrors fuzzy::suggest: a corrected query when some words match
+    // nothing, keeping phrases, exclusions and filters as typed
+    async suggest(query) {
+        if (this.backend === 'server') return this.serverSuggestions.get(query.trim()) ?? null;
+        const parsed = this.parseQuery(query);
+        let changed = false;
+        const clauses = [];
+        for (const clause of parsed.clauses) {
+            if (clause.kind === 'word' && !clause.negated) {
+                const corrected = await this.correct(clause.text);
+                if (corrected !== null) {
+                    changed = true;
+                    clauses.push({ ...clause, text: corrected });
+                    continue;
+                }
+            }
+            clauses.push(clause);
+        }
+        return changed ? this.formatQuery({ clauses, trailingSpace: false }) : null;
+    }
+
+    // Mirrors fuzzy::correct
+    async correct(text) {
+        const corrected = [];
+        let changed = false;
+
+        for (const token of this.queryTokens(text)) {
+            let matches = false;
+            for (const term of await this.resolve(token)) matches = matches || await this.docFreq(term) > 0;
+            if (matches) {
+                corrected.push(token);
+                continue;
+            }
+
+            const max = this.maxEdits([...token].length);
+            let best = null;
+            for (const word of await this.loadVocabulary()) {
+                if (word === token) continue;
+                const distance = this.levenshtein(token, word, max);
+                if (distance === null) continue;
+                let df = 0;
+                for (const term of await this.resolve(word)) df = Math.max(df, await this.docFreq(term));
+                if (df === 0) continue;
+                if (!best || (distance - best[1] || best[2] - df || this.compareStrings(word, best[0])) < 0) {
+                    best = [word, distance, df];
+                }
+            }
+
+            corrected.push(best ? best[0] : token);
+            changed = changed || best !== null;
+        }
+
+        return changed ? corrected.join(' ') : null;
+    }
+
+    // BM25F, mirrors RankingAlgorithm::rank in peta/src/search/ranking.rs.
+    // Returns [docIdx, score, exactly matched tokens]
+    rank(queryTerms, postingsByTerm) {
+        const { k1, b, boosts } = this.manifest.config.ranking;
+        const avgLengths = this.manifest.metadata.avg_field_lengths;
+        const totalDocs = this.manifest.documents.length;
+        const scores = new Map();
+        const exactTokens = new Map();
+
+        for (const { term, token, weight, exact } of queryTerms) {
+            const postings = postingsByTerm.get(term);
+            if (!postings || postings.length === 0) continue;
+            const df = postings.length;
+            const idf = Math.log(1 + (totalDocs - df + 0.5) / (df + 0.5));
+
+            for (const [docIdx, tf] of postings) {
+                const lengths = this.manifest.field_lengths[docIdx];
+                if (!lengths) continue;
+
+                let weighted = 0;
+                for (let field = 0; field < tf.length; field++) {
+                    if (tf[field] === 0) continue;
+                    const norm = avgLengths[field] > 0
+                        ? 1 - b + b * lengths[field] / avgLengths[field]
+                        : 1;
+                    weighted += boosts[field] * tf[field] / norm;
+                }
+                if (weighted > 0) {
+                    scores.set(docIdx, (scores.get(docIdx) || 0) + weight * idf * weighted / (k1 + weighted));
+                    if (exact) {
+                        if (!exactTokens.has(docIdx)) exactTokens.set(docIdx, new Set());
+                        exactTokens.get(docIdx).add(token);
+                    }
+                }
+            }
+        }
+
+        const matched = docIdx => (exactTokens.get(docIdx) || new Set()).size;
+        return [...scores.entries()]
+            .map(([docIdx, score]) => [docIdx, score, matched(docIdx)])
+            .sort((x, y) => (y[2] - x[2]) || (y[1] - x[1]) || (x[0] - y[0]));
+    }
+
+    // Mirrors Highlighter::for_query in peta/src/search/highlight.rs: query
+    // words, the terms they expand to and the surface forms of those terms
+    async highlighter(text, queryTerms) {
+        const terms = new Set(queryTerms.map(q => q.term));
+        const words = new Set([...this.queryTokens(text), ...terms]);
+        for (const key of new Set([...terms].map(term => this.shardKey(term)))) {
+            if (!this.manifest.shards.includes(key)) continue;
+            const shard = await this.loadShard(key);
+            for (const [surface, resolved] of Object.entries(shard.forms)) {
+                if (resolved.some(term => terms.has(term))) words.add(surface);
+            }
+        }
+        return words;
+    }
+
+    // Grapheme cluster boundaries of `text` as UTF-16 offsets, including its
+    // end; code point boundaries where Intl.Segmenter is unavailable
+    graphemeBounds(text) {
+        const bounds = [];
+        if (typeof Intl !== 'undefined' && Intl.Segmenter) {
+            const segmenter = new Intl.Segmenter(undefined, { granularity: 'grapheme' });
+            for (const { index } of segmenter.segment(text)) bounds.push(index);
+        } else {
+            let index = 0;
+            for (const c of text) {
+                bounds.push(index);
+                index += c.length;
+            }
+        }
+        bounds.push(text.length);
+        return bounds;
+    }
+
+    // First index in sorted `values` that is not below `value`
+    lowerBound(values, value) {
+        let low = 0;
+        let high = values.length;
+        while (low < high) {
+            const mid = (low + high) >> 1;
+            if (values[mid] < value) low = mid + 1; else high = mid;
+        }
+        return low;
+    }
+
+    // Mirrors Highlighter::matches: [start, end) ranges of matched words, on
+    // grapheme boundaries, merged
+    highlightMatches(text, words) {
+        const chars = [];
+        let offset = 0;
+        for (const c of text) {
+            chars.push([offset, c]);
+            offset += c.length;
+        }
+        const at = i => (i < chars.length ? chars[i][0] : text.length);
+        const isWordChar = c => /[\p{Alphabetic}\p{N}]/u.test(c);
+        const ranges = [];
+
+        let i = 0;
+        while (i < chars.length) {
+            if (!isWordChar(chars[i][1])) {
+                i++;
+                continue;
+            }
+            const cjk = this.isCjk(chars[i][1]);
+            let end = i + 1;
+            while (end < chars.length && isWordChar(chars[end][1]) && this.isCjk(chars[end][1]) === cjk) end++;
+
+            // CJK runs match by bigram, like the analyzer indexes them
+            const pieces = [];
+            if (cjk && end - i > 1) {
+                for (let k = i; k + 1 < end; k++) pieces.push([k, k + 2]);
+            } else {
+                pieces.push([i, end]);
+            }
+            for (const [start, stop] of pieces) {
+                const range = [at(start), at(stop)];
+                if (words.has(text.slice(range[0], range[1]).toLowerCase())) ranges.push(range);
+            }
+            i = end;
+        }
+
+        const bounds = this.graphemeBounds(text);
+        const merged = [];
+        for (const range of ranges) {
+            const start = bounds[this.lowerBound(bounds, range[0] + 1) - 1];
+            const end = bounds[this.lowerBound(bounds, range[1])];
+            const last = merged[merged.length - 1];
+            if (last && start <= last[1]) last[1] = Math.max(last[1], end);
+            else merged.push([start, end]);
+        }
+        return merged;
+    }
+
+    highlightSpans(text, window, matches) {
+        const spans = [];
+        let position = window[0];
+        for (const [start, end] of matches.filter(m => m[0] >= window[0] && m[1] <= window[1])) {
+            if (start > position) spans.push({ text: text.slice(position, start), matched: false });
+            spans.push({ text: text.slice(start, end), matched: true });
+            position = end;
+        }
+        if (position < window[1]) spans.push({ text: text.slice(position, window[1]), matched: false });
+        return spans;
+    }
+
+    // Mirrors Highlighter::highlight: the whole text with its matches marked
+    highlight(text, words) {
+        return {
+            spans: this.highlightSpans(text, [0, text.length], this.highlightMatches(text, words)),
+            truncated_start: false,
+            truncated_end: false
+        };
+    }
+
+    // Mirrors Highlighter::snippets: context windows around the matches,
+    // overlapping windows merged, trimmed to whole words
+    snippets(text, words, context = 40, maxSnippets = 2) {
+        const matches = this.highlightMatches(text, words);
+        const bounds = this.graphemeBounds(text);
+        const last = bounds.length - 1;
+        const isSpace = c => /\s/u.test(c);
+
+        const windows = [];
+        for (const [start, end] of matches) {
+            const first = this.lowerBound(bounds, start);
+            const after = this.lowerBound(bounds, end);
+            const window = [bounds[Math.max(first - context, 0)], bounds[Math.min(after + context, last)]];
+            const previous = windows[windows.length - 1];
+            if (previous && window[0] <= previous[1]) previous[1] = window[1];
+            else windows.push(window);
+        }
+
+        return windows.slice(0, maxSnippets).map(window => {
+            const inside = matches.filter(m => m[0] >= window[0] && m[1] <= window[1]);
+            let [start, end] = window;
+            const firstMatch = inside.length ? inside[0][0] : end;
+            const lastMatch = inside.length ? inside[inside.length - 1][1] : start;
+
+            // Drop the partial words at the edges, then the outer whitespace
+            const before = [...text.slice(0, start)].pop();
+            if (before !== undefined && !isSpace(before)) {
+                const space = text.slice(start, firstMatch).search(/\s/u);
+                if (space !== -1) start += space;
+            }
+            const following = [...text.slice(end, end + 2)][0];
+            if (following !== undefined && !isSpace(following)) {
+                const tail = [...text.slice(lastMatch, end)];
+                let offset = end - lastMatch;
+                for (let k = tail.length - 1; k >= 0; k--) {
+                    offset -= tail[k].length;
+                    if (isSpace(tail[k])) {
+                        end = lastMatch + offset;
+                        break;
+                    }
+                }
+            }
+            const inner = text.slice(start, end);
+            start += inner.length - inner.trimStart().length;
+            end = Math.max(end - (inner.length - inner.trimEnd().length), start);
+
+            return {
+                spans: this.highlightSpans(text, [start, end], inside),
+                truncated_start: text.slice(0, start).trim().length > 0,
+                truncated_end: text.slice(end).trim().length > 0
+            };
+        });
+    }
+
+    // Mirrors QueryProcessor::generate_highlights in peta/src/search/query.rs
+    generateHighlights(doc, body, words) {
+        const highlights = [];
+        const hasMatch = snippet => snippet.spans.some(span => span.matched);
+
+        const title = this.highlight(doc.title, words);
+        if (hasMatch(title)) highlights.push({ field: 'title', snippet: title });
+        if (doc.section) {
+            const section = this.highlight(doc.section, words);
+            if (hasMatch(section)) highlights.push({ field: 'section', snippet: section });
+        }
+        for (const snippet of this.snippets(body || doc.excerpt, words)) {
+            highlights.push({ field: 'content', snippet });
+        }
         return highlights;
     }
 
+    // Spans escaped, matches wrapped in <mark>, mirrors Snippet::to_html
+    snippetHtml(snippet) {
+        const mark = '<mark style="background: rgba(59, 130, 246, 0.2); color: var(--color-primary-700); padding: 0.125rem 0.25rem; border-radius: 3px; font-weight: 500;">';
+        const spans = snippet.spans
+            .map(span => (span.matched ? `${mark}${this.escapeHtml(span.text)}</mark>` : this.escapeHtml(span.text)))
+            .join('');
+        return `${snippet.truncated_start ? '…' : ''}${spans}${snippet.truncated_end ? '…' : ''}`;
+    }
+
     sortResults(results, sortBy) {
         switch (sortBy) {
             case 'relevance':
-                results.sort((a, b) => b.score - a.score);
+                results.sort((a, b) => (b.matched - a.matched) || (b.score - a.score));
                 break;
             case 'date':
                 results.sort((a, b) => new Date(b.document.date) - new Date(a.document.date));
@@ -202,13 +949,22 @@ class PetaSearch {
         }
     }
 
-    renderResults(results, query) {
+    renderSuggestion(suggestion) {
+        if (!suggestion) return '';
+        return `
+            <p class="search-suggestion" style="color: var(--text-secondary); margin-bottom: 1rem;">
+                Did you mean <a href="?q=${encodeURIComponent(suggestion)}"><strong>${this.escapeHtml(suggestion)}</strong></a>?
+            </p>
+        `;
+    }
+
+    renderResults(results, query, suggestion = null) {
         const resultsDiv = document.getElementById('searchResults');
 
         if (!resultsDiv) return;
 
         if (results.length === 0) {
-            resultsDiv.innerHTML = `
+            resultsDiv.innerHTML = this.renderSuggestion(suggestion) + `
                 <div class="search-no-results" style="color: var(--text-secondary); text-align: center; padding: 3rem;">
                     <svg width="64" height="64" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5" style="margin-bottom: 1rem; opacity: 0.5;">
                         <circle cx="11" cy="11" r="8"/>
@@ -228,7 +984,7 @@ class PetaSearch {
             project: '<svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><polygon points="12 2 2 7 12 12 22 7 12 2"/><polyline points="2 17 12 22 22 17"/><polyline points="2 12 12 17 22 12"/></svg>'
         };
 
-        let html = `
+        let html = this.renderSuggestion(suggestion) + `
             <div class="search-meta" style="color: var(--text-secondary); margin-bottom: 1.5rem; padding: 0.75rem 1rem; background: var(--background-secondary); border-radius: 8px;">
                 Found <strong>${results.length}</strong> result${results.length !== 1 ? 's' : ''} for "<strong>${this.escapeHtml(query)}</strong>"
             </div>
@@ -238,7 +994,11 @@ class PetaSearch {
         for (const result of results) {
             const doc = result.document;
             const icon = typeIcons[doc.content_type] || typeIcons.article;
-            const highlight = result.highlights.find(h => h.type === 'excerpt');
+            const fieldHtml = (field, text) => {
+                const highlight = result.highlights.find(h => h.field === field);
+                return highlight ? this.snippetHtml(highlight.snippet) : this.escapeHtml(text);
+            };
+            const snippets = result.highlights.filter(h => h.field === 'content');
 
             html += `
                 <div class="search-result-item" style="background: var(--background-primary); border: 1px solid var(--border-color); border-radius: 12px; padding: 1.25rem; transition: all 0.2s ease;">
@@ -249,7 +1009,8 @@ class PetaSearch {
                         <div style="flex: 1; min-width: 0;">
                             <h3 style="margin: 0; font-size: 1.125rem; font-weight: 600;">
                                 <a href="${doc.url}" style="color: var(--text-color); text-decoration: none; transition: color 0.2s ease;" onmouseover="this.style.color='var(--color-primary-500)'" onmouseout="this.style.color='var(--text-color)'">
-                                    ${this.highlightText(doc.title, query)}
+                                    ${fieldHtml('title', doc.title)}
+                                    ${doc.section ? `<span class="result-section" style="color: var(--text-secondary); font-weight: 500;">› ${fieldHtml('section', doc.section)}</span>` : ''}
                                 </a>
                             </h3>
                             <div class="result-meta" style="display: flex; align-items: center; gap: 1rem; margin-top: 0.5rem; font-size: 0.875rem; color: var(--text-muted);">
@@ -268,9 +1029,9 @@ class PetaSearch {
                             ${doc.tags.map(tag => `<span class="tag" style="font-size: 0.75rem; padding: 0.25rem 0.75rem; background: var(--background-secondary); border-radius: 9999px; color: var(--text-muted);">${this.escapeHtml(tag)}</span>`).join('')}
                         </div>
                     ` : ''}
-                    ${highlight ? `
+                    ${snippets.length > 0 ? `
                         <p class="result-excerpt" style="margin: 0; color: var(--text-secondary); line-height: 1.6; font-size: 0.9375rem;">
-                            ${this.highlightText(highlight.text, query)}
+                            ${snippets.map(h => this.snippetHtml(h.snippet)).join(' ')}
                         </p>
                     ` : `
                         <p class="result-excerpt" style="margin: 0; color: var(--text-secondary); line-height: 1.6; font-size: 0.9375rem;">
@@ -314,27 +1075,10 @@ class PetaSearch {
         });
     }
 
-    highlightText(text, query) {
-        if (!query || query.length < 2) return this.escapeHtml(text);
-        const terms = this.tokenize(query);
-        let result = this.escapeHtml(text);
-
-        for (const term of terms) {
-            const regex = new RegExp(`(${this.escapeRegex(term)})`, 'gi');
-            result = result.replace(regex, '<mark style="background: rgba(59, 130, 246, 0.2); color: var(--color-primary-700); padding: 0.125rem 0.25rem; border-radius: 3px; font-weight: 500;">$1</mark>');
-        }
-
-        return result;
-    }
-
+    // Mirrors highlight::escape_html, safe for content and attributes
     escapeHtml(text) {
-        const div = document.createElement('div');
-        div.textContent = text;
-        return div.innerHTML;
-    }
-
-    escapeRegex(string) {
-        return string.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+        const entities = { '&': '&amp;', '<': '&lt;', '>': '&gt;', '"': '&quot;', "'": '&#39;' };
+        return String(text).replace(/[&<>"']/g, c => entities[c]);
     }
 
     formatDate(dateStr) {
@@ -390,11 +1134,14 @@ document.addEventListener('DOMContentLoaded', async () => {
             </style>
         `;
 
-        // Perform search
-        const results = petaSearch.search(trimmedQuery);
+        // Perform search; a trailing space ends prefix completion of the last word
+        const results = await petaSearch.search(query);
+
+        // Offer a correction when nothing matched every word as typed
+        const suggestion = results.every(r => !r.exact) ? await petaSearch.suggest(trimmedQuery) : null;
 
         // Render results
-        petaSearch.renderResults(results, trimmedQuery);
+        petaSearch.renderResults(results, trimmedQuery, suggestion);
     };
 
     // Handle URL query parameter
@@ -128,7 +128,8 @@
                                 {left: '$', right: '$', display: false},
                                 {left: '\\[', right: '\\]', display: true},
                                 {left: '\\(', right: '\\)', display: false}
-                            ]
+                            ],
+                            macros: Object.assign({}, window.petaMathMacros || {})
                         });
                         return;
                     }
@@ -156,7 +157,8 @@
                                         {left: '$', right: '$', display: false},
                                         {left: '\\[', right: '\\]', display: true},
                                         {left: '\\(', right: '\\)', display: false}
-                                    ]
+                                    ],
+                                    macros: Object.assign({}, window.petaMathMacros || {})
                                 });
                             };
                             document.body.appendChild(autoRender);
@@ -171,7 +173,8 @@
                                     {left: '$', right: '$', display: false},
                                     {left: '\\[', right: '\\]', display: true},
                                     {left: '\\(', right: '\\)', display: false}
-                                ]
+                                ],
+                                macros: Object.assign({}, window.petaMathMacros || {})
                             });
                         }
                     }